@@ -2,6 +2,7 @@ use std::{path::PathBuf, str::FromStr as _};
 
 use anyhow::anyhow;
 use fj_host::Parameters;
+use fj_interop::units::Units;
 use fj_kernel::algorithms::approx::Tolerance;
 use fj_math::Scalar;
 
@@ -10,8 +11,22 @@ use fj_math::Scalar;
 #[command(version = fj::version::VERSION_FULL.to_string())]
 pub struct Args {
     /// The model to open
+    ///
+    /// Either the directory of a model crate, or a `.json` or `.ron` file that
+    /// contains a serialized shape.
     pub model: Option<PathBuf>,
 
+    /// The name of the model to open, if the crate contains several
+    ///
+    /// Models are named after their function. By default, the first model
+    /// that the crate registers is opened.
+    #[arg(long, value_name = "NAME")]
+    pub model_name: Option<String>,
+
+    /// List the models that the crate contains, instead of opening one
+    #[arg(long)]
+    pub list_models: bool,
+
     /// Export model to this path
     #[arg(short, long, value_name = "PATH")]
     pub export: Option<PathBuf>,
@@ -23,6 +38,29 @@ pub struct Args {
     /// Model deviation tolerance
     #[arg(short, long, value_parser = parse_tolerance)]
     pub tolerance: Option<Tolerance>,
+
+    /// Units to export the model in (`mm`, `cm`, `m`, or `in`)
+    ///
+    /// The model is converted from the units it declares. If this is not
+    /// specified, it is exported in those units. STL files are always
+    /// exported in millimeters, as they don't declare their units. Only `mm`
+    /// is accepted for them. Requires `--export`.
+    #[arg(short, long, requires = "export")]
+    pub units: Option<Units>,
+
+    /// Print a description of the model's parameters, instead of opening it
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub describe: Option<DescribeFormat>,
+}
+
+/// The format of the description printed by `--describe`
+#[derive(Clone, Copy, clap::ValueEnum)]
+pub enum DescribeFormat {
+    /// A JSON Schema of the model's parameters
+    JsonSchema,
+
+    /// Markdown documentation of the model and its parameters
+    Markdown,
 }
 
 impl Args {
@@ -16,18 +16,23 @@ mod args;
 mod config;
 mod path;
 
-use std::{env, error::Error};
+use std::{env, error::Error, path::Path};
 
 use anyhow::{anyhow, Context};
 use fj_export::export;
 use fj_host::Parameters;
+use fj_interop::{mesh::Mesh, units::Units};
+use fj_math::Point;
 use fj_operations::shape_processor::ShapeProcessor;
 use fj_window::run::run;
 use path::ModelPath;
 use tracing_subscriber::fmt::format;
 use tracing_subscriber::EnvFilter;
 
-use crate::{args::Args, config::Config};
+use crate::{
+    args::{Args, DescribeFormat},
+    config::Config,
+};
 
 fn main() -> anyhow::Result<()> {
     // Respect `RUST_LOG`. If that's not defined, log warnings and above. Fail if it's erroneous.
@@ -44,15 +49,60 @@ fn main() -> anyhow::Result<()> {
         tolerance: args.tolerance,
     };
 
-    let model = model_path.map(|m| m.load_model(parameters)).transpose()?;
+    let model = model_path
+        .map(|m| m.load_model(parameters))
+        .transpose()?
+        .map(|model| match args.model_name {
+            Some(name) => model.with_name(name),
+            None => model,
+        });
+
+    if args.list_models {
+        for model in model.with_context(no_model_error)?.list()? {
+            match model.description {
+                Some(description) => {
+                    println!("{}: {description}", model.name);
+                }
+                None => println!("{}", model.name),
+            }
+        }
+
+        return Ok(());
+    }
+
+    if let Some(format) = args.describe {
+        let description = model.with_context(no_model_error)?.describe()?;
+
+        match format {
+            DescribeFormat::JsonSchema => {
+                println!("{:#}", description.json_schema());
+            }
+            DescribeFormat::Markdown => print!("{}", description.markdown()),
+        }
+
+        return Ok(());
+    }
 
     if let Some(export_path) = args.export {
         // export only mode. just load model, process, export and exit
 
+        if is_stl(&export_path)
+            && !matches!(args.units, None | Some(Units::Millimeters))
+        {
+            return Err(anyhow!(
+                "STL files don't declare their units, and are always exported \
+                in millimeters. Export to 3MF to use other units."
+            ));
+        }
+
         let evaluation = model.with_context(no_model_error)?.evaluate()?;
         let shape = shape_processor.process(&evaluation.shape)?;
 
-        export(&shape.mesh, &export_path)?;
+        let units = args.units.unwrap_or(evaluation.units);
+        let mesh =
+            converted(&shape.mesh, evaluation.units.conversion_factor(units));
+
+        export(&mesh, &shape.materials, &export_path, units)?;
 
         return Ok(());
     }
@@ -63,9 +113,31 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+fn is_stl(path: &Path) -> bool {
+    matches!(
+        path.extension(),
+        Some(extension) if extension.eq_ignore_ascii_case("stl")
+    )
+}
+
+/// Convert a mesh into other units, by scaling it with the conversion factor
+fn converted(mesh: &Mesh<Point<3>>, factor: f64) -> Mesh<Point<3>> {
+    if factor == 1. {
+        return mesh.clone();
+    }
+
+    let mut converted = Mesh::new();
+    for triangle in mesh.triangles() {
+        let points = triangle.inner.points().map(|point| point * factor);
+        converted.push_triangle(points, triangle.color, triangle.material);
+    }
+
+    converted
+}
+
 fn no_model_error() -> anyhow::Error {
     anyhow!(
-        "You must specify a model to start Fornjot in export only mode.\n\
+        "You must specify a model to export, describe, or list it.\n\
         - Pass a model as a command-line argument. See `fj-app --help`.\n\
         - Specify a default model in the configuration file."
     )
@@ -14,26 +14,45 @@
 
 #![warn(missing_docs)]
 
+mod threemf;
+
 use std::{fs::File, path::Path};
 
 use thiserror::Error;
 
-use fj_interop::mesh::Mesh;
+use fj_interop::{
+    mesh::{Color, Material, Mesh},
+    units::Units,
+};
 use fj_math::{Point, Triangle};
 
 /// Export the provided mesh to the file at the given path.
 ///
+/// The materials are those that the triangles of the mesh are made of. 3MF
+/// files contain the colors of all triangles, with the names and densities of
+/// their materials. STL files contain neither.
+///
 /// This function will create a file if it does not exist, and will truncate it if it does.
 ///
 /// Currently 3MF & STL file types are supported. The case insensitive file extension of
 /// the provided path is used to switch between supported types.
-pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+///
+/// The coordinates of the mesh are in the provided units. 3MF files declare
+/// these units. STL files don't have a way to do that, and are assumed to be
+/// in millimeters by most software. Meshes are converted to millimeters when
+/// they are exported to STL.
+pub fn export(
+    mesh: &Mesh<Point<3>>,
+    materials: &[Material],
+    path: &Path,
+    units: Units,
+) -> Result<(), Error> {
     match path.extension() {
         Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
-            export_3mf(mesh, path)
+            export_3mf(mesh, materials, path, units)
         }
         Some(extension) if extension.to_ascii_uppercase() == "STL" => {
-            export_stl(mesh, path)
+            export_stl(mesh, path, units)
         }
         Some(extension) => Err(Error::InvalidExtension(
             extension.to_string_lossy().into_owned(),
@@ -42,7 +61,12 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     }
 }
 
-fn export_3mf(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+fn export_3mf(
+    mesh: &Mesh<Point<3>>,
+    materials: &[Material],
+    path: &Path,
+    units: Units,
+) -> Result<(), Error> {
     let vertices = mesh.vertices().map(Into::into).collect();
 
     let indices: Vec<_> = mesh.indices().collect();
@@ -57,24 +81,69 @@ fn export_3mf(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
         })
         .collect();
 
+    // Each named material becomes a material of the 3MF file, and so does the
+    // color of each triangle that isn't made of one. Those are named after the
+    // color.
+    let mut keys = Vec::new();
+    let mut base_materials: Vec<threemf::BaseMaterial> = Vec::new();
+    let triangle_materials = mesh
+        .triangles()
+        .map(|triangle| {
+            let material = triangle.material.and_then(|id| {
+                materials.iter().find(|material| material.id == id)
+            });
+            let key = match material {
+                Some(material) => (Some(material.id), material.color),
+                None => (None, triangle.color),
+            };
+
+            if let Some(i) = keys.iter().position(|k| *k == key) {
+                return i;
+            }
+
+            let (_, Color(color)) = key;
+            let [r, g, b, a] = color;
+            keys.push(key);
+            base_materials.push(threemf::BaseMaterial {
+                name: match material {
+                    Some(material) => material.name.clone(),
+                    None => format!("#{r:02X}{g:02X}{b:02X}{a:02X}"),
+                },
+                color,
+                density: material.and_then(|material| material.density),
+            });
+
+            base_materials.len() - 1
+        })
+        .collect();
+
     let mesh = threemf::TriangleMesh {
         vertices,
         triangles,
+        triangle_materials,
+        materials: base_materials,
     };
 
-    threemf::write(path, &mesh)?;
+    threemf::write(path, &mesh, units)?;
 
     Ok(())
 }
 
-fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+fn export_stl(
+    mesh: &Mesh<Point<3>>,
+    path: &Path,
+    units: Units,
+) -> Result<(), Error> {
     let points = mesh
         .triangles()
         .map(|triangle| triangle.inner.points())
         .collect::<Vec<_>>();
 
+    let scale = units.in_millimeters();
     let vertices = points.iter().map(|points| {
-        points.map(|point| point.coords.components.map(|s| s.into_f32()))
+        points.map(|point| {
+            point.coords.components.map(|s| (s * scale).into_f32())
+        })
     });
 
     let normals = points
@@ -131,7 +200,45 @@ pub enum Error {
     #[error("maximum triangle count exceeded")]
     InvalidTriangleCount,
 
-    /// Threemf error whilst exporting to 3MF file
-    #[error("threemf error whilst exporting to 3MF file")]
-    ThreeMF(#[from] threemf::Error),
+    /// Error writing the ZIP archive of a 3MF file
+    #[error("zip error whilst exporting to 3MF file")]
+    Zip(#[from] zip::result::ZipError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs::File, process};
+
+    use fj_interop::{
+        mesh::{Color, Mesh},
+        units::Units,
+    };
+    use fj_math::Point;
+
+    #[test]
+    fn export_stl_in_millimeters() -> Result<(), super::Error> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 2., 0.]].map(Point::from),
+            Color::default(),
+            None,
+        );
+
+        let path = env::temp_dir()
+            .join(format!("fj-export-test-{}.stl", process::id()));
+        super::export(&mesh, &[], &path, Units::Inches)?;
+
+        let stl = stl::read_stl(&mut File::open(&path)?)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(stl.header.num_triangles, 1);
+        let triangle = &stl.triangles[0];
+        assert_eq!(triangle.normal, [0., 0., 1.]);
+        assert_eq!(
+            [triangle.v1, triangle.v2, triangle.v3],
+            [[0., 0., 0.], [25.4, 0., 0.], [0., 50.8, 0.]]
+        );
+
+        Ok(())
+    }
 }
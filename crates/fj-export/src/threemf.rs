@@ -0,0 +1,405 @@
+//! Writer for 3MF files
+//!
+//! 3MF files are ZIP archives that contain an XML description of the model,
+//! as defined by the [3MF core specification].
+//!
+//! [3MF core specification]: https://github.com/3MFConsortium/spec_core
+
+use std::{fmt::Write as _, fs::File, io::Write as _, path::Path};
+
+use fj_interop::units::Units;
+use zip::{write::FileOptions, ZipWriter};
+
+use crate::Error;
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+  <Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>
+</Types>
+"#;
+
+const RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>
+</Relationships>
+"#;
+
+/// The namespace of the attributes that are specific to Fornjot
+const FORNJOT_NAMESPACE: &str = "https://www.fornjot.app/3mf";
+
+const MATERIALS_ID: u32 = 1;
+const OBJECT_ID: u32 = 2;
+
+/// A triangle mesh, as it is written to a 3MF file
+pub struct TriangleMesh {
+    /// The vertices of the mesh
+    pub vertices: Vec<[f64; 3]>,
+
+    /// The triangles of the mesh, as indices into the vertices
+    pub triangles: Vec<[usize; 3]>,
+
+    /// The materials of the triangles, as indices into the materials
+    pub triangle_materials: Vec<usize>,
+
+    /// The materials that the triangles are made of
+    pub materials: Vec<BaseMaterial>,
+}
+
+/// A material of a 3MF file
+///
+/// Written as part of a base materials group, which assigns a name and a
+/// display color to the triangles that are made of the material.
+pub struct BaseMaterial {
+    /// The name of the material
+    pub name: String,
+
+    /// The display color of the material in RGBA
+    pub color: [u8; 4],
+
+    /// The density of the material in g/cm³, if it is known
+    ///
+    /// 3MF has no attribute for the density of a material. It is written as an
+    /// attribute in the Fornjot namespace, which other consumers ignore.
+    pub density: Option<f64>,
+}
+
+/// Write a mesh to a 3MF file
+pub fn write(
+    path: &Path,
+    mesh: &TriangleMesh,
+    units: Units,
+) -> Result<(), Error> {
+    let file = File::create(path)?;
+    let mut archive = ZipWriter::new(file);
+
+    for (name, content) in [
+        ("[Content_Types].xml", CONTENT_TYPES.to_owned()),
+        ("_rels/.rels", RELATIONSHIPS.to_owned()),
+        ("3D/3dmodel.model", model(mesh, units)),
+    ] {
+        archive.start_file(name, FileOptions::default())?;
+        archive.write_all(content.as_bytes())?;
+    }
+
+    archive.finish()?;
+
+    Ok(())
+}
+
+fn model(mesh: &TriangleMesh, units: Units) -> String {
+    model_inner(mesh, units)
+        .expect("Expected `write!` to `String` to never fail")
+}
+
+fn model_inner(
+    mesh: &TriangleMesh,
+    units: Units,
+) -> Result<String, std::fmt::Error> {
+    let unit = match units {
+        Units::Millimeters => "millimeter",
+        Units::Centimeters => "centimeter",
+        Units::Meters => "meter",
+        Units::Inches => "inch",
+    };
+
+    let mut xml = String::new();
+
+    writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        xml,
+        r#"<model unit="{unit}" xml:lang="en-US" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02" xmlns:fj="{FORNJOT_NAMESPACE}">"#
+    )?;
+    writeln!(xml, "  <resources>")?;
+
+    // A base materials group must contain at least one material. Meshes
+    // without triangles don't have any, and are written without one.
+    if mesh.materials.is_empty() {
+        writeln!(xml, r#"    <object id="{OBJECT_ID}" type="model">"#)?;
+    } else {
+        writeln!(xml, r#"    <basematerials id="{MATERIALS_ID}">"#)?;
+        for material in &mesh.materials {
+            let name = escaped(&material.name);
+            let [r, g, b, a] = material.color;
+            write!(
+                xml,
+                r##"      <base name="{name}" displaycolor="#{r:02X}{g:02X}{b:02X}{a:02X}""##
+            )?;
+            if let Some(density) = material.density {
+                write!(xml, r#" fj:density="{density}""#)?;
+            }
+            writeln!(xml, "/>")?;
+        }
+        writeln!(xml, "    </basematerials>")?;
+
+        writeln!(
+            xml,
+            r#"    <object id="{OBJECT_ID}" type="model" pid="{MATERIALS_ID}" pindex="0">"#
+        )?;
+    }
+
+    writeln!(xml, "      <mesh>")?;
+
+    writeln!(xml, "        <vertices>")?;
+    for [x, y, z] in &mesh.vertices {
+        writeln!(xml, r#"          <vertex x="{x}" y="{y}" z="{z}"/>"#)?;
+    }
+    writeln!(xml, "        </vertices>")?;
+
+    writeln!(xml, "        <triangles>")?;
+    for (i, [v1, v2, v3]) in mesh.triangles.iter().enumerate() {
+        write!(xml, r#"          <triangle v1="{v1}" v2="{v2}" v3="{v3}""#)?;
+        if let Some(p1) = mesh.triangle_materials.get(i) {
+            write!(xml, r#" p1="{p1}""#)?;
+        }
+        writeln!(xml, "/>")?;
+    }
+    writeln!(xml, "        </triangles>")?;
+
+    writeln!(xml, "      </mesh>")?;
+    writeln!(xml, "    </object>")?;
+    writeln!(xml, "  </resources>")?;
+    writeln!(xml, "  <build>")?;
+    writeln!(xml, r#"    <item objectid="{OBJECT_ID}"/>"#)?;
+    writeln!(xml, "  </build>")?;
+    writeln!(xml, "</model>")?;
+
+    Ok(xml)
+}
+
+/// Escape the characters of a string that can't appear in XML attributes
+fn escaped(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fmt::Debug, fs, io::Read, process, str::FromStr};
+
+    use fj_interop::units::Units;
+    use roxmltree::{Document, Node};
+    use zip::ZipArchive;
+
+    use crate::Error;
+
+    use super::{BaseMaterial, TriangleMesh};
+
+    const CORE_NAMESPACE: &str =
+        "http://schemas.microsoft.com/3dmanufacturing/core/2015/02";
+
+    #[test]
+    fn read_back() -> Result<(), Error> {
+        let files = write_and_read("read-back", &mesh(), Units::Inches)?;
+
+        let names: Vec<_> =
+            files.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["[Content_Types].xml", "_rels/.rels", "3D/3dmodel.model"]
+        );
+
+        let content_types = document(&files, "[Content_Types].xml");
+        let extensions: Vec<_> = content_types
+            .descendants()
+            .filter(|node| node.has_tag_name("Default"))
+            .filter_map(|node| node.attribute("Extension"))
+            .collect();
+        assert_eq!(extensions, ["rels", "model"]);
+
+        let relationships = document(&files, "_rels/.rels");
+        let target = relationships
+            .descendants()
+            .find(|node| node.has_tag_name("Relationship"))
+            .and_then(|node| node.attribute("Target"));
+        assert_eq!(target, Some("/3D/3dmodel.model"));
+
+        let model = document(&files, "3D/3dmodel.model");
+        let root = model.root_element();
+        assert!(root.has_tag_name((CORE_NAMESPACE, "model")));
+        assert_eq!(root.attribute("unit"), Some("inch"));
+
+        let vertices: Vec<[f64; 3]> = elements(&model, "vertex")
+            .map(|vertex| ["x", "y", "z"].map(|name| number(vertex, name)))
+            .collect();
+        assert_eq!(vertices, mesh().vertices);
+
+        let triangles: Vec<[usize; 3]> = elements(&model, "triangle")
+            .map(|triangle| {
+                ["v1", "v2", "v3"].map(|name| number(triangle, name))
+            })
+            .collect();
+        assert_eq!(triangles, mesh().triangles);
+
+        let objects: Vec<_> = elements(&model, "object")
+            .filter_map(|object| object.attribute("id"))
+            .collect();
+        let items: Vec<_> = elements(&model, "item")
+            .filter_map(|item| item.attribute("objectid"))
+            .collect();
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects, items);
+
+        Ok(())
+    }
+
+    #[test]
+    fn base_materials() -> Result<(), Error> {
+        let files = write_and_read("materials", &mesh(), Units::Millimeters)?;
+        let model = document(&files, "3D/3dmodel.model");
+
+        let groups: Vec<_> = elements(&model, "basematerials").collect();
+        assert_eq!(groups.len(), 1);
+        let group = groups[0].attribute("id");
+
+        let materials: Vec<_> = elements(&model, "base")
+            .map(|base| {
+                (
+                    base.attribute("name"),
+                    base.attribute("displaycolor"),
+                    base.attribute((super::FORNJOT_NAMESPACE, "density")),
+                )
+            })
+            .collect();
+        assert_eq!(
+            materials,
+            [
+                (
+                    Some("Steel \"S235\" <rolled>"),
+                    Some("#808080FF"),
+                    Some("7.85")
+                ),
+                (Some("#FF00007F"), Some("#FF00007F"), None),
+            ]
+        );
+
+        // The object refers to the group, and so do its triangles, through it.
+        let object =
+            elements(&model, "object").next().expect("Expected object");
+        assert_eq!(object.attribute("pid"), group);
+        assert_eq!(object.attribute("pindex"), Some("0"));
+
+        let triangle_materials: Vec<usize> = elements(&model, "triangle")
+            .map(|triangle| number(triangle, "p1"))
+            .collect();
+        assert_eq!(triangle_materials, mesh().triangle_materials);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_materials() -> Result<(), Error> {
+        let mesh = TriangleMesh {
+            vertices: Vec::new(),
+            triangles: Vec::new(),
+            triangle_materials: Vec::new(),
+            materials: Vec::new(),
+        };
+
+        let files = write_and_read("no-materials", &mesh, Units::Millimeters)?;
+        let model = document(&files, "3D/3dmodel.model");
+
+        assert_eq!(elements(&model, "basematerials").count(), 0);
+        let object =
+            elements(&model, "object").next().expect("Expected object");
+        assert_eq!(object.attribute("pid"), None);
+
+        Ok(())
+    }
+
+    fn mesh() -> TriangleMesh {
+        TriangleMesh {
+            vertices: vec![
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [0., 0.5, 0.],
+                [0., 0., 1.25],
+            ],
+            triangles: vec![[0, 2, 1], [0, 1, 3], [0, 3, 2]],
+            triangle_materials: vec![0, 1, 0],
+            materials: vec![
+                BaseMaterial {
+                    name: String::from("Steel \"S235\" <rolled>"),
+                    color: [128, 128, 128, 255],
+                    density: Some(7.85),
+                },
+                BaseMaterial {
+                    name: String::from("#FF00007F"),
+                    color: [255, 0, 0, 127],
+                    density: None,
+                },
+            ],
+        }
+    }
+
+    /// Write a mesh to a 3MF file, and read back the files in its archive
+    ///
+    /// Returns the name and content of each file, in the order of the archive.
+    fn write_and_read(
+        name: &str,
+        mesh: &TriangleMesh,
+        units: Units,
+    ) -> Result<Vec<(String, String)>, Error> {
+        let path = env::temp_dir()
+            .join(format!("fj-export-test-{}-{name}.3mf", process::id()));
+        super::write(&path, mesh, units)?;
+
+        let mut archive = ZipArchive::new(fs::File::open(&path)?)?;
+        let mut files = Vec::new();
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+
+            files.push((file.name().to_owned(), content));
+        }
+
+        drop(archive);
+        fs::remove_file(&path)?;
+
+        Ok(files)
+    }
+
+    fn document<'f>(files: &'f [(String, String)], name: &str) -> Document<'f> {
+        let (_, content) = files
+            .iter()
+            .find(|(n, _)| n == name)
+            .unwrap_or_else(|| panic!("Expected `{name}` in archive"));
+
+        Document::parse(content).expect("Expected valid XML")
+    }
+
+    /// Iterate over the elements of the 3MF core namespace with the given name
+    fn elements<'a, 'input: 'a>(
+        document: &'a Document<'input>,
+        name: &'a str,
+    ) -> impl Iterator<Item = Node<'a, 'input>> {
+        document
+            .descendants()
+            .filter(move |node| node.has_tag_name((CORE_NAMESPACE, name)))
+    }
+
+    fn number<T>(node: Node, attribute: &str) -> T
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        node.attribute(attribute)
+            .unwrap_or_else(|| panic!("Expected attribute `{attribute}`"))
+            .parse()
+            .expect("Expected number")
+    }
+}
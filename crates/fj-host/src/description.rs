@@ -0,0 +1,262 @@
+use std::fmt::Write as _;
+
+use fj::models::{ArgumentMetadata, Metadata, ModelMetadata};
+use serde_json::{json, Map, Value};
+
+use crate::model::units;
+
+/// The metadata that a model provides about itself
+///
+/// See [`Model::describe`]. A description can be turned into a JSON Schema of
+/// the model's parameters, or into Markdown documentation.
+///
+/// [`Model::describe`]: crate::Model::describe
+pub struct Description {
+    /// Metadata about the model's package
+    pub metadata: Metadata,
+
+    /// Metadata about the model itself, including its arguments
+    pub model: ModelMetadata,
+}
+
+impl Description {
+    /// Generate a JSON Schema of the model's parameters
+    ///
+    /// The schema describes an object with a property for each argument.
+    /// Arguments without a default value are required. Limits that are
+    /// expressions, instead of numbers, can't be checked by the schema, and
+    /// are stored as `x-minimum` and `x-maximum`. The units and the metadata
+    /// of the package are stored as `x-units` and `x-package`.
+    pub fn json_schema(&self) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+
+        for argument in &self.model.arguments {
+            if argument.default_value.is_none() {
+                required.push(argument.name.clone());
+            }
+
+            properties.insert(argument.name.clone(), argument_schema(argument));
+        }
+
+        let mut schema = Map::new();
+        schema.insert(
+            "$schema".into(),
+            "https://json-schema.org/draft/2020-12/schema".into(),
+        );
+        schema.insert("title".into(), self.model.name.clone().into());
+        if let Some(description) = &self.model.description {
+            schema.insert("description".into(), description.clone().into());
+        }
+        schema.insert("type".into(), "object".into());
+        schema.insert("properties".into(), properties.into());
+        schema.insert("required".into(), required.into());
+        schema.insert("additionalProperties".into(), false.into());
+        schema.insert(
+            "x-units".into(),
+            units(self.model.units).to_string().into(),
+        );
+        schema.insert("x-package".into(), package(&self.metadata));
+
+        schema.into()
+    }
+
+    /// Generate Markdown documentation of the model and its parameters
+    pub fn markdown(&self) -> String {
+        let Metadata {
+            name,
+            version,
+            short_description,
+            description,
+            homepage,
+            repository,
+            license,
+        } = &self.metadata;
+
+        // Writing to a `String` can't fail, hence the ignored results.
+        let mut markdown = String::new();
+
+        let _ = writeln!(markdown, "# {}\n", self.model.name);
+        if let Some(description) = &self.model.description {
+            let _ = writeln!(markdown, "{description}\n");
+        }
+        let _ = writeln!(markdown, "Units: `{}`\n", units(self.model.units));
+
+        let _ = writeln!(markdown, "## Parameters\n");
+        if self.model.arguments.is_empty() {
+            let _ = writeln!(markdown, "This model has no parameters.\n");
+        } else {
+            let _ = writeln!(
+                markdown,
+                "| Name | Type | Default | Constraints | Description |\n\
+                | --- | --- | --- | --- | --- |"
+            );
+            for argument in &self.model.arguments {
+                let _ = writeln!(
+                    markdown,
+                    "| `{}` | {} | {} | {} | {} |",
+                    table_cell(&argument.name),
+                    code(argument.type_name.as_deref()),
+                    code(argument.default_value.as_deref()),
+                    table_cell(&constraints(argument)),
+                    table_cell(
+                        argument.description.as_deref().unwrap_or_default()
+                    ),
+                );
+            }
+            markdown.push('\n');
+        }
+
+        let _ = writeln!(markdown, "## Package\n");
+        let _ = writeln!(markdown, "- Name: `{name}`");
+        let _ = writeln!(markdown, "- Version: {version}");
+        for (label, value) in [
+            ("Description", short_description),
+            ("License", license),
+            ("Homepage", homepage),
+            ("Repository", repository),
+        ] {
+            if let Some(value) = value {
+                let _ = writeln!(markdown, "- {label}: {value}");
+            }
+        }
+        if let Some(description) = description {
+            let _ = write!(markdown, "\n{description}\n");
+        }
+
+        markdown
+    }
+}
+
+fn argument_schema(argument: &ArgumentMetadata) -> Value {
+    let ty = json_type(argument);
+
+    let mut schema = Map::new();
+    if let Some(ty) = ty {
+        schema.insert("type".into(), ty.into());
+    }
+    if let Some(description) = &argument.description {
+        schema.insert("description".into(), description.clone().into());
+    }
+    if let Some(default_value) = &argument.default_value {
+        schema.insert("default".into(), json_value(ty, default_value));
+    }
+    if ty != Some("boolean") && !argument.choices.is_empty() {
+        schema.insert("enum".into(), argument.choices.clone().into());
+    }
+    for (keyword, limit) in [
+        ("minimum", &argument.minimum),
+        ("maximum", &argument.maximum),
+    ] {
+        let Some(limit) = limit else {
+            continue;
+        };
+
+        let number = match ty {
+            Some("integer") => limit.parse::<i64>().ok().map(|v| json!(v)),
+            _ => limit.parse::<f64>().ok().map(|v| json!(v)),
+        };
+
+        match number {
+            Some(number) => {
+                schema.insert(keyword.into(), number);
+            }
+            None => {
+                schema.insert(format!("x-{keyword}"), limit.clone().into());
+            }
+        }
+    }
+
+    schema.into()
+}
+
+/// The JSON Schema type of an argument, if it can be determined
+fn json_type(argument: &ArgumentMetadata) -> Option<&'static str> {
+    let ty = match argument.type_name.as_deref()? {
+        "f32" | "f64" => "number",
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16"
+        | "u32" | "u64" | "u128" | "usize" => "integer",
+        "bool" => "boolean",
+        "String" => "string",
+        _ if !argument.choices.is_empty() => "string",
+        _ => return None,
+    };
+
+    Some(ty)
+}
+
+/// Convert a value, as written in the model's code, to JSON
+///
+/// Falls back to a string, if the value can't be parsed as the given type.
+fn json_value(ty: Option<&str>, value: &str) -> Value {
+    let parsed = match ty {
+        Some("number") => value.parse::<f64>().ok().map(|v| json!(v)),
+        Some("integer") => value.parse::<i64>().ok().map(|v| json!(v)),
+        Some("boolean") => value.parse::<bool>().ok().map(|v| json!(v)),
+        _ => None,
+    };
+
+    parsed.unwrap_or_else(|| value.into())
+}
+
+fn package(metadata: &Metadata) -> Value {
+    let Metadata {
+        name,
+        version,
+        short_description,
+        description,
+        homepage,
+        repository,
+        license,
+    } = metadata;
+
+    let mut package = Map::new();
+    package.insert("name".into(), name.clone().into());
+    package.insert("version".into(), version.clone().into());
+    for (key, value) in [
+        ("short_description", short_description),
+        ("description", description),
+        ("homepage", homepage),
+        ("repository", repository),
+        ("license", license),
+    ] {
+        if let Some(value) = value {
+            package.insert(key.into(), value.clone().into());
+        }
+    }
+
+    package.into()
+}
+
+/// Describe the limits and choices of an argument
+fn constraints(argument: &ArgumentMetadata) -> String {
+    let mut constraints = Vec::new();
+
+    if let Some(minimum) = &argument.minimum {
+        constraints.push(format!("at least `{minimum}`"));
+    }
+    if let Some(maximum) = &argument.maximum {
+        constraints.push(format!("at most `{maximum}`"));
+    }
+    if !argument.choices.is_empty() {
+        let choices: Vec<_> = argument
+            .choices
+            .iter()
+            .map(|choice| format!("`{choice}`"))
+            .collect();
+        constraints.push(format!("one of {}", choices.join(", ")));
+    }
+
+    constraints.join("; ")
+}
+
+fn code(value: Option<&str>) -> String {
+    value
+        .map(|value| format!("`{}`", table_cell(value)))
+        .unwrap_or_default()
+}
+
+/// Escape text, so it can be put into a cell of a Markdown table
+fn table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
@@ -1,4 +1,4 @@
-use std::thread;
+use std::{path::PathBuf, thread};
 
 use crossbeam_channel::{Receiver, SendError, Sender};
 
@@ -8,6 +8,7 @@ use crate::{Error, Evaluation, Model};
 pub struct Evaluator {
     trigger_tx: Sender<TriggerEvaluation>,
     event_rx: Receiver<ModelEvent>,
+    files_rx: Receiver<Vec<PathBuf>>,
 }
 
 impl Evaluator {
@@ -15,6 +16,7 @@ impl Evaluator {
     pub fn from_model(model: Model) -> Self {
         let (event_tx, event_rx) = crossbeam_channel::bounded(0);
         let (trigger_tx, trigger_rx) = crossbeam_channel::bounded(0);
+        let (files_tx, files_rx) = crossbeam_channel::unbounded();
 
         thread::spawn(move || {
             while matches!(trigger_rx.recv(), Ok(TriggerEvaluation)) {
@@ -36,8 +38,12 @@ impl Evaluator {
                     }
                 };
 
+                // Only fails, if nobody is receiving the files anymore. That's
+                // not a reason to stop evaluating.
+                let _ = files_tx.send(evaluation.files.clone());
+
                 if let Err(SendError(_)) =
-                    event_tx.send(ModelEvent::Evaluation(evaluation))
+                    event_tx.send(ModelEvent::Evaluation(Box::new(evaluation)))
                 {
                     break;
                 };
@@ -51,6 +57,7 @@ impl Evaluator {
         Self {
             trigger_tx,
             event_rx,
+            files_rx,
         }
     }
 
@@ -63,6 +70,13 @@ impl Evaluator {
     pub fn events(&self) -> Receiver<ModelEvent> {
         self.event_rx.clone()
     }
+
+    /// Access a channel for receiving the files that the model reads
+    ///
+    /// Receives [`Evaluation::files`] after every successful evaluation.
+    pub fn files(&self) -> Receiver<Vec<PathBuf>> {
+        self.files_rx.clone()
+    }
 }
 
 /// Command received by [`Evaluator`] through its channel
@@ -74,7 +88,7 @@ pub enum ModelEvent {
     ChangeDetected,
 
     /// The model has been evaluated
-    Evaluation(Evaluation),
+    Evaluation(Box<Evaluation>),
 
     /// An error
     Error(Error),
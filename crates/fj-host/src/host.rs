@@ -4,6 +4,7 @@ use crate::{Error, Evaluator, Model, ModelEvent, Watcher};
 
 /// A Fornjot model host
 pub struct Host {
+    model: Model,
     evaluator: Evaluator,
     _watcher: Watcher,
 }
@@ -15,15 +16,24 @@ impl Host {
     /// changes. If you don't, just keep using `Model`.
     pub fn from_model(model: Model) -> Result<Self, Error> {
         let watch_path = model.watch_path();
-        let evaluator = Evaluator::from_model(model);
+        let evaluator = Evaluator::from_model(model.clone());
         let watcher = Watcher::watch_model(watch_path, &evaluator)?;
 
         Ok(Self {
+            model,
             evaluator,
             _watcher: watcher,
         })
     }
 
+    /// Access the model that is being hosted
+    ///
+    /// Can be used to host a variation of it, like another model from the
+    /// same crate.
+    pub fn model(&self) -> &Model {
+        &self.model
+    }
+
     /// Access a channel with evaluation events
     pub fn events(&self) -> Receiver<ModelEvent> {
         self.evaluator.events()
@@ -9,12 +9,14 @@
 //!
 //! The purpose of this library is to load Fornjot models and watch them for
 //! changes. Fornjot models are basically plugins that can be loaded into a CAD
-//! application. This library is the host for these model plugins.
+//! application. This library is the host for these model plugins. It can also
+//! load shapes that were serialized into data files by other tools.
 //!
 //! [Fornjot]: https://www.fornjot.app/
 
 #![warn(missing_docs)]
 
+mod description;
 mod evaluator;
 mod host;
 mod model;
@@ -23,9 +25,10 @@ mod platform;
 mod watcher;
 
 pub use self::{
+    description::Description,
     evaluator::{Evaluator, ModelEvent},
     host::Host,
-    model::{Error, Evaluation, Model},
-    parameters::Parameters,
+    model::{DataFormat, Error, Evaluation, Model},
+    parameters::{ParameterError, Parameters},
     watcher::Watcher,
 };
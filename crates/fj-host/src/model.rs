@@ -1,34 +1,54 @@
 use std::{
-    io,
+    f64::consts::TAU,
+    ffi::OsStr,
+    fs, io,
     path::{Path, PathBuf},
     process::Command,
     str,
 };
 
 use fj::{abi, version::Version};
+use fj_interop::units::Units;
 use tracing::{debug, warn};
 
-use crate::{platform::HostPlatform, Parameters};
+use crate::{platform::HostPlatform, Description, ParameterError, Parameters};
 
 /// Represents a Fornjot model
+#[derive(Clone)]
 pub struct Model {
-    src_path: PathBuf,
-    lib_path: PathBuf,
-    manifest_path: PathBuf,
+    source: Source,
     parameters: Parameters,
+    name: Option<String>,
 }
 
 impl Model {
-    /// Initialize the model using the path to its crate
+    /// Initialize the model using the path to its crate or data file
     ///
-    /// The path expected here is the root directory of the model's Cargo
-    /// package, that is the folder containing `Cargo.toml`.
+    /// The path expected here is either the root directory of the model's
+    /// Cargo package, that is the folder containing `Cargo.toml`, or a file
+    /// that contains a serialized [`fj::Shape`]. See [`DataFormat`] for the
+    /// supported file formats.
     pub fn new(
         path: impl AsRef<Path>,
         parameters: Parameters,
     ) -> Result<Self, Error> {
         let path = path.as_ref();
 
+        if let Some(format) = DataFormat::from_path(path) {
+            if !parameters.0.is_empty() {
+                warn!("Data files don't have parameters. Ignoring them.");
+            }
+
+            return Ok(Self {
+                source: Source::Data {
+                    path: path.canonicalize()?,
+                    format,
+                },
+                parameters,
+                name: None,
+            });
+        }
+
         let crate_dir = path.canonicalize()?;
 
         let metadata = cargo_metadata::MetadataCommand::new()
@@ -47,21 +67,204 @@ impl Model {
         };
 
         Ok(Self {
-            src_path,
-            lib_path,
-            manifest_path: pkg.manifest_path.as_std_path().to_path_buf(),
+            source: Source::Crate {
+                src_path,
+                lib_path,
+                manifest_path: pkg.manifest_path.as_std_path().to_path_buf(),
+            },
             parameters,
+            name: None,
         })
     }
 
+    /// Select the model with the given name
+    ///
+    /// A model crate can register several models. They are named after their
+    /// [`fj::models::ModelMetadata::name`], which is the name of the function,
+    /// for models defined using `#[fj::model]`. If no model is selected, the
+    /// first one that the crate registers is used.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        if let Source::Data { .. } = self.source {
+            warn!("Data files contain a single shape. Ignoring model name.");
+        }
+
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
     /// Access the path that needs to be watched for changes
+    ///
+    /// The files that the model reads need to be watched too. They are only
+    /// known after evaluating the model. See [`Evaluation::files`].
     pub fn watch_path(&self) -> PathBuf {
-        self.src_path.clone()
+        match &self.source {
+            Source::Crate { src_path, .. } => src_path.clone(),
+            Source::Data { path, .. } => path.clone(),
+        }
     }
 
     /// Evaluate the model
     pub fn evaluate(&self) -> Result<Evaluation, Error> {
-        let manifest_path = self.manifest_path.display().to_string();
+        match &self.source {
+            Source::Crate {
+                lib_path,
+                manifest_path,
+                ..
+            } => self.evaluate_crate(lib_path, manifest_path),
+            Source::Data { path, format } => {
+                let data = fs::read_to_string(path)?;
+                let mut shape = format.deserialize(&data)?;
+
+                let base = path.parent().unwrap_or_else(|| Path::new(""));
+                let files = shape.resolve_paths(base);
+
+                Ok(Evaluation {
+                    shape,
+                    files,
+                    units: Units::default(),
+                    model_name: None,
+                    model_names: Vec::new(),
+                    compile_time: None,
+                })
+            }
+        }
+    }
+
+    /// Describe the model, without evaluating it
+    ///
+    /// Loads the model and returns the metadata it provides about itself and
+    /// its arguments. Only model crates provide this metadata, not data files.
+    pub fn describe(&self) -> Result<Description, Error> {
+        match &self.source {
+            Source::Crate {
+                lib_path,
+                manifest_path,
+                ..
+            } => {
+                let (description, _) = self.load_crate(
+                    lib_path,
+                    manifest_path,
+                    |metadata, models, _| {
+                        Ok(Description {
+                            metadata,
+                            model: self.select(models)?.metadata(),
+                        })
+                    },
+                )?;
+
+                Ok(description)
+            }
+            Source::Data { path, .. } => {
+                Err(Error::NoMetadata { path: path.clone() })
+            }
+        }
+    }
+
+    /// List the models that the crate registers
+    ///
+    /// Loads the crate and returns the metadata of each of its models, in the
+    /// order in which they were registered.
+    pub fn list(&self) -> Result<Vec<fj::models::ModelMetadata>, Error> {
+        match &self.source {
+            Source::Crate {
+                lib_path,
+                manifest_path,
+                ..
+            } => {
+                let (models, _) = self.load_crate(
+                    lib_path,
+                    manifest_path,
+                    |_, models, _| {
+                        Ok(models
+                            .iter()
+                            .map(|model| model.metadata())
+                            .collect())
+                    },
+                )?;
+
+                Ok(models)
+            }
+            Source::Data { path, .. } => {
+                Err(Error::NoMetadata { path: path.clone() })
+            }
+        }
+    }
+
+    fn evaluate_crate(
+        &self,
+        lib_path: &Path,
+        manifest_path: &Path,
+    ) -> Result<Evaluation, Error> {
+        let ((mut shape, metadata, model_names), compile_time) = self
+            .load_crate(lib_path, manifest_path, |_, models, host| {
+                let model = self.select(models)?;
+
+                let metadata = model.metadata();
+                self.parameters
+                    .validate(&metadata.arguments)
+                    .map_err(Error::InvalidParameters)?;
+
+                let shape = model.shape(host).map_err(Error::Shape)?;
+                let model_names =
+                    models.iter().map(|model| model.metadata().name).collect();
+
+                Ok((shape, metadata, model_names))
+            })?;
+
+        let base = manifest_path.parent().unwrap_or_else(|| Path::new(""));
+        let files = shape.resolve_paths(base);
+
+        Ok(Evaluation {
+            shape,
+            files,
+            units: units(metadata.units),
+            model_name: Some(metadata.name),
+            model_names,
+            compile_time: Some(compile_time),
+        })
+    }
+
+    /// Select the model named by [`Model::with_name`], or the first one
+    fn select<'m>(
+        &self,
+        models: &'m [Box<dyn fj::models::Model>],
+    ) -> Result<&'m dyn fj::models::Model, Error> {
+        let Some(name) = &self.name else {
+            return models
+                .first()
+                .map(AsRef::as_ref)
+                .ok_or(Error::NoModelRegistered);
+        };
+
+        models
+            .iter()
+            .find(|model| &model.metadata().name == name)
+            .map(AsRef::as_ref)
+            .ok_or_else(|| Error::UnknownModel {
+                name: name.clone(),
+                available: models
+                    .iter()
+                    .map(|model| model.metadata().name)
+                    .collect(),
+            })
+    }
+
+    /// Compile and load the model crate, then pass its models to `f`
+    ///
+    /// Returns what `f` returns, and the time it took to compile the crate.
+    fn load_crate<T>(
+        &self,
+        lib_path: &Path,
+        manifest_path: &Path,
+        f: impl FnOnce(
+            fj::models::Metadata,
+            &[Box<dyn fj::models::Model>],
+            &Host,
+        ) -> Result<T, Error>,
+    ) -> Result<(T, String), Error> {
+        let manifest_path = manifest_path.display().to_string();
 
         let cargo_output = Command::new("cargo")
             .arg("rustc")
@@ -101,8 +304,8 @@ impl Model {
         // I don't know of a way to fix this. We should take this as motivation
         // to switch to a better technique:
         // https://github.com/hannobraun/Fornjot/issues/71
-        let shape = unsafe {
-            let lib = libloading::Library::new(&self.lib_path)
+        let output = unsafe {
+            let lib = libloading::Library::new(lib_path)
                 .map_err(Error::LoadingLibrary)?;
 
             let version_pkg_host = fj::version::VERSION_PKG.to_string();
@@ -148,22 +351,192 @@ impl Model {
 
             let mut host = Host::new(&self.parameters);
 
-            match init(&mut abi::Host::from(&mut host)) {
-                abi::ffi_safe::Result::Ok(_metadata) => {}
+            let metadata = match init(&mut abi::Host::from(&mut host)) {
+                abi::ffi_safe::Result::Ok(metadata) => metadata.into(),
                 abi::ffi_safe::Result::Err(e) => {
                     return Err(Error::InitializeModel(e.into()));
                 }
+            };
+
+            let models = host.take_models();
+            if models.is_empty() {
+                return Err(Error::NoModelRegistered);
             }
 
-            let model = host.take_model().ok_or(Error::NoModelRegistered)?;
+            f(metadata, &models, &host)?
+        };
+
+        Ok((output, seconds_taken.into()))
+    }
+}
+
+/// Where a model comes from
+#[derive(Clone)]
+enum Source {
+    /// A model crate, that is compiled into a dynamic library
+    Crate {
+        src_path: PathBuf,
+        lib_path: PathBuf,
+        manifest_path: PathBuf,
+    },
+
+    /// A file that contains a serialized shape
+    Data { path: PathBuf, format: DataFormat },
+}
+
+/// The format of a file that contains a serialized [`fj::Shape`]
+///
+/// The format is determined by the file extension.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DataFormat {
+    /// JSON, with the extension `.json`
+    Json,
+
+    /// RON (Rusty Object Notation), with the extension `.ron`
+    Ron,
+}
+
+impl DataFormat {
+    /// Determine the format of a data file from its extension
+    ///
+    /// Returns `None`, if the path doesn't have an extension of a supported
+    /// format.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(OsStr::to_str) {
+            Some("json") => Some(Self::Json),
+            Some("ron") => Some(Self::Ron),
+            _ => None,
+        }
+    }
 
-            model.shape(&host).map_err(Error::Shape)?
+    fn deserialize(&self, data: &str) -> Result<fj::Shape, Error> {
+        let shape = match self {
+            Self::Json => serde_json::from_str(data)?,
+            Self::Ron => ron::from_str(data)?,
         };
 
-        Ok(Evaluation {
-            shape,
-            compile_time: seconds_taken.into(),
-        })
+        validate(&shape)?;
+
+        Ok(shape)
+    }
+}
+
+/// Check the invariants that the constructors of shapes would have checked
+///
+/// Deserializing a shape bypasses its constructors, so a data file can contain
+/// shapes that couldn't have been created in code.
+fn validate(shape: &fj::Shape) -> Result<(), Error> {
+    match shape {
+        fj::Shape::Chamfer(shape) => validate_sweep(shape.sweep()),
+        fj::Shape::Difference(shape) => {
+            shape.shapes().iter().try_for_each(validate)
+        }
+        fj::Shape::Group(shape) => {
+            validate(&shape.a)?;
+            validate(&shape.b)
+        }
+        fj::Shape::Fillet(shape) => validate_sweep(shape.sweep()),
+        fj::Shape::Hollow(shape) => validate(shape.shape()),
+        fj::Shape::Intersection(shape) => {
+            shape.shapes().iter().try_for_each(validate)
+        }
+        fj::Shape::Loft(shape) => {
+            if shape.profiles().len() < 2 {
+                return Err(invalid_shape(
+                    "Loft requires at least two profiles",
+                ));
+            }
+
+            shape
+                .profiles()
+                .iter()
+                .try_for_each(|profile| validate_sketch(profile.sketch()))
+        }
+        fj::Shape::Pattern(shape) => {
+            if let fj::PatternLayout::Circular { angle, .. } = shape.layout() {
+                validate_angle(*angle)?;
+            }
+
+            validate(shape.shape())
+        }
+        fj::Shape::Revolve(shape) => {
+            validate_angle(shape.angle())?;
+            validate_2d(shape.shape())
+        }
+        fj::Shape::Shape2d(shape) => validate_2d(shape),
+        fj::Shape::Sweep(shape) => validate_sweep(shape),
+        fj::Shape::SweepAlongPath(shape) => validate_2d(shape.shape()),
+        fj::Shape::Transform(shape) => validate(&shape.shape),
+        fj::Shape::Union(shape) => shape.shapes().iter().try_for_each(validate),
+        fj::Shape::Cone(_)
+        | fj::Shape::Cuboid(_)
+        | fj::Shape::Cylinder(_)
+        | fj::Shape::Sphere(_)
+        | fj::Shape::Torus(_) => Ok(()),
+    }
+}
+
+fn validate_2d(shape: &fj::Shape2d) -> Result<(), Error> {
+    match shape {
+        fj::Shape2d::Difference(shape) => {
+            shape.shapes().iter().try_for_each(validate_2d)
+        }
+        fj::Shape2d::Import(shape) => validate_workplane(shape.workplane()),
+        fj::Shape2d::Intersection(shape) => {
+            shape.shapes().iter().try_for_each(validate_2d)
+        }
+        fj::Shape2d::Offset(shape) => validate_2d(shape.shape()),
+        fj::Shape2d::Sketch(shape) => validate_sketch(shape),
+        fj::Shape2d::Text(shape) => validate_workplane(shape.workplane()),
+        fj::Shape2d::Union(shape) => {
+            shape.shapes().iter().try_for_each(validate_2d)
+        }
+    }
+}
+
+fn validate_sketch(sketch: &fj::Sketch) -> Result<(), Error> {
+    validate_workplane(sketch.workplane())
+}
+
+fn validate_sweep(sweep: &fj::Sweep) -> Result<(), Error> {
+    validate_angle(sweep.draft())?;
+    validate_2d(sweep.shape())
+}
+
+fn validate_workplane(workplane: &fj::Workplane) -> Result<(), Error> {
+    match workplane {
+        fj::Workplane::Plane { .. } => Ok(()),
+        fj::Workplane::Face { shape, .. } => validate(shape),
+    }
+}
+
+/// Check that an angle is wrapped, like [`fj::Angle::from_rad`] does
+fn validate_angle(angle: fj::Angle) -> Result<(), Error> {
+    if !(0. ..TAU).contains(&angle.rad()) {
+        return Err(invalid_shape(format!(
+            "Angle of {} radians is not in the range [0, 2π)",
+            angle.rad()
+        )));
+    }
+
+    Ok(())
+}
+
+fn invalid_shape(reason: impl Into<String>) -> Error {
+    Error::InvalidShape {
+        reason: reason.into(),
+    }
+}
+
+/// Convert the units that a model declares
+///
+/// Models that don't declare their units are in millimeters.
+pub(crate) fn units(units: Option<fj::models::Units>) -> Units {
+    match units.unwrap_or_default() {
+        fj::models::Units::Millimeters => Units::Millimeters,
+        fj::models::Units::Centimeters => Units::Centimeters,
+        fj::models::Units::Meters => Units::Meters,
+        fj::models::Units::Inches => Units::Inches,
     }
 }
 
@@ -172,33 +545,59 @@ impl Model {
 /// See [`Model::evaluate`].
 pub struct Evaluation {
     /// The shape
+    ///
+    /// Relative paths of files that the shape reads have been resolved, as
+    /// described in [`fj::Shape::resolve_paths`]. They are relative to the
+    /// directory of the model crate, or of the data file.
     pub shape: fj::Shape,
 
+    /// The files that the shape reads, like fonts or imported drawings
+    pub files: Vec<PathBuf>,
+
+    /// The units that the shape's coordinates are in
+    ///
+    /// Millimeters, if the model doesn't declare its units, or if it was
+    /// loaded from a data file.
+    pub units: Units,
+
+    /// The name of the model that was evaluated
+    ///
+    /// `None`, if the model was loaded from a data file.
+    pub model_name: Option<String>,
+
+    /// The names of all models that the crate registers
+    ///
+    /// Any of them can be selected using [`Model::with_name`]. Empty, if the
+    /// model was loaded from a data file.
+    pub model_names: Vec<String>,
+
     /// The time it took to compile the shape, from the Cargo output
-    pub compile_time: String,
+    ///
+    /// `None`, if the model was loaded from a data file.
+    pub compile_time: Option<String>,
 }
 
 pub struct Host<'a> {
     args: &'a Parameters,
-    model: Option<Box<dyn fj::models::Model>>,
+    models: Vec<Box<dyn fj::models::Model>>,
 }
 
 impl<'a> Host<'a> {
     pub fn new(parameters: &'a Parameters) -> Self {
         Self {
             args: parameters,
-            model: None,
+            models: Vec::new(),
         }
     }
 
-    pub fn take_model(&mut self) -> Option<Box<dyn fj::models::Model>> {
-        self.model.take()
+    pub fn take_models(&mut self) -> Vec<Box<dyn fj::models::Model>> {
+        std::mem::take(&mut self.models)
     }
 }
 
 impl<'a> fj::models::Host for Host<'a> {
     fn register_boxed_model(&mut self, model: Box<dyn fj::models::Model>) {
-        self.model = Some(model);
+        self.models.push(model);
     }
 }
 
@@ -299,6 +698,21 @@ pub enum Error {
     #[error("I/O error while loading model")]
     Io(#[from] io::Error),
 
+    /// Error deserializing a shape from a JSON file
+    #[error("Failed to read shape from JSON file")]
+    Json(#[from] serde_json::Error),
+
+    /// Error deserializing a shape from a RON file
+    #[error("Failed to read shape from RON file")]
+    Ron(#[from] ron::error::SpannedError),
+
+    /// A shape from a data file is invalid
+    #[error("Invalid shape in data file: {reason}")]
+    InvalidShape {
+        /// Why the shape is invalid
+        reason: String,
+    },
+
     /// Initializing a model failed.
     #[error("Unable to initialize the model")]
     InitializeModel(#[source] fj::models::Error),
@@ -308,6 +722,36 @@ pub enum Error {
     #[error("No model was registered")]
     NoModelRegistered,
 
+    /// The crate doesn't register a model with the selected name
+    #[error(
+        "No model named \"{name}\" was registered, expected one of {}",
+        available.iter().map(|name| format!("\"{name}\"")).collect::<Vec<_>>().join(", ")
+    )]
+    UnknownModel {
+        /// The name of the selected model
+        name: String,
+
+        /// The names of the models that were registered
+        available: Vec<String>,
+    },
+
+    /// The user asked for the metadata of a model that doesn't have any.
+    #[error(
+        "\"{}\" is a data file, which doesn't describe its parameters",
+        path.display()
+    )]
+    NoMetadata {
+        /// The path of the data file
+        path: PathBuf,
+    },
+
+    /// The parameters don't match the model's arguments
+    #[error(
+        "Invalid model parameters{}",
+        .0.iter().map(|e| format!("\n- {e}")).collect::<String>()
+    )]
+    InvalidParameters(Vec<ParameterError>),
+
     /// An error was returned from [`fj::models::Model::shape()`].
     #[error("Unable to determine the model's geometry")]
     Shape(#[source] fj::models::Error),
@@ -338,3 +782,169 @@ pub enum Error {
         possible_paths: Vec<PathBuf>,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+        process,
+    };
+
+    use crate::Parameters;
+
+    use super::{DataFormat, Error, Evaluation, Model};
+
+    /// Write `data` to a file named `name` in a new directory, and evaluate it
+    ///
+    /// Returns the evaluation, and the canonical path of the directory.
+    fn evaluate(
+        name: &str,
+        data: &str,
+    ) -> Result<(Result<Evaluation, Error>, PathBuf), Error> {
+        let dir =
+            env::temp_dir().join(format!("fj-host-{}-{name}", process::id()));
+        fs::create_dir_all(&dir)?;
+
+        let path = dir.join(name);
+        fs::write(&path, data)?;
+
+        let evaluation = Model::new(&path, Parameters::empty())
+            .and_then(|model| model.evaluate());
+
+        let dir = dir.canonicalize()?;
+        fs::remove_dir_all(&dir)?;
+
+        Ok((evaluation, dir))
+    }
+
+    fn shape() -> fj::Shape {
+        let sketch =
+            fj::Sketch::from_points(vec![[1., 0.], [2., 0.], [1., 1.]]);
+        let revolve = fj::Revolve::from_axis_and_angle(
+            sketch.into(),
+            [0., 0.],
+            [0., 1.],
+            fj::Angle::from_rad(1.5),
+        );
+
+        let label = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]])
+            .with_workplane(fj::Workplane::from_face(
+                revolve.clone(),
+                fj::NamedFace::Top,
+            ));
+
+        fj::Group {
+            a: revolve.into(),
+            b: label.into(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn data_format_from_path() {
+        assert_eq!(
+            DataFormat::from_path(Path::new("model.json")),
+            Some(DataFormat::Json)
+        );
+        assert_eq!(
+            DataFormat::from_path(Path::new("model.ron")),
+            Some(DataFormat::Ron)
+        );
+        assert_eq!(DataFormat::from_path(Path::new("model.toml")), None);
+        assert_eq!(DataFormat::from_path(Path::new("model")), None);
+    }
+
+    #[test]
+    fn evaluate_json() -> Result<(), Error> {
+        let (evaluation, _) =
+            evaluate("shape.json", &serde_json::to_string(&shape())?)?;
+        let evaluation = evaluation?;
+
+        assert_eq!(evaluation.shape, shape());
+        assert!(evaluation.files.is_empty());
+        assert_eq!(evaluation.model_name, None);
+        assert_eq!(evaluation.compile_time, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_ron() -> Result<(), Error> {
+        let data = ron::to_string(&shape()).expect("Failed to write RON");
+
+        let (evaluation, _) = evaluate("shape.ron", &data)?;
+        assert_eq!(evaluation?.shape, shape());
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_malformed_data() -> Result<(), Error> {
+        let (evaluation, _) = evaluate("malformed.json", "{\"Cuboid\":")?;
+        assert!(matches!(evaluation, Err(Error::Json(_))));
+
+        let (evaluation, _) = evaluate("malformed.ron", "Cuboid(")?;
+        assert!(matches!(evaluation, Err(Error::Ron(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_resolves_paths() -> Result<(), Error> {
+        let text = fj::Text::from_text_size_and_font("A", 1., "fonts/font.ttf");
+        let shape = fj::Shape::from(text);
+
+        let (evaluation, dir) =
+            evaluate("text.json", &serde_json::to_string(&shape)?)?;
+        let evaluation = evaluation?;
+
+        let font = dir.join("fonts/font.ttf");
+        assert_eq!(evaluation.files, [font.clone()]);
+
+        let fj::Shape::Shape2d(fj::Shape2d::Text(text)) = evaluation.shape
+        else {
+            panic!("Expected text");
+        };
+        assert_eq!(text.font(), font.to_str().unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_loft_with_too_few_profiles() -> Result<(), Error> {
+        let profile = |height| {
+            fj::LoftProfile::at_height(
+                fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]),
+                height,
+            )
+        };
+        let loft = fj::Shape::from(fj::Loft::from_profiles(vec![
+            profile(0.),
+            profile(1.),
+        ]));
+
+        let mut data = serde_json::to_value(&loft)?;
+        data["Loft"]["profiles"]
+            .as_array_mut()
+            .expect("Expected profiles")
+            .truncate(1);
+
+        let (evaluation, _) = evaluate("loft.json", &data.to_string())?;
+        assert!(matches!(evaluation, Err(Error::InvalidShape { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_unwrapped_angle() -> Result<(), Error> {
+        let mut data = serde_json::to_value(&shape())?;
+        data["Group"]["b"]["Shape2d"]["Sketch"]["workplane"]["Face"]["shape"]
+            ["Revolve"]["angle"]["rad"] = 10.0.into();
+
+        let (evaluation, _) = evaluate("angle.json", &data.to_string())?;
+        assert!(matches!(evaluation, Err(Error::InvalidShape { .. })));
+
+        Ok(())
+    }
+}
@@ -3,6 +3,9 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
+use fj::models::ArgumentMetadata;
+use tracing::warn;
+
 /// Parameters that are passed to a model.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Parameters(pub HashMap<String, String>);
@@ -23,6 +26,134 @@ impl Parameters {
         self.0.insert(key.into(), value.to_string());
         self
     }
+
+    /// Check the parameters against the metadata of the model's arguments
+    ///
+    /// Returns an error for each parameter that the model doesn't have, that
+    /// can't be parsed as the type of its argument, or that is outside of its
+    /// argument's limits. The errors are sorted by the name of the parameter.
+    ///
+    /// Limits can be numbers, or arithmetic expressions of numbers and of the
+    /// other arguments, like `inner * 1.01`. Arguments that aren't passed as
+    /// parameters have their default values. Limits that the host can't
+    /// evaluate, like constants or function calls, are skipped with a warning.
+    /// The model still checks them when it is evaluated.
+    ///
+    /// Only the types that the host knows about are checked here. The model
+    /// checks everything else when it is evaluated.
+    pub fn validate(
+        &self,
+        arguments: &[ArgumentMetadata],
+    ) -> Result<(), Vec<ParameterError>> {
+        let mut names: Vec<_> = self.0.keys().collect();
+        names.sort();
+
+        let mut errors = Vec::new();
+
+        for name in names {
+            let value = &self.0[name];
+
+            let Some(argument) = arguments.iter().find(|a| &a.name == name)
+            else {
+                errors.push(ParameterError::Unknown {
+                    name: name.clone(),
+                    value: value.clone(),
+                    known: arguments.iter().map(|a| a.name.clone()).collect(),
+                });
+                continue;
+            };
+
+            if let Err(expected) = check_type(argument, value) {
+                errors.push(ParameterError::Invalid {
+                    name: name.clone(),
+                    value: value.clone(),
+                    expected,
+                });
+                continue;
+            }
+
+            // Only numbers have limits.
+            let Ok(number) = value.parse::<f64>() else {
+                continue;
+            };
+
+            if let Some(minimum) = &argument.minimum {
+                match self.evaluate(argument, minimum, arguments, 0) {
+                    Some(min) if number < min => {
+                        errors.push(ParameterError::BelowMinimum {
+                            name: name.clone(),
+                            value: value.clone(),
+                            minimum: minimum.clone(),
+                        });
+                    }
+                    Some(_) => {}
+                    None => warn_unchecked(name, minimum),
+                }
+            }
+            if let Some(maximum) = &argument.maximum {
+                match self.evaluate(argument, maximum, arguments, 0) {
+                    Some(max) if number > max => {
+                        errors.push(ParameterError::AboveMaximum {
+                            name: name.clone(),
+                            value: value.clone(),
+                            maximum: maximum.clone(),
+                        });
+                    }
+                    Some(_) => {}
+                    None => warn_unchecked(name, maximum),
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Evaluate an expression, that belongs to the given argument
+    ///
+    /// Names in the expression refer to the other arguments, and resolve to
+    /// the values of their parameters, or their defaults. In a group of
+    /// arguments, like `hinge.pin_diameter`, they refer to the other arguments
+    /// of the group first.
+    fn evaluate(
+        &self,
+        argument: &ArgumentMetadata,
+        expression: &str,
+        arguments: &[ArgumentMetadata],
+        depth: usize,
+    ) -> Option<f64> {
+        // Defaults can refer to other arguments too. Limiting the depth stops
+        // arguments that refer to each other from recursing forever.
+        if depth > arguments.len() {
+            return None;
+        }
+
+        let resolve = |name: &str| {
+            let group = argument.name.rsplit_once('.').map(|(group, _)| group);
+            let other = group
+                .and_then(|group| {
+                    let name = format!("{group}.{name}");
+                    arguments.iter().find(|a| a.name == name)
+                })
+                .or_else(|| arguments.iter().find(|a| a.name == name))?;
+
+            let value =
+                self.0.get(&other.name).or(other.default_value.as_ref())?;
+            self.evaluate(other, value, arguments, depth + 1)
+        };
+
+        let tokens = tokenize(expression)?;
+        let mut expression = Expression {
+            tokens: &tokens,
+            resolve: &resolve,
+        };
+        let value = expression.sum()?;
+
+        expression.tokens.is_empty().then_some(value)
+    }
 }
 
 impl Deref for Parameters {
@@ -38,3 +169,378 @@ impl DerefMut for Parameters {
         &mut self.0
     }
 }
+
+/// A parameter that doesn't match the metadata of the model's arguments
+///
+/// See [`Parameters::validate`].
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+pub enum ParameterError {
+    /// The model doesn't have an argument with the parameter's name
+    #[error(
+        "Unknown parameter `{name}` (received \"{value}\"), expected one of {}",
+        list(.known)
+    )]
+    Unknown {
+        /// The name of the parameter
+        name: String,
+
+        /// The value that was received
+        value: String,
+
+        /// The names of the model's arguments
+        known: Vec<String>,
+    },
+
+    /// The value can't be parsed as the type of the argument
+    #[error("Parameter `{name}` expects {expected}, but received \"{value}\"")]
+    Invalid {
+        /// The name of the parameter
+        name: String,
+
+        /// The value that was received
+        value: String,
+
+        /// A description of the values that the argument accepts
+        expected: String,
+    },
+
+    /// The value is smaller than the argument's minimum
+    #[error(
+        "Parameter `{name}` must be at least {minimum}, but received {value}"
+    )]
+    BelowMinimum {
+        /// The name of the parameter
+        name: String,
+
+        /// The value that was received
+        value: String,
+
+        /// The minimum of the argument
+        minimum: String,
+    },
+
+    /// The value is larger than the argument's maximum
+    #[error(
+        "Parameter `{name}` must be at most {maximum}, but received {value}"
+    )]
+    AboveMaximum {
+        /// The name of the parameter
+        name: String,
+
+        /// The value that was received
+        value: String,
+
+        /// The maximum of the argument
+        maximum: String,
+    },
+}
+
+fn warn_unchecked(name: &str, limit: &str) {
+    warn!(
+        "Not checking parameter `{name}` against limit `{limit}`, as it isn't \
+        arithmetic of numbers and other parameters"
+    );
+}
+
+/// Check whether a value can be parsed as the type of an argument
+///
+/// Returns a description of the values that the argument accepts, if it
+/// can't. Values of types that the host doesn't know about are accepted.
+fn check_type(argument: &ArgumentMetadata, value: &str) -> Result<(), String> {
+    if !argument.choices.is_empty() {
+        if argument.choices.iter().any(|choice| choice == value) {
+            return Ok(());
+        }
+
+        return Err(format!("one of {}", list(&argument.choices)));
+    }
+
+    let Some(type_name) = argument.type_name.as_deref() else {
+        return Ok(());
+    };
+
+    let parses = match type_name {
+        "f32" => value.parse::<f32>().is_ok(),
+        "f64" => value.parse::<f64>().is_ok(),
+        "i8" => value.parse::<i8>().is_ok(),
+        "i16" => value.parse::<i16>().is_ok(),
+        "i32" => value.parse::<i32>().is_ok(),
+        "i64" => value.parse::<i64>().is_ok(),
+        "i128" => value.parse::<i128>().is_ok(),
+        "isize" => value.parse::<isize>().is_ok(),
+        "u8" => value.parse::<u8>().is_ok(),
+        "u16" => value.parse::<u16>().is_ok(),
+        "u32" => value.parse::<u32>().is_ok(),
+        "u64" => value.parse::<u64>().is_ok(),
+        "u128" => value.parse::<u128>().is_ok(),
+        "usize" => value.parse::<usize>().is_ok(),
+        _ => true,
+    };
+
+    if parses {
+        Ok(())
+    } else {
+        Err(format!("a value of type `{type_name}`"))
+    }
+}
+
+fn list(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token<'a> {
+    Number(f64),
+    Name(&'a str),
+    Operator(char),
+}
+
+fn tokenize(expression: &str) -> Option<Vec<Token<'_>>> {
+    let mut tokens = Vec::new();
+    let mut rest = expression.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        let len = if c.is_ascii_digit() || c == '.' {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.'))
+                .unwrap_or(rest.len());
+            let number =
+                rest[..len].trim_end_matches("f64").trim_end_matches("f32");
+            tokens.push(Token::Number(number.parse().ok()?));
+            len
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Name(&rest[..len]));
+            len
+        } else if "+-*/()".contains(c) {
+            tokens.push(Token::Operator(c));
+            1
+        } else {
+            return None;
+        };
+
+        rest = rest[len..].trim_start();
+    }
+
+    Some(tokens)
+}
+
+/// An arithmetic expression, that is evaluated while it is parsed
+struct Expression<'r, 't> {
+    tokens: &'t [Token<'t>],
+    resolve: &'r dyn Fn(&str) -> Option<f64>,
+}
+
+impl Expression<'_, '_> {
+    fn sum(&mut self) -> Option<f64> {
+        let mut value = self.product()?;
+
+        loop {
+            if self.operator('+') {
+                value += self.product()?;
+            } else if self.operator('-') {
+                value -= self.product()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn product(&mut self) -> Option<f64> {
+        let mut value = self.factor()?;
+
+        loop {
+            if self.operator('*') {
+                value *= self.factor()?;
+            } else if self.operator('/') {
+                value /= self.factor()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Option<f64> {
+        if self.operator('-') {
+            return Some(-self.factor()?);
+        }
+        if self.operator('(') {
+            let value = self.sum()?;
+            return self.operator(')').then_some(value);
+        }
+
+        let (token, rest) = self.tokens.split_first()?;
+        self.tokens = rest;
+
+        match *token {
+            Token::Number(number) => Some(number),
+            Token::Name(name) => (self.resolve)(name),
+            Token::Operator(_) => None,
+        }
+    }
+
+    fn operator(&mut self, operator: char) -> bool {
+        match self.tokens.split_first() {
+            Some((Token::Operator(c), rest)) if *c == operator => {
+                self.tokens = rest;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj::models::ArgumentMetadata;
+
+    use super::{ParameterError, Parameters};
+
+    fn arguments() -> Vec<ArgumentMetadata> {
+        vec![
+            ArgumentMetadata::new("outer")
+                .with_type_name("f64")
+                .with_default_value("1.0")
+                .with_minimum("inner * 1.01"),
+            ArgumentMetadata::new("inner")
+                .with_type_name("f64")
+                .with_default_value("0.5")
+                .with_minimum("0.1")
+                .with_maximum("outer * 0.99"),
+            ArgumentMetadata::new("count")
+                .with_type_name("u32")
+                .with_maximum("(sides - 1) / 2"),
+            ArgumentMetadata::new("sides")
+                .with_type_name("u32")
+                .with_default_value("7"),
+            ArgumentMetadata::new("hinge.pin_diameter")
+                .with_type_name("f64")
+                .with_maximum("pin_length / 2."),
+            ArgumentMetadata::new("hinge.pin_length")
+                .with_type_name("f64")
+                .with_default_value("10.0"),
+            ArgumentMetadata::new("angle")
+                .with_type_name("f64")
+                .with_maximum("std::f64::consts::PI"),
+            ArgumentMetadata::new("standard")
+                .with_type_name("Standard")
+                .with_choices(["Iso", "Ansi"]),
+        ]
+    }
+
+    fn validate(
+        parameters: &[(&str, &str)],
+    ) -> Result<(), Vec<ParameterError>> {
+        let mut p = Parameters::empty();
+        for (name, value) in parameters {
+            p.insert(*name, value);
+        }
+
+        p.validate(&arguments())
+    }
+
+    #[test]
+    fn validate_valid_parameters() {
+        assert_eq!(validate(&[]), Ok(()));
+        assert_eq!(
+            validate(&[
+                ("outer", "2.0"),
+                ("inner", "1.5"),
+                ("count", "3"),
+                ("hinge.pin_diameter", "5"),
+                ("standard", "Ansi"),
+            ]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_unknown_parameter() {
+        let errors = validate(&[("height", "1.0")]).unwrap_err();
+
+        assert!(matches!(
+            &errors[..],
+            [ParameterError::Unknown { name, known, .. }]
+                if name == "height" && known.len() == arguments().len()
+        ));
+    }
+
+    #[test]
+    fn validate_invalid_parameters() {
+        let errors = validate(&[
+            ("count", "-1"),
+            ("inner", "wide"),
+            ("standard", "Din"),
+        ])
+        .unwrap_err();
+
+        let errors: Vec<_> = errors
+            .iter()
+            .map(|error| match error {
+                ParameterError::Invalid {
+                    name,
+                    value,
+                    expected,
+                } => (name.as_str(), value.as_str(), expected.as_str()),
+                error => panic!("Unexpected error: {error}"),
+            })
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                ("count", "-1", "a value of type `u32`"),
+                ("inner", "wide", "a value of type `f64`"),
+                ("standard", "Din", "one of `Iso`, `Ansi`"),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_limits() {
+        assert_eq!(
+            validate(&[("inner", "0.05")]),
+            Err(vec![ParameterError::BelowMinimum {
+                name: "inner".into(),
+                value: "0.05".into(),
+                minimum: "0.1".into(),
+            }])
+        );
+        assert_eq!(
+            validate(&[("outer", "0.5")]),
+            Err(vec![ParameterError::BelowMinimum {
+                name: "outer".into(),
+                value: "0.5".into(),
+                minimum: "inner * 1.01".into(),
+            }])
+        );
+        assert_eq!(
+            validate(&[("count", "4")]),
+            Err(vec![ParameterError::AboveMaximum {
+                name: "count".into(),
+                value: "4".into(),
+                maximum: "(sides - 1) / 2".into(),
+            }])
+        );
+        assert_eq!(
+            validate(&[("hinge.pin_diameter", "6")]),
+            Err(vec![ParameterError::AboveMaximum {
+                name: "hinge.pin_diameter".into(),
+                value: "6".into(),
+                maximum: "pin_length / 2.".into(),
+            }])
+        );
+    }
+
+    #[test]
+    fn validate_limit_that_cant_be_evaluated() {
+        // The model checks limits like this one itself.
+        assert_eq!(validate(&[("angle", "1.0")]), Ok(()));
+        assert_eq!(validate(&[("angle", "4.0")]), Ok(()));
+    }
+}
@@ -1,12 +1,22 @@
-use std::{collections::HashSet, ffi::OsStr, path::Path, thread};
+use std::{
+    collections::HashSet,
+    ffi::OsStr,
+    path::Path,
+    sync::{Arc, Mutex},
+    thread,
+};
 
 use notify::Watcher as _;
+use tracing::warn;
 
 use crate::{evaluator::TriggerEvaluation, Error, Evaluator};
 
 /// Watches a model for changes, reloading it continually
+///
+/// Besides the model itself, the files that the model reads are watched, once
+/// the evaluator reports them.
 pub struct Watcher {
-    _watcher: Box<dyn notify::Watcher>,
+    _watcher: Arc<Mutex<notify::RecommendedWatcher>>,
 }
 
 impl Watcher {
@@ -19,6 +29,7 @@ impl Watcher {
 
         let watch_tx = evaluator.trigger();
         let watch_tx_2 = evaluator.trigger();
+        let files_rx = evaluator.files();
 
         let mut watcher = notify::recommended_watcher(
             move |event: notify::Result<notify::Event>| {
@@ -83,8 +94,45 @@ impl Watcher {
                 .expect("Channel is disconnected");
         });
 
-        Ok(Self {
-            _watcher: Box::new(watcher),
-        })
+        let watcher = Arc::new(Mutex::new(watcher));
+
+        // Watch the files that the model reads, as they become known. Only a
+        // weak reference to the watcher is kept here, so dropping `Watcher`
+        // stops watching. The thread ends, once the evaluator is dropped.
+        let weak_watcher = Arc::downgrade(&watcher);
+        thread::spawn(move || {
+            let mut watched = HashSet::new();
+
+            for files in files_rx {
+                let Some(watcher) = weak_watcher.upgrade() else {
+                    break;
+                };
+
+                for path in files {
+                    if watched.contains(&path) {
+                        continue;
+                    }
+
+                    let result = watcher
+                        .lock()
+                        .expect("Watcher lock is poisoned")
+                        .watch(&path, notify::RecursiveMode::NonRecursive);
+
+                    // The file might not exist. Then computing the shape fails
+                    // anyway, and watching it is tried again after the next
+                    // evaluation.
+                    match result {
+                        Ok(()) => {
+                            watched.insert(path);
+                        }
+                        Err(err) => {
+                            warn!("Can't watch {}: {err}", path.display());
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Self { _watcher: watcher })
     }
 }
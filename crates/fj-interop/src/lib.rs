@@ -18,3 +18,4 @@ pub mod debug;
 pub mod ext;
 pub mod mesh;
 pub mod processed_shape;
+pub mod units;
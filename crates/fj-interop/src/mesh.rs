@@ -77,6 +77,7 @@ impl Mesh<Point<3>> {
         &mut self,
         triangle: impl Into<fj_math::Triangle<3>>,
         color: Color,
+        material: Option<MaterialId>,
     ) {
         let triangle = triangle.into();
 
@@ -87,6 +88,7 @@ impl Mesh<Point<3>> {
         self.triangles.push(Triangle {
             inner: triangle,
             color,
+            material,
         });
     }
 }
@@ -109,7 +111,8 @@ pub type Index = u32;
 
 /// A triangle
 ///
-/// Extension of [`fj_math::Triangle`] that also includes a color.
+/// Extension of [`fj_math::Triangle`] that also includes a color, and the
+/// material it is made of.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Triangle {
     /// The points of the triangle
@@ -117,6 +120,75 @@ pub struct Triangle {
 
     /// The color of the triangle
     pub color: Color,
+
+    /// The material of the triangle, if it has one
+    pub material: Option<MaterialId>,
+}
+
+/// A named material
+///
+/// Triangles are made of a material, if they refer to its ID.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Material {
+    /// The ID of the material
+    pub id: MaterialId,
+
+    /// The name of the material
+    pub name: String,
+
+    /// The color of the material
+    pub color: Color,
+
+    /// The density of the material in g/cm³, if it is known
+    pub density: Option<f64>,
+}
+
+/// The ID of a [`Material`]
+///
+/// Materials with the same ID are the same material. Unlike their colors, IDs
+/// are never shared by different materials.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct MaterialId(pub u64);
+
+impl MaterialId {
+    /// Compute the ID of the material with the given properties
+    ///
+    /// Materials are identified by their name, color, and density, so any two
+    /// materials that are equal have the same ID. The ID is a hash of those,
+    /// which doesn't depend on the platform or the version of Rust, so it is
+    /// the same in every build of Fornjot.
+    pub fn from_properties(
+        name: &str,
+        color: [u8; 4],
+        density: Option<f64>,
+    ) -> Self {
+        let mut bytes = Vec::new();
+
+        // The name is prefixed with its length, so it can't run into the
+        // color.
+        bytes.extend((name.len() as u64).to_le_bytes());
+        bytes.extend(name.as_bytes());
+        bytes.extend(color);
+        match density {
+            Some(density) => {
+                bytes.push(1);
+                bytes.extend(density.to_bits().to_le_bytes());
+            }
+            None => bytes.push(0),
+        }
+
+        Self(fnv1a(&bytes))
+    }
+}
+
+/// Compute the 64-bit FNV-1a hash of some bytes
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
 }
 
 /// RGBA color
@@ -129,3 +201,29 @@ impl Default for Color {
         Self([255, 0, 0, 255])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MaterialId;
+
+    #[test]
+    fn material_id() {
+        let steel = |color, density| {
+            MaterialId::from_properties("Steel", color, density)
+        };
+        let gray = [128, 128, 128, 255];
+
+        // IDs don't depend on the build, so they can be checked exactly.
+        assert_eq!(steel(gray, None), MaterialId(0xfff982b31107222a));
+        assert_eq!(steel(gray, Some(7.85)), MaterialId(0xee8719dc54e5e5da));
+
+        let other = [
+            steel([128, 128, 128, 254], None),
+            MaterialId::from_properties("Steel2", gray, None),
+            steel(gray, Some(7.8)),
+        ];
+        for id in other {
+            assert_ne!(id, steel(gray, None));
+        }
+    }
+}
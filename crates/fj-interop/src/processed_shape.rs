@@ -2,7 +2,10 @@
 
 use fj_math::{Aabb, Point};
 
-use crate::{debug::DebugInfo, mesh::Mesh};
+use crate::{
+    debug::DebugInfo,
+    mesh::{Material, Mesh},
+};
 
 /// A processed shape
 #[derive(Clone, Debug)]
@@ -13,6 +16,9 @@ pub struct ProcessedShape {
     /// The triangle mesh that approximates the original shape
     pub mesh: Mesh<Point<3>>,
 
+    /// The materials that the triangles of the mesh are made of
+    pub materials: Vec<Material>,
+
     /// The debug info generated while processing the shape
     pub debug_info: DebugInfo,
 }
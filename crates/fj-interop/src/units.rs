@@ -0,0 +1,112 @@
+//! Units of length
+
+use std::{fmt, str::FromStr};
+
+/// The units that the coordinates of a shape or mesh are in
+///
+/// Shapes that don't come with units are assumed to be in millimeters.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Units {
+    /// Millimeters, written as `mm`
+    #[default]
+    Millimeters,
+
+    /// Centimeters, written as `cm`
+    Centimeters,
+
+    /// Meters, written as `m`
+    Meters,
+
+    /// Inches, written as `in` or `inch`
+    Inches,
+}
+
+impl Units {
+    /// The length of one unit in millimeters
+    pub fn in_millimeters(&self) -> f64 {
+        match self {
+            Self::Millimeters => 1.,
+            Self::Centimeters => 10.,
+            Self::Meters => 1000.,
+            Self::Inches => 25.4,
+        }
+    }
+
+    /// The factor that converts lengths in these units into the other units
+    pub fn conversion_factor(&self, other: Units) -> f64 {
+        self.in_millimeters() / other.in_millimeters()
+    }
+}
+
+impl fmt::Display for Units {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = match self {
+            Self::Millimeters => "mm",
+            Self::Centimeters => "cm",
+            Self::Meters => "m",
+            Self::Inches => "in",
+        };
+
+        write!(f, "{units}")
+    }
+}
+
+impl FromStr for Units {
+    type Err = UnknownUnits;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mm" => Ok(Self::Millimeters),
+            "cm" => Ok(Self::Centimeters),
+            "m" => Ok(Self::Meters),
+            "in" | "inch" => Ok(Self::Inches),
+            _ => Err(UnknownUnits(s.into())),
+        }
+    }
+}
+
+/// The error returned when parsing [`Units`] from an unknown name
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UnknownUnits(pub String);
+
+impl fmt::Display for UnknownUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unknown units `{}`; expected one of `mm`, `cm`, `m`, `in`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnknownUnits {}
+
+#[cfg(test)]
+mod tests {
+    use super::Units;
+
+    #[test]
+    fn parse_and_display() {
+        for units in [
+            Units::Millimeters,
+            Units::Centimeters,
+            Units::Meters,
+            Units::Inches,
+        ] {
+            assert_eq!(units.to_string().parse(), Ok(units));
+        }
+
+        assert_eq!("inch".parse(), Ok(Units::Inches));
+        assert!("ft".parse::<Units>().is_err());
+    }
+
+    #[test]
+    fn conversion_factor() {
+        assert_eq!(Units::Inches.conversion_factor(Units::Millimeters), 25.4);
+        assert_eq!(
+            Units::Millimeters.conversion_factor(Units::Centimeters),
+            0.1
+        );
+        assert_eq!(Units::Meters.conversion_factor(Units::Meters), 1.);
+    }
+}
@@ -30,15 +30,32 @@ impl Approx for (&Handle<Curve>, RangeOnPath) {
     ) -> Self::Approximation {
         let (curve, range) = self;
 
-        let global_curve = curve.global_form().clone();
-        let global_curve_approx = match cache.get(global_curve.clone(), range) {
-            Some(approx) => approx,
-            None => {
-                let approx = approx_global_curve(curve, range, tolerance);
-                cache.insert(global_curve, range, approx)
-            }
+        // Global curves can be shared between faces, which approximate them in
+        // opposite directions. Normalize the range, so they can share the same
+        // approximation. Computing the approximation separately could lead to
+        // slightly different results, depending on the surface the curve is
+        // defined on.
+        let [a, b] = range.boundary;
+        let (range, is_reversed) = if a <= b {
+            (range, false)
+        } else {
+            (RangeOnPath::from([b, a]), true)
         };
 
+        let global_curve = curve.global_form().clone();
+        let mut global_curve_approx =
+            match cache.get(global_curve.clone(), range) {
+                Some(approx) => approx,
+                None => {
+                    let approx = approx_global_curve(curve, range, tolerance);
+                    cache.insert(global_curve, range, approx)
+                }
+            };
+
+        if is_reversed {
+            global_curve_approx.points.reverse();
+        }
+
         CurveApprox::empty().with_points(
             global_curve_approx.points.into_iter().map(|point| {
                 let point_surface =
@@ -4,7 +4,7 @@
 
 use std::collections::BTreeSet;
 
-use fj_interop::mesh::Color;
+use fj_interop::mesh::{Color, MaterialId};
 
 use crate::{
     objects::{Face, FaceSet, Handedness},
@@ -104,6 +104,7 @@ impl Approx for &Face {
             exterior,
             interiors,
             color: self.color(),
+            material: self.material(),
             coord_handedness: self.coord_handedness(),
         }
     }
@@ -121,6 +122,9 @@ pub struct FaceApprox {
     /// The color of the approximated face
     pub color: Color,
 
+    /// The material of the approximated face, if it has one
+    pub material: Option<MaterialId>,
+
     /// The handedness of the approximated face's front-side coordinate system
     pub coord_handedness: Handedness,
 }
@@ -115,6 +115,10 @@ fn approx_circle<const D: usize>(
     points
 }
 
+/// The fraction of an increment, within which points are too close to a
+/// boundary of the approximated range
+const BOUNDARY_MARGIN: f64 = 1e-6;
+
 struct PathApproxParams {
     increment: Scalar,
 }
@@ -124,7 +128,7 @@ impl PathApproxParams {
         circle: &Circle<D>,
         tolerance: impl Into<Tolerance>,
     ) -> Self {
-        let radius = circle.a().magnitude();
+        let radius = circle.max_radius();
 
         let num_vertices_to_approx_full_circle = Scalar::max(
             Scalar::PI
@@ -154,8 +158,12 @@ impl PathApproxParams {
 
         // We can't generate a point exactly at the boundaries of the range as
         // part of the approximation. Make sure we stay inside the range.
-        let min = min.floor() + 1.;
-        let max = max.ceil() - 1.;
+        //
+        // Boundaries that are meant to be on an increment can end up slightly
+        // beyond it, due to floating point accuracy. The range is shrunk a bit,
+        // so we don't generate a point that is almost at the boundary.
+        let min = (min + BOUNDARY_MARGIN).floor() + 1.;
+        let max = (max - BOUNDARY_MARGIN).ceil() - 1.;
 
         let [start, end] = match direction {
             Sign::Negative => [max, min],
@@ -229,6 +237,9 @@ mod tests {
         test_path([[2.], [TAU]], [2., 3.]);
         test_path([[0.], [TAU - 2.]], [1., 2.]);
 
+        // Boundaries that are just beyond an increment don't get a point there.
+        test_path([[-1e-12], [TAU + 1e-12]], [1., 2., 3.]);
+
         // And everything again, but in reverse.
         test_path([[TAU], [0.]], [3., 2., 1.]);
         test_path([[TAU], [1.]], [3., 2., 1.]);
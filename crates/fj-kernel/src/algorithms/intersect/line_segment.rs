@@ -1,4 +1,4 @@
-use fj_math::{Aabb, Line, Point, Scalar, Segment, Vector};
+use fj_math::{Line, Point, Scalar, Segment};
 
 /// An intersection between a [`Line`] and a [`Segment`]
 #[derive(Debug, Eq, PartialEq)]
@@ -23,20 +23,17 @@ impl LineSegmentIntersection {
         // Ericson. See section 5.1.9.1, 2D Segment Intersection.
 
         let [a, b] = segment.points();
+        let ab = b - a;
 
-        // Find vector that is orthogonal to `segment`.
-        let n = {
-            let ab = b - a;
-            Vector::from([ab.v, ab.u])
-        };
+        // The line is defined as `origin + t * direction`, the segment as
+        // `a + s * ab`, with `s` in the range `[0, 1]`. We're looking for the
+        // `t` and `s` where those are equal.
+        let ab_cross_direction = ab.cross2d(&line.direction());
 
-        let n_dot_origin = n.dot(&(b - line.origin()));
-        let n_dot_direction = n.dot(&line.direction());
-
-        if n_dot_direction == Scalar::ZERO {
+        if ab_cross_direction == Scalar::ZERO {
             // `line` and `segment` are parallel
 
-            if n_dot_origin == Scalar::ZERO {
+            if (a - line.origin()).cross2d(&line.direction()) == Scalar::ZERO {
                 // `line` and `segment` are not just parallel, but coincident!
                 return Some(Self::Coincident {
                     points_on_line: segment
@@ -50,11 +47,17 @@ impl LineSegmentIntersection {
 
         // Now we ruled out the special cases. Compute where `line` hits the
         // line defined by `segment`'s points.
-        let t = n_dot_origin / n_dot_direction;
-
-        let point_is_on_segment = Aabb::<2>::from_points(segment.points())
-            .contains(line.point_from_line_coords([t]));
-        if !point_is_on_segment {
+        //
+        // Checking the segment coordinate `s` instead of whether the
+        // intersection point is within the segment's bounding box makes sure
+        // that segments which are aligned with one of the axes don't miss hits,
+        // due to floating point inaccuracies in the other axis.
+        let t =
+            (a - line.origin()).cross2d(&ab) / line.direction().cross2d(&ab);
+        let s =
+            (line.origin() - a).cross2d(&line.direction()) / ab_cross_direction;
+
+        if s < Scalar::ZERO || s > Scalar::ONE {
             return None;
         }
 
@@ -86,6 +89,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compute_one_hit_diagonal() {
+        let line =
+            Line::from_origin_and_direction(Point::origin(), Vector::unit_u());
+
+        assert_eq!(
+            LineSegmentIntersection::compute(
+                &line,
+                &Segment::from_points([[0., -1.], [2., 1.]]),
+            ),
+            Some(LineSegmentIntersection::Point {
+                point_on_line: Point::from([Scalar::ONE])
+            }),
+        );
+    }
+
     #[test]
     fn compute_coincident() {
         let line =
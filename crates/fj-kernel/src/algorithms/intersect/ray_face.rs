@@ -93,9 +93,9 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Handle<Face>) {
             // solve those equations for `u` and `v` independently.
             //
             // By doing some math, we get the following solutions:
-            let v = (d1y * (orz - opz) + (opy - ory) * d1z)
-                / (d1y * d2z - d2y * d1z);
-            let u = (ory - opy - d2y * v) / d1y;
+            let det = d1y * d2z - d2y * d1z;
+            let v = (d1y * (orz - opz) + (opy - ory) * d1z) / det;
+            let u = ((ory - opy) * d2z - d2y * (orz - opz)) / det;
             let t = opx - orx + d1x * u + d2x * v;
 
             (t, u, v)
@@ -142,7 +142,7 @@ pub enum RayFaceIntersection {
 
 #[cfg(test)]
 mod tests {
-    use fj_math::Point;
+    use fj_math::{Line, Point, Vector};
 
     use crate::{
         algorithms::{
@@ -153,8 +153,10 @@ mod tests {
             transform::TransformObject,
         },
         builder::FaceBuilder,
+        geometry::{path::GlobalPath, surface::SurfaceGeometry},
         insert::Insert,
         iter::ObjectIters,
+        objects::Surface,
         partial::{Partial, PartialFace, PartialObject},
         services::Services,
     };
@@ -208,6 +210,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ray_hits_face_with_surface_axis_in_xz_plane() {
+        let mut services = Services::new();
+
+        let ray = HorizontalRayToTheRight::from([0., 0., 0.]);
+
+        // The u-axis of the surface has no y-component.
+        let surface = Surface::new(SurfaceGeometry {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                Point::from([1., 0., -1.]),
+                Vector::from([1., 0., 1.]),
+            )),
+            v: Vector::from([0., 1., 0.]),
+        })
+        .insert(&mut services.objects);
+
+        let mut face = PartialFace::default();
+        face.exterior.write().surface = Partial::from(surface);
+        face.update_exterior_as_polygon([
+            [0., -1.],
+            [2., -1.],
+            [2., 1.],
+            [0., 1.],
+        ]);
+        let face = face
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        assert_eq!(
+            (&ray, &face).intersect(),
+            Some(RayFaceIntersection::RayHitsFace)
+        );
+    }
+
     #[test]
     fn ray_hits_surface_but_misses_face() {
         let mut services = Services::new();
@@ -0,0 +1,828 @@
+//! Lofting through multiple profiles
+//!
+//! See [`Loft`].
+
+use std::collections::BTreeMap;
+
+use fj_interop::{
+    ext::ArrayExt,
+    mesh::{Color, MaterialId},
+};
+use fj_math::{Line, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::approx::{path::RangeOnPath, Approx, Tolerance},
+    geometry::{
+        path::{GlobalPath, SurfacePath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Curve, Cycle, Face, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge,
+        Objects, Shell, Sketch, Solid, Surface, SurfaceVertex, Vertex,
+    },
+    services::Service,
+    storage::{Handle, ObjectId},
+    validate::ValidationConfig,
+};
+
+/// Loft through a sequence of profiles
+///
+/// The exterior of each profile is connected to the exterior of the next one,
+/// by ruled side faces. The first and the last profile become the caps of the
+/// lofted shape.
+///
+/// Curved edges of the profiles are approximated by straight lines, within the
+/// given tolerance. If the profiles have a different number of vertices,
+/// vertices are inserted into the longest edges of the profiles that have fewer
+/// of them, until all numbers match.
+pub trait Loft: Sized {
+    /// The object that is created by lofting through the profiles
+    type Lofted;
+
+    /// Loft through the profiles
+    ///
+    /// Returns an error, if there are fewer than two profiles, or they don't
+    /// have the same number of holes. Sketches that are used as profiles must
+    /// have exactly one face.
+    fn loft(
+        self,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Lofted, LoftError> {
+        let mut cache = LoftCache::default();
+        self.loft_with_cache(tolerance, &mut cache, objects)
+    }
+
+    /// Loft through the profiles, using the provided cache
+    fn loft_with_cache(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut LoftCache,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Lofted, LoftError>;
+}
+
+/// An error that can occur while lofting
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum LoftError {
+    /// Fewer than two profiles were provided
+    #[error("Loft requires at least two profiles")]
+    TooFewProfiles,
+
+    /// A sketch that is used as a profile has no faces
+    #[error("Can't loft through empty sketch")]
+    EmptySketch,
+
+    /// A sketch that is used as a profile has more than one face
+    #[error("Can't loft through sketch with more than one face")]
+    MultipleFaces,
+
+    /// The profiles have different numbers of holes
+    #[error("Profiles of loft must have the same number of holes")]
+    HolesDiffer,
+}
+
+/// A cache used for lofting
+///
+/// See [`Loft`].
+#[derive(Default)]
+pub struct LoftCache {
+    /// Cache for global edges, keyed by the global vertices they connect
+    pub global_edge: BTreeMap<[ObjectId; 2], Handle<GlobalEdge>>,
+}
+
+impl LoftCache {
+    /// Access the global edge between two global vertices
+    ///
+    /// Creates the global edge, if it doesn't exist yet.
+    fn global_edge(
+        &mut self,
+        vertices: [Handle<GlobalVertex>; 2],
+        objects: &mut Service<Objects>,
+    ) -> Handle<GlobalEdge> {
+        let mut key = vertices.each_ref_ext().map(|vertex| vertex.id());
+        key.sort();
+
+        self.global_edge
+            .entry(key)
+            .or_insert_with(|| {
+                GlobalEdge::new(GlobalCurve.insert(objects), vertices)
+                    .insert(objects)
+            })
+            .clone()
+    }
+}
+
+impl Loft for Vec<Handle<Face>> {
+    type Lofted = Handle<Shell>;
+
+    fn loft_with_cache(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut LoftCache,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Lofted, LoftError> {
+        let tolerance = tolerance.into();
+
+        if self.len() < 2 {
+            return Err(LoftError::TooFewProfiles);
+        }
+
+        let num_interiors = self[0].interiors().count();
+        if self
+            .iter()
+            .any(|face| face.interiors().count() != num_interiors)
+        {
+            return Err(LoftError::HolesDiffer);
+        }
+
+        let mut profiles = self
+            .iter()
+            .map(|face| {
+                ProfilePolygons::from_face(face, tolerance, cache, objects)
+            })
+            .collect::<Vec<_>>();
+
+        // All profiles must wind around the direction of the loft in the same
+        // way, for their vertices to be connected.
+        let centers = profiles
+            .iter()
+            .map(|profile| center_of(&profile.exterior))
+            .collect::<Vec<_>>();
+        for (i, profile) in profiles.iter_mut().enumerate() {
+            let direction = if i + 1 < centers.len() {
+                centers[i + 1] - centers[i]
+            } else {
+                centers[i] - centers[i - 1]
+            };
+
+            profile.orient(direction);
+        }
+
+        for c in 0..=num_interiors {
+            let num_vertices = profiles
+                .iter()
+                .map(|profile| profile.cycle(c).len())
+                .max()
+                .expect("Loft has profiles");
+            for profile in &mut profiles {
+                resample(profile.cycle_mut(c), num_vertices, objects);
+            }
+
+            // Connect each vertex to the closest one of the previous profile,
+            // as far as that is possible without reordering the vertices.
+            for i in 1..profiles.len() {
+                let shift =
+                    best_shift(profiles[i - 1].cycle(c), profiles[i].cycle(c));
+                profiles[i].cycle_mut(c).rotate_left(shift);
+            }
+        }
+
+        Ok(connect_profiles(&profiles, cache, objects))
+    }
+}
+
+impl Loft for Vec<Handle<Sketch>> {
+    type Lofted = Handle<Solid>;
+
+    fn loft_with_cache(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut LoftCache,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Lofted, LoftError> {
+        let faces = self
+            .iter()
+            .map(|sketch| {
+                let mut faces = sketch.faces().into_iter();
+                let face = faces.next().ok_or(LoftError::EmptySketch)?;
+                if faces.next().is_some() {
+                    return Err(LoftError::MultipleFaces);
+                }
+                Ok(face.clone())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let shell = faces.loft_with_cache(tolerance, cache, objects)?;
+        Ok(Solid::new([shell]).insert(objects))
+    }
+}
+
+/// The polygons that approximate the cycles of a profile
+pub(crate) struct ProfilePolygons {
+    /// The polygon that approximates the exterior cycle
+    pub exterior: Vec<Handle<GlobalVertex>>,
+
+    /// The polygons that approximate the interior cycles
+    pub interiors: Vec<Vec<Handle<GlobalVertex>>>,
+
+    /// The color of the profile
+    pub color: Color,
+
+    /// The material of the profile, if it has one
+    pub material: Option<MaterialId>,
+}
+
+impl ProfilePolygons {
+    /// Compute the polygons that approximate the cycles of a face
+    ///
+    /// Curved edges are approximated within the given tolerance. The global
+    /// edges of straight edges are added to the cache, so they are reused by
+    /// the caps.
+    pub fn from_face(
+        face: &Handle<Face>,
+        tolerance: Tolerance,
+        cache: &mut LoftCache,
+        objects: &mut Service<Objects>,
+    ) -> Self {
+        let mut cycles = face.all_cycles().map(|cycle| {
+            polygon_from_cycle(face, cycle, tolerance, cache, objects)
+        });
+
+        let exterior = cycles.next().expect("Face has an exterior cycle");
+        let interiors = cycles.collect();
+
+        Self {
+            exterior,
+            interiors,
+            color: face.color(),
+            material: face.material(),
+        }
+    }
+
+    /// Access the cycle with the given index
+    ///
+    /// The exterior has index `0`, followed by the interiors.
+    pub fn cycle(&self, index: usize) -> &Vec<Handle<GlobalVertex>> {
+        match index {
+            0 => &self.exterior,
+            i => &self.interiors[i - 1],
+        }
+    }
+
+    /// Access the cycle with the given index mutably
+    ///
+    /// See [`ProfilePolygons::cycle`].
+    pub fn cycle_mut(
+        &mut self,
+        index: usize,
+    ) -> &mut Vec<Handle<GlobalVertex>> {
+        match index {
+            0 => &mut self.exterior,
+            i => &mut self.interiors[i - 1],
+        }
+    }
+
+    /// Make the polygons wind around the given direction
+    ///
+    /// The exterior winds around it counterclockwise, the interiors clockwise.
+    /// Side faces that connect two profiles that have been oriented like this,
+    /// point outward.
+    pub fn orient(&mut self, direction: Vector<3>) {
+        if normal_of(&self.exterior).dot(&direction) < Scalar::ZERO {
+            self.exterior.reverse();
+        }
+        for interior in &mut self.interiors {
+            if normal_of(interior).dot(&direction) > Scalar::ZERO {
+                interior.reverse();
+            }
+        }
+    }
+
+    /// Reverse all polygons of the profile
+    fn reversed(&self) -> Self {
+        let reverse = |polygon: &Vec<Handle<GlobalVertex>>| {
+            polygon.iter().rev().cloned().collect::<Vec<_>>()
+        };
+
+        Self {
+            exterior: reverse(&self.exterior),
+            interiors: self.interiors.iter().map(reverse).collect(),
+            color: self.color,
+            material: self.material,
+        }
+    }
+}
+
+/// Connect profiles with side faces, and close the ends with caps
+///
+/// The profiles must have been oriented (see [`ProfilePolygons::orient`]), and
+/// their corresponding cycles must have the same number of vertices.
+pub(crate) fn connect_profiles(
+    profiles: &[ProfilePolygons],
+    cache: &mut LoftCache,
+    objects: &mut Service<Objects>,
+) -> Handle<Shell> {
+    let min_distance = ValidationConfig::default().distinct_min_distance;
+    let mut faces = Vec::new();
+
+    for pair in profiles.windows(2) {
+        let [bottom, top] = [&pair[0], &pair[1]];
+        let (color, material) = (bottom.color, bottom.material);
+
+        for c in 0..=bottom.interiors.len() {
+            let [bottom, top] = [bottom.cycle(c), top.cycle(c)];
+            let num_vertices = bottom.len();
+
+            for j in 0..num_vertices {
+                let k = (j + 1) % num_vertices;
+                // Where neighboring vertices of a profile are merged, the quad
+                // degenerates into a triangle, or vanishes completely.
+                let quad = without_repeated_vertices(&[
+                    bottom[j].clone(),
+                    bottom[k].clone(),
+                    top[k].clone(),
+                    top[j].clone(),
+                ]);
+
+                let triangles = match quad.as_slice() {
+                    [a, b, c] => vec![[a.clone(), b.clone(), c.clone()]],
+                    [a, b, c, d] => {
+                        if Plane::from_polygon(&quad).contains(&quad) {
+                            faces.push(polygon_face(
+                                &quad,
+                                &[],
+                                color,
+                                material,
+                                cache,
+                                objects,
+                            ));
+                            continue;
+                        }
+
+                        vec![
+                            [a.clone(), b.clone(), c.clone()],
+                            [a.clone(), c.clone(), d.clone()],
+                        ]
+                    }
+                    _ => continue,
+                };
+
+                for triangle in triangles {
+                    let [a, b, c] =
+                        triangle.clone().map(|vertex| vertex.position());
+                    if (b - a).cross(&(c - a)).magnitude() < min_distance {
+                        // Degenerate triangles are created, where vertices of
+                        // neighboring profiles coincide.
+                        continue;
+                    }
+
+                    faces.push(polygon_face(
+                        &triangle,
+                        &[],
+                        color,
+                        material,
+                        cache,
+                        objects,
+                    ));
+                }
+            }
+        }
+    }
+
+    // The profiles wind around the direction of the loft, so the first one
+    // must be reversed to point outward.
+    let start = profiles[0].reversed();
+    let end = &profiles[profiles.len() - 1];
+    for profile in [&start, end] {
+        let interiors = profile
+            .interiors
+            .iter()
+            .map(|interior| without_repeated_vertices(interior))
+            .collect::<Vec<_>>();
+
+        faces.push(polygon_face(
+            &without_repeated_vertices(&profile.exterior),
+            &interiors,
+            profile.color,
+            profile.material,
+            cache,
+            objects,
+        ));
+    }
+
+    Shell::new(faces).insert(objects)
+}
+
+/// Create faces from a polygon, that might not be planar
+///
+/// A planar polygon becomes a single face. Otherwise, the polygon is split into
+/// triangles around its first vertex. Polygons with holes must be planar.
+pub(crate) fn polygon_faces(
+    exterior: &[Handle<GlobalVertex>],
+    interiors: &[Vec<Handle<GlobalVertex>>],
+    color: Color,
+    material: Option<MaterialId>,
+    cache: &mut LoftCache,
+    objects: &mut Service<Objects>,
+) -> Vec<Handle<Face>> {
+    if !interiors.is_empty() || Plane::from_polygon(exterior).contains(exterior)
+    {
+        return vec![polygon_face(
+            exterior, interiors, color, material, cache, objects,
+        )];
+    }
+
+    (1..exterior.len() - 1)
+        .map(|i| {
+            let triangle = [&exterior[0], &exterior[i], &exterior[i + 1]]
+                .map(Clone::clone);
+            polygon_face(&triangle, &[], color, material, cache, objects)
+        })
+        .collect()
+}
+
+/// Remove the vertices of a polygon that are the same as the previous one
+fn without_repeated_vertices(
+    polygon: &[Handle<GlobalVertex>],
+) -> Vec<Handle<GlobalVertex>> {
+    let mut vertices: Vec<Handle<GlobalVertex>> = Vec::new();
+    for vertex in polygon {
+        if vertices.last().map(|last| last.id()) != Some(vertex.id()) {
+            vertices.push(vertex.clone());
+        }
+    }
+
+    while vertices.len() > 1
+        && vertices[0].id() == vertices[vertices.len() - 1].id()
+    {
+        vertices.pop();
+    }
+
+    vertices
+}
+
+/// Compute the polygon that approximates a cycle of a face
+fn polygon_from_cycle(
+    face: &Handle<Face>,
+    cycle: &Handle<Cycle>,
+    tolerance: Tolerance,
+    cache: &mut LoftCache,
+    objects: &mut Service<Objects>,
+) -> Vec<Handle<GlobalVertex>> {
+    let surface = face.surface().geometry();
+    let mut vertices = Vec::new();
+
+    for half_edge in cycle.half_edges() {
+        vertices.push(half_edge.back().global_form().clone());
+
+        match half_edge.curve().path() {
+            SurfacePath::Line(_) => {
+                let mut key = half_edge
+                    .global_form()
+                    .vertices()
+                    .access_in_normalized_order()
+                    .map(|vertex| vertex.id());
+                key.sort();
+
+                cache
+                    .global_edge
+                    .insert(key, half_edge.global_form().clone());
+            }
+            path @ SurfacePath::Circle(_) => {
+                let range = RangeOnPath::from(
+                    [half_edge.back(), half_edge.front()]
+                        .map(|vertex| vertex.position()),
+                );
+
+                for (_, point) in (path, range).approx(tolerance) {
+                    let point = surface.point_from_surface_coords(point);
+                    vertices.push(GlobalVertex::new(point).insert(objects));
+                }
+            }
+        }
+    }
+
+    vertices
+}
+
+/// Insert vertices into a profile, until it has the given number of them
+///
+/// Vertices are distributed among the edges of the profile, such that the
+/// resulting edges are as short as possible.
+fn resample(
+    profile: &mut Vec<Handle<GlobalVertex>>,
+    num_vertices: usize,
+    objects: &mut Service<Objects>,
+) {
+    let lengths = (0..profile.len())
+        .map(|i| {
+            let [a, b] = [&profile[i], &profile[(i + 1) % profile.len()]];
+            a.position().distance_to(&b.position())
+        })
+        .collect::<Vec<_>>();
+
+    let mut num_inserted = vec![0; profile.len()];
+    for _ in profile.len()..num_vertices {
+        let longest = (0..profile.len())
+            .max_by(|&a, &b| {
+                let [a, b] = [a, b].map(|i| {
+                    lengths[i] / Scalar::from((num_inserted[i] + 1) as f64)
+                });
+                a.cmp(&b)
+            })
+            .expect("Profile has vertices");
+
+        num_inserted[longest] += 1;
+    }
+
+    let mut resampled = Vec::new();
+    for (i, &num_inserted) in num_inserted.iter().enumerate() {
+        let [a, b] = [&profile[i], &profile[(i + 1) % profile.len()]]
+            .map(|vertex| vertex.position());
+
+        resampled.push(profile[i].clone());
+        for j in 1..=num_inserted {
+            let t = Scalar::from(j as f64)
+                / Scalar::from((num_inserted + 1) as f64);
+            let point = a + (b - a) * t;
+            resampled.push(GlobalVertex::new(point).insert(objects));
+        }
+    }
+
+    *profile = resampled;
+}
+
+/// Find the rotation of a profile, that best matches the previous profile
+///
+/// Returns the number of vertices, by which the profile needs to be rotated to
+/// the left. The profiles are compared relative to their centers.
+fn best_shift(
+    previous: &[Handle<GlobalVertex>],
+    profile: &[Handle<GlobalVertex>],
+) -> usize {
+    let [previous_center, center] = [previous, profile].map(center_of);
+
+    (0..profile.len())
+        .min_by_key(|&shift| {
+            previous
+                .iter()
+                .enumerate()
+                .map(|(i, vertex)| {
+                    let a = vertex.position() - previous_center;
+                    let b = profile[(i + shift) % profile.len()].position()
+                        - center;
+                    (b - a).magnitude()
+                })
+                .fold(Scalar::ZERO, |sum, distance| sum + distance * distance)
+        })
+        .unwrap_or(0)
+}
+
+/// Compute the average position of a polygon's vertices
+fn center_of(polygon: &[Handle<GlobalVertex>]) -> Point<3> {
+    let sum = polygon
+        .iter()
+        .fold(Vector::from([0., 0., 0.]), |sum, vertex| {
+            sum + vertex.position().coords
+        });
+
+    Point {
+        coords: sum / Scalar::from(polygon.len() as f64),
+    }
+}
+
+/// Compute the normal of a polygon, using Newell's method
+///
+/// The normal points in the direction that the polygon winds around
+/// counterclockwise. Its magnitude is not normalized.
+pub(crate) fn normal_of(polygon: &[Handle<GlobalVertex>]) -> Vector<3> {
+    (0..polygon.len()).fold(Vector::from([0., 0., 0.]), |normal, i| {
+        let [a, b] = [&polygon[i], &polygon[(i + 1) % polygon.len()]]
+            .map(|vertex| vertex.position().coords);
+        normal + a.cross(&b)
+    })
+}
+
+/// The plane that a polygon lies in
+///
+/// Its axes are orthonormal, and its normal points in the direction that the
+/// polygon winds around counterclockwise.
+struct Plane {
+    origin: Point<3>,
+    u: Vector<3>,
+    v: Vector<3>,
+}
+
+impl Plane {
+    fn from_polygon(polygon: &[Handle<GlobalVertex>]) -> Self {
+        let origin = polygon[0].position();
+        let normal = normal_of(polygon).normalize();
+        let u = (polygon[1].position() - origin).normalize();
+        let v = normal.cross(&u);
+
+        Self { origin, u, v }
+    }
+
+    fn geometry(&self) -> SurfaceGeometry {
+        SurfaceGeometry {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                self.origin,
+                self.u,
+            )),
+            v: self.v,
+        }
+    }
+
+    fn surface_coords(&self, point: Point<3>) -> Point<2> {
+        let offset = point - self.origin;
+        Point::from([offset.dot(&self.u), offset.dot(&self.v)])
+    }
+
+    /// Indicate whether all vertices of a polygon lie in the plane
+    ///
+    /// The vertices must be accurate enough to pass validation, if they're
+    /// placed in a surface with the plane's geometry.
+    fn contains(&self, polygon: &[Handle<GlobalVertex>]) -> bool {
+        let max_distance = ValidationConfig::default().identical_max_distance;
+        let geometry = self.geometry();
+
+        polygon.iter().all(|vertex| {
+            let point = geometry.point_from_surface_coords(
+                self.surface_coords(vertex.position()),
+            );
+            point.distance_to(&vertex.position()) < max_distance / 2.
+        })
+    }
+}
+
+/// Create a planar face from polygons of global vertices
+///
+/// The global edges between the vertices are shared with all other faces that
+/// are created using the same cache.
+pub(crate) fn polygon_face(
+    exterior: &[Handle<GlobalVertex>],
+    interiors: &[Vec<Handle<GlobalVertex>>],
+    color: Color,
+    material: Option<MaterialId>,
+    cache: &mut LoftCache,
+    objects: &mut Service<Objects>,
+) -> Handle<Face> {
+    let plane = Plane::from_polygon(exterior);
+    let surface = Surface::new(plane.geometry()).insert(objects);
+
+    let mut cycles = Vec::new();
+    for polygon in [exterior]
+        .into_iter()
+        .chain(interiors.iter().map(Vec::as_slice))
+    {
+        let surface_vertices = polygon
+            .iter()
+            .map(|global_form| {
+                let position = plane.surface_coords(global_form.position());
+                SurfaceVertex::new(
+                    position,
+                    surface.clone(),
+                    global_form.clone(),
+                )
+                .insert(objects)
+            })
+            .collect::<Vec<_>>();
+
+        let half_edges = (0..polygon.len())
+            .map(|i| {
+                let j = (i + 1) % polygon.len();
+
+                let global_form = cache.global_edge(
+                    [polygon[i].clone(), polygon[j].clone()],
+                    objects,
+                );
+
+                let vertices: [(Point<1>, Handle<SurfaceVertex>); 2] = [
+                    ([Scalar::ZERO].into(), surface_vertices[i].clone()),
+                    ([Scalar::ONE].into(), surface_vertices[j].clone()),
+                ];
+                let path =
+                    SurfacePath::Line(Line::from_points_with_line_coords(
+                        vertices.each_ref_ext().map(
+                            |(position, surface_vertex)| {
+                                (*position, surface_vertex.position())
+                            },
+                        ),
+                    ));
+                let curve = Curve::new(
+                    surface.clone(),
+                    path,
+                    global_form.curve().clone(),
+                )
+                .insert(objects);
+
+                let vertices = vertices.map(|(position, surface_form)| {
+                    Vertex::new(position, curve.clone(), surface_form)
+                        .insert(objects)
+                });
+
+                HalfEdge::new(vertices, global_form).insert(objects)
+            })
+            .collect::<Vec<_>>();
+
+        cycles.push(Cycle::new(half_edges).insert(objects));
+    }
+
+    let mut cycles = cycles.into_iter();
+    let exterior = cycles.next().expect("Face has an exterior");
+    Face::new(exterior, cycles, color, material).insert(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Transform;
+
+    use crate::{
+        algorithms::transform::TransformObject,
+        builder::FaceBuilder,
+        insert::Insert,
+        objects::{Face, Objects},
+        partial::{Partial, PartialFace, PartialObject},
+        services::{Service, Services},
+        storage::Handle,
+    };
+
+    use super::{Loft, LoftError};
+
+    const SQUARE: [[f64; 2]; 4] = [[0., 0.], [2., 0.], [2., 2.], [0., 2.]];
+
+    #[test]
+    fn loft_matching_profiles() -> Result<(), LoftError> {
+        let mut services = Services::new();
+
+        let profiles = vec![
+            polygon(SQUARE, 0., &mut services.objects),
+            polygon(
+                [[0.5, 0.5], [1.5, 0.5], [1.5, 1.5], [0.5, 1.5]],
+                1.,
+                &mut services.objects,
+            ),
+        ];
+        let shell = profiles.loft(0.001, &mut services.objects)?;
+
+        // Two caps, and a planar side face for each edge.
+        assert_eq!(shell.faces().into_iter().count(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loft_resampled_profiles() -> Result<(), LoftError> {
+        let mut services = Services::new();
+
+        let profiles = vec![
+            polygon(SQUARE, 0., &mut services.objects),
+            polygon([[0., 0.], [2., 0.], [0., 2.]], 1., &mut services.objects),
+        ];
+        let shell = profiles.loft(0.001, &mut services.objects)?;
+
+        // A vertex is inserted into the longest edge of the triangle. Two of
+        // the resulting side faces aren't planar, and are split in two.
+        assert_eq!(shell.faces().into_iter().count(), 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loft_invalid_profiles() {
+        let mut services = Services::new();
+
+        let profiles = vec![polygon(SQUARE, 0., &mut services.objects)];
+        assert!(matches!(
+            profiles.loft(0.001, &mut services.objects),
+            Err(LoftError::TooFewProfiles)
+        ));
+
+        let mut with_hole = PartialFace::default();
+        with_hole.exterior.write().surface =
+            Partial::from(services.objects.surfaces.xy_plane());
+        with_hole.update_exterior_as_polygon(SQUARE);
+        with_hole.add_interior_polygon([
+            [0.5, 0.5],
+            [0.5, 1.5],
+            [1.5, 1.5],
+            [1.5, 0.5],
+        ]);
+        let with_hole = with_hole
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let profiles =
+            vec![with_hole, polygon(SQUARE, 1., &mut services.objects)];
+        assert!(matches!(
+            profiles.loft(0.001, &mut services.objects),
+            Err(LoftError::HolesDiffer)
+        ));
+    }
+
+    fn polygon<const N: usize>(
+        points: [[f64; 2]; N],
+        height: f64,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Face> {
+        let mut face = PartialFace::default();
+        face.exterior.write().surface =
+            Partial::from(objects.surfaces.xy_plane());
+        face.update_exterior_as_polygon(points);
+        face.build(objects)
+            .insert(objects)
+            .transform(&Transform::translation([0., 0., height]), objects)
+    }
+}
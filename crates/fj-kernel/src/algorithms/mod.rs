@@ -5,7 +5,10 @@
 
 pub mod approx;
 pub mod intersect;
+pub mod loft;
+pub mod offset;
 pub mod reverse;
+pub mod revolve;
 pub mod sweep;
 pub mod transform;
 pub mod triangulate;
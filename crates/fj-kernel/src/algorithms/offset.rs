@@ -0,0 +1,389 @@
+//! Offsetting the faces of shells
+//!
+//! See [`Offset`].
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    algorithms::loft::{normal_of, polygon_faces, LoftCache},
+    geometry::path::{GlobalPath, SurfacePath},
+    insert::Insert,
+    objects::{Face, GlobalVertex, Handedness, Objects, Shell},
+    services::Service,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+/// Move the faces of an object along their normals
+///
+/// Each face is moved along the normal of its front side, by the distance that
+/// is given for it. A negative distance moves it backward. Each vertex is moved
+/// to where the moved faces that share it meet, so the faces stay connected.
+///
+/// Only planar faces with straight edges are supported. Where more than three
+/// faces meet at a vertex, the moved faces don't necessarily meet at a single
+/// point. The vertex is moved to where it best fits all of them then, and the
+/// faces around it might be split into triangles.
+///
+/// # Panics
+///
+/// Panics, if a face is curved or has curved edges.
+pub trait Offset: Sized {
+    /// The object that is created by offsetting the implementing object
+    type Offset;
+
+    /// Move all faces by the same distance
+    ///
+    /// Returns an error, if moving a face turns it inside out.
+    fn offset(
+        self,
+        distance: impl Into<Scalar>,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Offset, OffsetError> {
+        let distance = distance.into();
+        self.offset_faces(|_| distance, objects)
+    }
+
+    /// Move each face by the distance that is returned for it
+    ///
+    /// Returns an error, if moving a face turns it inside out.
+    fn offset_faces(
+        self,
+        distance: impl Fn(&Handle<Face>) -> Scalar,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Offset, OffsetError>;
+}
+
+/// An error that can occur while offsetting the faces of an object
+#[derive(Clone, Copy, Debug, PartialEq, thiserror::Error)]
+pub enum OffsetError {
+    /// Moving a face turns it inside out
+    ///
+    /// The faces have been moved so far, that the edges between them shrank to
+    /// nothing and grew in the opposite direction.
+    #[error("Offset of {distance} turns face inside out")]
+    TurnsInsideOut {
+        /// The distance that the face was moved by
+        distance: f64,
+    },
+}
+
+impl Offset for Handle<Shell> {
+    type Offset = Handle<Shell>;
+
+    fn offset_faces(
+        self,
+        distance: impl Fn(&Handle<Face>) -> Scalar,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Offset, OffsetError> {
+        let faces = self.faces().into_iter().cloned().collect::<Vec<_>>();
+        let planes = faces
+            .iter()
+            .map(|face| (normal_of_face(face), distance(face)))
+            .collect::<Vec<_>>();
+
+        // Faces don't necessarily share their global vertices, so vertices are
+        // identified by their position.
+        let min_distance = ValidationConfig::default().distinct_min_distance;
+        let mut vertices: Vec<(Point<3>, Vec<usize>)> = Vec::new();
+
+        let cycles = faces
+            .iter()
+            .enumerate()
+            .map(|(i, face)| {
+                face.all_cycles()
+                    .map(|cycle| {
+                        cycle
+                            .half_edges()
+                            .map(|half_edge| {
+                                assert!(
+                                    matches!(
+                                        half_edge.curve().path(),
+                                        SurfacePath::Line(_)
+                                    ),
+                                    "Only faces with straight edges can be \
+                                    offset"
+                                );
+
+                                let position =
+                                    half_edge.back().global_form().position();
+                                let index = vertices
+                                    .iter()
+                                    .position(|(other, _)| {
+                                        other.distance_to(&position)
+                                            < min_distance
+                                    })
+                                    .unwrap_or_else(|| {
+                                        vertices.push((position, Vec::new()));
+                                        vertices.len() - 1
+                                    });
+
+                                let (_, faces_of_vertex) = &mut vertices[index];
+                                if !faces_of_vertex.contains(&i) {
+                                    faces_of_vertex.push(i);
+                                }
+
+                                index
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let moved = vertices
+            .iter()
+            .map(|(position, faces_of_vertex)| {
+                let planes = faces_of_vertex
+                    .iter()
+                    .map(|&i| planes[i])
+                    .collect::<Vec<_>>();
+
+                GlobalVertex::new(*position + displacement(&planes))
+                    .insert(objects)
+            })
+            .collect::<Vec<_>>();
+
+        let mut cache = LoftCache::default();
+        let mut offset_faces = Vec::new();
+
+        for ((face, cycles), (normal, distance)) in
+            faces.iter().zip(cycles).zip(planes)
+        {
+            // If the faces are moved too far, the edges between them shrink to
+            // nothing, and then grow in the opposite direction.
+            for cycle in &cycles {
+                for (i, &a) in cycle.iter().enumerate() {
+                    let b = cycle[(i + 1) % cycle.len()];
+
+                    let before = vertices[b].0 - vertices[a].0;
+                    let after = moved[b].position() - moved[a].position();
+                    if before.dot(&after) <= Scalar::ZERO {
+                        return Err(OffsetError::TurnsInsideOut {
+                            distance: distance.into_f64(),
+                        });
+                    }
+                }
+            }
+
+            let mut polygons = cycles.into_iter().map(|cycle| {
+                cycle
+                    .into_iter()
+                    .map(|index| moved[index].clone())
+                    .collect::<Vec<_>>()
+            });
+
+            let exterior = polygons.next().expect("Face has an exterior");
+            let interiors = polygons.collect::<Vec<_>>();
+
+            if normal_of(&exterior).dot(&normal) <= Scalar::ZERO {
+                return Err(OffsetError::TurnsInsideOut {
+                    distance: distance.into_f64(),
+                });
+            }
+
+            offset_faces.extend(polygon_faces(
+                &exterior,
+                &interiors,
+                face.color(),
+                face.material(),
+                &mut cache,
+                objects,
+            ));
+        }
+
+        Ok(Shell::new(offset_faces).insert(objects))
+    }
+}
+
+/// The normal of a planar face's front side
+fn normal_of_face(face: &Handle<Face>) -> Vector<3> {
+    let geometry = face.surface().geometry();
+    let u = match geometry.u {
+        GlobalPath::Circle(_) => panic!("Only planar faces can be offset"),
+        GlobalPath::Line(line) => line.direction(),
+    };
+
+    let normal = u.cross(&geometry.v).normalize();
+
+    match face.coord_handedness() {
+        Handedness::LeftHanded => -normal,
+        Handedness::RightHanded => normal,
+    }
+}
+
+/// Compute how far a vertex moves, if the faces that share it are moved
+///
+/// Each face is given by its unit normal, and the distance it is moved.
+fn displacement(planes: &[(Vector<3>, Scalar)]) -> Vector<3> {
+    // Faces that are parallel to each other don't add any information, but
+    // would make the problem harder to solve.
+    let mut distinct: Vec<(Vector<3>, Scalar)> = Vec::new();
+    for &(normal, distance) in planes {
+        if distinct
+            .iter()
+            .all(|(other, _)| other.dot(&normal) < Scalar::ONE - 1e-9)
+        {
+            distinct.push((normal, distance));
+        }
+    }
+
+    if distinct.len() >= 3 {
+        // Find the point that fits all planes best, in a least-squares sense.
+        let rows = [0, 1, 2].map(|k| {
+            distinct
+                .iter()
+                .fold(Vector::from([0., 0., 0.]), |row, (normal, _)| {
+                    row + *normal * normal.components[k]
+                })
+        });
+        let b = distinct
+            .iter()
+            .fold(Vector::from([0., 0., 0.]), |b, (normal, distance)| {
+                b + *normal * *distance
+            });
+
+        let det = rows[0].dot(&rows[1].cross(&rows[2]));
+        if det.abs() > Scalar::from(1e-9) {
+            return (rows[1].cross(&rows[2]) * b.x
+                + rows[2].cross(&rows[0]) * b.y
+                + rows[0].cross(&rows[1]) * b.z)
+                / det;
+        }
+
+        // All normals are almost in a plane. The two that are most different
+        // are enough to define the point.
+        let mut pairs = Vec::new();
+        for (i, a) in distinct.iter().enumerate() {
+            for b in &distinct[i + 1..] {
+                pairs.push([*a, *b]);
+            }
+        }
+        let pair = pairs
+            .into_iter()
+            .min_by_key(|[(a, _), (b, _)]| a.dot(b))
+            .expect("There are at least three planes");
+
+        distinct = pair.to_vec();
+    }
+
+    match distinct.as_slice() {
+        [(normal, distance)] => *normal * *distance,
+        [(a, distance_a), (b, distance_b)] => {
+            let cos = a.dot(b);
+            (*a * (*distance_a - *distance_b * cos)
+                + *b * (*distance_b - *distance_a * cos))
+                / (Scalar::ONE - cos * cos)
+        }
+        _ => unreachable!("Vertex is shared by one to three faces"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point, Scalar};
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        builder::FaceBuilder,
+        insert::Insert,
+        objects::{Objects, Shell},
+        partial::{Partial, PartialFace, PartialObject},
+        services::{Service, Services},
+        storage::Handle,
+    };
+
+    use super::{Offset, OffsetError};
+
+    #[test]
+    fn offset_box_inward() {
+        let mut services = Services::new();
+
+        let shell = cube(&mut services.objects);
+        let offset = shell.offset(-0.25, &mut services.objects).unwrap();
+
+        assert_eq!(offset.faces().into_iter().count(), 6);
+        assert_eq!(
+            bounds(&offset),
+            Aabb {
+                min: Point::from([-0.75, -0.75, 0.25]),
+                max: Point::from([0.75, 0.75, 0.75]),
+            }
+        );
+    }
+
+    #[test]
+    fn offset_faces_by_different_distances() {
+        let mut services = Services::new();
+
+        // Only the top face of the box is moved.
+        let shell = cube(&mut services.objects);
+        let offset = shell
+            .offset_faces(
+                |face| {
+                    let is_top = face.all_cycles().all(|cycle| {
+                        cycle.half_edges().all(|half_edge| {
+                            half_edge.back().global_form().position().z
+                                == Scalar::ONE
+                        })
+                    });
+                    if is_top {
+                        Scalar::from(0.5)
+                    } else {
+                        Scalar::ZERO
+                    }
+                },
+                &mut services.objects,
+            )
+            .unwrap();
+
+        assert_eq!(
+            bounds(&offset),
+            Aabb {
+                min: Point::from([-1., -1., 0.]),
+                max: Point::from([1., 1., 1.5]),
+            }
+        );
+    }
+
+    #[test]
+    fn offset_larger_than_shell() {
+        let mut services = Services::new();
+
+        let shell = cube(&mut services.objects);
+        assert!(matches!(
+            shell.offset(-0.75, &mut services.objects),
+            Err(OffsetError::TurnsInsideOut { distance }) if distance == -0.75
+        ));
+    }
+
+    fn cube(objects: &mut Service<Objects>) -> Handle<Shell> {
+        let mut face = PartialFace::default();
+        face.exterior.write().surface =
+            Partial::from(objects.surfaces.xy_plane());
+        face.update_exterior_as_polygon([
+            [-1., -1.],
+            [1., -1.],
+            [1., 1.],
+            [-1., 1.],
+        ]);
+        let face = face.build(objects).insert(objects);
+
+        face.sweep([0., 0., 1.], objects)
+    }
+
+    fn bounds(shell: &Handle<Shell>) -> Aabb<3> {
+        let points = shell
+            .faces()
+            .into_iter()
+            .flat_map(|face| face.all_cycles().cloned().collect::<Vec<_>>())
+            .flat_map(|cycle| {
+                cycle
+                    .half_edges()
+                    .map(|half_edge| half_edge.back().global_form().position())
+                    .collect::<Vec<_>>()
+            });
+
+        Aabb::<3>::from_points(points)
+    }
+}
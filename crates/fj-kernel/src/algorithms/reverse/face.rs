@@ -27,6 +27,7 @@ impl Reverse for Handle<Face> {
             exterior,
             interiors,
             color: Some(self.color()),
+            material: self.material(),
         };
         face.build(objects).insert(objects)
     }
@@ -0,0 +1,401 @@
+use fj_interop::{
+    ext::ArrayExt,
+    mesh::{Color, MaterialId},
+};
+use fj_math::{Circle, Line, Point, Scalar, Vector, Winding};
+
+use crate::{
+    algorithms::{reverse::Reverse, transform::TransformObject},
+    geometry::{
+        path::{GlobalPath, SurfacePath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Curve, Cycle, Face, GlobalEdge, GlobalVertex, HalfEdge, Objects,
+        Surface, SurfaceVertex, Vertex,
+    },
+    services::Service,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::{Revolution, Revolve, RevolveCache, RevolveError, RevolvedVertex};
+
+impl Revolve for (Handle<HalfEdge>, Color, Option<MaterialId>) {
+    /// `None`, if the half-edge lies on the axis
+    type Revolved = Option<Handle<Face>>;
+
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Revolved, RevolveError> {
+        let (half_edge, color, material) = self;
+        let angle = angle.into();
+        let revolution = Revolution::new(axis, angle)?;
+
+        if let SurfacePath::Circle(_) = half_edge.curve().path() {
+            return Err(RevolveError::CurvedEdge);
+        }
+
+        let min_distance = ValidationConfig::default().distinct_min_distance;
+
+        let global_vertices = half_edge
+            .vertices()
+            .each_ref_ext()
+            .map(|vertex| vertex.global_form().clone());
+        let positions = global_vertices
+            .each_ref_ext()
+            .map(|global_vertex| global_vertex.position());
+        let radii =
+            positions.map(|point| revolution.radius_of(point).magnitude());
+
+        let [p0, p1] = positions;
+        let [r0, r1] = radii;
+
+        if r0 < min_distance && r1 < min_distance {
+            // An edge on the axis doesn't sweep out any area.
+            return Ok(None);
+        }
+
+        let is_parallel = (r0 - r1).abs() < min_distance;
+        let is_perpendicular =
+            (p1 - p0).dot(&revolution.direction).abs() < min_distance;
+        if !is_parallel && !is_perpendicular {
+            return Err(RevolveError::SlantedEdge);
+        }
+
+        let [v0, v1] = global_vertices.clone();
+        let revolved_vertices = [
+            v0.revolve_with_cache(axis, angle, cache, objects)?,
+            v1.revolve_with_cache(axis, angle, cache, objects)?,
+        ];
+
+        let end_edge = if revolution.is_full() {
+            half_edge.global_form().clone()
+        } else {
+            half_edge.global_form().clone().transform_with_cache(
+                &revolution.rotation(),
+                objects,
+                &mut cache.transform,
+            )
+        };
+
+        let edge = RevolvedEdge {
+            half_edge,
+            global_vertices,
+            revolved_vertices,
+            end_edge,
+            revolution,
+        };
+
+        let (exterior, interiors, surface_normal) = if is_parallel {
+            edge.cylinder(objects)
+        } else {
+            edge.disc(objects)
+        };
+
+        let face =
+            Face::new(exterior, interiors, color, material).insert(objects);
+
+        // The face is on the left side of the half-edge, when looking at it
+        // from the direction it is revolved in. Make sure the face points
+        // away from it.
+        let outside = {
+            let radius = if r0 > r1 {
+                revolution.radius_of(p0)
+            } else {
+                revolution.radius_of(p1)
+            };
+            let tangent = revolution.direction.cross(&radius).normalize();
+
+            (p1 - p0).cross(&tangent)
+        };
+        let normal = match face.exterior().winding() {
+            Winding::Ccw => surface_normal,
+            Winding::Cw => -surface_normal,
+        };
+
+        if normal.dot(&outside) < Scalar::ZERO {
+            Ok(Some(face.reverse(objects)))
+        } else {
+            Ok(Some(face))
+        }
+    }
+}
+
+/// The inputs required to create the face that a half-edge revolves into
+struct RevolvedEdge {
+    half_edge: Handle<HalfEdge>,
+    global_vertices: [Handle<GlobalVertex>; 2],
+    revolved_vertices: [RevolvedVertex; 2],
+    end_edge: Handle<GlobalEdge>,
+    revolution: Revolution,
+}
+
+impl RevolvedEdge {
+    /// Create the cylindrical face of an edge that is parallel to the axis
+    fn cylinder(
+        self,
+        objects: &mut Service<Objects>,
+    ) -> (Handle<Cycle>, Vec<Handle<Cycle>>, Vector<3>) {
+        let [p0, p1] = self
+            .global_vertices
+            .each_ref_ext()
+            .map(|global_vertex| global_vertex.position());
+        let [t0, t1] = self
+            .half_edge
+            .vertices()
+            .each_ref_ext()
+            .map(|vertex| vertex.position());
+
+        // Both vertices have a distance from the axis, or this wouldn't be a
+        // cylinder.
+        let [(arc0, end0), (arc1, end1)] =
+            self.revolved_vertices.map(|revolved| {
+                revolved.expect("Vertex of cylinder wall is on axis")
+            });
+
+        let center = self.revolution.center_of(p0);
+        let a = p0 - center;
+        let b = self.revolution.direction.cross(&a);
+        let v = p1 - p0;
+
+        let surface = Surface::new(SurfaceGeometry {
+            u: GlobalPath::Circle(Circle::new(center, a, b)),
+            v,
+        })
+        .insert(objects);
+
+        let angle = self.revolution.angle;
+        let [v0, v1] = self.global_vertices;
+
+        let [s00, s01, s11, s10] = [
+            ([Scalar::ZERO, Scalar::ZERO], v0),
+            ([Scalar::ZERO, Scalar::ONE], v1),
+            ([angle, Scalar::ONE], end1),
+            ([angle, Scalar::ZERO], end0),
+        ]
+        .map(|(position, global_form)| {
+            SurfaceVertex::new(position, surface.clone(), global_form)
+                .insert(objects)
+        });
+
+        let start = Point::from([Scalar::ZERO]);
+        let end = Point::from([angle]);
+
+        let half_edges = [
+            (
+                self.half_edge.global_form().clone(),
+                [(t0, s00.clone()), (t1, s01.clone())],
+            ),
+            (arc1, [(start, s01), (end, s11.clone())]),
+            (self.end_edge, [(t1, s11), (t0, s10.clone())]),
+            (arc0, [(end, s10), (start, s00)]),
+        ]
+        .map(|(global_form, vertices)| {
+            line_half_edge(&surface, global_form, vertices, objects)
+        });
+
+        let exterior = Cycle::new(half_edges).insert(objects);
+        (exterior, Vec::new(), b.cross(&v))
+    }
+
+    /// Create the planar face of an edge that is perpendicular to the axis
+    fn disc(
+        self,
+        objects: &mut Service<Objects>,
+    ) -> (Handle<Cycle>, Vec<Handle<Cycle>>, Vector<3>) {
+        let [p0, p1] = self
+            .global_vertices
+            .each_ref_ext()
+            .map(|global_vertex| global_vertex.position());
+        let [t0, t1] = self
+            .half_edge
+            .vertices()
+            .each_ref_ext()
+            .map(|vertex| vertex.position());
+
+        // The edge doesn't cross the axis, so both vertices are located in the
+        // same direction from it. Use that direction for the surface, so the
+        // angles of the circles start at the vertices.
+        let far = if self.revolution.radius_of(p0).magnitude()
+            > self.revolution.radius_of(p1).magnitude()
+        {
+            p0
+        } else {
+            p1
+        };
+        let origin = self.revolution.center_of(far);
+        let u = self.revolution.radius_of(far).normalize();
+        let v = self.revolution.direction.cross(&u);
+
+        let surface = Surface::new(SurfaceGeometry {
+            u: GlobalPath::Line(Line::from_origin_and_direction(origin, u)),
+            v,
+        })
+        .insert(objects);
+
+        let surface_vertex =
+            |global_form: Handle<GlobalVertex>,
+             objects: &mut Service<Objects>| {
+                let offset = global_form.position() - origin;
+                let position = [offset.dot(&u), offset.dot(&v)];
+
+                SurfaceVertex::new(position, surface.clone(), global_form)
+                    .insert(objects)
+            };
+
+        let angle = self.revolution.angle;
+        let start = Point::from([Scalar::ZERO]);
+        let end = Point::from([angle]);
+
+        let [v0, v1] = self.global_vertices;
+        let [revolved0, revolved1] = self.revolved_vertices;
+
+        if self.revolution.is_full() {
+            // The edge revolves into an annulus, or a full disc, if one of its
+            // vertices is on the axis.
+            let (outer, inner) = if far == p0 {
+                ((v0, revolved0), (v1, revolved1))
+            } else {
+                ((v1, revolved1), (v0, revolved0))
+            };
+
+            let cycle_from_circle =
+                |(global_vertex, revolved): (
+                    Handle<GlobalVertex>,
+                    RevolvedVertex,
+                ),
+                 range: [Point<1>; 2],
+                 objects: &mut Service<Objects>| {
+                    let (arc, _) = revolved?;
+
+                    let surface_vertex = surface_vertex(global_vertex, objects);
+                    let radius = surface_vertex.position().u;
+
+                    let half_edge = circle_half_edge(
+                        &surface,
+                        radius,
+                        arc,
+                        range.map(|point| (point, surface_vertex.clone())),
+                        objects,
+                    );
+
+                    Some(Cycle::new([half_edge]).insert(objects))
+                };
+
+            let exterior = cycle_from_circle(outer, [start, end], objects)
+                .expect("Outer vertex of revolved edge is on axis");
+            let interiors =
+                cycle_from_circle(inner, [end, start], objects).into_iter();
+
+            return (exterior, interiors.collect(), u.cross(&v));
+        }
+
+        let [end0, end1] = [&revolved0, &revolved1]
+            .map(|revolved| revolved.as_ref().map(|(_, end)| end.clone()));
+
+        // Vertices on the axis stay where they are. Use the same surface vertex
+        // for them, so the cycle is closed.
+        let s0 = surface_vertex(v0, objects);
+        let s1 = surface_vertex(v1, objects);
+        let s0_end = match end0 {
+            Some(end0) => surface_vertex(end0, objects),
+            None => s0.clone(),
+        };
+        let s1_end = match end1 {
+            Some(end1) => surface_vertex(end1, objects),
+            None => s1.clone(),
+        };
+
+        let mut half_edges = vec![line_half_edge(
+            &surface,
+            self.half_edge.global_form().clone(),
+            [(t0, s0.clone()), (t1, s1.clone())],
+            objects,
+        )];
+        if let Some((arc1, _)) = revolved1 {
+            let radius = s1.position().u;
+            half_edges.push(circle_half_edge(
+                &surface,
+                radius,
+                arc1,
+                [(start, s1), (end, s1_end.clone())],
+                objects,
+            ));
+        }
+        half_edges.push(line_half_edge(
+            &surface,
+            self.end_edge,
+            [(t1, s1_end), (t0, s0_end.clone())],
+            objects,
+        ));
+        if let Some((arc0, _)) = revolved0 {
+            let radius = s0.position().u;
+            half_edges.push(circle_half_edge(
+                &surface,
+                radius,
+                arc0,
+                [(end, s0_end), (start, s0)],
+                objects,
+            ));
+        }
+
+        let exterior = Cycle::new(half_edges).insert(objects);
+        (exterior, Vec::new(), u.cross(&v))
+    }
+}
+
+/// Create a half-edge that is a straight line in the given surface
+fn line_half_edge(
+    surface: &Handle<Surface>,
+    global_form: Handle<GlobalEdge>,
+    vertices: [(Point<1>, Handle<SurfaceVertex>); 2],
+    objects: &mut Service<Objects>,
+) -> Handle<HalfEdge> {
+    let path = SurfacePath::Line(Line::from_points_with_line_coords(
+        vertices.each_ref_ext().map(|(position, surface_vertex)| {
+            (*position, surface_vertex.position())
+        }),
+    ));
+
+    half_edge(surface, path, global_form, vertices, objects)
+}
+
+/// Create a half-edge that is a circle around the surface origin
+fn circle_half_edge(
+    surface: &Handle<Surface>,
+    radius: Scalar,
+    global_form: Handle<GlobalEdge>,
+    vertices: [(Point<1>, Handle<SurfaceVertex>); 2],
+    objects: &mut Service<Objects>,
+) -> Handle<HalfEdge> {
+    let path = SurfacePath::Circle(Circle::new(
+        Point::origin(),
+        Vector::from([radius, Scalar::ZERO]),
+        Vector::from([Scalar::ZERO, radius]),
+    ));
+
+    half_edge(surface, path, global_form, vertices, objects)
+}
+
+fn half_edge(
+    surface: &Handle<Surface>,
+    path: SurfacePath,
+    global_form: Handle<GlobalEdge>,
+    vertices: [(Point<1>, Handle<SurfaceVertex>); 2],
+    objects: &mut Service<Objects>,
+) -> Handle<HalfEdge> {
+    let curve = Curve::new(surface.clone(), path, global_form.curve().clone())
+        .insert(objects);
+
+    let vertices = vertices.map(|(position, surface_form)| {
+        Vertex::new(position, curve.clone(), surface_form).insert(objects)
+    });
+
+    HalfEdge::new(vertices, global_form).insert(objects)
+}
@@ -0,0 +1,233 @@
+use fj_math::{Line, Scalar};
+
+use crate::{
+    algorithms::{reverse::Reverse, transform::TransformObject},
+    geometry::path::GlobalPath,
+    insert::Insert,
+    objects::{Face, Objects, Shell},
+    partial::{Partial, PartialObject, PartialShell},
+    services::Service,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::{Revolution, Revolve, RevolveCache, RevolveError};
+
+impl Revolve for Handle<Face> {
+    type Revolved = Handle<Shell>;
+
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Revolved, RevolveError> {
+        let angle = angle.into();
+        let revolution = Revolution::new(axis, angle)?;
+
+        let min_distance = ValidationConfig::default().distinct_min_distance;
+
+        let normal = {
+            let u = match self.surface().geometry().u {
+                GlobalPath::Circle(_) => {
+                    return Err(RevolveError::CurvedSurface)
+                }
+                GlobalPath::Line(line) => line.direction(),
+            };
+            let v = self.surface().geometry().v;
+
+            u.cross(&v).normalize()
+        };
+
+        let origin = self
+            .surface()
+            .geometry()
+            .point_from_surface_coords([Scalar::ZERO, Scalar::ZERO]);
+        if normal.dot(&revolution.direction).abs() >= min_distance
+            || normal.dot(&(revolution.origin - origin)).abs() >= min_distance
+        {
+            return Err(RevolveError::AxisOutsideOfPlane);
+        }
+
+        // Within the plane of the face, this direction points away from the
+        // axis. Points on that side of the axis move along the normal of the
+        // face, if revolved.
+        let side = normal.cross(&revolution.direction);
+        let distances = self
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| {
+                let position = half_edge.back().global_form().position();
+                (position - revolution.origin).dot(&side)
+            })
+            .collect::<Vec<_>>();
+
+        if !distances.iter().all(|&distance| distance > -min_distance)
+            && !distances.iter().all(|&distance| distance < min_distance)
+        {
+            return Err(RevolveError::FaceCrossesAxis);
+        }
+
+        let is_negative_revolution =
+            distances.iter().all(|&distance| distance < min_distance);
+
+        let mut faces = Vec::new();
+
+        // Make sure all vertices are revolved before anything is rotated into
+        // the end position. Vertices on the axis must not be moved by that.
+        for cycle in self.all_cycles() {
+            for half_edge in cycle.half_edges() {
+                half_edge
+                    .back()
+                    .global_form()
+                    .clone()
+                    .revolve_with_cache(axis, angle, cache, objects)?;
+            }
+        }
+
+        if !revolution.is_full() {
+            let start_face = if is_negative_revolution {
+                self.clone()
+            } else {
+                self.clone().reverse(objects)
+            };
+            faces.push(start_face);
+
+            let end_face = {
+                let mut face = self.clone().transform_with_cache(
+                    &revolution.rotation(),
+                    objects,
+                    &mut cache.transform,
+                );
+
+                if is_negative_revolution {
+                    face = face.reverse(objects);
+                }
+
+                face
+            };
+            faces.push(end_face);
+        }
+
+        // Generate side faces
+        for cycle in self.all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let half_edge = if is_negative_revolution {
+                    half_edge.clone().reverse(objects)
+                } else {
+                    half_edge.clone()
+                };
+
+                let face = (half_edge, self.color(), self.material())
+                    .revolve_with_cache(axis, angle, cache, objects)?;
+
+                faces.extend(face);
+            }
+        }
+
+        let faces = faces.into_iter().map(Partial::from).collect();
+        Ok(PartialShell { faces }.build(objects).insert(objects))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, TAU};
+
+    use fj_math::{Line, Point, Vector};
+
+    use crate::{
+        builder::FaceBuilder,
+        insert::Insert,
+        objects::{Face, Objects},
+        partial::{Partial, PartialFace, PartialObject},
+        services::{Service, Services},
+        storage::Handle,
+    };
+
+    use super::{Revolve, RevolveError};
+
+    #[test]
+    fn revolve_partial() -> Result<(), RevolveError> {
+        let mut services = Services::new();
+
+        let face = rectangle([1., 2.], &mut services.objects);
+        let shell =
+            face.clone()
+                .revolve(y_axis(), FRAC_PI_2, &mut services.objects)?;
+
+        // Two caps and a side face for each edge.
+        assert_eq!(shell.faces().into_iter().count(), 6);
+
+        // Revolving in this direction moves the face against its normal, so
+        // it can be used as the start cap as-is.
+        assert!(shell.find_face(&face).is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_full() -> Result<(), RevolveError> {
+        let mut services = Services::new();
+
+        let face = rectangle([1., 2.], &mut services.objects);
+        let shell = face.revolve(y_axis(), TAU, &mut services.objects)?;
+
+        // No caps, only a side face for each edge.
+        assert_eq!(shell.faces().into_iter().count(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_touching_axis() -> Result<(), RevolveError> {
+        let mut services = Services::new();
+
+        let face = rectangle([0., 1.], &mut services.objects);
+        let shell = face.revolve(y_axis(), TAU, &mut services.objects)?;
+
+        // The edge on the axis doesn't create a face.
+        assert_eq!(shell.faces().into_iter().count(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_invalid() {
+        let mut services = Services::new();
+
+        let face = rectangle([-1., 1.], &mut services.objects);
+        assert!(matches!(
+            face.clone().revolve(y_axis(), TAU, &mut services.objects),
+            Err(RevolveError::FaceCrossesAxis)
+        ));
+        assert!(matches!(
+            face.revolve(y_axis(), 0., &mut services.objects),
+            Err(RevolveError::ZeroAngle)
+        ));
+    }
+
+    fn y_axis() -> Line<3> {
+        Line::from_origin_and_direction(
+            Point::origin(),
+            Vector::from([0., 1., 0.]),
+        )
+    }
+
+    fn rectangle(
+        [x_min, x_max]: [f64; 2],
+        objects: &mut Service<Objects>,
+    ) -> Handle<Face> {
+        let mut face = PartialFace::default();
+        face.exterior.write().surface =
+            Partial::from(objects.surfaces.xy_plane());
+        face.update_exterior_as_polygon([
+            [x_min, 0.],
+            [x_max, 0.],
+            [x_max, 1.],
+            [x_min, 1.],
+        ]);
+        face.build(objects).insert(objects)
+    }
+}
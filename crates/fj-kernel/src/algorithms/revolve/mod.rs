@@ -0,0 +1,171 @@
+//! Revolving objects around an axis to create new objects
+//!
+//! # Limitations
+//!
+//! Only faces that are bounded by straight edges can be revolved, and each of
+//! those edges must be parallel or perpendicular to the axis. Revolving any
+//! other edge would result in a surface (like a cone, sphere, or torus) that
+//! can't be represented yet. A [`RevolveError`] is returned for those.
+
+mod edge;
+mod face;
+mod sketch;
+mod vertex;
+
+use std::collections::BTreeMap;
+
+use fj_math::{Line, Point, Scalar, Transform, Vector};
+
+use crate::{
+    objects::{GlobalEdge, GlobalVertex, Objects},
+    services::Service,
+    storage::{Handle, ObjectId},
+};
+
+use super::transform::TransformCache;
+
+/// Revolve an object around an axis to create another object
+pub trait Revolve: Sized {
+    /// The object that is created by revolving the implementing object
+    type Revolved;
+
+    /// Revolve the object around the given axis, by the given angle
+    ///
+    /// The angle is given in radians. A positive angle revolves the object
+    /// counterclockwise around the axis, when looking against the axis'
+    /// direction. An angle of a full turn (or more) results in a full
+    /// revolution.
+    ///
+    /// Returns an error, if the angle is zero, the axis has no direction, or
+    /// the object can't be revolved (see the module documentation).
+    fn revolve(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Revolved, RevolveError> {
+        let mut cache = RevolveCache::default();
+        self.revolve_with_cache(axis, angle, &mut cache, objects)
+    }
+
+    /// Revolve the object around the given axis, using the provided cache
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Revolved, RevolveError>;
+}
+
+/// An error that can occur while revolving an object
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum RevolveError {
+    /// The angle of the revolution is zero
+    #[error("Can't revolve by an angle of zero")]
+    ZeroAngle,
+
+    /// The direction of the axis is zero
+    #[error("Can't revolve around an axis without a direction")]
+    ZeroAxis,
+
+    /// An edge is a circle or an arc
+    #[error("Revolving circles and arcs is not supported yet")]
+    CurvedEdge,
+
+    /// An edge is neither parallel nor perpendicular to the axis
+    #[error(
+        "Revolving edges that are neither parallel nor perpendicular to the \
+        axis is not supported yet"
+    )]
+    SlantedEdge,
+
+    /// A face is defined in a curved surface
+    #[error(
+        "Revolving faces that are defined in curved surfaces is not supported"
+    )]
+    CurvedSurface,
+
+    /// The axis doesn't lie in the plane of a face
+    #[error("Can't revolve face around an axis that doesn't lie in its plane")]
+    AxisOutsideOfPlane,
+
+    /// A face crosses the axis
+    #[error("Can't revolve face that crosses the axis")]
+    FaceCrossesAxis,
+}
+
+/// A cache used for revolving
+///
+/// See [`Revolve`].
+#[derive(Default)]
+pub struct RevolveCache {
+    /// Cache for global vertices
+    pub global_vertex: BTreeMap<ObjectId, RevolvedVertex>,
+
+    /// Cache for objects that are rotated into the end position of a revolution
+    pub transform: TransformCache,
+}
+
+/// The result of revolving a global vertex
+///
+/// Contains the edge that is created by revolving the global vertex, and the
+/// vertex at the end of that edge. `None`, if the global vertex is located on
+/// the axis.
+pub type RevolvedVertex = Option<(Handle<GlobalEdge>, Handle<GlobalVertex>)>;
+
+/// The axis and angle of a revolution, in normalized form
+#[derive(Clone, Copy)]
+struct Revolution {
+    origin: Point<3>,
+    direction: Vector<3>,
+    angle: Scalar,
+}
+
+impl Revolution {
+    fn new(axis: Line<3>, angle: Scalar) -> Result<Self, RevolveError> {
+        if angle == Scalar::ZERO {
+            return Err(RevolveError::ZeroAngle);
+        }
+        if axis.direction().magnitude() == Scalar::ZERO {
+            return Err(RevolveError::ZeroAxis);
+        }
+
+        // A negative angle is the same as a positive angle around the reversed
+        // axis.
+        let direction = axis.direction().normalize() * angle.sign().to_scalar();
+        let angle = if angle.abs() < Scalar::TAU {
+            angle.abs()
+        } else {
+            Scalar::TAU
+        };
+
+        Ok(Self {
+            origin: axis.origin(),
+            direction,
+            angle,
+        })
+    }
+
+    fn is_full(&self) -> bool {
+        self.angle == Scalar::TAU
+    }
+
+    /// The point on the axis that is closest to the given point
+    fn center_of(&self, point: Point<3>) -> Point<3> {
+        self.origin
+            + self.direction * (point - self.origin).dot(&self.direction)
+    }
+
+    /// The vector from the axis to the given point, perpendicular to the axis
+    fn radius_of(&self, point: Point<3>) -> Vector<3> {
+        point - self.center_of(point)
+    }
+
+    /// The rotation that moves objects into the end position
+    fn rotation(&self) -> Transform {
+        Transform::translation(self.origin.coords)
+            * Transform::rotation(self.direction * self.angle)
+            * Transform::translation(-self.origin.coords)
+    }
+}
@@ -0,0 +1,34 @@
+use fj_math::{Line, Scalar};
+
+use crate::{
+    insert::Insert,
+    objects::{Objects, Sketch, Solid},
+    partial::{Partial, PartialObject, PartialSolid},
+    services::Service,
+    storage::Handle,
+};
+
+use super::{Revolve, RevolveCache, RevolveError};
+
+impl Revolve for Handle<Sketch> {
+    type Revolved = Handle<Solid>;
+
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Revolved, RevolveError> {
+        let angle = angle.into();
+
+        let mut shells = Vec::new();
+        for face in self.faces().clone() {
+            let shell = face.revolve_with_cache(axis, angle, cache, objects)?;
+            shells.push(shell);
+        }
+
+        let shells = shells.into_iter().map(Partial::from).collect();
+        Ok(PartialSolid { shells }.build(objects).insert(objects))
+    }
+}
@@ -0,0 +1,59 @@
+use fj_math::{Line, Scalar};
+
+use crate::{
+    algorithms::transform::TransformObject,
+    insert::Insert,
+    objects::{GlobalCurve, GlobalEdge, GlobalVertex, Objects},
+    services::Service,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::{Revolution, Revolve, RevolveCache, RevolveError, RevolvedVertex};
+
+impl Revolve for Handle<GlobalVertex> {
+    type Revolved = RevolvedVertex;
+
+    fn revolve_with_cache(
+        self,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        cache: &mut RevolveCache,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Revolved, RevolveError> {
+        if let Some(revolved) = cache.global_vertex.get(&self.id()) {
+            return Ok(revolved.clone());
+        }
+
+        let revolution = Revolution::new(axis, angle.into())?;
+
+        let radius = revolution.radius_of(self.position()).magnitude();
+        let revolved =
+            if radius < ValidationConfig::default().distinct_min_distance {
+                // A vertex on the axis doesn't go anywhere. Make sure it stays the
+                // same vertex, when anything that references it is rotated into
+                // the end position.
+                cache.transform.insert(self.clone(), self.clone());
+                None
+            } else {
+                let end = if revolution.is_full() {
+                    self.clone()
+                } else {
+                    self.clone().transform_with_cache(
+                        &revolution.rotation(),
+                        objects,
+                        &mut cache.transform,
+                    )
+                };
+
+                let curve = GlobalCurve.insert(objects);
+                let edge = GlobalEdge::new(curve, [self.clone(), end.clone()])
+                    .insert(objects);
+
+                Some((edge, end))
+            };
+
+        cache.global_vertex.insert(self.id(), revolved.clone());
+        Ok(revolved)
+    }
+}
@@ -0,0 +1,790 @@
+use std::collections::BTreeSet;
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{path::RangeOnPath, Approx, Tolerance},
+        loft::{connect_profiles, LoftCache, ProfilePolygons},
+    },
+    builder::{CycleBuilder, HalfEdgeBuilder},
+    geometry::path::SurfacePath,
+    insert::Insert,
+    objects::{Cycle, Face, GlobalVertex, Objects, Shell, Sketch, Solid},
+    partial::{
+        Partial, PartialCycle, PartialFace, PartialObject, PartialSolid,
+    },
+    services::Service,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::Sweep;
+
+/// What the blended edges of a sweep are replaced with
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Blend {
+    /// A rounded face with the given radius
+    Fillet(Scalar),
+
+    /// A planar face, that cuts the given distance into both adjacent faces
+    Chamfer(Scalar),
+}
+
+/// The edges of a sweep that are blended
+///
+/// Each edge of a sweep is identified by the half-edge of the profile that it
+/// originates from. Half-edges are numbered in the order of the profile's
+/// cycles, starting with the exterior. For a sketch, the numbers continue
+/// through its faces.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SweptEdges {
+    /// Edges along the path, swept from the back vertex of each half-edge
+    pub along_path: BTreeSet<usize>,
+
+    /// Edges of the cap at the start of the path
+    pub start_cap: BTreeSet<usize>,
+
+    /// Edges of the cap at the end of the path
+    pub end_cap: BTreeSet<usize>,
+}
+
+impl SweptEdges {
+    /// Select the edges that originate from a range of half-edges
+    ///
+    /// The selected edges are renumbered, so the range starts at zero.
+    fn range(&self, start: usize, len: usize) -> Self {
+        let select = |edges: &BTreeSet<usize>| {
+            edges
+                .range(start..start + len)
+                .map(|index| index - start)
+                .collect()
+        };
+
+        Self {
+            along_path: select(&self.along_path),
+            start_cap: select(&self.start_cap),
+            end_cap: select(&self.end_cap),
+        }
+    }
+}
+
+/// Sweep an object along a straight path, while blending some of its edges
+///
+/// Edges along the path are only blended where two straight edges of the
+/// profile meet at an angle. They are replaced exactly, by an arc or a line in
+/// the profile. Fillets of the edges of the caps are approximated by planar
+/// faces, and force curved edges of the profile to be approximated too. Both
+/// approximations are within the given tolerance.
+///
+/// # Panics
+///
+/// Panics, if the blend doesn't fit into the faces next to an edge.
+pub trait SweepWithBlend: Sized {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given path, blending the selected edges
+    fn sweep_with_blend(
+        self,
+        path: impl Into<Vector<3>>,
+        blend: Blend,
+        edges: &SweptEdges,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Swept;
+}
+
+impl SweepWithBlend for Handle<Face> {
+    type Swept = Handle<Shell>;
+
+    fn sweep_with_blend(
+        self,
+        path: impl Into<Vector<3>>,
+        blend: Blend,
+        edges: &SweptEdges,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Swept {
+        let path = path.into();
+        let tolerance = tolerance.into();
+
+        let (face, cap_edges) = blend_corners(&self, blend, edges, objects);
+
+        if edges.start_cap.is_empty() && edges.end_cap.is_empty() {
+            return face.sweep(path, objects);
+        }
+
+        blend_caps(&face, &cap_edges, path, blend, tolerance, objects)
+    }
+}
+
+impl SweepWithBlend for Handle<Sketch> {
+    type Swept = Handle<Solid>;
+
+    fn sweep_with_blend(
+        self,
+        path: impl Into<Vector<3>>,
+        blend: Blend,
+        edges: &SweptEdges,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self::Swept {
+        let path = path.into();
+        let tolerance = tolerance.into();
+
+        let mut shells = Vec::new();
+        let mut start = 0;
+        for face in self.faces().clone() {
+            let len = face
+                .all_cycles()
+                .map(|cycle| cycle.half_edges().count())
+                .sum();
+
+            let edges = edges.range(start, len);
+            shells.push(
+                face.sweep_with_blend(path, blend, &edges, tolerance, objects),
+            );
+
+            start += len;
+        }
+
+        let shells = shells.into_iter().map(Partial::from).collect();
+        PartialSolid { shells }.build(objects).insert(objects)
+    }
+}
+
+/// A section of a cycle, after its corners have been blended
+struct Segment {
+    /// The point where the segment starts
+    start: Point<2>,
+
+    /// The angle of the segment, if it is an arc
+    arc_angle: Option<Scalar>,
+
+    /// Whether the segment is blended at the start and end cap
+    caps: [bool; 2],
+}
+
+/// Replace the selected corners of a face's cycles
+///
+/// Returns the new face, and which caps each of its half-edges is blended at.
+/// The face is returned unchanged, if no corner is replaced.
+fn blend_corners(
+    face: &Handle<Face>,
+    blend: Blend,
+    edges: &SweptEdges,
+    objects: &mut Service<Objects>,
+) -> (Handle<Face>, Vec<Vec<[bool; 2]>>) {
+    let mut changed = false;
+    let mut cycles = Vec::new();
+    let mut cap_edges = Vec::new();
+
+    let mut first_index = 0;
+    for cycle in face.all_cycles() {
+        let num_half_edges = cycle.half_edges().count();
+        let segments = blend_corners_of_cycle(cycle, first_index, blend, edges);
+
+        let cycle = match segments {
+            Some(segments) => {
+                changed = true;
+                cap_edges.push(
+                    segments.iter().map(|segment| segment.caps).collect(),
+                );
+
+                let mut partial = PartialCycle {
+                    surface: Partial::from(face.surface().clone()),
+                    ..Default::default()
+                };
+                let half_edges = partial.update_as_polygon_from_points(
+                    segments.iter().map(|segment| segment.start),
+                );
+                for (mut half_edge, segment) in
+                    half_edges.into_iter().zip(&segments)
+                {
+                    if let Some(angle) = segment.arc_angle {
+                        half_edge.write().update_as_arc(angle);
+                    }
+                }
+
+                Partial::from_partial(partial)
+            }
+            None => {
+                cap_edges.push(
+                    (first_index..first_index + num_half_edges)
+                        .map(|i| {
+                            [&edges.start_cap, &edges.end_cap]
+                                .map(|selected| selected.contains(&i))
+                        })
+                        .collect(),
+                );
+
+                Partial::from(cycle.clone())
+            }
+        };
+        cycles.push(cycle);
+
+        first_index += num_half_edges;
+    }
+
+    if !changed {
+        return (face.clone(), cap_edges);
+    }
+
+    let exterior = cycles.remove(0);
+    let face = PartialFace {
+        exterior,
+        interiors: cycles,
+        color: Some(face.color()),
+        material: face.material(),
+    }
+    .build(objects)
+    .insert(objects);
+
+    (face, cap_edges)
+}
+
+/// Replace the selected corners of a cycle
+///
+/// Returns `None`, if no corner of the cycle is replaced. `first_index` is the
+/// number of the cycle's first half-edge (see [`SweptEdges`]).
+fn blend_corners_of_cycle(
+    cycle: &Handle<Cycle>,
+    first_index: usize,
+    blend: Blend,
+    edges: &SweptEdges,
+) -> Option<Vec<Segment>> {
+    let half_edges = cycle.half_edges().collect::<Vec<_>>();
+    let num_half_edges = half_edges.len();
+
+    let lines = half_edges
+        .iter()
+        .map(|half_edge| match half_edge.curve().path() {
+            SurfacePath::Line(_) => {
+                let [a, b] = [half_edge.back(), half_edge.front()]
+                    .map(|vertex| vertex.surface_form().position());
+                Some((a, (b - a).magnitude(), (b - a).normalize()))
+            }
+            SurfacePath::Circle(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    // The corner at the back vertex of each half-edge, defined by the distance
+    // it is cut back along both lines, and its turning angle.
+    let corners = (0..num_half_edges)
+        .map(|i| {
+            if num_half_edges < 2
+                || !edges.along_path.contains(&(first_index + i))
+            {
+                return None;
+            }
+
+            let previous = lines[(i + num_half_edges - 1) % num_half_edges]?;
+            let next = lines[i]?;
+
+            let [a, b] = [previous.2, next.2];
+            let angle = Scalar::atan2(a.u * b.v - a.v * b.u, a.dot(&b));
+            if angle == Scalar::ZERO {
+                return None;
+            }
+
+            let distance = match blend {
+                Blend::Fillet(radius) => radius * (angle.abs() / 2.).tan(),
+                Blend::Chamfer(distance) => distance,
+            };
+
+            Some((distance, angle))
+        })
+        .collect::<Vec<_>>();
+
+    if corners.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let caps_of = |i: usize| {
+        [&edges.start_cap, &edges.end_cap]
+            .map(|selected| selected.contains(&(first_index + i)))
+    };
+
+    let mut segments = Vec::new();
+    for (i, half_edge) in half_edges.iter().enumerate() {
+        let next = (i + 1) % num_half_edges;
+        let caps = caps_of(i);
+
+        let start = half_edge.back().surface_form().position();
+
+        if let Some((distance, angle)) = corners[i] {
+            let previous = (i + num_half_edges - 1) % num_half_edges;
+            let direction = lines[previous]
+                .expect("Blended corners are between lines")
+                .2;
+
+            // The corner is blended at a cap, only if both of its lines are.
+            let [previous_caps, caps] = [caps_of(previous), caps];
+            let caps = [0, 1].map(|j| previous_caps[j] && caps[j]);
+
+            segments.push(Segment {
+                start: start - direction * distance,
+                arc_angle: match blend {
+                    Blend::Fillet(_) => Some(angle),
+                    Blend::Chamfer(_) => None,
+                },
+                caps,
+            });
+        }
+
+        match lines[i] {
+            Some((start, length, direction)) => {
+                let [back, front] = [i, next].map(|j| {
+                    corners[j].map_or(Scalar::ZERO, |(distance, _)| distance)
+                });
+
+                let remaining = length - back - front;
+                assert!(
+                    remaining > -Scalar::from(1e-9) * length,
+                    "Blend of corners doesn't fit into edge of length {length}"
+                );
+
+                // If the blends of both corners take up the whole line, there
+                // is nothing left of it.
+                if remaining > Scalar::from(1e-9) * length {
+                    segments.push(Segment {
+                        start: start + direction * back,
+                        arc_angle: None,
+                        caps,
+                    });
+                }
+            }
+            None => {
+                let [back, front] = [half_edge.back(), half_edge.front()]
+                    .map(|vertex| vertex.position().t);
+
+                segments.push(Segment {
+                    start,
+                    arc_angle: Some(front - back),
+                    caps,
+                });
+            }
+        }
+    }
+
+    Some(segments)
+}
+
+/// Sweep a face, while blending the selected edges of its caps
+///
+/// `cap_edges` defines, which caps each half-edge of the face is blended at.
+fn blend_caps(
+    face: &Handle<Face>,
+    cap_edges: &[Vec<[bool; 2]>],
+    path: Vector<3>,
+    blend: Blend,
+    tolerance: Tolerance,
+    objects: &mut Service<Objects>,
+) -> Handle<Shell> {
+    let polygons = face
+        .all_cycles()
+        .zip(cap_edges)
+        .map(|(cycle, caps)| polygon_from_cycle(face, cycle, caps, tolerance))
+        .collect::<Vec<_>>();
+
+    // All cycles are offset to their left. That is the inside of the face,
+    // regardless of whether it's the exterior or an interior.
+    let normal = polygons[0]
+        .iter()
+        .zip(polygons[0].iter().cycle().skip(1))
+        .fold(Vector::from([0., 0., 0.]), |normal, (a, b)| {
+            normal + a.point.coords.cross(&b.point.coords)
+        })
+        .normalize();
+
+    // The blend, as a sequence of distances from the cap and the distances
+    // that the blended edges are offset at each of them. The sequence starts
+    // at the cap.
+    let steps = match blend {
+        Blend::Fillet(radius) => {
+            let num_steps = num_fillet_steps(radius, tolerance);
+            (0..=num_steps)
+                .rev()
+                .map(|step| {
+                    let angle = Scalar::PI / 2. * Scalar::from(step as f64)
+                        / Scalar::from(num_steps as f64);
+                    (
+                        radius * (Scalar::ONE - angle.sin()),
+                        radius * (Scalar::ONE - angle.cos()),
+                    )
+                })
+                .collect::<Vec<_>>()
+        }
+        Blend::Chamfer(distance) => {
+            vec![(Scalar::ZERO, distance), (distance, Scalar::ZERO)]
+        }
+    };
+
+    let length = path.magnitude();
+    let direction = path.normalize();
+    let min_distance = ValidationConfig::default().distinct_min_distance;
+
+    // Each level is defined by its distance from the start cap, and the offsets
+    // of the edges blended at the start and end cap.
+    let mut levels = Vec::new();
+    if cap_edges.iter().flatten().any(|[start, _]| *start) {
+        levels.extend(
+            steps
+                .iter()
+                .map(|&(distance, offset)| (distance, [offset, Scalar::ZERO])),
+        );
+    } else {
+        levels.push((Scalar::ZERO, [Scalar::ZERO; 2]));
+    }
+    let end_levels = if cap_edges.iter().flatten().any(|[_, end]| *end) {
+        steps
+            .iter()
+            .rev()
+            .map(|&(distance, offset)| {
+                (length - distance, [Scalar::ZERO, offset])
+            })
+            .collect()
+    } else {
+        vec![(length, [Scalar::ZERO; 2])]
+    };
+    for level in end_levels {
+        let (previous, _) = levels[levels.len() - 1];
+        assert!(
+            level.0 >= previous,
+            "Blends of caps don't fit into sweep of length {length}"
+        );
+
+        // Where the blends of both caps meet, the profiles are the same.
+        if level.0 > previous {
+            levels.push(level);
+        }
+    }
+
+    let mut profiles = levels
+        .into_iter()
+        .map(|(distance, cap_offsets)| {
+            let mut polygons = polygons
+                .iter()
+                .map(|polygon| {
+                    let offset_of = |i: usize| {
+                        let caps = polygon[i % polygon.len()].caps;
+                        caps.into_iter().zip(cap_offsets).fold(
+                            Scalar::ZERO,
+                            |sum, (blended, offset)| {
+                                if blended {
+                                    sum + offset
+                                } else {
+                                    sum
+                                }
+                            },
+                        )
+                    };
+                    // Arcs are offset along their normals, not those of the
+                    // lines that approximate them. Otherwise an arc that is
+                    // offset by its radius would turn inside out, instead of
+                    // shrinking to a point.
+                    let left_of = |i: usize| {
+                        let [a, b] = [
+                            &polygon[i % polygon.len()],
+                            &polygon[(i + 1) % polygon.len()],
+                        ];
+                        let chord = b.point - a.point;
+
+                        [a.point, b.point].map(|point| {
+                            let direction = match a.center {
+                                Some(center) => {
+                                    let tangent =
+                                        normal.cross(&(point - center));
+                                    if tangent.dot(&chord) < Scalar::ZERO {
+                                        -tangent
+                                    } else {
+                                        tangent
+                                    }
+                                }
+                                None => chord,
+                            };
+
+                            normal.cross(&direction).normalize()
+                        })
+                    };
+
+                    let points = (0..polygon.len()).map(|i| {
+                        let previous = i + polygon.len() - 1;
+                        let offset = offset_between(
+                            [left_of(previous)[1], left_of(i)[0]],
+                            [offset_of(previous), offset_of(i)],
+                        );
+
+                        polygon[i].point + offset + direction * distance
+                    });
+
+                    // Where a blended cap meets a blended corner, the offset
+                    // corner can shrink to a single point. Its vertices are
+                    // merged then, which degenerates the adjacent side faces.
+                    let mut vertices: Vec<Handle<GlobalVertex>> = Vec::new();
+                    for point in points {
+                        let vertex = match vertices.last() {
+                            Some(previous)
+                                if previous.position().distance_to(&point)
+                                    < min_distance =>
+                            {
+                                previous.clone()
+                            }
+                            _ => GlobalVertex::new(point).insert(objects),
+                        };
+                        vertices.push(vertex);
+                    }
+                    if vertices[vertices.len() - 1]
+                        .position()
+                        .distance_to(&vertices[0].position())
+                        < min_distance
+                    {
+                        let first = vertices[0].clone();
+                        for vertex in vertices.iter_mut().rev() {
+                            if vertex.position().distance_to(&first.position())
+                                >= min_distance
+                            {
+                                break;
+                            }
+                            *vertex = first.clone();
+                        }
+                    }
+
+                    vertices
+                })
+                .collect::<Vec<_>>();
+
+            let exterior = polygons.remove(0);
+            ProfilePolygons {
+                exterior,
+                interiors: polygons,
+                color: face.color(),
+                material: face.material(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for profile in &mut profiles {
+        profile.orient(path);
+    }
+
+    connect_profiles(&profiles, &mut LoftCache::default(), objects)
+}
+
+/// A vertex of the polygon that approximates a cycle
+struct PolygonVertex {
+    /// The position of the vertex
+    point: Point<3>,
+
+    /// Whether the edge starting at the vertex is blended at either cap
+    caps: [bool; 2],
+
+    /// The center of the arc that the edge starting at the vertex is part of
+    center: Option<Point<3>>,
+}
+
+/// Compute the polygon that approximates a cycle of a face
+///
+/// Curved edges are approximated within the given tolerance.
+fn polygon_from_cycle(
+    face: &Handle<Face>,
+    cycle: &Handle<Cycle>,
+    caps: &[[bool; 2]],
+    tolerance: Tolerance,
+) -> Vec<PolygonVertex> {
+    let surface = face.surface().geometry();
+    let mut points = Vec::new();
+
+    for (half_edge, &caps) in cycle.half_edges().zip(caps) {
+        let center = match half_edge.curve().path() {
+            SurfacePath::Circle(circle) => {
+                Some(surface.point_from_surface_coords(circle.center()))
+            }
+            SurfacePath::Line(_) => None,
+        };
+
+        points.push(PolygonVertex {
+            point: half_edge.back().global_form().position(),
+            caps,
+            center,
+        });
+
+        let range = RangeOnPath::from(
+            [half_edge.back(), half_edge.front()]
+                .map(|vertex| vertex.position()),
+        );
+        for (_, point) in (half_edge.curve().path(), range).approx(tolerance) {
+            points.push(PolygonVertex {
+                point: surface.point_from_surface_coords(point),
+                caps,
+                center,
+            });
+        }
+    }
+
+    points
+}
+
+/// Compute the number of steps that a fillet along a cap is divided into
+///
+/// A fillet is a quarter of a circle. It is divided like the approximation of
+/// a full circle with the same radius, within the same tolerance.
+fn num_fillet_steps(radius: Scalar, tolerance: Tolerance) -> usize {
+    // A tolerance larger than the radius would make any number of steps
+    // acceptable, but the fillet still needs at least one.
+    let ratio = (tolerance.inner() / radius).min(Scalar::ONE);
+    let num_segments =
+        Scalar::max(Scalar::PI / (Scalar::ONE - ratio).acos(), 3.).ceil();
+
+    (num_segments / 4.).ceil().into_f64().max(1.) as usize
+}
+
+/// Compute how far a vertex moves, if its adjacent edges are offset
+///
+/// The edges are moved along the given unit normals, by the given distances.
+fn offset_between(
+    [a, b]: [Vector<3>; 2],
+    [distance_a, distance_b]: [Scalar; 2],
+) -> Vector<3> {
+    let cos = a.dot(&b);
+
+    // Where edges are almost parallel, the point where their offsets meet is
+    // far away, or doesn't exist at all. The vertex is moved along the
+    // bisector instead, which is exact for equal distances.
+    if distance_a == distance_b || Scalar::ONE - cos * cos < Scalar::from(0.25)
+    {
+        return (a + b) / (Scalar::ONE + cos)
+            * ((distance_a + distance_b) / 2.);
+    }
+
+    (a * (distance_a - distance_b * cos) + b * (distance_b - distance_a * cos))
+        / (Scalar::ONE - cos * cos)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::Scalar;
+
+    use crate::{
+        builder::FaceBuilder,
+        insert::Insert,
+        objects::{Face, Objects},
+        partial::{Partial, PartialFace, PartialObject},
+        services::{Service, Services},
+        storage::Handle,
+    };
+
+    use super::{Blend, SweepWithBlend, SweptEdges};
+
+    #[test]
+    fn fillet_edges_along_path() {
+        let mut services = Services::new();
+
+        let face = square(&mut services.objects);
+        let edges = SweptEdges {
+            along_path: (0..4).collect(),
+            ..SweptEdges::default()
+        };
+        let shell = face.sweep_with_blend(
+            [0., 0., 1.],
+            Blend::Fillet(Scalar::from(0.5)),
+            &edges,
+            0.001,
+            &mut services.objects,
+        );
+
+        // Two caps, a side face for each edge, and a rounded side face for
+        // each corner.
+        assert_eq!(shell.faces().into_iter().count(), 10);
+    }
+
+    #[test]
+    fn chamfer_edges_of_end_cap() {
+        let mut services = Services::new();
+
+        let face = square(&mut services.objects);
+        let edges = SweptEdges {
+            end_cap: BTreeSet::from([0, 2]),
+            ..SweptEdges::default()
+        };
+        let shell = face.sweep_with_blend(
+            [0., 0., 1.],
+            Blend::Chamfer(Scalar::from(0.5)),
+            &edges,
+            0.001,
+            &mut services.objects,
+        );
+
+        // Two caps, and two side faces for each edge. Those of the edges that
+        // aren't chamfered are coplanar, but separate faces.
+        assert_eq!(shell.faces().into_iter().count(), 10);
+    }
+
+    #[test]
+    fn fillet_edges_of_end_cap() {
+        let mut services = Services::new();
+
+        let face = square(&mut services.objects);
+        let edges = SweptEdges {
+            end_cap: (0..4).collect(),
+            ..SweptEdges::default()
+        };
+        let mut fillet = |tolerance: f64| {
+            face.clone()
+                .sweep_with_blend(
+                    [0., 0., 1.],
+                    Blend::Fillet(Scalar::from(0.5)),
+                    &edges,
+                    tolerance,
+                    &mut services.objects,
+                )
+                .faces()
+                .into_iter()
+                .count()
+        };
+
+        // Two caps, the side faces below the fillet, and a side face for each
+        // edge and step of the fillet. A full circle with a radius of 0.5 is
+        // approximated by 50 segments within a tolerance of 0.001, so a
+        // quarter of it takes 13 steps.
+        assert_eq!(fillet(0.001), 2 + 4 + 4 * 13);
+
+        // A coarser tolerance results in fewer steps.
+        assert!(fillet(0.1) < fillet(0.001));
+    }
+
+    #[test]
+    #[should_panic]
+    fn fillet_larger_than_edge() {
+        let mut services = Services::new();
+
+        let face = square(&mut services.objects);
+        let edges = SweptEdges {
+            along_path: (0..4).collect(),
+            ..SweptEdges::default()
+        };
+        face.sweep_with_blend(
+            [0., 0., 1.],
+            Blend::Fillet(Scalar::from(1.5)),
+            &edges,
+            0.001,
+            &mut services.objects,
+        );
+    }
+
+    fn square(objects: &mut Service<Objects>) -> Handle<Face> {
+        let mut face = PartialFace::default();
+        face.exterior.write().surface =
+            Partial::from(objects.surfaces.xy_plane());
+        face.update_exterior_as_polygon([
+            [-1., -1.],
+            [1., -1.],
+            [1., 1.],
+            [-1., 1.],
+        ]);
+        face.build(objects).insert(objects)
+    }
+}
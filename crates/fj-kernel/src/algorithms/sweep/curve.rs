@@ -60,7 +60,7 @@ impl Sweep for Handle<Curve> {
                     .geometry()
                     .vector_from_surface_coords(circle.b());
 
-                let circle = Circle::new(center, a, b);
+                let circle = Circle::ellipse(center, a, b);
 
                 GlobalPath::Circle(circle)
             }
@@ -0,0 +1,346 @@
+use fj_math::{Scalar, Transform, Vector};
+
+use crate::{
+    algorithms::{
+        approx::Tolerance,
+        loft::{connect_profiles, normal_of, LoftCache, ProfilePolygons},
+    },
+    insert::Insert,
+    objects::{Face, GlobalVertex, Objects, Shell, Sketch, Solid},
+    partial::{Partial, PartialObject, PartialSolid},
+    services::Service,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+
+use super::Sweep;
+
+/// The number of steps a twisted sweep is divided into, per full turn
+const NUM_TWIST_STEPS: usize = 128;
+
+/// Sweep an object along a straight path, with draft and twist
+///
+/// The draft angle tilts the side faces, relative to the path. A positive
+/// draft moves every edge of the profile into the profile, as it is swept
+/// along the path. This means the exterior shrinks, while any holes grow. A
+/// negative draft does the opposite.
+///
+/// The twist angle rotates the profile counterclockwise around the path, when
+/// looking against the direction of the path. The axis of that rotation goes
+/// through the origin of the profile's surface. It can be larger than a full
+/// turn.
+///
+/// If neither draft nor twist are applied, this is equivalent to [`Sweep`].
+/// Otherwise, curved edges are approximated by straight lines within the given
+/// tolerance, and twisted side faces are approximated by triangles.
+pub trait SweepWithDraft: Sized {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given path, with draft and twist
+    ///
+    /// Returns an error, if the draft is too large for the object.
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
+        twist: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Swept, SweepWithDraftError>;
+}
+
+/// An error that can occur while sweeping an object with draft and twist
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SweepWithDraftError {
+    /// The draft angle is a quarter turn or more
+    #[error("Draft must be less than a quarter turn in either direction")]
+    DraftTooLarge,
+
+    /// The draft shrinks an edge of the profile to nothing
+    #[error(
+        "Draft is too large for the profile, which collapses along the path"
+    )]
+    ProfileCollapses,
+}
+
+impl SweepWithDraft for Handle<Face> {
+    type Swept = Handle<Shell>;
+
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
+        twist: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Swept, SweepWithDraftError> {
+        let path = path.into();
+        let draft = draft.into();
+        let twist = twist.into();
+
+        if draft == Scalar::ZERO && twist == Scalar::ZERO {
+            return Ok(self.sweep(path, objects));
+        }
+        if draft.abs() >= Scalar::PI / 2. {
+            return Err(SweepWithDraftError::DraftTooLarge);
+        }
+
+        let mut cache = LoftCache::default();
+        let profile = ProfilePolygons::from_face(
+            &self,
+            tolerance.into(),
+            &mut cache,
+            objects,
+        );
+
+        // All cycles are offset to their left. That is the inside of the face,
+        // regardless of whether it's the exterior or an interior.
+        let normal = normal_of(&profile.exterior).normalize();
+        let offsets = [&profile.exterior]
+            .into_iter()
+            .chain(&profile.interiors)
+            .map(|polygon| miter_offsets(polygon, normal))
+            .collect::<Vec<_>>();
+
+        let origin = self
+            .surface()
+            .geometry()
+            .point_from_surface_coords([Scalar::ZERO, Scalar::ZERO]);
+        let distance = path.magnitude() * draft.tan();
+
+        // The edges keep their directions, while they are offset. If one of
+        // them would shrink to nothing, the profile collapses.
+        let min_distance = ValidationConfig::default().distinct_min_distance;
+        for (polygon, offsets) in [&profile.exterior]
+            .into_iter()
+            .chain(&profile.interiors)
+            .zip(&offsets)
+        {
+            for i in 0..polygon.len() {
+                let j = (i + 1) % polygon.len();
+
+                let [a, b] = [i, j].map(|k| polygon[k].position());
+                let [a_offset, b_offset] =
+                    [a + offsets[i] * distance, b + offsets[j] * distance];
+
+                let direction = (b - a).normalize();
+                if (b_offset - a_offset).dot(&direction) <= min_distance {
+                    return Err(SweepWithDraftError::ProfileCollapses);
+                }
+            }
+        }
+
+        let num_steps = (twist.abs() / Scalar::TAU
+            * Scalar::from(NUM_TWIST_STEPS as f64))
+        .ceil()
+        .into_u64()
+        .max(1);
+
+        let mut profiles = vec![profile];
+        for step in 1..=num_steps {
+            let t = Scalar::from(step as f64) / Scalar::from(num_steps as f64);
+
+            let transform = Transform::translation(origin.coords + path * t)
+                * Transform::rotation(path.normalize() * twist * t)
+                * Transform::translation(-origin.coords);
+
+            let mut polygons = [&profiles[0].exterior]
+                .into_iter()
+                .chain(&profiles[0].interiors)
+                .zip(&offsets)
+                .map(|(polygon, offsets)| {
+                    polygon
+                        .iter()
+                        .zip(offsets)
+                        .map(|(vertex, &offset)| {
+                            let position =
+                                vertex.position() + offset * distance * t;
+                            GlobalVertex::new(
+                                transform.transform_point(&position),
+                            )
+                            .insert(objects)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            let exterior = polygons.remove(0);
+            profiles.push(ProfilePolygons {
+                exterior,
+                interiors: polygons,
+                color: self.color(),
+                material: self.material(),
+            });
+        }
+
+        for profile in &mut profiles {
+            profile.orient(path);
+        }
+
+        Ok(connect_profiles(&profiles, &mut cache, objects))
+    }
+}
+
+impl SweepWithDraft for Handle<Sketch> {
+    type Swept = Handle<Solid>;
+
+    fn sweep_with_draft(
+        self,
+        path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
+        twist: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Swept, SweepWithDraftError> {
+        let path = path.into();
+        let draft = draft.into();
+        let twist = twist.into();
+        let tolerance = tolerance.into();
+
+        let mut shells = Vec::new();
+        for face in self.faces().clone() {
+            let shell =
+                face.sweep_with_draft(path, draft, twist, tolerance, objects)?;
+            shells.push(shell);
+        }
+
+        let shells = shells.into_iter().map(Partial::from).collect();
+        Ok(PartialSolid { shells }.build(objects).insert(objects))
+    }
+}
+
+/// Compute the direction each vertex of a polygon moves in, if it is offset
+///
+/// Each edge of the polygon is moved to its left, by one unit. The returned
+/// vectors move the vertices along the bisectors of their adjacent edges, such
+/// that the edges keep their directions.
+fn miter_offsets(
+    polygon: &[Handle<GlobalVertex>],
+    normal: Vector<3>,
+) -> Vec<Vector<3>> {
+    let left_of = |i: usize| {
+        let [a, b] = [&polygon[i], &polygon[(i + 1) % polygon.len()]]
+            .map(|vertex| vertex.position());
+        normal.cross(&(b - a)).normalize()
+    };
+
+    (0..polygon.len())
+        .map(|i| {
+            let previous = left_of((i + polygon.len() - 1) % polygon.len());
+            let next = left_of(i);
+
+            (previous + next) / (Scalar::ONE + previous.dot(&next))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, TAU};
+
+    use crate::{
+        builder::FaceBuilder,
+        insert::Insert,
+        objects::{Face, Objects},
+        partial::{Partial, PartialFace, PartialObject},
+        services::{Service, Services},
+        storage::Handle,
+    };
+
+    use super::{SweepWithDraft, SweepWithDraftError};
+
+    #[test]
+    fn sweep_with_draft() -> Result<(), SweepWithDraftError> {
+        let mut services = Services::new();
+
+        let face = square(&mut services.objects);
+        let shell = face.sweep_with_draft(
+            [0., 0., 1.],
+            0.1,
+            0.,
+            0.001,
+            &mut services.objects,
+        )?;
+
+        // Two caps, and a planar side face for each edge.
+        assert_eq!(shell.faces().into_iter().count(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_with_twist() -> Result<(), SweepWithDraftError> {
+        let mut services = Services::new();
+
+        let face = square(&mut services.objects);
+        let shell = face.sweep_with_draft(
+            [0., 0., 1.],
+            0.,
+            FRAC_PI_2,
+            0.001,
+            &mut services.objects,
+        )?;
+
+        // A quarter turn is divided into 32 steps. Each edge creates two
+        // triangles per step.
+        assert_eq!(shell.faces().into_iter().count(), 2 + 32 * 4 * 2);
+
+        // Twists of more than a full turn are not wrapped around.
+        let face = square(&mut services.objects);
+        let shell = face.sweep_with_draft(
+            [0., 0., 1.],
+            0.,
+            -TAU * 1.5,
+            0.001,
+            &mut services.objects,
+        )?;
+        assert_eq!(shell.faces().into_iter().count(), 2 + 192 * 4 * 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_with_too_large_draft() {
+        let mut services = Services::new();
+
+        // Every edge of the square moves inward by the length of the path,
+        // which is half the width of the square.
+        let face = square(&mut services.objects);
+        assert!(matches!(
+            face.sweep_with_draft(
+                [0., 0., 1.],
+                FRAC_PI_4,
+                0.,
+                0.001,
+                &mut services.objects,
+            ),
+            Err(SweepWithDraftError::ProfileCollapses)
+        ));
+
+        let face = square(&mut services.objects);
+        assert!(matches!(
+            face.sweep_with_draft(
+                [0., 0., 1.],
+                -FRAC_PI_2,
+                0.,
+                0.001,
+                &mut services.objects,
+            ),
+            Err(SweepWithDraftError::DraftTooLarge)
+        ));
+    }
+
+    fn square(objects: &mut Service<Objects>) -> Handle<Face> {
+        let mut face = PartialFace::default();
+        face.exterior.write().surface =
+            Partial::from(objects.surfaces.xy_plane());
+        face.update_exterior_as_polygon([
+            [-1., -1.],
+            [1., -1.],
+            [1., 1.],
+            [-1., 1.],
+        ]);
+        face.build(objects).insert(objects)
+    }
+}
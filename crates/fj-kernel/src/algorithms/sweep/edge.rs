@@ -1,4 +1,7 @@
-use fj_interop::{ext::ArrayExt, mesh::Color};
+use fj_interop::{
+    ext::ArrayExt,
+    mesh::{Color, MaterialId},
+};
 use fj_math::{Line, Scalar, Vector};
 use iter_fixed::IntoIteratorFixed;
 
@@ -17,7 +20,7 @@ use crate::{
 
 use super::{Sweep, SweepCache};
 
-impl Sweep for (Handle<HalfEdge>, Color) {
+impl Sweep for (Handle<HalfEdge>, Color, Option<MaterialId>) {
     type Swept = Handle<Face>;
 
     fn sweep_with_cache(
@@ -26,7 +29,7 @@ impl Sweep for (Handle<HalfEdge>, Color) {
         cache: &mut SweepCache,
         objects: &mut Service<Objects>,
     ) -> Self::Swept {
-        let (edge, color) = self;
+        let (edge, color, material) = self;
         let path = path.into();
 
         let surface =
@@ -177,6 +180,7 @@ impl Sweep for (Handle<HalfEdge>, Color) {
         let face = PartialFace {
             exterior: Partial::from(cycle),
             color: Some(color),
+            material,
             ..Default::default()
         };
         face.build(objects).insert(objects)
@@ -214,7 +218,7 @@ mod tests {
                 .insert(&mut services.objects)
         };
 
-        let face = (half_edge, Color::default())
+        let face = (half_edge, Color::default(), None)
             .sweep([0., 0., 1.], &mut services.objects);
 
         let expected_face = {
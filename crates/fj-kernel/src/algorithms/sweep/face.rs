@@ -69,7 +69,7 @@ impl Sweep for Handle<Face> {
                     half_edge.clone()
                 };
 
-                let face = (half_edge, self.color())
+                let face = (half_edge, self.color(), self.material())
                     .sweep_with_cache(path, cache, objects);
 
                 faces.push(face);
@@ -145,7 +145,7 @@ mod tests {
                     .build(&mut services.objects)
                     .insert(&mut services.objects)
             };
-            (half_edge, Color::default()).sweep(UP, &mut services.objects)
+            (half_edge, Color::default(), None).sweep(UP, &mut services.objects)
         });
 
         assert!(side_faces
@@ -198,7 +198,8 @@ mod tests {
                     .insert(&mut services.objects)
                     .reverse(&mut services.objects)
             };
-            (half_edge, Color::default()).sweep(DOWN, &mut services.objects)
+            (half_edge, Color::default(), None)
+                .sweep(DOWN, &mut services.objects)
         });
 
         assert!(side_faces
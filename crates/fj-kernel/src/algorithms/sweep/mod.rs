@@ -1,11 +1,20 @@
 //! Sweeping objects along a path to create new objects
 
+mod blend;
 mod curve;
+mod draft;
 mod edge;
 mod face;
+mod path;
 mod sketch;
 mod vertex;
 
+pub use self::{
+    blend::{Blend, SweepWithBlend, SweptEdges},
+    draft::{SweepWithDraft, SweepWithDraftError},
+    path::{ProfileOrientation, SweepAlongPath, SweepAlongPathError},
+};
+
 use std::collections::BTreeMap;
 
 use fj_math::Vector;
@@ -0,0 +1,753 @@
+use std::{collections::BTreeMap, iter};
+
+use fj_interop::{
+    ext::ArrayExt,
+    mesh::{Color, MaterialId},
+};
+use fj_math::{Line, Point, Scalar, Transform, Vector};
+
+use crate::{
+    algorithms::{
+        reverse::Reverse,
+        transform::{TransformCache, TransformObject},
+    },
+    geometry::{
+        path::{GlobalPath, SurfacePath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Curve, Cycle, Face, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge,
+        Objects, Shell, Sketch, Solid, Surface, SurfaceVertex, Vertex,
+    },
+    partial::{Partial, PartialObject, PartialShell, PartialSolid},
+    services::Service,
+    storage::{Handle, ObjectId},
+    validate::ValidationConfig,
+};
+
+use super::{Sweep, SweepCache};
+
+/// Sweep an object along a path that consists of straight segments
+///
+/// Curved paths can be swept along, by approximating them with a polyline
+/// first.
+pub trait SweepAlongPath: Sized {
+    /// The object that is created by sweeping the implementing object
+    type Swept;
+
+    /// Sweep the object along the given path
+    ///
+    /// The path is a polyline, defined by its points. The object is moved
+    /// from the first point of the path to the last one. If the profile follows
+    /// the tangent of the path, it is rotated around the points of the path.
+    ///
+    /// Returns an error, if the path or the object don't meet the requirements
+    /// of the orientation (see [`ProfileOrientation`]).
+    fn sweep_along_path(
+        self,
+        path: impl IntoIterator<Item = impl Into<Point<3>>>,
+        orientation: ProfileOrientation,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Swept, SweepAlongPathError>;
+}
+
+/// An error that can occur while sweeping an object along a path
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SweepAlongPathError {
+    /// The path has no segments
+    #[error("Path to sweep along must have at least one segment")]
+    EmptyPath,
+
+    /// A face is defined in a curved surface
+    #[error(
+        "Sweeping faces that are defined in curved surfaces is not supported"
+    )]
+    CurvedSurface,
+
+    /// A segment of the path doesn't move the profile away from its plane
+    #[error(
+        "Every segment of the path must move the profile away from its plane, \
+        in the same direction"
+    )]
+    SegmentAlongProfile,
+
+    /// The path reverses its direction
+    #[error("Path to sweep along must not reverse direction")]
+    PathReverses,
+
+    /// The profile is too large for a bend in the path
+    #[error("Profile is too large for the bend in the path to sweep along")]
+    ProfileTooLargeForBend,
+
+    /// An edge of the profile is a circle or an arc
+    #[error(
+        "Sweeping circles and arcs along a path, while following its tangent, \
+        is not supported yet"
+    )]
+    CurvedEdge,
+}
+
+/// The orientation of the profile, while it is swept along a path
+///
+/// See [`SweepAlongPath`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ProfileOrientation {
+    /// The profile keeps its orientation along the whole path
+    ///
+    /// Only moves the profile along the path, without rotating it. Each
+    /// segment of the path must move the profile away from its plane, in the
+    /// same direction, or [`SweepAlongPathError::SegmentAlongProfile`] is
+    /// returned.
+    Fixed,
+
+    /// The profile is rotated along with the tangent of the path
+    ///
+    /// Where two segments of the path meet, the profile is rotated by the angle
+    /// between them. The joint between the segments is mitered.
+    ///
+    /// Only profiles that are bounded by straight edges are supported, as the
+    /// mitered joints of any other edges can't be represented yet. The path
+    /// must not reverse its direction, and its bends must be wide enough for
+    /// the profile.
+    FollowTangent,
+}
+
+impl SweepAlongPath for Handle<Face> {
+    type Swept = Handle<Shell>;
+
+    fn sweep_along_path(
+        self,
+        path: impl IntoIterator<Item = impl Into<Point<3>>>,
+        orientation: ProfileOrientation,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Swept, SweepAlongPathError> {
+        let min_distance = ValidationConfig::default().distinct_min_distance;
+
+        // Segments that are too short would result in invalid faces.
+        let mut points: Vec<Point<3>> = Vec::new();
+        for point in path.into_iter().map(Into::into) {
+            if let Some(last) = points.last() {
+                if point.distance_to(last) < min_distance {
+                    continue;
+                }
+            }
+
+            points.push(point);
+        }
+
+        if points.len() < 2 {
+            return Err(SweepAlongPathError::EmptyPath);
+        }
+
+        let normal = {
+            let u = match self.surface().geometry().u {
+                GlobalPath::Circle(_) => {
+                    return Err(SweepAlongPathError::CurvedSurface)
+                }
+                GlobalPath::Line(line) => line.direction(),
+            };
+            let v = self.surface().geometry().v;
+
+            u.cross(&v)
+        };
+
+        let is_negative_sweep =
+            normal.dot(&(points[1] - points[0])) < Scalar::ZERO;
+
+        match orientation {
+            ProfileOrientation::Fixed => {
+                for segment in points.windows(2) {
+                    // Can't panic, as we passed `2` to `windows`.
+                    let distance = normal.dot(&(segment[1] - segment[0]));
+
+                    if distance == Scalar::ZERO
+                        || (distance < Scalar::ZERO) != is_negative_sweep
+                    {
+                        return Err(SweepAlongPathError::SegmentAlongProfile);
+                    }
+                }
+            }
+            ProfileOrientation::FollowTangent => {
+                let is_curved = self
+                    .all_cycles()
+                    .flat_map(|cycle| cycle.half_edges())
+                    .any(|half_edge| {
+                        matches!(
+                            half_edge.curve().path(),
+                            SurfacePath::Circle(_)
+                        )
+                    });
+                if is_curved {
+                    return Err(SweepAlongPathError::CurvedEdge);
+                }
+            }
+        }
+
+        let mut faces = Vec::new();
+
+        let start_face = if is_negative_sweep {
+            self.clone()
+        } else {
+            self.clone().reverse(objects)
+        };
+        faces.push(start_face);
+
+        let end_face = match orientation {
+            ProfileOrientation::Fixed => sweep_with_fixed_orientation(
+                &self,
+                &points,
+                is_negative_sweep,
+                &mut faces,
+                objects,
+            ),
+            ProfileOrientation::FollowTangent => sweep_following_tangent(
+                &self,
+                &points,
+                is_negative_sweep,
+                &mut faces,
+                objects,
+            )?,
+        };
+
+        let end_face = if is_negative_sweep {
+            end_face.reverse(objects)
+        } else {
+            end_face
+        };
+        faces.push(end_face);
+
+        let faces = faces.into_iter().map(Partial::from).collect();
+        Ok(PartialShell { faces }.build(objects).insert(objects))
+    }
+}
+
+impl SweepAlongPath for Handle<Sketch> {
+    type Swept = Handle<Solid>;
+
+    fn sweep_along_path(
+        self,
+        path: impl IntoIterator<Item = impl Into<Point<3>>>,
+        orientation: ProfileOrientation,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self::Swept, SweepAlongPathError> {
+        let path = path.into_iter().map(Into::into).collect::<Vec<Point<3>>>();
+
+        let mut shells = Vec::new();
+        for face in self.faces().clone() {
+            let shell = face.sweep_along_path(
+                path.iter().copied(),
+                orientation,
+                objects,
+            )?;
+            shells.push(shell);
+        }
+
+        let shells = shells.into_iter().map(Partial::from).collect();
+        Ok(PartialSolid { shells }.build(objects).insert(objects))
+    }
+}
+
+/// Create the side faces of a sweep with fixed orientation
+///
+/// Each segment is a regular sweep of the profile, which has been moved to the
+/// start of the segment. Returns the profile, moved to the end of the path.
+///
+/// Expects every segment to move the profile away from its plane, in the same
+/// direction.
+fn sweep_with_fixed_orientation(
+    face: &Handle<Face>,
+    points: &[Point<3>],
+    is_negative_sweep: bool,
+    faces: &mut Vec<Handle<Face>>,
+    objects: &mut Service<Objects>,
+) -> Handle<Face> {
+    let mut profile = face.clone();
+
+    for segment in points.windows(2) {
+        // Can't panic, as we passed `2` to `windows`.
+        let path = segment[1] - segment[0];
+
+        let mut sweep_cache = SweepCache::default();
+        let mut transform_cache = TransformCache::default();
+
+        for cycle in profile.all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let half_edge_to_sweep = if is_negative_sweep {
+                    half_edge.clone().reverse(objects)
+                } else {
+                    half_edge.clone()
+                };
+
+                let face =
+                    (half_edge_to_sweep, profile.color(), profile.material())
+                        .sweep_with_cache(path, &mut sweep_cache, objects);
+
+                // The profile at the end of the segment needs to share its
+                // edges with the side faces. Otherwise the approximations of
+                // curved edges might not match exactly.
+                let top_edge = face
+                    .exterior()
+                    .half_edges()
+                    .nth(2)
+                    .expect("Swept face should have four edges")
+                    .global_form()
+                    .clone();
+                transform_cache.insert(
+                    half_edge.global_form().curve().clone(),
+                    top_edge.curve().clone(),
+                );
+                transform_cache
+                    .insert(half_edge.global_form().clone(), top_edge);
+
+                faces.push(face);
+            }
+        }
+
+        for (&id, top_vertex) in &sweep_cache.global_vertex {
+            let vertex = profile
+                .all_cycles()
+                .flat_map(|cycle| cycle.half_edges())
+                .map(|half_edge| half_edge.back().global_form())
+                .find(|vertex| vertex.id() == id)
+                .expect("Swept vertex should be part of profile")
+                .clone();
+            transform_cache.insert(vertex, top_vertex.clone());
+        }
+
+        profile = profile.transform_with_cache(
+            &Transform::translation(path),
+            objects,
+            &mut transform_cache,
+        );
+    }
+
+    profile
+}
+
+/// Create the side faces of a sweep that follows the tangent of the path
+///
+/// Returns the profile, moved and rotated to the end of the path. Expects the
+/// profile to be bounded by straight edges.
+fn sweep_following_tangent(
+    face: &Handle<Face>,
+    points: &[Point<3>],
+    is_negative_sweep: bool,
+    faces: &mut Vec<Handle<Face>>,
+    objects: &mut Service<Objects>,
+) -> Result<Handle<Face>, SweepAlongPathError> {
+    let min_distance = ValidationConfig::default().distinct_min_distance;
+
+    let directions = points
+        .windows(2)
+        .map(|segment| (segment[1] - segment[0]).normalize())
+        .collect::<Vec<_>>();
+
+    // The rotation of the profile on each segment, relative to its original
+    // orientation.
+    let mut rotations = vec![Transform::identity()];
+    for pair in directions.windows(2) {
+        let [a, b] = [pair[0], pair[1]];
+        let axis = a.cross(&b);
+
+        let rotation = if axis.magnitude() == Scalar::ZERO {
+            if a.dot(&b) < Scalar::ZERO {
+                return Err(SweepAlongPathError::PathReverses);
+            }
+            Transform::identity()
+        } else {
+            let angle = Scalar::atan2(axis.magnitude(), a.dot(&b));
+            Transform::rotation(axis.normalize() * angle)
+        };
+
+        // Can't panic, as we initialized the `Vec` with one element.
+        let previous = *rotations.last().unwrap();
+        rotations.push(rotation * previous);
+    }
+
+    let start = points[0];
+    let end = Transform::translation(points[points.len() - 1].coords)
+        * rotations[rotations.len() - 1]
+        * Transform::translation(-start.coords);
+
+    // Compute the position of every vertex at every joint between segments.
+    // The positions at index `0` are the original ones.
+    let mut positions = Vec::new();
+    for cycle in face.all_cycles() {
+        for half_edge in cycle.half_edges() {
+            let vertex = half_edge.back().global_form();
+            let offset = vertex.position() - start;
+
+            let mut joints = vec![vertex.position()];
+            for (i, point) in points.iter().enumerate().skip(1) {
+                let position = if i == points.len() - 1 {
+                    end.transform_point(&vertex.position())
+                } else {
+                    // The vertex moves along the segment before the joint,
+                    // until it hits the plane that bisects the joint.
+                    let direction = directions[i - 1];
+                    let bisector = direction + directions[i];
+
+                    let offset = rotations[i - 1].transform_vector(&offset);
+                    let distance =
+                        -offset.dot(&bisector) / direction.dot(&bisector);
+
+                    *point + offset + direction * distance
+                };
+
+                // If the profile reaches beyond the center of a bend, the
+                // vertex would move backwards along the segment.
+                let length = (position - joints[i - 1]).dot(&directions[i - 1]);
+                if length <= min_distance {
+                    return Err(SweepAlongPathError::ProfileTooLargeForBend);
+                }
+
+                joints.push(position);
+            }
+
+            positions.push((vertex.clone(), joints));
+        }
+    }
+
+    // The vertices at index `0` are the original ones.
+    let vertices = positions
+        .into_iter()
+        .map(|(vertex, joints)| {
+            let joints = iter::once(vertex.clone())
+                .chain(joints.into_iter().skip(1).map(|position| {
+                    GlobalVertex::new(position).insert(objects)
+                }))
+                .collect::<Vec<_>>();
+
+            (vertex.id(), joints)
+        })
+        .collect::<BTreeMap<ObjectId, Vec<Handle<GlobalVertex>>>>();
+
+    // Compute the edges that connect the vertices at each joint. The edges at
+    // index `0` are the original ones.
+    let mut edges: BTreeMap<ObjectId, Vec<Handle<GlobalEdge>>> =
+        BTreeMap::new();
+    for cycle in face.all_cycles() {
+        for half_edge in cycle.half_edges() {
+            let [a, b] = [half_edge.back(), half_edge.front()]
+                .map(|vertex| &vertices[&vertex.global_form().id()]);
+
+            let mut joints = vec![half_edge.global_form().clone()];
+            for i in 1..points.len() {
+                let edge = GlobalEdge::new(
+                    GlobalCurve.insert(objects),
+                    [a[i].clone(), b[i].clone()],
+                )
+                .insert(objects);
+                joints.push(edge);
+            }
+
+            edges.insert(half_edge.global_form().id(), joints);
+        }
+    }
+
+    // Each vertex creates an edge along each segment.
+    let paths = vertices
+        .iter()
+        .map(|(&id, joints)| {
+            let paths = joints
+                .windows(2)
+                .map(|pair| {
+                    GlobalEdge::new(
+                        GlobalCurve.insert(objects),
+                        [pair[0].clone(), pair[1].clone()],
+                    )
+                    .insert(objects)
+                })
+                .collect::<Vec<_>>();
+
+            (id, paths)
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    for cycle in face.all_cycles() {
+        for half_edge in cycle.half_edges() {
+            let half_edge = if is_negative_sweep {
+                half_edge.clone().reverse(objects)
+            } else {
+                half_edge.clone()
+            };
+
+            for (i, &direction) in directions.iter().enumerate() {
+                let face = side_face(
+                    &half_edge,
+                    [i, i + 1],
+                    direction,
+                    &vertices,
+                    &edges,
+                    &paths,
+                    face.color(),
+                    face.material(),
+                    objects,
+                );
+                faces.push(face);
+            }
+        }
+    }
+
+    // Make sure the profile at the end of the path is made from the vertices
+    // and edges that have been created for the side faces.
+    let mut cache = TransformCache::default();
+    for (&id, joints) in &vertices {
+        let original = &joints[0];
+        debug_assert_eq!(original.id(), id);
+
+        cache.insert(original.clone(), joints[joints.len() - 1].clone());
+    }
+    for joints in edges.values() {
+        let original = &joints[0];
+        let transformed = &joints[joints.len() - 1];
+
+        cache.insert(original.curve().clone(), transformed.curve().clone());
+        cache.insert(original.clone(), transformed.clone());
+    }
+
+    Ok(face.clone().transform_with_cache(&end, objects, &mut cache))
+}
+
+/// Create the planar face that a straight half-edge creates along a segment
+#[allow(clippy::too_many_arguments)]
+fn side_face(
+    half_edge: &Handle<HalfEdge>,
+    [i, j]: [usize; 2],
+    direction: Vector<3>,
+    vertices: &BTreeMap<ObjectId, Vec<Handle<GlobalVertex>>>,
+    edges: &BTreeMap<ObjectId, Vec<Handle<GlobalEdge>>>,
+    paths: &BTreeMap<ObjectId, Vec<Handle<GlobalEdge>>>,
+    color: Color,
+    material: Option<MaterialId>,
+    objects: &mut Service<Objects>,
+) -> Handle<Face> {
+    let [a, b] = [half_edge.back(), half_edge.front()]
+        .map(|vertex| vertex.global_form().id());
+    let [t_a, t_b] =
+        [half_edge.back(), half_edge.front()].map(|vertex| vertex.position());
+
+    let [a_i, b_i, b_j, a_j] = [(a, i), (b, i), (b, j), (a, j)]
+        .map(|(id, k)| vertices[&id][k].clone());
+
+    let surface = Surface::new(SurfaceGeometry {
+        u: GlobalPath::Line(Line::from_points_with_line_coords([
+            ([0.], a_i.position()),
+            ([1.], b_i.position()),
+        ])),
+        v: direction,
+    })
+    .insert(objects);
+
+    // The vertices move along the segment, so their surface coordinates only
+    // differ in the v-coordinate.
+    let [length_a, length_b] =
+        [(&a_i, &a_j), (&b_i, &b_j)].map(|(start, end)| {
+            (end.position() - start.position()).dot(&direction)
+        });
+
+    let [s_a_i, s_b_i, s_b_j, s_a_j] = [
+        ([Scalar::ZERO, Scalar::ZERO], a_i),
+        ([Scalar::ONE, Scalar::ZERO], b_i),
+        ([Scalar::ONE, length_b], b_j),
+        ([Scalar::ZERO, length_a], a_j),
+    ]
+    .map(|(position, global_form)| {
+        SurfaceVertex::new(position, surface.clone(), global_form)
+            .insert(objects)
+    });
+
+    let global_edge = half_edge.global_form().id();
+    let half_edges = [
+        (
+            edges[&global_edge][i].clone(),
+            [(t_a, s_a_i.clone()), (t_b, s_b_i.clone())],
+        ),
+        (
+            paths[&b][i].clone(),
+            [
+                ([Scalar::ZERO].into(), s_b_i),
+                ([Scalar::ONE].into(), s_b_j.clone()),
+            ],
+        ),
+        (
+            edges[&global_edge][j].clone(),
+            [(t_b, s_b_j), (t_a, s_a_j.clone())],
+        ),
+        (
+            paths[&a][i].clone(),
+            [
+                ([Scalar::ONE].into(), s_a_j),
+                ([Scalar::ZERO].into(), s_a_i),
+            ],
+        ),
+    ]
+    .map(|(global_form, vertices)| {
+        let path = SurfacePath::Line(Line::from_points_with_line_coords(
+            vertices.each_ref_ext().map(|(position, surface_vertex)| {
+                (*position, surface_vertex.position())
+            }),
+        ));
+        let curve =
+            Curve::new(surface.clone(), path, global_form.curve().clone())
+                .insert(objects);
+
+        let vertices = vertices.map(|(position, surface_form)| {
+            Vertex::new(position, curve.clone(), surface_form).insert(objects)
+        });
+
+        HalfEdge::new(vertices, global_form).insert(objects)
+    });
+
+    let exterior = Cycle::new(half_edges).insert(objects);
+    Face::new(exterior, [], color, material).insert(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        builder::{FaceBuilder, HalfEdgeBuilder},
+        insert::Insert,
+        objects::{Face, Objects},
+        partial::{
+            Partial, PartialCycle, PartialFace, PartialHalfEdge, PartialObject,
+        },
+        services::{Service, Services},
+        storage::Handle,
+    };
+
+    use super::{ProfileOrientation, SweepAlongPath, SweepAlongPathError};
+
+    const PATH: [[f64; 3]; 3] = [[0., 0., 0.], [0., 0., 1.], [1., 0., 2.]];
+
+    #[test]
+    fn sweep_along_path_with_fixed_orientation(
+    ) -> Result<(), SweepAlongPathError> {
+        let mut services = Services::new();
+
+        let face = triangle(&mut services.objects);
+        let shell = face.clone().sweep_along_path(
+            PATH,
+            ProfileOrientation::Fixed,
+            &mut services.objects,
+        )?;
+
+        // Two caps and a side face for each edge and segment.
+        assert_eq!(shell.faces().into_iter().count(), 8);
+
+        // The path moves the face along its normal, so the face is used as
+        // the start cap in reverse.
+        assert!(shell.find_face(&face).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_path_following_tangent() -> Result<(), SweepAlongPathError> {
+        let mut services = Services::new();
+
+        let face = triangle(&mut services.objects);
+        let shell = face.sweep_along_path(
+            PATH,
+            ProfileOrientation::FollowTangent,
+            &mut services.objects,
+        )?;
+
+        // Two caps and a side face for each edge and segment.
+        assert_eq!(shell.faces().into_iter().count(), 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_invalid_path() {
+        let mut services = Services::new();
+
+        let mut sweep = |path: &[[f64; 3]], orientation| {
+            let face = triangle(&mut services.objects);
+            face.sweep_along_path(
+                path.iter().copied(),
+                orientation,
+                &mut services.objects,
+            )
+        };
+
+        assert!(matches!(
+            sweep(&[[0., 0., 0.]], ProfileOrientation::Fixed),
+            Err(SweepAlongPathError::EmptyPath)
+        ));
+
+        // The second segment of an L-shaped path moves the profile within its
+        // plane.
+        assert!(matches!(
+            sweep(
+                &[[0., 0., 0.], [0., 0., 1.], [1., 0., 1.]],
+                ProfileOrientation::Fixed
+            ),
+            Err(SweepAlongPathError::SegmentAlongProfile)
+        ));
+
+        assert!(matches!(
+            sweep(
+                &[[0., 0., 0.], [0., 0., 1.], [0., 0., 0.5]],
+                ProfileOrientation::FollowTangent
+            ),
+            Err(SweepAlongPathError::PathReverses)
+        ));
+
+        // The profile reaches as far towards the inside of the bend as the
+        // bend is from the start of the path.
+        assert!(matches!(
+            sweep(
+                &[[0., 0., 0.], [0., 0., 1.], [1., 0., 1.]],
+                ProfileOrientation::FollowTangent
+            ),
+            Err(SweepAlongPathError::ProfileTooLargeForBend)
+        ));
+    }
+
+    #[test]
+    fn sweep_circle_following_tangent() {
+        let mut services = Services::new();
+
+        let surface = Partial::from(services.objects.surfaces.xy_plane());
+
+        let mut half_edge = PartialHalfEdge::default();
+        half_edge.curve().write().surface = surface.clone();
+        for vertex in &mut half_edge.vertices {
+            vertex.write().surface_form.write().surface = surface.clone();
+        }
+        half_edge.update_as_circle_from_radius(1.);
+
+        let mut cycle = PartialCycle {
+            surface,
+            ..Default::default()
+        };
+        cycle.half_edges.push(Partial::from_partial(half_edge));
+
+        let face = PartialFace {
+            exterior: Partial::from_partial(cycle),
+            ..Default::default()
+        }
+        .build(&mut services.objects)
+        .insert(&mut services.objects);
+
+        assert!(matches!(
+            face.sweep_along_path(
+                PATH,
+                ProfileOrientation::FollowTangent,
+                &mut services.objects,
+            ),
+            Err(SweepAlongPathError::CurvedEdge)
+        ));
+    }
+
+    fn triangle(objects: &mut Service<Objects>) -> Handle<Face> {
+        let mut face = PartialFace::default();
+        face.exterior.write().surface =
+            Partial::from(objects.surfaces.xy_plane());
+        face.update_exterior_as_polygon([[0., 0.], [1., 0.], [0., 1.]]);
+        face.build(objects).insert(objects)
+    }
+}
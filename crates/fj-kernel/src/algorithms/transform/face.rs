@@ -1,8 +1,10 @@
 use fj_math::Transform;
 
 use crate::{
-    objects::{Face, FaceSet, Objects},
+    algorithms::reverse::Reverse,
+    objects::{Cycle, Face, FaceSet, Objects},
     services::Service,
+    storage::Handle,
 };
 
 use super::{TransformCache, TransformObject};
@@ -14,18 +16,31 @@ impl TransformObject for Face {
         objects: &mut Service<Objects>,
         cache: &mut TransformCache,
     ) -> Self {
-        // Color does not need to be transformed.
+        // Color and material do not need to be transformed.
         let color = self.color();
+        let material = self.material();
 
-        let exterior = self
-            .exterior()
-            .clone()
-            .transform_with_cache(transform, objects, cache);
-        let interiors = self.interiors().cloned().map(|interior| {
-            interior.transform_with_cache(transform, objects, cache)
-        });
+        // A mirroring transform turns the face around, meaning its front side
+        // ends up where its back side should be. Reversing its cycles fixes
+        // that.
+        let mut transform_cycle = |cycle: Handle<Cycle>| {
+            let cycle = cycle.transform_with_cache(transform, objects, cache);
 
-        Self::new(exterior, interiors, color)
+            if transform.is_mirroring() {
+                cycle.reverse(objects)
+            } else {
+                cycle
+            }
+        };
+
+        let exterior = transform_cycle(self.exterior().clone());
+        let interiors = self
+            .interiors()
+            .cloned()
+            .map(transform_cycle)
+            .collect::<Vec<_>>();
+
+        Self::new(exterior, interiors, color, material)
     }
 }
 
@@ -114,7 +114,11 @@ impl TransformCache {
         map.get(&key.id())
     }
 
-    fn insert<T: 'static>(&mut self, key: Handle<T>, value: Handle<T>) {
+    pub(crate) fn insert<T: 'static>(
+        &mut self,
+        key: Handle<T>,
+        value: Handle<T>,
+    ) {
         let map = self
             .0
             .entry::<BTreeMap<ObjectId, Handle<T>>>()
@@ -22,3 +22,74 @@ impl TransformObject for Solid {
         Self::new(shells)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Transform, Vector};
+
+    use crate::{
+        algorithms::{sweep::Sweep, transform::TransformObject},
+        builder::SketchBuilder,
+        geometry::path::GlobalPath,
+        insert::Insert,
+        objects::{Face, Handedness},
+        partial::{PartialObject, PartialSketch},
+        services::Services,
+    };
+
+    #[test]
+    fn mirror() {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xy_plane();
+        let mut sketch = PartialSketch::default();
+        sketch.add_polygon_from_points(
+            surface,
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+        );
+        let solid = sketch
+            .build(&mut services.objects)
+            .insert(&mut services.objects)
+            .sweep([0., 0., 1.], &mut services.objects);
+
+        // Every object is validated as it's inserted, so the mirrored solid is
+        // valid, if this doesn't panic when `services` is dropped.
+        let solid = solid.transform(
+            &Transform::scaling([-1., 1., 1.]),
+            &mut services.objects,
+        );
+
+        let center = Point::from([-0.5, 0.5, 0.5]);
+        for face in solid.shells().flat_map(|shell| shell.faces()) {
+            let outward = centroid(face) - center;
+            assert!(normal(face).dot(&outward) > Scalar::ZERO);
+        }
+    }
+
+    fn normal(face: &Face) -> Vector<3> {
+        let geometry = face.surface().geometry();
+        let u = match geometry.u {
+            GlobalPath::Line(line) => line.direction(),
+            GlobalPath::Circle(_) => unreachable!("Cube has only flat faces"),
+        };
+
+        let normal = u.cross(&geometry.v);
+        match face.coord_handedness() {
+            Handedness::LeftHanded => -normal,
+            Handedness::RightHanded => normal,
+        }
+    }
+
+    fn centroid(face: &Face) -> Point<3> {
+        let points = face
+            .exterior()
+            .half_edges()
+            .map(|half_edge| half_edge.back().global_form().position())
+            .collect::<Vec<_>>();
+
+        let sum = points
+            .iter()
+            .fold(Vector::from([0., 0., 0.]), |sum, point| sum + point.coords);
+        Point::origin() + sum / Scalar::from(points.len() as f64)
+    }
+}
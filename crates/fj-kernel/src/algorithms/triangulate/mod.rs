@@ -65,7 +65,7 @@ impl Triangulate for FaceApprox {
 
         for triangle in triangles {
             let points = triangle.map(|point| point.point_global);
-            mesh.push_triangle(points, self.color);
+            mesh.push_triangle(points, self.color, self.material);
         }
     }
 }
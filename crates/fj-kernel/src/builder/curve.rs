@@ -1,4 +1,4 @@
-use fj_math::{Point, Scalar, Vector};
+use fj_math::{Circle, Point, Scalar, Vector};
 
 use crate::{geometry::path::SurfacePath, partial::PartialCurve};
 
@@ -13,6 +13,13 @@ pub trait CurveBuilder {
     /// Update partial curve to be a circle, from the provided radius
     fn update_as_circle_from_radius(&mut self, radius: impl Into<Scalar>);
 
+    /// Update partial curve to be a circle, from the provided center and radius
+    fn update_as_circle_from_center_and_radius(
+        &mut self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    );
+
     /// Update partial curve to be a line, from the provided points
     fn update_as_line_from_points(&mut self, points: [impl Into<Point<2>>; 2]);
 }
@@ -36,6 +43,16 @@ impl CurveBuilder for PartialCurve {
         self.path = Some(SurfacePath::circle_from_radius(radius));
     }
 
+    fn update_as_circle_from_center_and_radius(
+        &mut self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    ) {
+        self.path = Some(SurfacePath::Circle(Circle::from_center_and_radius(
+            center, radius,
+        )));
+    }
+
     fn update_as_line_from_points(&mut self, points: [impl Into<Point<2>>; 2]) {
         let (path, _) = SurfacePath::line_from_points(points);
         self.path = Some(path);
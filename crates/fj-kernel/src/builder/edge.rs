@@ -1,5 +1,5 @@
 use fj_interop::ext::ArrayExt;
-use fj_math::{Point, Scalar};
+use fj_math::{Arc, Point, Scalar};
 
 use crate::{
     objects::{GlobalEdge, Surface},
@@ -23,6 +23,26 @@ pub trait HalfEdgeBuilder {
     /// Update partial half-edge to be a circle, from the given radius
     fn update_as_circle_from_radius(&mut self, radius: impl Into<Scalar>);
 
+    /// Update partial half-edge to be a circle, from the given center and
+    /// radius
+    fn update_as_circle_from_center_and_radius(
+        &mut self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    );
+
+    /// Update partial half-edge to be an arc, spanning the given angle
+    ///
+    /// The arc connects the surface positions of the half-edge's vertices,
+    /// which must already be set. A positive angle results in a
+    /// counterclockwise arc, a negative angle in a clockwise one.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the vertices don't have surface positions, or if the angle
+    /// is not valid for an arc. See [`Arc::from_endpoints_and_angle`].
+    fn update_as_arc(&mut self, angle_rad: impl Into<Scalar>);
+
     /// Update partial half-edge to be a line segment, from the given points
     fn update_as_line_segment_from_points(
         &mut self,
@@ -50,8 +70,18 @@ impl HalfEdgeBuilder for PartialHalfEdge {
     }
 
     fn update_as_circle_from_radius(&mut self, radius: impl Into<Scalar>) {
+        self.update_as_circle_from_center_and_radius(Point::origin(), radius);
+    }
+
+    fn update_as_circle_from_center_and_radius(
+        &mut self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    ) {
         let mut curve = self.curve();
-        curve.write().update_as_circle_from_radius(radius);
+        curve
+            .write()
+            .update_as_circle_from_center_and_radius(center, radius);
 
         let path = curve
             .read()
@@ -79,6 +109,36 @@ impl HalfEdgeBuilder for PartialHalfEdge {
         self.infer_global_form();
     }
 
+    fn update_as_arc(&mut self, angle_rad: impl Into<Scalar>) {
+        let [p0, p1] = self.vertices.each_ref_ext().map(|vertex| {
+            vertex
+                .read()
+                .surface_form
+                .read()
+                .position
+                .expect("Can't infer arc without surface position")
+        });
+
+        let arc = Arc::from_endpoints_and_angle(p0, p1, angle_rad);
+
+        self.curve()
+            .write()
+            .update_as_circle_from_center_and_radius(
+                arc.center(),
+                arc.radius(),
+            );
+
+        for (vertex, position) in self
+            .vertices
+            .each_mut_ext()
+            .zip_ext([arc.start_angle(), arc.end_angle()])
+        {
+            vertex.write().position = Some([position].into());
+        }
+
+        self.infer_global_form();
+    }
+
     fn update_as_line_segment_from_points(
         &mut self,
         surface: impl Into<Partial<Surface>>,
@@ -1,20 +1,31 @@
 use std::array;
 
-use fj_interop::ext::{ArrayExt, SliceExt};
-use fj_math::Scalar;
+use fj_interop::{
+    ext::{ArrayExt, SliceExt},
+    mesh::Color,
+};
+use fj_math::{Point, Scalar, Vector};
 use iter_fixed::IntoIteratorFixed;
 
 use crate::{
-    algorithms::transform::TransformObject,
+    algorithms::{
+        approx::Tolerance,
+        loft::{polygon_face, LoftCache},
+        sweep::Sweep,
+        transform::TransformObject,
+    },
     builder::{
         FaceBuilder, HalfEdgeBuilder, SurfaceBuilder, SurfaceVertexBuilder,
     },
-    objects::{HalfEdge, Objects},
+    insert::Insert,
+    objects::{GlobalVertex, HalfEdge, Objects},
     partial::{
-        Partial, PartialCycle, PartialFace, PartialHalfEdge, PartialShell,
-        PartialSurface, PartialSurfaceVertex,
+        FullToPartialCache, Partial, PartialCycle, PartialFace,
+        PartialHalfEdge, PartialObject, PartialShell, PartialSurface,
+        PartialSurfaceVertex,
     },
     services::Service,
+    storage::Handle,
 };
 
 /// Builder API for [`PartialShell`]
@@ -24,6 +35,62 @@ pub trait ShellBuilder {
         edge_length: impl Into<Scalar>,
         objects: &mut Service<Objects>,
     ) -> Self;
+
+    /// Create a cuboid from its size along the x-, y-, and z-axis
+    ///
+    /// The cuboid is centered on the z-axis, with its bottom face in the
+    /// xy-plane.
+    fn create_cuboid_from_size(
+        size: impl Into<Vector<3>>,
+        objects: &mut Service<Objects>,
+    ) -> Self;
+
+    /// Create a cylinder from its radius and height
+    ///
+    /// The axis of the cylinder is the z-axis, and its bottom face lies in the
+    /// xy-plane.
+    fn create_cylinder_from_radius_and_height(
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        objects: &mut Service<Objects>,
+    ) -> Self;
+
+    /// Create a cone from the radii of its ends and its height
+    ///
+    /// The axis of the cone is the z-axis, and its bottom face lies in the
+    /// xy-plane. Either radius may be zero. Unless both radii are equal, the
+    /// curved face of the cone is approximated by planar faces around the
+    /// z-axis, within the given tolerance.
+    fn create_cone_from_radii_and_height(
+        bottom_radius: impl Into<Scalar>,
+        top_radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self;
+
+    /// Create a sphere from its radius
+    ///
+    /// The sphere is centered on the origin. Its surface is approximated by
+    /// planar faces within the given tolerance, arranged around the z-axis and
+    /// from pole to pole.
+    fn create_sphere_from_radius(
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self;
+
+    /// Create a torus from its major and minor radius
+    ///
+    /// The torus is centered on the origin and goes around the z-axis. Its
+    /// surface is approximated by planar faces within the given tolerance,
+    /// arranged around the z-axis and around the tube.
+    fn create_torus_from_radii(
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self;
 }
 
 impl ShellBuilder for PartialShell {
@@ -343,4 +410,433 @@ impl ShellBuilder for PartialShell {
                 .collect(),
         }
     }
+
+    fn create_cuboid_from_size(
+        size: impl Into<Vector<3>>,
+        objects: &mut Service<Objects>,
+    ) -> Self {
+        let [x, y, z] = size.into().components.map(|s| s / 2.);
+
+        let mut face = PartialFace::default();
+        face.exterior.write().surface =
+            Partial::from(objects.surfaces.xy_plane());
+        face.update_exterior_as_polygon([[-x, -y], [x, -y], [x, y], [-x, y]]);
+
+        let face = face.build(objects).insert(objects);
+        let shell = face.sweep([Scalar::ZERO, Scalar::ZERO, z * 2.], objects);
+        PartialShell::from_full(&shell, &mut FullToPartialCache::default())
+    }
+
+    fn create_cylinder_from_radius_and_height(
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        objects: &mut Service<Objects>,
+    ) -> Self {
+        let surface = Partial::from(objects.surfaces.xy_plane());
+
+        let mut half_edge = PartialHalfEdge::default();
+        half_edge.curve().write().surface = surface.clone();
+        for vertex in &mut half_edge.vertices {
+            vertex.write().surface_form.write().surface = surface.clone();
+        }
+        half_edge.update_as_circle_from_radius(radius);
+
+        let mut face = PartialFace::default();
+        face.exterior
+            .write()
+            .half_edges
+            .push(Partial::from_partial(half_edge));
+
+        let face = face.build(objects).insert(objects);
+        let path = [Scalar::ZERO, Scalar::ZERO, height.into()];
+        let shell = face.sweep(path, objects);
+        PartialShell::from_full(&shell, &mut FullToPartialCache::default())
+    }
+
+    fn create_cone_from_radii_and_height(
+        bottom_radius: impl Into<Scalar>,
+        top_radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self {
+        let bottom_radius = bottom_radius.into();
+        let top_radius = top_radius.into();
+        let height = height.into();
+
+        if bottom_radius == top_radius {
+            return Self::create_cylinder_from_radius_and_height(
+                bottom_radius,
+                height,
+                objects,
+            );
+        }
+
+        let profile = [
+            [Scalar::ZERO, Scalar::ZERO],
+            [bottom_radius, Scalar::ZERO],
+            [top_radius, height],
+            [Scalar::ZERO, height],
+        ];
+        let num_axial_segments =
+            num_segments(bottom_radius.max(top_radius), tolerance.into());
+        revolve_profile(&profile, num_axial_segments, objects)
+    }
+
+    fn create_sphere_from_radius(
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self {
+        let radius = radius.into();
+
+        // The profile is half of a circle.
+        let num_axial_segments = num_segments(radius, tolerance.into());
+        let num_profile_segments = (num_axial_segments + 1) / 2;
+
+        let mut profile = (0..=num_profile_segments)
+            .map(|i| {
+                let angle = Scalar::PI * Scalar::from(i as f64)
+                    / Scalar::from(num_profile_segments as f64);
+                let (sin, cos) = angle.sin_cos();
+                [radius * sin, -radius * cos]
+            })
+            .collect::<Vec<_>>();
+
+        // The poles must lie exactly on the axis, which the sine of the angle
+        // doesn't guarantee.
+        for i in [0, num_profile_segments] {
+            profile[i][0] = Scalar::ZERO;
+        }
+        revolve_profile(&profile, num_axial_segments, objects)
+    }
+
+    fn create_torus_from_radii(
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) -> Self {
+        let major_radius = major_radius.into();
+        let minor_radius = minor_radius.into();
+        let tolerance = tolerance.into();
+
+        let num_tube_segments = num_segments(minor_radius, tolerance);
+        let profile = (0..num_tube_segments)
+            .map(|i| {
+                let angle = Scalar::TAU * Scalar::from(i as f64)
+                    / Scalar::from(num_tube_segments as f64);
+                let (sin, cos) = angle.sin_cos();
+                [major_radius + minor_radius * cos, minor_radius * sin]
+            })
+            .collect::<Vec<_>>();
+
+        let num_axial_segments =
+            num_segments(major_radius + minor_radius, tolerance);
+        revolve_profile(&profile, num_axial_segments, objects)
+    }
+}
+
+/// Compute the number of segments that approximate a full circle
+///
+/// Like the approximation of circles, the corners of the segments lie on the
+/// circle, and the segments are no further than the tolerance from it.
+fn num_segments(radius: Scalar, tolerance: Tolerance) -> usize {
+    // A tolerance larger than the radius would make any number of segments
+    // acceptable, but the approximation still needs at least three.
+    let ratio = (tolerance.inner() / radius).min(Scalar::ONE);
+    let num_segments = (Scalar::PI / (Scalar::ONE - ratio).acos()).ceil();
+
+    num_segments.into_f64().max(3.) as usize
+}
+
+/// Revolve a polygonal profile around the z-axis, approximating the result
+///
+/// The profile is given in terms of the distance from the z-axis and the
+/// height along it. It must wind counterclockwise in those coordinates, and
+/// must not cross the z-axis. Edges of the profile that lie on the z-axis
+/// don't result in a face. Edges that are perpendicular to the z-axis result
+/// in a single planar face. All other edges result in a ring of planar faces,
+/// one for each segment around the axis.
+fn revolve_profile(
+    profile: &[[Scalar; 2]],
+    num_axial_segments: usize,
+    objects: &mut Service<Objects>,
+) -> PartialShell {
+    let color = Color::default();
+    let mut cache = LoftCache::default();
+
+    // Each point of the profile becomes a ring of vertices around the axis,
+    // unless it lies on the axis. Then a single vertex takes its place.
+    let rings = profile
+        .iter()
+        .map(|&[r, z]| {
+            let num_vertices = if r == Scalar::ZERO {
+                1
+            } else {
+                num_axial_segments
+            };
+
+            (0..num_vertices)
+                .map(|i| {
+                    let angle = Scalar::TAU * Scalar::from(i as f64)
+                        / Scalar::from(num_axial_segments as f64);
+                    let (sin, cos) = angle.sin_cos();
+                    GlobalVertex::new(Point::from([r * cos, r * sin, z]))
+                        .insert(objects)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut faces = Vec::new();
+    for i in 0..profile.len() {
+        let j = (i + 1) % profile.len();
+
+        let [[r_a, z_a], [r_b, z_b]] = [profile[i], profile[j]];
+        let [a, b] = [&rings[i], &rings[j]];
+
+        if r_a == Scalar::ZERO && r_b == Scalar::ZERO {
+            continue;
+        }
+
+        if z_a == z_b {
+            // The profile winds counterclockwise, so its outside is to the
+            // right of the edge. If the edge points away from the axis, the
+            // face points down, and the rings need to be reversed.
+            let (outer, inner) = if r_a > r_b { (a, b) } else { (b, a) };
+            let mut exterior = outer.clone();
+            let mut interior = inner.iter().rev().cloned().collect::<Vec<_>>();
+            if r_b > r_a {
+                exterior.reverse();
+                interior.reverse();
+            }
+
+            let interiors = if interior.len() > 1 {
+                vec![interior]
+            } else {
+                Vec::new()
+            };
+
+            faces.push(polygon_face(
+                &exterior, &interiors, color, None, &mut cache, objects,
+            ));
+            continue;
+        }
+
+        for k in 0..num_axial_segments {
+            let l = (k + 1) % num_axial_segments;
+
+            let vertex = |ring: &Vec<Handle<GlobalVertex>>, k: usize| {
+                ring[k % ring.len()].clone()
+            };
+            let polygon = if r_a == Scalar::ZERO {
+                vec![vertex(a, k), vertex(b, l), vertex(b, k)]
+            } else if r_b == Scalar::ZERO {
+                vec![vertex(a, k), vertex(a, l), vertex(b, k)]
+            } else {
+                vec![vertex(a, k), vertex(a, l), vertex(b, l), vertex(b, k)]
+            };
+
+            faces.push(polygon_face(
+                &polygon,
+                &[],
+                color,
+                None,
+                &mut cache,
+                objects,
+            ));
+        }
+    }
+
+    let mut cache = FullToPartialCache::default();
+    PartialShell {
+        faces: faces
+            .into_iter()
+            .map(|face| Partial::from_full(face, &mut cache))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cmp::Ordering, collections::BTreeMap, f64::consts::PI};
+
+    use fj_interop::ext::ArrayExt;
+    use fj_math::{Aabb, Point};
+
+    use crate::{
+        algorithms::triangulate::Triangulate,
+        objects::Shell,
+        partial::{PartialObject, PartialShell},
+        services::Services,
+    };
+
+    use super::ShellBuilder;
+
+    #[test]
+    fn cylinder() {
+        let mut services = Services::new();
+
+        let shell = PartialShell::create_cylinder_from_radius_and_height(
+            1.,
+            2.,
+            &mut services.objects,
+        )
+        .build(&mut services.objects);
+
+        // The side is a single curved face.
+        assert_eq!(shell.faces().into_iter().count(), 3);
+        assert!(is_closed(&shell));
+        assert_bounding_volume(&shell, [[-1., -1., 0.], [1., 1., 2.]]);
+    }
+
+    #[test]
+    fn cone() {
+        let mut services = Services::new();
+
+        let tolerance = 0.001;
+        let frustum = PartialShell::create_cone_from_radii_and_height(
+            1.,
+            0.5,
+            2.,
+            tolerance,
+            &mut services.objects,
+        )
+        .build(&mut services.objects);
+        let cone = PartialShell::create_cone_from_radii_and_height(
+            1.,
+            0.,
+            2.,
+            tolerance,
+            &mut services.objects,
+        )
+        .build(&mut services.objects);
+
+        // Both ends, and a planar face for each segment around the axis. The
+        // tip of a cone is a single vertex, and has no face.
+        let num_segments = num_segments(1., tolerance);
+        assert_eq!(frustum.faces().into_iter().count(), num_segments + 2);
+        assert_eq!(cone.faces().into_iter().count(), num_segments + 1);
+
+        for shell in [&frustum, &cone] {
+            assert!(is_closed(shell));
+            assert_bounding_volume(shell, [[-1., -1., 0.], [1., 1., 2.]]);
+        }
+    }
+
+    #[test]
+    fn sphere() {
+        let mut services = Services::new();
+
+        let tolerance = 0.001;
+        let shell = PartialShell::create_sphere_from_radius(
+            2.,
+            tolerance,
+            &mut services.objects,
+        )
+        .build(&mut services.objects);
+
+        // A planar face for each segment of the profile and around the axis.
+        // The profile is half of a circle.
+        let num_segments = num_segments(2., tolerance);
+        assert_eq!(
+            shell.faces().into_iter().count(),
+            (num_segments + 1) / 2 * num_segments
+        );
+        assert!(is_closed(&shell));
+        assert_bounding_volume(&shell, [[-2., -2., -2.], [2., 2., 2.]]);
+    }
+
+    #[test]
+    fn torus() {
+        let mut services = Services::new();
+
+        let shell = PartialShell::create_torus_from_radii(
+            2.,
+            0.5,
+            0.001,
+            &mut services.objects,
+        )
+        .build(&mut services.objects);
+        let coarse = PartialShell::create_torus_from_radii(
+            2.,
+            0.5,
+            0.01,
+            &mut services.objects,
+        )
+        .build(&mut services.objects);
+
+        assert!(is_closed(&shell));
+        assert_bounding_volume(&shell, [[-2.5, -2.5, -0.5], [2.5, 2.5, 0.5]]);
+
+        // A larger tolerance results in fewer faces.
+        assert!(is_closed(&coarse));
+        assert!(
+            coarse.faces().into_iter().count()
+                < shell.faces().into_iter().count()
+        );
+    }
+
+    /// The number of segments that approximate a full circle within the
+    /// tolerance
+    fn num_segments(radius: f64, tolerance: f64) -> usize {
+        (PI / (1. - tolerance / radius).acos()).ceil() as usize
+    }
+
+    /// Check that each edge of the shell is shared by exactly two of its faces,
+    /// which use it in opposite directions
+    fn is_closed(shell: &Shell) -> bool {
+        let key = |point: Point<3>| {
+            point
+                .coords
+                .components
+                .map(|s| (s.into_f64() * 1e6).round() as i64)
+        };
+
+        let mut uses = BTreeMap::new();
+        for half_edge in shell
+            .faces()
+            .into_iter()
+            .flat_map(|face| face.all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+        {
+            let [a, b] = half_edge
+                .vertices()
+                .each_ref_ext()
+                .map(|vertex| key(vertex.global_form().position()));
+
+            let (key, direction) = match a.cmp(&b) {
+                Ordering::Less => ([a, b], 1),
+                Ordering::Equal => ([a, b], 0),
+                Ordering::Greater => ([b, a], -1),
+            };
+
+            let (count, directions) = uses.entry(key).or_insert((0, 0));
+            *count += 1;
+            *directions += direction;
+        }
+
+        uses.values()
+            .all(|&(count, directions)| count == 2 && directions == 0)
+    }
+
+    fn assert_bounding_volume(shell: &Shell, [min, max]: [[f64; 3]; 2]) {
+        let tolerance = 0.001;
+
+        let mesh = (shell, tolerance.into()).triangulate();
+        let aabb = Aabb::<3>::from_points(mesh.vertices());
+
+        // Curved faces are approximated within the tolerance, so the bounding
+        // volume of the mesh may be that much smaller.
+        for (actual, expected) in [(aabb.min, min), (aabb.max, max)] {
+            let within_tolerance = actual
+                .coords
+                .components
+                .into_iter()
+                .zip(expected)
+                .all(|(a, e)| (a.into_f64() - e).abs() <= tolerance);
+            assert!(within_tolerance, "{actual:?} != {expected:?}");
+        }
+    }
 }
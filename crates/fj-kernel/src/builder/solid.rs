@@ -1,6 +1,7 @@
-use fj_math::Scalar;
+use fj_math::{Scalar, Vector};
 
 use crate::{
+    algorithms::approx::Tolerance,
     objects::Objects,
     partial::{Partial, PartialShell, PartialSolid},
     services::Service,
@@ -16,6 +17,58 @@ pub trait SolidBuilder {
         edge_length: impl Into<Scalar>,
         objects: &mut Service<Objects>,
     );
+
+    /// Add a cuboid with the given size to the solid
+    ///
+    /// See [`ShellBuilder::create_cuboid_from_size`].
+    fn with_cuboid_from_size(
+        &mut self,
+        size: impl Into<Vector<3>>,
+        objects: &mut Service<Objects>,
+    );
+
+    /// Add a cylinder with the given radius and height to the solid
+    ///
+    /// See [`ShellBuilder::create_cylinder_from_radius_and_height`].
+    fn with_cylinder_from_radius_and_height(
+        &mut self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        objects: &mut Service<Objects>,
+    );
+
+    /// Add a cone with the given radii and height to the solid
+    ///
+    /// See [`ShellBuilder::create_cone_from_radii_and_height`].
+    fn with_cone_from_radii_and_height(
+        &mut self,
+        bottom_radius: impl Into<Scalar>,
+        top_radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    );
+
+    /// Add a sphere with the given radius to the solid
+    ///
+    /// See [`ShellBuilder::create_sphere_from_radius`].
+    fn with_sphere_from_radius(
+        &mut self,
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    );
+
+    /// Add a torus with the given radii to the solid
+    ///
+    /// See [`ShellBuilder::create_torus_from_radii`].
+    fn with_torus_from_radii(
+        &mut self,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    );
 }
 
 impl SolidBuilder for PartialSolid {
@@ -28,4 +81,70 @@ impl SolidBuilder for PartialSolid {
             PartialShell::create_cube_from_edge_length(edge_length, objects);
         self.shells.push(Partial::from_partial(shell));
     }
+
+    fn with_cuboid_from_size(
+        &mut self,
+        size: impl Into<Vector<3>>,
+        objects: &mut Service<Objects>,
+    ) {
+        let shell = PartialShell::create_cuboid_from_size(size, objects);
+        self.shells.push(Partial::from_partial(shell));
+    }
+
+    fn with_cylinder_from_radius_and_height(
+        &mut self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        objects: &mut Service<Objects>,
+    ) {
+        let shell = PartialShell::create_cylinder_from_radius_and_height(
+            radius, height, objects,
+        );
+        self.shells.push(Partial::from_partial(shell));
+    }
+
+    fn with_cone_from_radii_and_height(
+        &mut self,
+        bottom_radius: impl Into<Scalar>,
+        top_radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) {
+        let shell = PartialShell::create_cone_from_radii_and_height(
+            bottom_radius,
+            top_radius,
+            height,
+            tolerance,
+            objects,
+        );
+        self.shells.push(Partial::from_partial(shell));
+    }
+
+    fn with_sphere_from_radius(
+        &mut self,
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) {
+        let shell =
+            PartialShell::create_sphere_from_radius(radius, tolerance, objects);
+        self.shells.push(Partial::from_partial(shell));
+    }
+
+    fn with_torus_from_radii(
+        &mut self,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        objects: &mut Service<Objects>,
+    ) {
+        let shell = PartialShell::create_torus_from_radii(
+            major_radius,
+            minor_radius,
+            tolerance,
+            objects,
+        );
+        self.shells.push(Partial::from_partial(shell));
+    }
 }
@@ -1,6 +1,6 @@
 use std::slice;
 
-use fj_interop::ext::SliceExt;
+use fj_interop::ext::ArrayExt;
 use fj_math::{Scalar, Winding};
 
 use crate::{
@@ -58,54 +58,42 @@ impl Cycle {
     /// two possible windings, depending on the direction you look at the
     /// surface that the cycle is defined on from.
     pub fn winding(&self) -> Winding {
-        // The cycle could be made up of one or two circles. If that is the
-        // case, the winding of the cycle is determined by the winding of the
-        // first circle.
-        if self.half_edges.len() < 3 {
-            let first = self
-                .half_edges()
-                .next()
-                .expect("Invalid cycle: expected at least one half-edge");
-
-            let [a, b] = first.vertices();
-            let edge_direction_positive = a.position() < b.position();
-
-            let circle = match first.curve().path() {
-                SurfacePath::Circle(circle) => circle,
-                SurfacePath::Line(_) => unreachable!(
-                    "Invalid cycle: less than 3 edges, but not all are circles"
-                ),
+        // The winding is determined by the sign of the area that the cycle
+        // encloses. We compute (twice) that area using the shoelace formula,
+        // generalized to work with circular arcs, by integrating `p x dp` along
+        // each half-edge:
+        // https://en.wikipedia.org/wiki/Shoelace_formula
+        //
+        // This also covers cycles that are made up of one or two circles.
+
+        let mut double_area = Scalar::ZERO;
+
+        for half_edge in self.half_edges() {
+            let [a, b] = half_edge
+                .vertices()
+                .each_ref_ext()
+                .map(|vertex| vertex.surface_form().position());
+
+            double_area += match half_edge.curve().path() {
+                SurfacePath::Circle(circle) => {
+                    let [t_a, t_b] = half_edge
+                        .vertices()
+                        .each_ref_ext()
+                        .map(|vertex| vertex.position().t);
+
+                    circle.center().coords.cross2d(&(b - a))
+                        + circle.a().cross2d(&circle.b()) * (t_b - t_a)
+                }
+                SurfacePath::Line(_) => a.coords.cross2d(&b.coords),
             };
-            let cross_positive = circle.a().cross2d(&circle.b()) > Scalar::ZERO;
-
-            if edge_direction_positive == cross_positive {
-                return Winding::Ccw;
-            } else {
-                return Winding::Cw;
-            }
         }
 
-        // Now that we got the special case out of the way, we can treat the
-        // cycle as a polygon:
-        // https://stackoverflow.com/a/1165943
-
-        let mut sum = Scalar::ZERO;
-
-        for [a, b] in self.half_edges.as_slice().array_windows_ext() {
-            let [a, b] = [a, b].map(|half_edge| {
-                let [vertex, _] = half_edge.vertices();
-                vertex.surface_form().position()
-            });
-
-            sum += (b.u - a.u) * (b.v + a.v);
+        if double_area > Scalar::ZERO {
+            return Winding::Ccw;
         }
-
-        if sum > Scalar::ZERO {
+        if double_area < Scalar::ZERO {
             return Winding::Cw;
         }
-        if sum < Scalar::ZERO {
-            return Winding::Ccw;
-        }
 
         unreachable!("Encountered invalid cycle: {self:#?}");
     }
@@ -115,3 +103,81 @@ impl Cycle {
 ///
 /// Returned by [`Cycle::half_edges`].
 pub type HalfEdgesOfCycle<'a> = slice::Iter<'a, Handle<HalfEdge>>;
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::Winding;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        builder::{CycleBuilder, HalfEdgeBuilder},
+        partial::{Partial, PartialCycle, PartialObject},
+        services::Services,
+    };
+
+    #[test]
+    fn winding() {
+        let mut services = Services::new();
+
+        let surface = Partial::from(services.objects.surfaces.xy_plane());
+
+        let mut ccw = PartialCycle {
+            surface: surface.clone(),
+            ..Default::default()
+        };
+        ccw.update_as_polygon_from_points([
+            [1., 0.],
+            [2., 0.],
+            [2., 1.],
+            [1., 1.],
+        ]);
+        let ccw = ccw.build(&mut services.objects);
+
+        // The winding of this cycle is only visible when including the edge
+        // that closes the cycle, from the last vertex to the first.
+        let mut cw = PartialCycle {
+            surface,
+            ..Default::default()
+        };
+        cw.update_as_polygon_from_points([
+            [2., 1.],
+            [2., 0.],
+            [1., 0.],
+            [1., 1.],
+        ]);
+        let cw = cw.build(&mut services.objects);
+
+        assert_eq!(ccw.winding(), Winding::Ccw);
+        assert_eq!(cw.winding(), Winding::Cw);
+    }
+
+    #[test]
+    fn winding_with_arcs() {
+        let mut services = Services::new();
+
+        let surface = Partial::from(services.objects.surfaces.xy_plane());
+
+        // Only two vertices, so the winding depends entirely on which way the
+        // arc bulges.
+        let half_disc = |angle: f64, services: &mut Services| {
+            let mut cycle = PartialCycle {
+                surface: surface.clone(),
+                ..Default::default()
+            };
+            let half_edges =
+                cycle.update_as_polygon_from_points([[0., 0.], [0., 2.]]);
+            let mut arc = half_edges[1].clone();
+            arc.write().update_as_arc(angle);
+
+            cycle.build(&mut services.objects)
+        };
+
+        let ccw = half_disc(PI, &mut services);
+        let cw = half_disc(-PI, &mut services);
+
+        assert_eq!(ccw.winding(), Winding::Ccw);
+        assert_eq!(cw.winding(), Winding::Cw);
+    }
+}
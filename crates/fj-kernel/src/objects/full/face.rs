@@ -1,6 +1,6 @@
 use std::collections::{btree_set, BTreeSet};
 
-use fj_interop::mesh::Color;
+use fj_interop::mesh::{Color, MaterialId};
 use fj_math::Winding;
 
 use crate::{
@@ -37,6 +37,7 @@ pub struct Face {
     exterior: Handle<Cycle>,
     interiors: Vec<Handle<Cycle>>,
     color: Color,
+    material: Option<MaterialId>,
 }
 
 impl Face {
@@ -45,6 +46,7 @@ impl Face {
         exterior: Handle<Cycle>,
         interiors: impl IntoIterator<Item = Handle<Cycle>>,
         color: Color,
+        material: Option<MaterialId>,
     ) -> Self {
         let interiors = interiors.into_iter().collect();
 
@@ -52,6 +54,7 @@ impl Face {
             exterior,
             interiors,
             color,
+            material,
         }
     }
 
@@ -82,6 +85,11 @@ impl Face {
         self.color
     }
 
+    /// Access the material of the face, if it has one
+    pub fn material(&self) -> Option<MaterialId> {
+        self.material
+    }
+
     /// Determine handed-ness of the face's front-side coordinate system
     ///
     /// A face is defined on a surface, which has a coordinate system. Since
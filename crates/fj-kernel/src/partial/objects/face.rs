@@ -1,4 +1,4 @@
-use fj_interop::mesh::Color;
+use fj_interop::mesh::{Color, MaterialId};
 
 use crate::{
     objects::{Cycle, Face, Objects},
@@ -19,6 +19,9 @@ pub struct PartialFace {
 
     /// The color of the face
     pub color: Option<Color>,
+
+    /// The material of the face, if it has one
+    pub material: Option<MaterialId>,
 }
 
 impl PartialObject for PartialFace {
@@ -32,6 +35,7 @@ impl PartialObject for PartialFace {
                 .map(|cycle| Partial::from_full(cycle.clone(), cache))
                 .collect(),
             color: Some(face.color()),
+            material: face.material(),
         }
     }
 
@@ -41,6 +45,6 @@ impl PartialObject for PartialFace {
             self.interiors.into_iter().map(|cycle| cycle.build(objects));
         let color = self.color.unwrap_or_default();
 
-        Face::new(exterior, interiors, color)
+        Face::new(exterior, interiors, color, self.material)
     }
 }
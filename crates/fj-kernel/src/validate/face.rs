@@ -138,7 +138,12 @@ mod tests {
                 .insert(&mut services.objects);
 
             let interiors = [cycle];
-            Face::new(valid.exterior().clone(), interiors, valid.color())
+            Face::new(
+                valid.exterior().clone(),
+                interiors,
+                valid.color(),
+                valid.material(),
+            )
         };
 
         assert!(valid.validate().is_ok());
@@ -165,7 +170,12 @@ mod tests {
                 .map(|cycle| cycle.reverse(&mut services.objects))
                 .collect::<Vec<_>>();
 
-            Face::new(valid.exterior().clone(), interiors, valid.color())
+            Face::new(
+                valid.exterior().clone(),
+                interiors,
+                valid.color(),
+                valid.material(),
+            )
         };
 
         assert!(valid.validate().is_ok());
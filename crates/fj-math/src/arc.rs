@@ -0,0 +1,130 @@
+use crate::{Point, Scalar, Vector};
+
+/// A 2-dimensional arc
+///
+/// An arc is a section of a circle. It is defined by the circle's center and
+/// radius, and the angles at which it starts and ends. If the end angle is
+/// larger than the start angle, the arc goes counterclockwise; otherwise it
+/// goes clockwise.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Arc {
+    center: Point<2>,
+    radius: Scalar,
+    start_angle: Scalar,
+    end_angle: Scalar,
+}
+
+impl Arc {
+    /// Construct an `Arc` from two endpoints and the angle between them
+    ///
+    /// A positive angle results in a counterclockwise arc, a negative angle in
+    /// a clockwise one.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The endpoints must not be the same.
+    /// - The absolute value of the angle must be larger than zero and smaller
+    ///   than a full turn.
+    pub fn from_endpoints_and_angle(
+        p0: impl Into<Point<2>>,
+        p1: impl Into<Point<2>>,
+        angle_rad: impl Into<Scalar>,
+    ) -> Self {
+        let p0 = p0.into();
+        let p1 = p1.into();
+        let angle_rad = angle_rad.into();
+
+        assert_ne!(p0, p1, "Endpoints of arc must not be the same");
+        assert!(
+            angle_rad != Scalar::ZERO && angle_rad.abs() < Scalar::TAU,
+            "Arc angle must be non-zero and less than a full turn"
+        );
+
+        let chord = p1 - p0;
+        let half_angle = angle_rad.abs() / 2.;
+
+        let radius = chord.magnitude() / (half_angle.sin() * 2.);
+
+        // The center lies on the perpendicular bisector of the chord. For a
+        // counterclockwise arc that is less than a half turn, it's on the left
+        // side of the chord. Larger arcs, or clockwise ones, flip that.
+        let left = Vector::from([-chord.v, chord.u]).normalize();
+        let distance_to_center =
+            radius * half_angle.cos() * angle_rad.sign().to_scalar();
+        let center = p0 + chord / 2. + left * distance_to_center;
+
+        let start = p0 - center;
+        let start_angle = Scalar::atan2(start.v, start.u);
+        let end_angle = start_angle + angle_rad;
+
+        Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        }
+    }
+
+    /// Access the center point of the arc
+    pub fn center(&self) -> Point<2> {
+        self.center
+    }
+
+    /// Access the radius of the arc
+    pub fn radius(&self) -> Scalar {
+        self.radius
+    }
+
+    /// Access the angle at which the arc starts
+    pub fn start_angle(&self) -> Scalar {
+        self.start_angle
+    }
+
+    /// Access the angle at which the arc ends
+    pub fn end_angle(&self) -> Scalar {
+        self.end_angle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar};
+
+    use super::Arc;
+
+    #[test]
+    fn from_endpoints_and_angle_counterclockwise() {
+        let arc = Arc::from_endpoints_and_angle([1., 0.], [0., 1.], FRAC_PI_2);
+
+        assert_abs_diff_eq!(arc.center(), Point::from([0., 0.]));
+        assert_abs_diff_eq!(arc.radius(), Scalar::ONE);
+        assert_abs_diff_eq!(arc.start_angle(), Scalar::ZERO);
+        assert_abs_diff_eq!(arc.end_angle(), Scalar::from(FRAC_PI_2));
+    }
+
+    #[test]
+    fn from_endpoints_and_angle_clockwise() {
+        let arc = Arc::from_endpoints_and_angle([0., 1.], [1., 0.], -FRAC_PI_2);
+
+        assert_abs_diff_eq!(arc.center(), Point::from([0., 0.]));
+        assert_abs_diff_eq!(arc.radius(), Scalar::ONE);
+        assert_abs_diff_eq!(arc.start_angle(), Scalar::from(FRAC_PI_2));
+        assert_abs_diff_eq!(arc.end_angle(), Scalar::ZERO);
+    }
+
+    #[test]
+    fn from_endpoints_and_angle_more_than_half_turn() {
+        let arc = Arc::from_endpoints_and_angle([1., 0.], [0., -1.], PI * 1.5);
+
+        assert_abs_diff_eq!(arc.center(), Point::from([0., 0.]));
+        assert_abs_diff_eq!(arc.radius(), Scalar::ONE);
+        assert_abs_diff_eq!(arc.start_angle(), Scalar::ZERO);
+        assert_abs_diff_eq!(arc.end_angle(), Scalar::from(PI * 1.5));
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{Point, Scalar};
+
+/// An n-dimensional Bézier curve
+///
+/// The dimensionality of the curve is defined by the const generic `D`
+/// parameter. The degree of the curve is defined by the number of control
+/// points, which includes the start and end points.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct BezierCurve<const D: usize> {
+    control_points: Vec<Point<D>>,
+}
+
+impl<const D: usize> BezierCurve<D> {
+    /// Construct a Bézier curve from its control points
+    ///
+    /// # Panics
+    ///
+    /// Panics, if less than two control points are provided.
+    pub fn from_control_points(
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Self {
+        let control_points = control_points
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+
+        assert!(
+            control_points.len() >= 2,
+            "Bézier curve needs at least two control points"
+        );
+
+        Self { control_points }
+    }
+
+    /// Access the control points of the curve
+    pub fn control_points(&self) -> &[Point<D>] {
+        &self.control_points
+    }
+
+    /// Convert a point in curve coordinates into a `D`-dimensional point
+    ///
+    /// The curve starts at `0.` and ends at `1.`.
+    pub fn point_from_curve_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let t = point.into().t;
+
+        // De Casteljau's algorithm
+        let mut points = self.control_points.clone();
+        while points.len() > 1 {
+            points = points
+                .windows(2)
+                .map(|points| {
+                    // Can't panic, as we passed `2` to `windows`.
+                    let [a, b] = [points[0], points[1]];
+                    a + (b - a) * t
+                })
+                .collect();
+        }
+
+        points[0]
+    }
+
+    /// Approximate the curve with a number of points
+    ///
+    /// Returns `num_segments + 1` points, including the start and end points.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `num_segments` is zero.
+    pub fn approx(&self, num_segments: usize) -> Vec<Point<D>> {
+        assert_ne!(num_segments, 0, "Can't approximate with zero segments");
+
+        (0..=num_segments)
+            .map(|i| {
+                let t = Scalar::from(i as f64) / num_segments as f64;
+                self.point_from_curve_coords([t])
+            })
+            .collect()
+    }
+
+    /// Compute the number of segments that approximate the curve closely
+    ///
+    /// If the curve is approximated with the returned number of segments, using
+    /// [`BezierCurve::approx`], no point on the curve is further than
+    /// `tolerance` away from the approximation.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `tolerance` is not larger than zero.
+    pub fn num_segments(&self, tolerance: impl Into<Scalar>) -> usize {
+        let tolerance = tolerance.into();
+        assert!(
+            tolerance > Scalar::ZERO,
+            "Tolerance must be larger than zero"
+        );
+
+        // The second derivative of the curve is bounded by the second
+        // differences of its control points, times `n * (n - 1)`, for a curve
+        // of degree `n`. The distance between a curve and the line through two
+        // of its points is at most an eighth of that bound, times the square
+        // of the distance between the points in curve coordinates.
+        let degree = self.control_points.len() - 1;
+        let max_second_difference = self
+            .control_points
+            .windows(3)
+            .map(|points| {
+                // Can't panic, as we passed `3` to `windows`.
+                let [a, b, c] = [points[0], points[1], points[2]];
+                (a.coords - b.coords * 2. + c.coords).magnitude()
+            })
+            .fold(Scalar::ZERO, Scalar::max);
+
+        let bound = max_second_difference
+            * (degree * degree.saturating_sub(1)) as f64
+            / 8.;
+        let num_segments = (bound / tolerance).sqrt().ceil().into_f64();
+
+        (num_segments as usize).max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Scalar};
+
+    use super::BezierCurve;
+
+    #[test]
+    fn point_from_curve_coords() {
+        let curve =
+            BezierCurve::from_control_points([[0., 0.], [1., 2.], [2., 0.]]);
+
+        assert_eq!(curve.point_from_curve_coords([0.]), Point::from([0., 0.]));
+        assert_eq!(curve.point_from_curve_coords([0.5]), Point::from([1., 1.]));
+        assert_eq!(curve.point_from_curve_coords([1.]), Point::from([2., 0.]));
+    }
+
+    #[test]
+    fn approx() {
+        let curve = BezierCurve::from_control_points([[0., 0.], [2., 0.]]);
+
+        assert_eq!(
+            curve.approx(2),
+            vec![
+                Point::from([0., 0.]),
+                Point::from([1., 0.]),
+                Point::from([2., 0.]),
+            ]
+        );
+    }
+
+    #[test]
+    fn num_segments() {
+        let line = BezierCurve::from_control_points([[0., 0.], [2., 0.]]);
+        assert_eq!(line.num_segments(0.1), 1);
+
+        let curve =
+            BezierCurve::from_control_points([[0., 0.], [1., 2.], [2., 0.]]);
+        let tolerance = 0.02;
+        let num_segments = curve.num_segments(tolerance);
+
+        // The curve is furthest from each segment of the approximation in the
+        // middle between its points.
+        let points = curve.approx(num_segments);
+        for (i, segment) in points.windows(2).enumerate() {
+            let t = (i as f64 + 0.5) / num_segments as f64;
+            let point = curve.point_from_curve_coords([t]);
+            let middle = segment[0] + (segment[1] - segment[0]) * 0.5;
+
+            assert!(point.distance_to(&middle) <= Scalar::from(tolerance));
+        }
+        assert!(num_segments > 1);
+    }
+}
@@ -6,6 +6,18 @@ use crate::{Point, Scalar, Vector};
 ///
 /// The dimensionality of the circle is defined by the const generic `D`
 /// parameter.
+///
+/// # Ellipses
+///
+/// Applying a non-uniform scaling to a circle turns it into an ellipse. Since
+/// such an ellipse is still defined by a center and two vectors, `Circle` can
+/// represent it, too. See [`Circle::ellipse`].
+///
+/// The only invariant that holds for both is, that `a` and `b` are neither
+/// zero nor parallel. That they are of equal length and perpendicular to each
+/// other, is only guaranteed for circles that are constructed by
+/// [`Circle::new`] or [`Circle::from_center_and_radius`]. Methods that only
+/// make sense for circles document that.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Circle<const D: usize> {
     center: Point<D>,
@@ -33,9 +45,11 @@ impl<const D: usize> Circle<D> {
         let a = a.into();
         let b = b.into();
 
-        assert_eq!(
-            a.magnitude(),
-            b.magnitude(),
+        // Requiring the vectors to be of *precisely* equal length is not
+        // practical, for the same reason as explained below.
+        assert!(
+            (a.magnitude() - b.magnitude()).abs()
+                <= a.magnitude() * Scalar::default_epsilon() * 4.,
             "`a` and `b` must be of equal length"
         );
         assert_ne!(
@@ -54,6 +68,33 @@ impl<const D: usize> Circle<D> {
         Self { center, a, b }
     }
 
+    /// Construct an ellipse
+    ///
+    /// `a` and `b` are conjugate semi-diameters of the ellipse. Unlike with
+    /// [`Circle::new`], they don't need to be of equal length, nor
+    /// perpendicular to each other.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `a` and `b` are parallel, or if either of them is zero.
+    pub fn ellipse(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+
+        let [aa, bb, ab] = [a.dot(&a), b.dot(&b), a.dot(&b)];
+        assert!(
+            aa * bb - ab * ab > aa * bb * Scalar::default_epsilon(),
+            "`a` and `b` must not be parallel, or zero"
+        );
+
+        Self { center, a, b }
+    }
+
     /// Construct a `Circle` from a center point and a radius
     pub fn from_center_and_radius(
         center: impl Into<Point<D>>,
@@ -76,15 +117,34 @@ impl<const D: usize> Circle<D> {
     }
 
     /// Access the radius of the circle
+    ///
+    /// This is the length of [`Self::a`]. An ellipse has no single radius, and
+    /// the length of `a` can be anything between its semi-minor and semi-major
+    /// axis. Use [`Self::max_radius`] for a value that is meaningful for both.
     pub fn radius(&self) -> Scalar {
         self.a().magnitude()
     }
 
+    /// Access the largest distance between the center and the circle
+    ///
+    /// This is the radius of a circle, or the semi-major axis of an ellipse.
+    pub fn max_radius(&self) -> Scalar {
+        let [aa, bb, ab] = [
+            self.a.dot(&self.a),
+            self.b.dot(&self.b),
+            self.a.dot(&self.b),
+        ];
+
+        let difference = ((aa - bb) * (aa - bb) + ab * ab * 4.).sqrt();
+        ((aa + bb + difference) / 2.).sqrt()
+    }
+
     /// Access the vector that defines the starting point of the circle
     ///
     /// The point where this vector points from the circle center, is the zero
-    /// coordinate of the circle's coordinate system. The length of the vector
-    /// defines the circle's radius.
+    /// coordinate of the circle's coordinate system. For a circle, the length
+    /// of the vector is its radius. For an ellipse, it is one of two conjugate
+    /// semi-diameters, together with [`Self::b`].
     ///
     /// Please also refer to [`Self::b`].
     pub fn a(&self) -> Vector<D> {
@@ -93,9 +153,10 @@ impl<const D: usize> Circle<D> {
 
     /// Access the vector that defines the plane of the circle
     ///
-    /// Also defines the direction of the circle's coordinate system. The length
-    /// is equal to the circle's radius, and this vector is perpendicular to
-    /// [`Self::a`].
+    /// Also defines the direction of the circle's coordinate system. For a
+    /// circle, the length is equal to its radius, and this vector is
+    /// perpendicular to [`Self::a`]. For an ellipse, it is the semi-diameter
+    /// that is conjugate to `a`, and neither needs to be the case.
     pub fn b(&self) -> Vector<D> {
         self.b
     }
@@ -112,9 +173,10 @@ impl<const D: usize> Circle<D> {
     /// Converts the provided point into circle coordinates between `0.`
     /// (inclusive) and `PI * 2.` (exclusive).
     ///
-    /// Projects the point onto the circle before computing circle coordinate,
-    /// ignoring the radius. This is done to make this method robust against
-    /// floating point accuracy issues.
+    /// Projects the point into the plane of the circle, and from there onto the
+    /// circle along the line through the center, before computing the circle
+    /// coordinate. This is done to make this method robust against floating
+    /// point accuracy issues. It works the same way for ellipses.
     ///
     /// Callers are advised to be careful about the points they pass, as the
     /// point not being on the curve, intentional or not, will not result in an
@@ -123,8 +185,21 @@ impl<const D: usize> Circle<D> {
         &self,
         point: impl Into<Point<D>>,
     ) -> Point<1> {
-        let vector = (point.into() - self.center).to_uv();
-        let atan = Scalar::atan2(vector.v, vector.u);
+        // Express the point in terms of `a` and `b`, which don't need to be
+        // perpendicular, nor of equal length.
+        let vector = point.into() - self.center;
+        let [aa, bb, ab] = [
+            self.a.dot(&self.a),
+            self.b.dot(&self.b),
+            self.a.dot(&self.b),
+        ];
+        let [va, vb] = [vector.dot(&self.a), vector.dot(&self.b)];
+        let cos = bb * va - ab * vb;
+        let sin = aa * vb - ab * va;
+
+        // Both components are scaled by the same positive determinant of the
+        // Gram matrix, which doesn't change the angle.
+        let atan = Scalar::atan2(sin, cos);
         let coord = if atan >= Scalar::ZERO {
             atan
         } else {
@@ -169,12 +244,34 @@ impl<const D: usize> approx::AbsDiffEq for Circle<D> {
 
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::{FRAC_PI_2, PI};
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use approx::assert_abs_diff_eq;
 
-    use crate::{Point, Vector};
+    use crate::{Point, Scalar, Vector};
 
     use super::Circle;
 
+    #[test]
+    fn max_radius() {
+        let circle = Circle::from_center_and_radius([0., 0.], 2.);
+        assert_eq!(circle.max_radius(), Scalar::from(2.));
+
+        let ellipse = Circle::ellipse([0., 0.], [3., 0.], [0., 1.]);
+        assert_eq!(ellipse.max_radius(), Scalar::from(3.));
+
+        // The same ellipse, defined by a different pair of conjugate
+        // semi-diameters.
+        let [cos, sin] = [FRAC_PI_4.cos(), FRAC_PI_4.sin()];
+        let ellipse =
+            Circle::ellipse([0., 0.], [3. * cos, sin], [-3. * sin, cos]);
+        assert_abs_diff_eq!(
+            ellipse.max_radius(),
+            Scalar::from(3.),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
     #[test]
     fn point_to_circle_coords() {
         let circle = Circle {
@@ -200,4 +297,19 @@ mod tests {
             Point::from([FRAC_PI_2 * 3.]),
         );
     }
+
+    #[test]
+    fn point_to_circle_coords_of_ellipse() {
+        // Neither perpendicular, nor of equal length, nor in the xy-plane
+        let ellipse = Circle::ellipse([1., 2., 3.], [2., 0., 1.], [1., 0., 3.]);
+
+        for angle in [0., 1., 2., 3., 4., 5., 6.] {
+            let point = ellipse.point_from_circle_coords([angle]);
+            assert_abs_diff_eq!(
+                ellipse.point_to_circle_coords(point),
+                Point::from([angle]),
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+    }
 }
@@ -38,6 +38,8 @@
 pub mod robust;
 
 mod aabb;
+mod arc;
+mod bezier;
 mod circle;
 mod coordinates;
 mod line;
@@ -52,6 +54,8 @@ mod vector;
 
 pub use self::{
     aabb::Aabb,
+    arc::Arc,
+    bezier::BezierCurve,
     circle::Circle,
     coordinates::{Uv, Xyz, T},
     line::Line,
@@ -61,26 +61,36 @@ impl Plane {
         self.normal().dot(vector) == Scalar::ZERO
     }
 
+    /// Project a point into the plane
+    ///
+    /// Returns the point in the plane's coordinate system, as defined by the
+    /// origin and the u- and v-vectors.
+    pub fn project_point(&self, point: &Point<3>) -> Point<2> {
+        let coords = self.project_vector(&(point - self.origin()));
+        Point { coords }
+    }
+
     /// Project a vector into the plane
+    ///
+    /// Returns the vector in the plane's coordinate system, as defined by the
+    /// u- and v-vectors. Those don't need to be orthogonal or of unit length.
     pub fn project_vector(&self, vector: &Vector<3>) -> Vector<2> {
-        Vector::from([
-            self.u().scalar_projection_onto(vector),
-            self.v().scalar_projection_onto(vector),
-        ])
+        // Solving the normal equations of `vector = a * u + b * v`, ignoring
+        // the component of `vector` that is orthogonal to the plane.
+        let uu = self.u().dot(&self.u());
+        let uv = self.u().dot(&self.v());
+        let vv = self.v().dot(&self.v());
+        let ud = self.u().dot(vector);
+        let vd = self.v().dot(vector);
+
+        let det = uu * vv - uv * uv;
+
+        Vector::from([(vv * ud - uv * vd) / det, (uu * vd - uv * ud) / det])
     }
 
     /// Project a line into the plane
     pub fn project_line(&self, line: &Line<3>) -> Line<2> {
-        let line_origin_relative_to_plane = line.origin() - self.origin();
-        let line_origin_in_plane = Point {
-            coords: Vector::from([
-                self.u()
-                    .scalar_projection_onto(&line_origin_relative_to_plane),
-                self.v()
-                    .scalar_projection_onto(&line_origin_relative_to_plane),
-            ]),
-        };
-
+        let line_origin_in_plane = self.project_point(&line.origin());
         let line_direction_in_plane = self.project_vector(&line.direction());
 
         Line::from_origin_and_direction(
@@ -89,3 +99,43 @@ impl Plane {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Line, Plane, Point, Vector};
+
+    #[test]
+    fn project_point() {
+        let plane = Plane::from_parametric(
+            Point::from([1., 1., 1.]),
+            Vector::from([2., 0., 0.]),
+            Vector::from([1., 1., 0.]),
+        );
+
+        assert_eq!(
+            plane.project_point(&Point::from([4., 2., 5.])),
+            Point::from([1., 1.]),
+        );
+    }
+
+    #[test]
+    fn project_line() {
+        let plane = Plane::from_parametric(
+            Point::from([0., 0., 1.]),
+            Vector::from([0., 2., 0.]),
+            Vector::from([0., 0., 4.]),
+        );
+        let line = Line::from_origin_and_direction(
+            Point::from([0., 2., 1.]),
+            Vector::from([0., 0., 2.]),
+        );
+
+        assert_eq!(
+            plane.project_line(&line),
+            Line::from_origin_and_direction(
+                Point::from([1., 0.]),
+                Vector::from([0., 0.5]),
+            ),
+        );
+    }
+}
@@ -128,11 +128,26 @@ impl Scalar {
         self.0.round().into()
     }
 
+    /// Compute the square root
+    pub fn sqrt(self) -> Self {
+        self.0.sqrt().into()
+    }
+
+    /// Compute the sine
+    pub fn sin(self) -> Self {
+        self.0.sin().into()
+    }
+
     /// Compute the cosine
     pub fn cos(self) -> Self {
         self.0.cos().into()
     }
 
+    /// Compute the tangent
+    pub fn tan(self) -> Self {
+        self.0.tan().into()
+    }
+
     /// Compute sine and cosine
     pub fn sin_cos(self) -> (Self, Self) {
         let (sin, cos) = self.0.sin_cos();
@@ -40,6 +40,61 @@ impl Transform {
         ))
     }
 
+    /// Construct a scaling
+    ///
+    /// Scales along the x-, y-, and z-axis by the respective component of the
+    /// provided factors. A negative factor mirrors along that axis.
+    pub fn scaling(factors: impl Into<Vector<3>>) -> Self {
+        let factors = factors.into();
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            nalgebra::Matrix4::new_nonuniform_scaling(&factors.to_na()),
+        ))
+    }
+
+    /// Construct a transform from an affine matrix
+    ///
+    /// The matrix is provided as its first three rows. The last row of an
+    /// affine matrix is always `[0., 0., 0., 1.]`.
+    ///
+    /// Returns `None`, if any entry of the matrix is not finite, or if the
+    /// matrix is not invertible. Matrices that are so close to not being
+    /// invertible, that their inverse would be dominated by rounding errors,
+    /// count as not invertible.
+    pub fn from_matrix(rows: [[f64; 4]; 3]) -> Option<Self> {
+        if !rows.iter().flatten().all(|entry| entry.is_finite()) {
+            return None;
+        }
+
+        let last_row = [0., 0., 0., 1.];
+        let matrix = nalgebra::Matrix4::from_fn(|i, j| {
+            rows.get(i).unwrap_or(&last_row)[j]
+        });
+
+        // The determinant can't be larger than the product of the lengths of
+        // the columns. Comparing it to that product, instead of zero, makes the
+        // check independent of the scale of the matrix.
+        let linear = matrix.fixed_view::<3, 3>(0, 0);
+        let scale = linear
+            .column_iter()
+            .map(|column| column.norm())
+            .product::<f64>();
+        if linear.determinant().abs() <= scale * 1e-12 {
+            return None;
+        }
+
+        Some(Self(nalgebra::Transform::from_matrix_unchecked(matrix)))
+    }
+
+    /// Indicate whether this transform mirrors the objects it is applied to
+    ///
+    /// A mirroring transform changes the handedness of a coordinate system,
+    /// meaning it turns objects inside out, unless their orientation is
+    /// corrected.
+    pub fn is_mirroring(&self) -> bool {
+        self.0.matrix().fixed_view::<3, 3>(0, 0).determinant() < 0.
+    }
+
     /// Transform the given point
     pub fn transform_point(&self, point: &Point<3>) -> Point<3> {
         Point::from(self.0.transform_point(&point.to_na()))
@@ -80,8 +135,10 @@ impl Transform {
     }
 
     /// Transform the given circle
+    ///
+    /// If the transform doesn't scale uniformly, the result is an ellipse.
     pub fn transform_circle(&self, circle: &Circle<3>) -> Circle<3> {
-        Circle::new(
+        Circle::ellipse(
             self.transform_point(&circle.center()),
             self.transform_vector(&circle.a()),
             self.transform_vector(&circle.b()),
@@ -121,10 +178,9 @@ impl Transform {
 
     /// Transform the given axis-aligned bounding box
     pub fn transform_aabb(&self, aabb: &Aabb<3>) -> Aabb<3> {
-        Aabb {
-            min: self.transform_point(&aabb.min),
-            max: self.transform_point(&aabb.max),
-        }
+        Aabb::<3>::from_points(
+            aabb.vertices().map(|vertex| self.transform_point(&vertex)),
+        )
     }
 
     /// Exposes the data of this Transform as a slice of f64.
@@ -182,6 +238,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_matrix() {
+        let transform = Transform::from_matrix([
+            [-1., 0., 0., 2.],
+            [0., 1., 0., 0.],
+            [0., 0., 1., 0.],
+        ])
+        .unwrap();
+
+        assert_abs_diff_eq!(
+            transform.transform_point(&Point::from([3., 1., 2.])),
+            Point::from([-1., 1., 2.]),
+            epsilon = Scalar::from(1e-8),
+        );
+        assert!(transform.is_mirroring());
+        assert!(!(transform * transform).is_mirroring());
+        assert!(Transform::scaling([1., -2., 3.]).is_mirroring());
+
+        assert!(Transform::from_matrix([
+            [1., 0., 0., 0.],
+            [0., 0., 0., 0.],
+            [0., 0., 1., 0.],
+        ])
+        .is_none());
+    }
+
+    #[test]
+    fn from_matrix_not_finite() {
+        for entry in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(Transform::from_matrix([
+                [1., 0., 0., 0.],
+                [0., 1., 0., entry],
+                [0., 0., 1., 0.],
+            ])
+            .is_none());
+        }
+    }
+
+    #[test]
+    fn from_matrix_nearly_singular() {
+        // The columns are almost parallel.
+        assert!(Transform::from_matrix([
+            [1., 1., 0., 0.],
+            [1., 1. + 1e-15, 0., 0.],
+            [0., 0., 1., 0.],
+        ])
+        .is_none());
+        assert!(Transform::from_matrix([
+            [1e6, 1e6, 0., 0.],
+            [1e6, 1e6 + 1e-9, 0., 0.],
+            [0., 0., 1e6, 0.],
+        ])
+        .is_none());
+
+        // A small determinant alone doesn't make a matrix singular.
+        assert!(Transform::from_matrix([
+            [1e-6, 0., 0., 0.],
+            [0., 1e-6, 0., 0.],
+            [0., 0., 1e-6, 0.],
+        ])
+        .is_some());
+    }
+
     #[test]
     fn extract_rotation_translation() {
         let rotation =
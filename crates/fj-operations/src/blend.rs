@@ -0,0 +1,214 @@
+use std::ops::Deref;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        sweep::{Blend, SweepWithBlend, SweptEdges},
+    },
+    insert::Insert,
+    objects::{Objects, Solid},
+    services::Service,
+};
+use fj_math::{Aabb, Scalar, Vector};
+
+use super::{Error, Shape};
+
+impl Shape for fj::Fillet {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let blend = Blend::Fillet(Scalar::from(self.radius()));
+        sweep_with_blend(
+            self.sweep(),
+            self.edges(),
+            blend,
+            tolerance,
+            objects,
+            debug_info,
+        )
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        // Blends can add material in concave corners, but never beyond the
+        // faces next to them.
+        self.sweep().bounding_volume()
+    }
+}
+
+impl Shape for fj::Chamfer {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let blend = Blend::Chamfer(Scalar::from(self.distance()));
+        sweep_with_blend(
+            self.sweep(),
+            self.edges(),
+            blend,
+            tolerance,
+            objects,
+            debug_info,
+        )
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        self.sweep().bounding_volume()
+    }
+}
+
+/// Compute a sweep, blending the selected edges
+fn sweep_with_blend(
+    sweep: &fj::Sweep,
+    edges: &fj::EdgeSelection,
+    blend: Blend,
+    tolerance: Tolerance,
+    objects: &mut Service<Objects>,
+    debug_info: &mut DebugInfo,
+) -> Result<Solid, Error> {
+    if sweep.draft().rad() != 0. || sweep.twist() != 0. {
+        return Err(Error::BlendedDraftOrTwist);
+    }
+
+    let sketch = sweep.shape().compute_brep(tolerance, objects, debug_info)?;
+    let sketch = sketch.insert(objects);
+
+    let num_edges = sketch
+        .faces()
+        .into_iter()
+        .flat_map(|face| face.all_cycles())
+        .map(|cycle| cycle.half_edges().count())
+        .sum();
+    let edges = swept_edges(edges, num_edges)?;
+
+    let path = Vector::from(sweep.path());
+    let solid =
+        sketch.sweep_with_blend(path, blend, &edges, tolerance, objects);
+    Ok(solid.deref().clone())
+}
+
+/// Convert a selection of edges into the edges of a sweep
+///
+/// `num_edges` is the number of edges of the swept shape.
+fn swept_edges(
+    edges: &fj::EdgeSelection,
+    num_edges: usize,
+) -> Result<SweptEdges, Error> {
+    let all = || (0..num_edges).collect();
+
+    let edges = match edges {
+        fj::EdgeSelection::All => SweptEdges {
+            along_path: all(),
+            start_cap: all(),
+            end_cap: all(),
+        },
+        fj::EdgeSelection::Vertical => SweptEdges {
+            along_path: all(),
+            ..SweptEdges::default()
+        },
+        fj::EdgeSelection::Top => SweptEdges {
+            end_cap: all(),
+            ..SweptEdges::default()
+        },
+        fj::EdgeSelection::Bottom => SweptEdges {
+            start_cap: all(),
+            ..SweptEdges::default()
+        },
+        fj::EdgeSelection::Indices(indices) => {
+            let mut edges = SweptEdges::default();
+
+            for &index in indices.indices() {
+                let i = index as usize;
+                let selected = match i / num_edges.max(1) {
+                    0 => &mut edges.along_path,
+                    1 => &mut edges.start_cap,
+                    2 => &mut edges.end_cap,
+                    _ => {
+                        return Err(Error::EdgeIndexOutOfRange {
+                            index,
+                            num_edges: num_edges * 3,
+                        })
+                    }
+                };
+                selected.insert(i % num_edges);
+            }
+
+            edges
+        }
+    };
+
+    Ok(edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{algorithms::approx::Tolerance, services::Services};
+
+    use crate::{Error, Shape};
+
+    fn triangle() -> fj::Sweep {
+        let sketch =
+            fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]);
+        fj::Sweep::from_path(sketch.into(), [0., 0., 1.])
+    }
+
+    #[test]
+    fn edge_index_out_of_range() {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+
+        // A triangle has 3 edges, so its sweep has 9.
+        let chamfer = fj::Chamfer::from_edges_and_distance(
+            triangle(),
+            fj::EdgeSelection::from_indices(vec![8, 9]),
+            0.1,
+        );
+        assert!(matches!(
+            chamfer.compute_brep(
+                tolerance,
+                &mut services.objects,
+                &mut DebugInfo::new()
+            ),
+            Err(Error::EdgeIndexOutOfRange {
+                index: 9,
+                num_edges: 9
+            })
+        ));
+    }
+
+    #[test]
+    fn draft_or_twist() {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+
+        let sweeps = [
+            triangle().with_draft(fj::Angle::from_deg(10.)),
+            triangle().with_twist(0.1),
+        ];
+        for sweep in sweeps {
+            let fillet = fj::Fillet::from_edges_and_radius(
+                sweep,
+                fj::EdgeSelection::Vertical,
+                0.1,
+            );
+            assert!(matches!(
+                fillet.compute_brep(
+                    tolerance,
+                    &mut services.objects,
+                    &mut DebugInfo::new()
+                ),
+                Err(Error::BlendedDraftOrTwist)
+            ));
+        }
+    }
+}
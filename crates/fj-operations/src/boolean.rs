@@ -0,0 +1,1296 @@
+//! Infrastructure that is shared between the 3D boolean operations
+//!
+//! All boolean operations work the same way: The faces of each shape are split
+//! along the lines where they are intersected by the faces of the other shape.
+//! Each of the resulting pieces is then classified as being inside, outside, or
+//! on the boundary of the other shape. Which pieces end up in the result (and
+//! whether they are reversed) depends on the operation. Finally, the pieces of
+//! each face that end up in the result are merged again.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    iter,
+};
+
+use fj_interop::ext::ArrayExt;
+use fj_kernel::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        intersect::{
+            ray_face::RayFaceIntersection, FaceFaceIntersection,
+            HorizontalRayToTheRight, Intersect,
+        },
+        reverse::Reverse,
+        triangulate::Triangulate,
+    },
+    builder::{CycleBuilder, FaceBuilder, SurfaceBuilder},
+    geometry::{
+        path::{GlobalPath, SurfacePath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{Face, FaceSet, HalfEdge, Handedness, Objects},
+    partial::{
+        Partial, PartialCycle, PartialFace, PartialObject, PartialSurface,
+    },
+    services::Service,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+use fj_math::{Aabb, Line, Plane, Point, Scalar, Vector, Winding};
+
+use super::boolean_2d::{
+    link_edges, polygon_segments, signed_area, winding_number,
+};
+
+/// A boolean operation
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operation {
+    /// Subtract the second shape from the first one
+    Difference,
+
+    /// Keep what is part of both shapes
+    Intersection,
+
+    /// Keep what is part of either shape
+    Union,
+}
+
+/// Compute a boolean operation between two shapes
+///
+/// The bounding volumes are used to skip work for shapes that can't possibly
+/// interact, and must contain their respective shape.
+///
+/// # Limitations
+///
+/// Curved faces are approximated by planar facets within `tolerance`, unless
+/// the bounding volumes of the shapes don't overlap.
+pub fn compute(
+    operation: Operation,
+    shapes: [FaceSet; 2],
+    bounding_volumes: [Aabb<3>; 2],
+    tolerance: Tolerance,
+    objects: &mut Service<Objects>,
+) -> FaceSet {
+    let [a, b] = shapes;
+    let [aabb_a, aabb_b] = bounding_volumes;
+
+    // Shapes that don't overlap can't interact, and don't need to be split.
+    if !overlap(&aabb_a, &aabb_b) {
+        let faces = match operation {
+            Operation::Difference => a,
+            Operation::Intersection => FaceSet::new(),
+            Operation::Union => {
+                let mut faces = a;
+                faces.extend(b);
+                faces
+            }
+        };
+
+        return faces;
+    }
+
+    // We need to decide whether points lie on a face, which requires some kind
+    // of epsilon value. Deriving it from the size of the model is not perfect,
+    // but works for now.
+    let size = aabb_a.merged(&aabb_b).size().magnitude();
+    let epsilon = size * 1e-9;
+
+    // Faces can only be split along straight lines, so curved faces are
+    // replaced by the facets they would be triangulated into.
+    let [a, b] = [a, b].map(|faces| {
+        if (&faces).into_iter().all(|face| is_polygonal(face)) {
+            return faces;
+        }
+
+        facets(faces, tolerance, size * epsilon, objects)
+    });
+
+    // Pieces are computed independently of each other, which means vertices
+    // that should be shared between pieces might end up at slightly different
+    // positions. We need to snap those, or the resulting faces won't be valid.
+    let mut vertices = GlobalVertices::new([&a, &b]);
+
+    let pieces_a = split_faces(&a, &b, epsilon, &mut vertices, objects);
+    let pieces_b = split_faces(&b, &a, epsilon, &mut vertices, objects);
+
+    let mut pieces = Vec::new();
+
+    for piece in pieces_a {
+        let location = piece.classify(&b, &aabb_b, epsilon);
+
+        let keep = match (operation, location) {
+            (Operation::Difference, Location::Inside) => false,
+            (Operation::Difference, Location::Outside) => true,
+            (
+                Operation::Difference,
+                Location::Boundary { same_orientation },
+            ) => !same_orientation,
+            (Operation::Intersection, Location::Inside) => true,
+            (Operation::Intersection, Location::Outside) => false,
+            (
+                Operation::Intersection,
+                Location::Boundary { same_orientation },
+            ) => same_orientation,
+            (Operation::Union, Location::Inside) => false,
+            (Operation::Union, Location::Outside) => true,
+            (Operation::Union, Location::Boundary { same_orientation }) => {
+                same_orientation
+            }
+        };
+
+        if keep {
+            pieces.push((piece, false));
+        }
+    }
+
+    for piece in pieces_b {
+        let location = piece.classify(&a, &aabb_a, epsilon);
+
+        // Pieces on the boundary of the other shape have already been taken
+        // care of, when handling the pieces of `a`.
+        let keep = match (operation, location) {
+            (_, Location::Boundary { .. }) => None,
+            (Operation::Difference, Location::Inside) => Some(true),
+            (Operation::Intersection, Location::Inside) => Some(false),
+            (Operation::Union, Location::Outside) => Some(false),
+            (_, Location::Inside | Location::Outside) => None,
+        };
+
+        if let Some(reverse) = keep {
+            pieces.push((piece, reverse));
+        }
+    }
+
+    merge_pieces(pieces, vertices.snap_distance, objects)
+}
+
+/// Indicate whether a face is planar, and bounded by straight edges only
+pub fn is_polygonal(face: &Face) -> bool {
+    face_normal(face).is_some()
+        && face.all_cycles().flat_map(|cycle| cycle.half_edges()).all(
+            |half_edge| {
+                matches!(half_edge.curve().path(), SurfacePath::Line(_))
+            },
+        )
+}
+
+/// Replace the curved faces of a shape by planar facets that approximate them
+///
+/// Faces that are planar and bounded by straight edges are kept as they are.
+/// Facets whose doubled area is not larger than `min_double_area` are left out.
+fn facets(
+    faces: FaceSet,
+    tolerance: Tolerance,
+    min_double_area: Scalar,
+    objects: &mut Service<Objects>,
+) -> FaceSet {
+    let mut facets = FaceSet::new();
+
+    for face in faces {
+        if is_polygonal(&face) {
+            facets.extend([face]);
+            continue;
+        }
+
+        for triangle in (&*face).approx(tolerance).triangulate().triangles() {
+            let points = triangle.inner.points();
+
+            let [a, b, c] = points;
+            if (b - a).cross(&(c - a)).magnitude() <= min_double_area {
+                continue;
+            }
+
+            let (surface, coords) = PartialSurface::plane_from_points(points);
+
+            let mut facet = PartialFace::default();
+            facet.exterior.write().surface = Partial::from_partial(surface);
+            let half_edges = facet.update_exterior_as_polygon(coords);
+
+            // Neighboring facets share the points of the triangulation, so the
+            // global vertices must be at exactly those positions.
+            set_global_positions(half_edges, points);
+            facet.color = Some(face.color());
+            facet.material = face.material();
+
+            facets.extend([facet.build(objects).insert(objects)]);
+        }
+    }
+
+    facets
+}
+
+fn overlap(a: &Aabb<3>, b: &Aabb<3>) -> bool {
+    a.min
+        .coords
+        .components
+        .into_iter()
+        .zip(a.max.coords.components)
+        .zip(
+            b.min
+                .coords
+                .components
+                .into_iter()
+                .zip(b.max.coords.components),
+        )
+        .all(|((a_min, a_max), (b_min, b_max))| {
+            a_min <= b_max && b_min <= a_max
+        })
+}
+
+fn split_faces(
+    faces: &FaceSet,
+    other: &FaceSet,
+    epsilon: Scalar,
+    vertices: &mut GlobalVertices,
+    objects: &mut Service<Objects>,
+) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+
+    for face in faces {
+        let mut lines = Vec::new();
+
+        for other_face in other {
+            let intersection =
+                FaceFaceIntersection::compute([face, other_face], objects);
+
+            if let Some(intersection) = intersection {
+                let [curve, _] = intersection.intersection_curves;
+
+                match curve.path() {
+                    SurfacePath::Line(line) => lines.push(line),
+                    SurfacePath::Circle(_) => unreachable!(
+                        "Curved faces have been replaced by planar facets, and \
+                        planar faces intersect along lines"
+                    ),
+                }
+            } else {
+                // Coplanar faces don't intersect, but we still need to
+                // split along their boundary, to find out which parts are
+                // covered by the other face.
+                lines.extend(coplanar_edges(face, other_face, epsilon));
+            }
+        }
+
+        if lines.is_empty() {
+            pieces.push(Piece {
+                face: face.clone(),
+                polygon: None,
+            });
+            continue;
+        }
+
+        let mut polygons = convex_polygons(face);
+        for line in lines {
+            polygons = polygons
+                .into_iter()
+                .flat_map(|polygon| split_polygon(polygon, &line))
+                .collect();
+        }
+
+        let geometry = face.surface().geometry();
+        pieces.extend(polygons.into_iter().filter_map(|polygon| {
+            let polygon = polygon
+                .into_iter()
+                .map(|point| {
+                    let point_global = vertices
+                        .snap(geometry.point_from_surface_coords(point));
+                    (point, point_global)
+                })
+                .collect();
+            let polygon = remove_degenerate_points(polygon)?;
+            Some(Piece {
+                face: face.clone(),
+                polygon: Some(polygon),
+            })
+        }));
+    }
+
+    pieces
+}
+
+/// Project the edges of `other` into the surface of `face`, if they're coplanar
+fn coplanar_edges(face: &Face, other: &Face, epsilon: Scalar) -> Vec<Line<2>> {
+    let (normal, other_normal) = match (face_normal(face), face_normal(other)) {
+        (Some(normal), Some(other_normal)) => (normal, other_normal),
+        _ => return Vec::new(),
+    };
+    if normal.cross(&other_normal).magnitude() > Scalar::from(1e-9) {
+        return Vec::new();
+    }
+
+    let geometry = face.surface().geometry();
+    let project = |point| {
+        project_into_plane(&geometry, point)
+            .expect("Face with a normal must be planar")
+    };
+
+    let (_, distance) = project(
+        other
+            .surface()
+            .geometry()
+            .point_from_surface_coords([0., 0.]),
+    );
+    if distance.abs() > epsilon {
+        return Vec::new();
+    }
+
+    other
+        .all_cycles()
+        .flat_map(|cycle| cycle.half_edges())
+        .map(|half_edge| {
+            if let SurfacePath::Circle(_) = half_edge.curve().path() {
+                unreachable!(
+                    "Curved faces have been replaced by planar facets, which \
+                    are bounded by straight edges"
+                )
+            }
+
+            let points = half_edge.vertices().each_ref_ext().map(|vertex| {
+                let (point, _) = project(vertex.global_form().position());
+                point
+            });
+
+            let (line, _) = Line::from_points(points);
+            line
+        })
+        .collect()
+}
+
+/// Convert the face into convex polygons, in surface coordinates
+///
+/// The polygons have the same winding as the face's exterior.
+fn convex_polygons(face: &Handle<Face>) -> Vec<Vec<Point<2>>> {
+    if face.interiors().next().is_none() {
+        let mut polygon = Vec::new();
+
+        for half_edge in face.exterior().half_edges() {
+            if let SurfacePath::Circle(_) = half_edge.curve().path() {
+                polygon.clear();
+                break;
+            }
+
+            polygon.push(half_edge.back().surface_form().position());
+        }
+
+        if is_convex(&polygon) {
+            return vec![polygon];
+        }
+    }
+
+    // At this point, we know that only faces bounded by straight edges reach
+    // this point (anything else would have failed when computing the face
+    // intersections), which means the triangulation is exact and the value of
+    // the tolerance doesn't matter.
+    let tolerance =
+        Tolerance::from_scalar(1.).expect("Tolerance is larger than zero");
+
+    let geometry = face.surface().geometry();
+
+    (&**face)
+        .approx(tolerance)
+        .triangulate()
+        .triangles()
+        .map(|triangle| {
+            triangle.inner.points().map(|point| {
+                let (point, _) = project_into_plane(&geometry, point)
+                    .expect("Triangulated face must be planar");
+                point
+            })
+        })
+        .map(Vec::from)
+        .collect()
+}
+
+fn is_convex(polygon: &[Point<2>]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let mut has_positive = false;
+    let mut has_negative = false;
+
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let c = polygon[(i + 2) % polygon.len()];
+
+        let cross = (b - *a).cross2d(&(c - b));
+        has_positive |= cross > Scalar::ZERO;
+        has_negative |= cross < Scalar::ZERO;
+    }
+
+    !(has_positive && has_negative)
+}
+
+/// Split a convex polygon along a line
+///
+/// Returns the polygon itself, if the line doesn't cross it.
+fn split_polygon(polygon: Vec<Point<2>>, line: &Line<2>) -> Vec<Vec<Point<2>>> {
+    let epsilon = polygon_size(&polygon) * 1e-9;
+
+    let distances = polygon
+        .iter()
+        .map(|&point| {
+            line.direction().cross2d(&(point - line.origin()))
+                / line.direction().magnitude()
+        })
+        .collect::<Vec<_>>();
+
+    let in_front = |distance: Scalar| distance > epsilon;
+    let behind = |distance: Scalar| distance < -epsilon;
+
+    if !distances.iter().copied().any(in_front)
+        || !distances.iter().copied().any(behind)
+    {
+        return vec![polygon];
+    }
+
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+
+    for i in 0..polygon.len() {
+        let j = (i + 1) % polygon.len();
+
+        let [p_i, p_j] = [polygon[i], polygon[j]];
+        let [d_i, d_j] = [distances[i], distances[j]];
+
+        if !behind(d_i) {
+            front.push(p_i);
+        }
+        if !in_front(d_i) {
+            back.push(p_i);
+        }
+
+        if (in_front(d_i) && behind(d_j)) || (behind(d_i) && in_front(d_j)) {
+            let t = d_i / (d_i - d_j);
+            let point = p_i + (p_j - p_i) * t;
+
+            front.push(point);
+            back.push(point);
+        }
+    }
+
+    vec![front, back]
+}
+
+/// Remove points that have been snapped to the same vertex as their predecessor
+///
+/// Expects the points in surface and global coordinates. Returns `None`, if
+/// what remains isn't a proper polygon.
+fn remove_degenerate_points(
+    polygon: Vec<(Point<2>, Point<3>)>,
+) -> Option<Vec<(Point<2>, Point<3>)>> {
+    let mut points: Vec<(Point<2>, Point<3>)> = Vec::new();
+    for point in polygon {
+        if let Some(last) = points.last() {
+            if last.1 == point.1 {
+                continue;
+            }
+        }
+
+        points.push(point);
+    }
+    while points.len() > 1 {
+        let [first, last] = [points[0], points[points.len() - 1]];
+        if first.1 != last.1 {
+            break;
+        }
+
+        points.pop();
+    }
+
+    if points.len() < 3 {
+        return None;
+    }
+
+    let size = polygon_size(
+        &points.iter().map(|&(point, _)| point).collect::<Vec<_>>(),
+    );
+    let epsilon = size * 1e-9;
+
+    let mut double_area = Scalar::ZERO;
+    for (i, (a, _)) in points.iter().enumerate() {
+        let (b, _) = points[(i + 1) % points.len()];
+        double_area += a.coords.cross2d(&b.coords);
+    }
+    if double_area.abs() <= size * epsilon {
+        return None;
+    }
+
+    Some(points)
+}
+
+fn polygon_size(polygon: &[Point<2>]) -> Scalar {
+    let aabb = Aabb::<2>::from_points(polygon.iter().copied());
+    aabb.min.distance_to(&aabb.max)
+}
+
+/// Merge the pieces of each face, and turn them into faces of the result
+///
+/// Expects each piece together with whether it needs to be reversed.
+///
+/// Faces are split along the whole lines where the faces of the other shape
+/// intersect them, not only where those faces actually are. Pieces of the
+/// same face that end up in the result are merged again. The vertices that
+/// this leaves in the middle of straight edges are removed, unless another
+/// face has a corner there, in which case all faces that share the edge get a
+/// vertex there. Otherwise, the edges of neighboring faces wouldn't line up,
+/// and the result would have T-junctions.
+fn merge_pieces(
+    pieces: Vec<(Piece, bool)>,
+    snap_distance: Scalar,
+    objects: &mut Service<Objects>,
+) -> FaceSet {
+    let mut faces = FaceSet::new();
+    let mut groups: Vec<(Handle<Face>, bool, Vec<Polygon>)> = Vec::new();
+
+    for (piece, reverse) in pieces {
+        let polygon = match piece.polygon {
+            Some(polygon) => polygon,
+            None => {
+                let face = if reverse {
+                    piece.face.reverse(objects)
+                } else {
+                    piece.face
+                };
+                faces.extend([face]);
+                continue;
+            }
+        };
+
+        let group = groups
+            .iter_mut()
+            .find(|(face, r, _)| face.id() == piece.face.id() && *r == reverse);
+        match group {
+            Some((_, _, polygons)) => polygons.push(polygon),
+            None => groups.push((piece.face, reverse, vec![polygon])),
+        }
+    }
+
+    let regions = groups
+        .into_iter()
+        .flat_map(|(face, reverse, polygons)| {
+            outline(&face, polygons, snap_distance)
+                .into_iter()
+                .map(move |region| (face.clone(), reverse, region))
+        })
+        .collect::<Vec<_>>();
+
+    // The faces that have been kept as they are can't lose any vertices, and
+    // neither can the corners of the merged faces. Any other vertices are in
+    // the middle of a straight edge.
+    let mut corners = (&faces)
+        .into_iter()
+        .flat_map(|face| face.all_cycles())
+        .flat_map(|cycle| cycle.half_edges())
+        .map(|half_edge| half_edge.back().global_form().position())
+        .collect::<BTreeSet<_>>();
+    for (_, _, region) in &regions {
+        for polygon in region {
+            for (i, &(_, b)) in polygon.iter().enumerate() {
+                let (_, a) = polygon[(i + polygon.len() - 1) % polygon.len()];
+                let (_, c) = polygon[(i + 1) % polygon.len()];
+
+                if !is_between(a, b, c, snap_distance) {
+                    corners.insert(b);
+                }
+            }
+        }
+    }
+
+    // Each merged face ends up with exactly the corners that lie on its
+    // boundary. Where faces share an edge, they then share its vertices too.
+    for (face, reverse, region) in regions {
+        let geometry = face.surface().geometry();
+
+        let mut polygons = region.into_iter().map(|polygon| {
+            let mut points = Vec::new();
+
+            for (i, &(point, position)) in polygon.iter().enumerate() {
+                let (_, next) = polygon[(i + 1) % polygon.len()];
+
+                if corners.contains(&position) {
+                    points.push((point, position));
+                }
+
+                let mut on_edge = corners
+                    .iter()
+                    .copied()
+                    .filter(|&corner| {
+                        is_between(position, corner, next, snap_distance)
+                    })
+                    .collect::<Vec<_>>();
+                on_edge.sort_by_key(|&corner| corner.distance_to(&position));
+
+                points.extend(on_edge.into_iter().map(|corner| {
+                    let (point, _) = project_into_plane(&geometry, corner)
+                        .expect("Split faces must be planar");
+                    (point, corner)
+                }));
+            }
+
+            points
+        });
+
+        let mut merged = PartialFace::default();
+        merged.exterior.write().surface = Partial::from(face.surface().clone());
+
+        if let Some(exterior) = polygons.next() {
+            let half_edges = merged.update_exterior_as_polygon(
+                exterior.iter().map(|&(point, _)| point),
+            );
+            set_global_positions(
+                half_edges,
+                exterior.into_iter().map(|(_, point)| point),
+            );
+        }
+        for interior in polygons {
+            let mut cycle = PartialCycle {
+                surface: merged.exterior.read().surface.clone(),
+                ..Default::default()
+            };
+            let half_edges = cycle.update_as_polygon_from_points(
+                interior.iter().map(|&(point, _)| point),
+            );
+            set_global_positions(
+                half_edges,
+                interior.into_iter().map(|(_, point)| point),
+            );
+
+            merged.interiors.push(Partial::from_partial(cycle));
+        }
+
+        merged.color = Some(face.color());
+        merged.material = face.material();
+
+        let merged = merged.build(objects).insert(objects);
+        let merged = if reverse {
+            merged.reverse(objects)
+        } else {
+            merged
+        };
+        faces.extend([merged]);
+    }
+
+    faces
+}
+
+/// Compute the outline of the area that the pieces of a face cover
+///
+/// Returns the regions that make up the area. The first polygon of each region
+/// is its exterior, the others are its holes. All polygons have the winding
+/// that the cycles of the face need.
+fn outline(
+    face: &Face,
+    polygons: Vec<Polygon>,
+    snap_distance: Scalar,
+) -> Vec<Vec<Polygon>> {
+    let mut vertices: Vec<(Point<2>, Point<3>)> = Vec::new();
+    let mut edges = Vec::new();
+
+    for polygon in polygons {
+        let indices = polygon
+            .into_iter()
+            .map(|vertex| {
+                let existing = vertices
+                    .iter()
+                    .position(|&(_, position)| position == vertex.1);
+
+                existing.unwrap_or_else(|| {
+                    vertices.push(vertex);
+                    vertices.len() - 1
+                })
+            })
+            .collect::<Vec<_>>();
+
+        edges.extend(
+            indices
+                .iter()
+                .enumerate()
+                .map(|(i, &a)| [a, indices[(i + 1) % indices.len()]]),
+        );
+    }
+
+    // Pieces that touch each other share their edges, which cancel each other
+    // out. Unless one of the pieces has been split somewhere its neighbor
+    // hasn't, due to numerical inaccuracy. Splitting edges at any vertex that
+    // lies on them takes care of that.
+    let mut boundary = BTreeMap::<[usize; 2], usize>::new();
+    for [a, b] in edges {
+        let [start, end] = [a, b].map(|vertex| vertices[vertex].1);
+
+        let mut points = vertices
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(_, point))| {
+                is_between(start, point, end, snap_distance)
+            })
+            .map(|(vertex, &(_, point))| {
+                ((point - start).dot(&(end - start)), vertex)
+            })
+            .collect::<Vec<_>>();
+        points.sort_by_key(|&(t, _)| t);
+
+        let chain = [a]
+            .into_iter()
+            .chain(points.into_iter().map(|(_, vertex)| vertex))
+            .chain([b])
+            .collect::<Vec<_>>();
+
+        for edge in chain.windows(2) {
+            let [a, b] = [edge[0], edge[1]];
+
+            match boundary.get_mut(&[b, a]) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => *boundary.entry([a, b]).or_default() += 1,
+            }
+        }
+    }
+    let edges = boundary
+        .into_iter()
+        .flat_map(|(edge, count)| iter::repeat(edge).take(count))
+        .collect::<Vec<_>>();
+
+    // The polygons have the same winding as the face's exterior. Mirroring
+    // them, if that winding is clockwise, means exteriors always wind
+    // counterclockwise from here on.
+    let mirror = face.exterior().winding() == Winding::Cw;
+    let position = |vertex: usize| {
+        let (point, _) = vertices[vertex];
+        if mirror {
+            Point::from([point.u, -point.v])
+        } else {
+            point
+        }
+    };
+
+    let (exteriors, holes): (Vec<_>, Vec<_>) = link_edges(&edges, position)
+        .into_iter()
+        .filter_map(|loop_edges| {
+            let loop_vertices = loop_edges
+                .into_iter()
+                .map(|edge| edges[edge][0])
+                .collect::<Vec<_>>();
+            let points = loop_vertices
+                .iter()
+                .map(|&vertex| position(vertex))
+                .collect::<Vec<_>>();
+
+            let size = polygon_size(&points);
+            let area = signed_area(&points);
+            if area.abs() <= size * size * 1e-9 {
+                return None;
+            }
+
+            let polygon = loop_vertices
+                .into_iter()
+                .map(|vertex| vertices[vertex])
+                .collect::<Vec<_>>();
+            Some((polygon, points, area))
+        })
+        .partition(|&(_, _, area)| area > Scalar::ZERO);
+
+    // Each hole belongs to the smallest exterior that contains it.
+    let mut regions = exteriors
+        .iter()
+        .map(|(exterior, _, _)| vec![exterior.clone()])
+        .collect::<Vec<_>>();
+    for (hole, points, _) in holes {
+        let point = points[0] + (points[1] - points[0]) * 0.5;
+
+        let exterior = exteriors
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, exterior, _))| {
+                winding_number(point, polygon_segments(exterior)) != 0
+            })
+            .min_by_key(|(_, (_, _, area))| *area)
+            .map(|(i, _)| i);
+
+        if let Some(i) = exterior {
+            regions[i].push(hole);
+        }
+    }
+
+    regions
+}
+
+/// Indicate whether `b` lies on the line segment from `a` to `c`
+///
+/// Points within `snap_distance` of the segment count as being on it, but not
+/// the end points of the segment themselves.
+fn is_between(
+    a: Point<3>,
+    b: Point<3>,
+    c: Point<3>,
+    snap_distance: Scalar,
+) -> bool {
+    if a == c {
+        return false;
+    }
+
+    let ac = c - a;
+    let t = (b - a).dot(&ac) / ac.dot(&ac);
+
+    t > Scalar::ZERO
+        && t < Scalar::ONE
+        && b != a
+        && b != c
+        && (a + ac * t).distance_to(&b) < snap_distance
+}
+
+/// Set the positions of the global vertices that the half-edges start at
+fn set_global_positions(
+    half_edges: Vec<Partial<HalfEdge>>,
+    positions: impl IntoIterator<Item = Point<3>>,
+) {
+    for (half_edge, position) in half_edges.into_iter().zip(positions) {
+        half_edge
+            .read()
+            .back()
+            .read()
+            .surface_form
+            .read()
+            .global_form
+            .clone()
+            .write()
+            .position = Some(position);
+    }
+}
+
+/// Project a point into a planar surface
+///
+/// Returns the point in surface coordinates and its distance from the surface,
+/// or `None`, if the surface is not planar.
+fn project_into_plane(
+    geometry: &SurfaceGeometry,
+    point: Point<3>,
+) -> Option<(Point<2>, Scalar)> {
+    let line = match geometry.u {
+        GlobalPath::Circle(_) => return None,
+        GlobalPath::Line(line) => line,
+    };
+
+    let plane =
+        Plane::from_parametric(line.origin(), line.direction(), geometry.v);
+
+    let point_surface = plane.project_point(&point);
+    let distance = plane.normal().dot(&(point - plane.origin()));
+
+    Some((point_surface, distance))
+}
+
+/// The normal of a face's front side, if the face is planar
+pub fn face_normal(face: &Face) -> Option<Vector<3>> {
+    let geometry = face.surface().geometry();
+    let u = match geometry.u {
+        GlobalPath::Circle(_) => return None,
+        GlobalPath::Line(line) => line.direction(),
+    };
+
+    let normal = u.cross(&geometry.v).normalize();
+
+    match face.coord_handedness() {
+        Handedness::LeftHanded => Some(-normal),
+        Handedness::RightHanded => Some(normal),
+    }
+}
+
+/// A piece of a face, that is entirely inside or outside of the other shape
+struct Piece {
+    face: Handle<Face>,
+
+    /// The polygon that makes up the piece
+    ///
+    /// `None`, if the piece is the whole face.
+    polygon: Option<Polygon>,
+}
+
+/// A polygon, with each point in surface and in global coordinates
+type Polygon = Vec<(Point<2>, Point<3>)>;
+
+impl Piece {
+    fn classify(
+        &self,
+        other: &FaceSet,
+        other_aabb: &Aabb<3>,
+        epsilon: Scalar,
+    ) -> Location {
+        let normal = face_normal(&self.face);
+        let samples = self.sample_points();
+
+        let mut fallback = None;
+
+        for &point in &samples {
+            if let Some(normal) = normal {
+                if let Some(location) =
+                    location_on_boundary(point, normal, other, epsilon)
+                {
+                    return location;
+                }
+            }
+
+            if !other_aabb.contains(point) {
+                return Location::Outside;
+            }
+
+            let (location, is_ambiguous) = cast_ray(point, other);
+            if !is_ambiguous {
+                return location;
+            }
+
+            fallback.get_or_insert(location);
+        }
+
+        // All rays hit edges or vertices. Use the first result we got, which
+        // might be wrong, but is better than nothing.
+        fallback.unwrap_or(Location::Outside)
+    }
+
+    /// Compute points inside the piece, in global coordinates
+    ///
+    /// Returns multiple candidates, as some points might not be suitable for
+    /// classifying the piece.
+    fn sample_points(&self) -> Vec<Point<3>> {
+        let geometry = self.face.surface().geometry();
+
+        let points = match &self.polygon {
+            Some(polygon) => {
+                let centroid = Point {
+                    coords: polygon
+                        .iter()
+                        .map(|(point, _)| point.coords)
+                        .reduce(|a, b| a + b)
+                        .unwrap_or_default()
+                        / polygon.len() as f64,
+                };
+
+                [centroid]
+                    .into_iter()
+                    .chain(polygon.iter().map(|&(vertex, _)| {
+                        centroid + (vertex - centroid) * 0.5
+                    }))
+                    .collect::<Vec<_>>()
+            }
+            None => {
+                // The interior of the face is to the left of the exterior's
+                // half-edges, if the face's coordinate system is right-handed.
+                let side = match self.face.coord_handedness() {
+                    Handedness::LeftHanded => -1.,
+                    Handedness::RightHanded => 1.,
+                };
+
+                self.face
+                    .exterior()
+                    .half_edges()
+                    .map(|half_edge| {
+                        let path = half_edge.curve().path();
+                        let [a, b] = half_edge
+                            .vertices()
+                            .each_ref_ext()
+                            .map(|vertex| vertex.position().t);
+
+                        let middle = (a + b) / 2.;
+                        let point = path.point_from_path_coords([middle]);
+                        let tangent = path
+                            .point_from_path_coords([middle + (b - a) * 1e-3])
+                            - point;
+
+                        point + Vector::from([-tangent.v, tangent.u]) * side
+                    })
+                    .collect()
+            }
+        };
+
+        points
+            .into_iter()
+            .map(|point| geometry.point_from_surface_coords(point))
+            .collect()
+    }
+}
+
+/// Check whether a point lies on one of the faces
+fn location_on_boundary(
+    point: Point<3>,
+    normal: Vector<3>,
+    faces: &FaceSet,
+    epsilon: Scalar,
+) -> Option<Location> {
+    for face in faces {
+        let (point_surface, distance) =
+            match project_into_plane(&face.surface().geometry(), point) {
+                Some(projection) => projection,
+                None => continue,
+            };
+
+        if distance.abs() > epsilon {
+            continue;
+        }
+
+        if (face, &point_surface).intersect().is_some() {
+            let other_normal = face_normal(face)
+                .expect("Face on planar surface must have a normal");
+
+            return Some(Location::Boundary {
+                same_orientation: normal.dot(&other_normal) > Scalar::ZERO,
+            });
+        }
+    }
+
+    None
+}
+
+/// Determine whether the point is inside of the shape bounded by the faces
+///
+/// Also returns, whether the result is ambiguous, due to the ray hitting an
+/// edge or a vertex.
+fn cast_ray(point: Point<3>, faces: &FaceSet) -> (Location, bool) {
+    let ray = HorizontalRayToTheRight { origin: point };
+
+    let mut num_hits = 0;
+    let mut is_ambiguous = false;
+
+    for face in faces {
+        match (&ray, face).intersect() {
+            Some(RayFaceIntersection::RayHitsFace) => num_hits += 2,
+            Some(
+                RayFaceIntersection::RayHitsEdge(_)
+                | RayFaceIntersection::RayHitsVertex(_),
+            ) => {
+                // An edge is usually shared by two faces, so counting it as
+                // half a hit will do the right thing, most of the time.
+                num_hits += 1;
+                is_ambiguous = true;
+            }
+            Some(RayFaceIntersection::RayHitsFaceAndAreParallel) | None => {}
+        }
+    }
+
+    let location = if (num_hits / 2) % 2 == 1 {
+        Location::Inside
+    } else {
+        Location::Outside
+    };
+
+    (location, is_ambiguous)
+}
+
+/// The positions of all global vertices that are part of the result
+///
+/// Used to make sure that vertices which are meant to be identical actually end
+/// up at identical positions.
+struct GlobalVertices {
+    positions: Vec<Point<3>>,
+    snap_distance: Scalar,
+}
+
+impl GlobalVertices {
+    fn new(shapes: [&FaceSet; 2]) -> Self {
+        let positions = shapes
+            .into_iter()
+            .flatten()
+            .flat_map(|face| face.all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| half_edge.back().global_form().position())
+            .collect();
+
+        // Anything closer than this would be rejected by validation anyway.
+        let snap_distance = ValidationConfig::default().distinct_min_distance;
+
+        Self {
+            positions,
+            snap_distance,
+        }
+    }
+
+    /// Return the position of an existing vertex close to the point, if any
+    ///
+    /// Otherwise remembers the point as the position of a new vertex.
+    fn snap(&mut self, point: Point<3>) -> Point<3> {
+        let existing = self
+            .positions
+            .iter()
+            .find(|position| position.distance_to(&point) < self.snap_distance);
+
+        if let Some(&position) = existing {
+            return position;
+        }
+
+        self.positions.push(point);
+        point
+    }
+}
+
+/// The location of a piece, relative to the other shape
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Location {
+    Inside,
+    Outside,
+    Boundary { same_orientation: bool },
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cmp::Ordering, collections::BTreeMap};
+
+    use fj_interop::{debug::DebugInfo, ext::ArrayExt};
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        objects::FaceSet,
+        services::Services,
+    };
+    use fj_math::{Aabb, Point};
+
+    use crate::{Error, Shape};
+
+    /// A cube with an edge length of 2, with `min` as its minimum corner
+    fn cube(min: [f64; 3]) -> fj::Shape {
+        let cuboid = fj::Cuboid::from_size([2., 2., 2.]);
+        let [x, y, z] = min;
+        fj::Transform::translation(cuboid.into(), [x + 1., y + 1., z]).into()
+    }
+
+    fn compute(shape: fj::Shape) -> Result<FaceSet, Error> {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        shape.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )
+    }
+
+    /// Check that each edge of the faces is shared by exactly two of them
+    ///
+    /// Edges are identified by the positions of their vertices, and must be
+    /// used in opposite directions. An edge that ends on another one, instead
+    /// of sharing its vertices, is a T-junction and doesn't count.
+    fn is_closed(faces: &FaceSet) -> bool {
+        let key = |point: Point<3>| {
+            point
+                .coords
+                .components
+                .map(|s| (s.into_f64() * 1e6).round() as i64)
+        };
+
+        let mut uses = BTreeMap::new();
+        for half_edge in faces
+            .into_iter()
+            .flat_map(|face| face.all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+        {
+            let [a, b] = half_edge
+                .vertices()
+                .each_ref_ext()
+                .map(|vertex| key(vertex.global_form().position()));
+
+            // Edges that start and end at the same vertex, like circles, have
+            // no direction that could be checked.
+            let (key, direction) = match a.cmp(&b) {
+                Ordering::Less => ([a, b], 1),
+                Ordering::Equal => ([a, b], 0),
+                Ordering::Greater => ([b, a], -1),
+            };
+
+            let (count, directions) = uses.entry(key).or_insert((0, 0));
+            *count += 1;
+            *directions += direction;
+        }
+
+        uses.values()
+            .all(|&(count, directions)| count == 2 && directions == 0)
+    }
+
+    /// Compute the volume that the faces enclose
+    ///
+    /// The result is only positive, if all faces point outward.
+    fn volume(faces: &FaceSet) -> f64 {
+        let tolerance = Tolerance::from_scalar(0.1).unwrap();
+        let mesh = (faces, tolerance).triangulate();
+
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&b.coords.cross(&c.coords)).into_f64() / 6.
+            })
+            .sum::<f64>();
+
+        (volume * 1e9).round() / 1e9
+    }
+
+    fn aabb(faces: &FaceSet) -> Aabb<3> {
+        let points = faces
+            .into_iter()
+            .flat_map(|face| face.all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| half_edge.back().global_form().position())
+            .collect::<Vec<_>>();
+
+        Aabb::<3>::from_points(points)
+    }
+
+    #[test]
+    fn overlapping_cuboids() -> Result<(), Error> {
+        let [a, b] = [cube([0., 0., 0.]), cube([1., 1., 1.])];
+
+        let union = fj::Union::from_shapes([a.clone(), b.clone()]);
+        let intersection =
+            fj::Intersection::from_shapes([a.clone(), b.clone()]);
+        let difference = fj::Difference::from_shapes([a, b]);
+
+        // Each cube has three faces that are entirely outside of the other
+        // cube, and three faces with a quarter inside of it. The other three
+        // quarters of those faces are merged again.
+        let expected = [
+            (union.into(), 12, 15., [[0., 0., 0.], [3., 3., 3.]]),
+            (intersection.into(), 6, 1., [[1., 1., 1.], [2., 2., 2.]]),
+            (difference.into(), 9, 7., [[0., 0., 0.], [2., 2., 2.]]),
+        ];
+        for (shape, num_faces, expected_volume, [min, max]) in expected {
+            let faces = compute(shape)?;
+
+            assert_eq!((&faces).into_iter().count(), num_faces);
+            assert!(is_closed(&faces));
+            assert_eq!(volume(&faces), expected_volume);
+            assert_eq!(
+                aabb(&faces),
+                Aabb {
+                    min: Point::from(min),
+                    max: Point::from(max),
+                }
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn curved_shapes() -> Result<(), Error> {
+        let cylinder =
+            fj::Shape::from(fj::Cylinder::from_radius_and_height(0.5, 4.));
+
+        // A hole drilled through the cube. The cylinder is approximated by
+        // facets, which lie inside of it, so the hole is a bit smaller.
+        let drill =
+            fj::Transform::translation(cylinder.clone(), [1., 1., -1.]).into();
+        let difference =
+            fj::Difference::from_shapes([cube([0., 0., 0.]), drill]);
+        let faces = compute(difference.into())?;
+
+        let hole = std::f64::consts::PI * 0.5 * 0.5 * 2.;
+        assert!(is_closed(&faces));
+        assert!(volume(&faces) > 8. - hole);
+        assert!(volume(&faces) < 8. - hole * 0.95);
+        assert!((&faces).into_iter().all(|face| super::is_polygonal(face)));
+
+        // Shapes that don't overlap don't need to be split, so their curved
+        // faces are kept.
+        let union = fj::Union::from_shapes([cube([3., 0., 0.]), cylinder]);
+        let faces = compute(union.into())?;
+        assert!(is_closed(&faces));
+        assert!(!(&faces).into_iter().all(|face| super::is_polygonal(face)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,883 @@
+//! Infrastructure that is shared between the 2D boolean operations
+//!
+//! The boundaries of both shapes are approximated by polygons. Each edge of
+//! those polygons is split wherever it touches the boundary of the other shape,
+//! and each of the resulting edges is classified as being inside, outside, or
+//! on the boundary of the other shape. The edges that end up bounding the
+//! result (which ones those are depends on the operation) are then linked up
+//! into new cycles, which are grouped into faces.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+};
+
+use fj_interop::mesh::{Color, MaterialId};
+use fj_kernel::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        reverse::Reverse,
+    },
+    builder::CycleBuilder,
+    insert::Insert,
+    objects::{Cycle, Objects, Sketch, Surface},
+    partial::{
+        Partial, PartialCycle, PartialFace, PartialObject, PartialSketch,
+    },
+    services::Service,
+    storage::Handle,
+};
+use fj_math::{Aabb, Point, Scalar};
+
+use super::{boolean::Operation, Error};
+
+/// Compute a boolean operation between two sketches
+///
+/// The bounding volumes must contain their respective sketch. They are used to
+/// derive the epsilon value that decides whether points are identical.
+///
+/// # Limitations
+///
+/// Curved edges are approximated by straight lines within `tolerance`, unless
+/// the cycle they are part of doesn't touch the boundary of the other sketch
+/// and ends up in the result unchanged.
+///
+/// All faces of both sketches must be defined in the same surface. Sketches on
+/// different workplanes can't be combined.
+pub fn compute(
+    operation: Operation,
+    shapes: [Sketch; 2],
+    bounding_volumes: [Aabb<3>; 2],
+    tolerance: Tolerance,
+    color: [u8; 4],
+    material: Option<MaterialId>,
+    objects: &mut Service<Objects>,
+) -> Result<Sketch, Error> {
+    let surface = match shapes.iter().flat_map(|sketch| sketch.faces()).next() {
+        Some(face) => face.surface().clone(),
+        None => {
+            let sketch = PartialSketch { faces: Vec::new() }
+                .build(objects)
+                .insert(objects);
+            return Ok(sketch.deref().clone());
+        }
+    };
+
+    // Like the 3D boolean operations, we derive the epsilon value, which
+    // decides whether points are identical, from the size of the model.
+    let [aabb_a, aabb_b] = bounding_volumes;
+    let size = aabb_a.merged(&aabb_b).size().magnitude();
+    let epsilon = size * 1e-9;
+
+    let mut vertices = Vertices::new(epsilon);
+    let mut rings = Vec::new();
+
+    for (shape, sketch) in shapes.iter().enumerate() {
+        for face in sketch.faces() {
+            if face.surface() != &surface {
+                return Err(Error::DifferentSurfaces);
+            }
+
+            let cycles = [face.exterior()].into_iter().chain(face.interiors());
+            for (i, cycle) in cycles.enumerate() {
+                let is_exterior = i == 0;
+
+                if let Some(ring) = Ring::new(
+                    shape,
+                    cycle,
+                    is_exterior,
+                    tolerance,
+                    &mut vertices,
+                ) {
+                    rings.push(ring);
+                }
+            }
+        }
+    }
+
+    // Split every edge at the points where it touches the other shape. Since
+    // both shapes are split against each other, edges that overlap end up
+    // connecting the same vertices afterwards.
+    let segments = [0, 1].map(|shape| {
+        rings
+            .iter()
+            .filter(|ring| ring.shape == shape)
+            .flat_map(|ring| ring.polygon_edges())
+            .map(|edge| edge.map(|vertex| vertices.position(vertex)))
+            .collect::<Vec<_>>()
+    });
+    for ring in &mut rings {
+        ring.split(&segments[1 - ring.shape], epsilon, &mut vertices);
+    }
+
+    let edge_sets = [0, 1].map(|shape| {
+        rings
+            .iter()
+            .filter(|ring| ring.shape == shape)
+            .flat_map(|ring| ring.edges.iter().copied())
+            .collect::<BTreeSet<_>>()
+    });
+
+    let mut edges = Vec::new();
+
+    for (index, ring) in rings.iter().enumerate() {
+        let other = 1 - ring.shape;
+
+        for &edge in &ring.edges {
+            let location =
+                classify(edge, &edge_sets[other], &segments[other], &vertices);
+
+            let keep = if ring.shape == 0 {
+                let keep = match (operation, location) {
+                    (Operation::Difference, Location::Inside) => false,
+                    (Operation::Difference, Location::Outside) => true,
+                    (
+                        Operation::Difference,
+                        Location::Boundary { same_orientation },
+                    ) => !same_orientation,
+                    (Operation::Intersection, Location::Inside) => true,
+                    (Operation::Intersection, Location::Outside) => false,
+                    (
+                        Operation::Intersection,
+                        Location::Boundary { same_orientation },
+                    ) => same_orientation,
+                    (Operation::Union, Location::Inside) => false,
+                    (Operation::Union, Location::Outside) => true,
+                    (
+                        Operation::Union,
+                        Location::Boundary { same_orientation },
+                    ) => same_orientation,
+                };
+
+                keep.then_some(false)
+            } else {
+                // Edges on the boundary of both shapes have already been taken
+                // care of, when handling the edges of the first shape.
+                match (operation, location) {
+                    (_, Location::Boundary { .. }) => None,
+                    (Operation::Difference, Location::Inside) => Some(true),
+                    (Operation::Intersection, Location::Inside) => Some(false),
+                    (Operation::Union, Location::Outside) => Some(false),
+                    (_, Location::Inside | Location::Outside) => None,
+                }
+            };
+
+            if let Some(reversed) = keep {
+                let [a, b] = edge;
+                edges.push(Edge {
+                    vertices: if reversed { [b, a] } else { [a, b] },
+                    ring: index,
+                    reversed,
+                });
+            }
+        }
+    }
+
+    // Loops that wind counterclockwise are exteriors, the others are holes.
+    // Each hole belongs to the smallest exterior that contains it.
+    let edge_vertices =
+        edges.iter().map(|edge| edge.vertices).collect::<Vec<_>>();
+    let (exteriors, holes): (Vec<_>, Vec<_>) =
+        link_edges(&edge_vertices, |vertex| vertices.position(vertex))
+            .into_iter()
+            .filter_map(|loop_edges| {
+                Loop::new(
+                    &loop_edges,
+                    &edges,
+                    &rings,
+                    &vertices,
+                    size * epsilon,
+                )
+            })
+            .partition(|l| l.area > Scalar::ZERO);
+
+    let mut interiors = BTreeMap::<usize, Vec<Loop>>::new();
+    for hole in holes {
+        let point = hole.points[0] + (hole.points[1] - hole.points[0]) * 0.5;
+
+        let exterior = exteriors
+            .iter()
+            .enumerate()
+            .filter(|(_, exterior)| {
+                winding_number(point, polygon_segments(&exterior.points)) != 0
+            })
+            .min_by_key(|(_, exterior)| exterior.area)
+            .map(|(i, _)| i);
+
+        if let Some(i) = exterior {
+            interiors.entry(i).or_default().push(hole);
+        }
+    }
+
+    let faces = exteriors
+        .into_iter()
+        .enumerate()
+        .map(|(i, exterior)| {
+            let exterior = exterior.into_cycle(&rings, &surface, objects);
+            let interiors = interiors
+                .remove(&i)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|interior| {
+                    Partial::from(
+                        interior.into_cycle(&rings, &surface, objects),
+                    )
+                })
+                .collect();
+
+            Partial::from_partial(PartialFace {
+                exterior: Partial::from(exterior),
+                interiors,
+                color: Some(Color(color)),
+                material,
+            })
+        })
+        .collect();
+
+    let sketch = PartialSketch { faces }.build(objects).insert(objects);
+    Ok(sketch.deref().clone())
+}
+
+/// Determine the location of an edge, relative to the other shape
+fn classify(
+    edge: [usize; 2],
+    other_edges: &BTreeSet<[usize; 2]>,
+    other_segments: &[[Point<2>; 2]],
+    vertices: &Vertices,
+) -> Location {
+    let [a, b] = edge;
+
+    if other_edges.contains(&[a, b]) {
+        return Location::Boundary {
+            same_orientation: true,
+        };
+    }
+    if other_edges.contains(&[b, a]) {
+        return Location::Boundary {
+            same_orientation: false,
+        };
+    }
+
+    // All edges have been split where they touch the other shape, so the
+    // whole edge is on the same side as its midpoint.
+    let [a, b] = edge.map(|vertex| vertices.position(vertex));
+    let midpoint = a + (b - a) * 0.5;
+
+    if winding_number(midpoint, other_segments.iter().copied()) > 0 {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+/// Link up edges into closed loops
+///
+/// Returns the indices of the edges that make up each loop. Where more than
+/// one edge continues from a vertex, the loop takes the leftmost turn. This
+/// keeps the loops from touching themselves.
+pub(crate) fn link_edges(
+    edges: &[[usize; 2]],
+    position: impl Fn(usize) -> Point<2>,
+) -> Vec<Vec<usize>> {
+    let mut outgoing = BTreeMap::<usize, Vec<usize>>::new();
+    for (i, edge) in edges.iter().enumerate() {
+        outgoing.entry(edge[0]).or_default().push(i);
+    }
+
+    let direction = |edge: &[usize; 2]| {
+        let [a, b] = edge.map(&position);
+        b - a
+    };
+
+    let mut used = vec![false; edges.len()];
+    let mut loops = Vec::new();
+
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+
+        let mut edges_of_loop = vec![start];
+        let mut current = start;
+
+        loop {
+            let incoming = direction(&edges[current]);
+
+            let next = outgoing
+                .get(&edges[current][1])
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|&next| !used[next] || next == start)
+                .max_by_key(|&next| {
+                    let outgoing = direction(&edges[next]);
+                    Scalar::atan2(
+                        incoming.cross2d(&outgoing),
+                        incoming.dot(&outgoing),
+                    )
+                });
+
+            match next {
+                Some(next) if next == start => {
+                    loops.push(edges_of_loop);
+                    break;
+                }
+                Some(next) => {
+                    used[next] = true;
+                    edges_of_loop.push(next);
+                    current = next;
+                }
+                None => {
+                    // The edges don't form a closed loop, which can only
+                    // happen due to numerical problems. Nothing we can do
+                    // about that here.
+                    break;
+                }
+            }
+        }
+    }
+
+    loops
+}
+
+/// Compute how often the polygon edges wind around the point
+///
+/// Counterclockwise loops count positively, clockwise ones negatively.
+pub(crate) fn winding_number(
+    point: Point<2>,
+    segments: impl IntoIterator<Item = [Point<2>; 2]>,
+) -> i32 {
+    let mut winding_number = 0;
+
+    for [a, b] in segments {
+        let side = (b - a).cross2d(&(point - a));
+
+        if a.v <= point.v {
+            if b.v > point.v && side > Scalar::ZERO {
+                winding_number += 1;
+            }
+        } else if b.v <= point.v && side < Scalar::ZERO {
+            winding_number -= 1;
+        }
+    }
+
+    winding_number
+}
+
+/// Compute the signed area of a polygon
+///
+/// The area is positive, if the polygon winds counterclockwise.
+pub(crate) fn signed_area(points: &[Point<2>]) -> Scalar {
+    polygon_segments(points)
+        .map(|[a, b]| a.coords.cross2d(&b.coords))
+        .fold(Scalar::ZERO, |sum, double_area| sum + double_area)
+        / 2.
+}
+
+pub(crate) fn polygon_segments(
+    points: &[Point<2>],
+) -> impl Iterator<Item = [Point<2>; 2]> + '_ {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &a)| [a, points[(i + 1) % points.len()]])
+}
+
+/// Compute the points where another edge touches an edge
+///
+/// Only returns points in the interior of the edge, each together with its
+/// position along the edge.
+fn split_points(
+    edge: [Point<2>; 2],
+    other: [Point<2>; 2],
+    epsilon: Scalar,
+) -> Vec<(Scalar, Point<2>)> {
+    let [a, b] = edge;
+    let [c, d] = other;
+
+    let ab = b - a;
+    let cd = d - c;
+    let length = ab.magnitude();
+
+    let is_interior = |t: Scalar| {
+        t * length > epsilon && (Scalar::ONE - t) * length > epsilon
+    };
+
+    let mut points = Vec::new();
+
+    // The vertices of the other edge might lie on the edge. This also takes
+    // care of overlapping edges.
+    for point in [c, d] {
+        let t = (point - a).dot(&ab) / (length * length);
+        let distance = ab.cross2d(&(point - a)).abs() / length;
+
+        if distance <= epsilon && is_interior(t) {
+            points.push((t, point));
+        }
+    }
+
+    // Or the edges might cross each other.
+    let denominator = ab.cross2d(&cd);
+    if denominator.abs() > length * cd.magnitude() * 1e-9 {
+        let t = (c - a).cross2d(&cd) / denominator;
+        let u = (c - a).cross2d(&ab) / denominator;
+
+        let cd_length = cd.magnitude();
+        if is_interior(t)
+            && u * cd_length >= -epsilon
+            && (Scalar::ONE - u) * cd_length >= -epsilon
+        {
+            points.push((t, a + ab * t));
+        }
+    }
+
+    points
+}
+
+/// The positions of all vertices that are part of the computation
+///
+/// Used to make sure that points which are meant to be identical are
+/// represented by the same vertex.
+struct Vertices {
+    positions: Vec<Point<2>>,
+    snap_distance: Scalar,
+}
+
+impl Vertices {
+    fn new(snap_distance: Scalar) -> Self {
+        Self {
+            positions: Vec::new(),
+            snap_distance,
+        }
+    }
+
+    /// Return the index of an existing vertex close to the point, if any
+    ///
+    /// Otherwise creates a new vertex at the point.
+    fn snap(&mut self, point: Point<2>) -> usize {
+        let existing = self.positions.iter().position(|position| {
+            position.distance_to(&point) <= self.snap_distance
+        });
+
+        existing.unwrap_or_else(|| {
+            self.positions.push(point);
+            self.positions.len() - 1
+        })
+    }
+
+    fn position(&self, vertex: usize) -> Point<2> {
+        self.positions[vertex]
+    }
+}
+
+/// A polygon that approximates a cycle of one of the shapes
+struct Ring {
+    /// The index of the shape the cycle is part of
+    shape: usize,
+
+    /// The cycle that the polygon approximates
+    cycle: Handle<Cycle>,
+
+    /// Whether the polygon has the opposite winding of the cycle
+    ///
+    /// The polygons of exteriors always wind counterclockwise, the polygons of
+    /// interiors always wind clockwise.
+    reversed: bool,
+
+    /// The vertices of the polygon
+    vertices: Vec<usize>,
+
+    /// The edges of the polygon, after splitting them
+    edges: Vec<[usize; 2]>,
+}
+
+impl Ring {
+    fn new(
+        shape: usize,
+        cycle: &Handle<Cycle>,
+        is_exterior: bool,
+        tolerance: Tolerance,
+        vertices: &mut Vertices,
+    ) -> Option<Self> {
+        let mut points = (&**cycle)
+            .approx(tolerance)
+            .points()
+            .into_iter()
+            .map(|point| point.local_form)
+            .collect::<Vec<_>>();
+
+        // The approximation repeats the first point at the end.
+        points.pop();
+
+        let reversed = (signed_area(&points) > Scalar::ZERO) != is_exterior;
+        if reversed {
+            points.reverse();
+        }
+
+        let mut ring_vertices: Vec<usize> = Vec::new();
+        for point in points {
+            let vertex = vertices.snap(point);
+            if ring_vertices.last() != Some(&vertex) {
+                ring_vertices.push(vertex);
+            }
+        }
+        if ring_vertices.len() > 1
+            && ring_vertices.first() == ring_vertices.last()
+        {
+            ring_vertices.pop();
+        }
+
+        if ring_vertices.len() < 3 {
+            return None;
+        }
+
+        Some(Self {
+            shape,
+            cycle: cycle.clone(),
+            reversed,
+            vertices: ring_vertices,
+            edges: Vec::new(),
+        })
+    }
+
+    fn polygon_edges(&self) -> impl Iterator<Item = [usize; 2]> + '_ {
+        self.vertices.iter().enumerate().map(|(i, &a)| {
+            let b = self.vertices[(i + 1) % self.vertices.len()];
+            [a, b]
+        })
+    }
+
+    /// Split the edges of the polygon, where they touch the other shape
+    fn split(
+        &mut self,
+        other: &[[Point<2>; 2]],
+        epsilon: Scalar,
+        vertices: &mut Vertices,
+    ) {
+        let mut edges = Vec::new();
+
+        for [a, b] in self.polygon_edges().collect::<Vec<_>>() {
+            let edge = [a, b].map(|vertex| vertices.position(vertex));
+
+            let mut points = other
+                .iter()
+                .flat_map(|&other| split_points(edge, other, epsilon))
+                .collect::<Vec<_>>();
+            points.sort_by_key(|&(t, _)| t);
+
+            let mut chain = vec![a];
+            for (_, point) in points {
+                let vertex = vertices.snap(point);
+                if !chain.contains(&vertex) && vertex != b {
+                    chain.push(vertex);
+                }
+            }
+            chain.push(b);
+
+            edges.extend(chain.windows(2).map(|edge| [edge[0], edge[1]]));
+        }
+
+        self.edges = edges;
+    }
+
+    /// Indicate whether any edge of the polygon has been split
+    fn is_split(&self) -> bool {
+        self.edges.len() != self.vertices.len()
+    }
+}
+
+/// An edge that bounds the result
+struct Edge {
+    vertices: [usize; 2],
+
+    /// The index of the ring that the edge comes from
+    ring: usize,
+
+    /// Whether the edge has the opposite direction of the ring's edge
+    reversed: bool,
+}
+
+/// A closed loop of edges, that becomes a cycle of the result
+struct Loop {
+    /// The points of the loop, in surface coordinates
+    points: Vec<Point<2>>,
+
+    /// The signed area of the loop
+    area: Scalar,
+
+    /// The ring, if the loop is identical to an unsplit ring
+    ///
+    /// Includes whether the loop has the opposite direction of the ring.
+    ring: Option<(usize, bool)>,
+}
+
+impl Loop {
+    fn new(
+        loop_edges: &[usize],
+        edges: &[Edge],
+        rings: &[Ring],
+        vertices: &Vertices,
+        min_area: Scalar,
+    ) -> Option<Self> {
+        let first = &edges[loop_edges[0]];
+        let ring = &rings[first.ring];
+        let is_ring = !ring.is_split()
+            && loop_edges.len() == ring.edges.len()
+            && loop_edges.iter().all(|&edge| {
+                edges[edge].ring == first.ring
+                    && edges[edge].reversed == first.reversed
+            });
+
+        let mut points = loop_edges
+            .iter()
+            .map(|&edge| vertices.position(edges[edge].vertices[0]))
+            .collect::<Vec<_>>();
+
+        if !is_ring {
+            remove_collinear_points(&mut points);
+        }
+        if points.len() < 3 {
+            return None;
+        }
+
+        let area = signed_area(&points);
+        if area.abs() <= min_area {
+            return None;
+        }
+
+        Some(Self {
+            points,
+            area,
+            ring: is_ring.then_some((first.ring, first.reversed)),
+        })
+    }
+
+    fn into_cycle(
+        self,
+        rings: &[Ring],
+        surface: &Handle<Surface>,
+        objects: &mut Service<Objects>,
+    ) -> Handle<Cycle> {
+        if let Some((ring, reversed)) = self.ring {
+            let ring = &rings[ring];
+
+            // The loop is made from an unsplit cycle of one of the shapes. We
+            // can use that cycle as-is, which preserves any curved edges. All
+            // cycles of a face must be defined on the identical surface though,
+            // not just an equal one.
+            if ring.cycle.surface().id() == surface.id() {
+                let cycle = ring.cycle.clone();
+
+                return if ring.reversed != reversed {
+                    cycle.reverse(objects)
+                } else {
+                    cycle
+                };
+            }
+        }
+
+        let mut cycle = PartialCycle {
+            surface: Partial::from(surface.clone()),
+            ..Default::default()
+        };
+        cycle.update_as_polygon_from_points(self.points);
+
+        cycle.build(objects).insert(objects)
+    }
+}
+
+/// Remove points that lie on the straight line between their neighbors
+///
+/// Splitting edges leaves such points behind, where the split edge ends up
+/// being part of the result.
+fn remove_collinear_points(points: &mut Vec<Point<2>>) {
+    while points.len() > 3 {
+        let collinear = (0..points.len()).find(|&i| {
+            let previous = points[(i + points.len() - 1) % points.len()];
+            let next = points[(i + 1) % points.len()];
+
+            let [incoming, outgoing] = [points[i] - previous, next - points[i]];
+            let sin = incoming.cross2d(&outgoing)
+                / (incoming.magnitude() * outgoing.magnitude());
+
+            sin.abs() <= Scalar::from(1e-9)
+                && incoming.dot(&outgoing) > Scalar::ZERO
+        });
+
+        match collinear {
+            Some(i) => {
+                points.remove(i);
+            }
+            None => break,
+        }
+    }
+}
+
+/// The location of an edge, relative to the other shape
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Location {
+    Inside,
+    Outside,
+    Boundary { same_orientation: bool },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::approx::Tolerance, objects::Cycle, services::Services,
+    };
+
+    use crate::{Error, Shape};
+
+    /// The operations, in the order that the expected results are given in
+    type Operations = [fn([fj::Shape2d; 2]) -> fj::Shape2d; 3];
+
+    const OPERATIONS: Operations = [
+        |shapes| fj::Union2d::from_shapes(shapes).into(),
+        |shapes| fj::Intersection2d::from_shapes(shapes).into(),
+        |shapes| fj::Difference2d::from_shapes(shapes).into(),
+    ];
+
+    /// The area of the exterior, and the areas of the holes, of each face
+    type Areas = Vec<(f64, Vec<f64>)>;
+
+    fn square([x, y]: [f64; 2], size: f64) -> Vec<[f64; 2]> {
+        vec![[x, y], [x + size, y], [x + size, y + size], [x, y + size]]
+    }
+
+    fn sketch(points: Vec<[f64; 2]>) -> fj::Shape2d {
+        fj::Sketch::from_points(points).into()
+    }
+
+    /// A square with a side length of 4, and a square hole of side length 2
+    fn frame() -> fj::Shape2d {
+        fj::Sketch::from_points(square([0., 0.], 4.))
+            .with_interior(fj::PolyChain::from_points(square([1., 1.], 2.)))
+            .into()
+    }
+
+    fn areas(shape: fj::Shape2d) -> Result<Areas, Error> {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        let sketch = shape.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )?;
+
+        let area = |cycle: &Cycle| {
+            let points = cycle
+                .half_edges()
+                .map(|half_edge| half_edge.back().surface_form().position())
+                .collect::<Vec<_>>();
+            let area = points
+                .iter()
+                .zip(points.iter().cycle().skip(1))
+                .map(|(a, b)| (a.u * b.v - b.u * a.v).into_f64() / 2.)
+                .sum::<f64>()
+                .abs();
+            (area * 1e9).round() / 1e9
+        };
+
+        let mut areas = sketch
+            .faces()
+            .into_iter()
+            .map(|face| {
+                let mut interiors = face
+                    .interiors()
+                    .map(|cycle| area(cycle))
+                    .collect::<Vec<_>>();
+                interiors.sort_by(f64::total_cmp);
+                (area(face.exterior()), interiors)
+            })
+            .collect::<Vec<_>>();
+        areas.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        Ok(areas)
+    }
+
+    fn check(a: fj::Shape2d, b: fj::Shape2d, expected: [Areas; 3]) {
+        for (operation, expected) in OPERATIONS.into_iter().zip(expected) {
+            let shape = operation([a.clone(), b.clone()]);
+            let areas = areas(shape.clone()).expect("Failed to compute shape");
+            assert_eq!(areas, expected, "Unexpected result of {shape:?}");
+        }
+    }
+
+    #[test]
+    fn overlapping() {
+        check(
+            sketch(square([0., 0.], 2.)),
+            sketch(square([1., 1.], 2.)),
+            [vec![(7., vec![])], vec![(1., vec![])], vec![(3., vec![])]],
+        );
+    }
+
+    #[test]
+    fn disjoint() {
+        check(
+            sketch(square([0., 0.], 2.)),
+            sketch(square([3., 3.], 1.)),
+            [vec![(1., vec![]), (4., vec![])], vec![], vec![(4., vec![])]],
+        );
+    }
+
+    #[test]
+    fn touching() {
+        check(
+            sketch(square([0., 0.], 2.)),
+            sketch(square([2., 0.], 2.)),
+            [vec![(8., vec![])], vec![], vec![(4., vec![])]],
+        );
+    }
+
+    #[test]
+    fn nested() {
+        // The difference of nested shapes is a hole, like it was before the
+        // difference was computed like the other operations.
+        check(
+            sketch(square([0., 0.], 2.)),
+            sketch(square([0.5, 0.5], 1.)),
+            [vec![(4., vec![])], vec![(1., vec![])], vec![(4., vec![1.])]],
+        );
+    }
+
+    #[test]
+    fn different_workplanes() {
+        let a = fj::Sketch::from_points(square([0., 0.], 2.));
+        let b = fj::Sketch::from_points(square([1., 1.], 2.))
+            .with_workplane(fj::Workplane::xz());
+
+        for operation in OPERATIONS {
+            let shape = operation([a.clone().into(), b.clone().into()]);
+            assert!(matches!(areas(shape), Err(Error::DifferentSurfaces)));
+        }
+    }
+
+    #[test]
+    fn holes() {
+        // The other shape covers the right half of the frame, and of its hole.
+        check(
+            frame(),
+            sketch(vec![[2., 0.], [5., 0.], [5., 4.], [2., 4.]]),
+            [
+                vec![(20., vec![2.])],
+                vec![(6., vec![])],
+                vec![(6., vec![])],
+            ],
+        );
+
+        // The other shape is within the hole.
+        check(
+            frame(),
+            sketch(square([1.5, 1.5], 1.)),
+            [
+                vec![(1., vec![]), (16., vec![4.])],
+                vec![],
+                vec![(16., vec![4.])],
+            ],
+        );
+    }
+}
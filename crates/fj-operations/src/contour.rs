@@ -0,0 +1,355 @@
+//! Closed outlines that are assembled into sketches
+//!
+//! Used for shapes that are made from outlines of unknown nesting and winding,
+//! like the glyphs of a font, or the contents of a drawing file.
+
+use std::f64::consts::TAU;
+
+use fj_math::{BezierCurve, Point, Scalar};
+
+/// The number of points at which curves are sampled, to check for containment
+const NUM_SAMPLES_PER_CURVE: usize = 8;
+
+/// Assemble contours into sketches
+///
+/// Each contour that is not within another one becomes a sketch, with the
+/// contours directly inside of it as holes. Contours within those holes start
+/// the next level of sketches, and so on.
+///
+/// Contours must not overlap.
+pub fn sketches(
+    contours: Vec<Contour>,
+    color: [u8; 4],
+    workplane: &fj::Workplane,
+) -> Vec<fj::Sketch> {
+    let contours = contours
+        .into_iter()
+        .filter(|contour| contour.segments.len() > 1)
+        .collect::<Vec<_>>();
+
+    // Contours don't overlap, so the number of contours that contain any
+    // point of a contour, tells us how deeply it is nested.
+    let containers = contours
+        .iter()
+        .enumerate()
+        .map(|(i, contour)| {
+            contours
+                .iter()
+                .enumerate()
+                .filter(|&(j, other)| j != i && other.contains(contour.start))
+                .map(|(j, _)| j)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut sketches = Vec::new();
+    let mut sketch_of_contour = vec![None; contours.len()];
+
+    for (i, contour) in contours.iter().enumerate() {
+        if containers[i].len() % 2 == 0 {
+            // The exterior of a sketch must wind counterclockwise. Interiors
+            // are fixed up when the sketch is computed.
+            let segments = if contour.signed_area() < 0. {
+                contour.reversed_segments()
+            } else {
+                contour.segments.clone()
+            };
+
+            sketch_of_contour[i] = Some(sketches.len());
+            sketches.push(
+                fj::Sketch::from_segments(segments)
+                    .with_color(color)
+                    .with_workplane(workplane.clone()),
+            );
+        }
+    }
+
+    for (i, contour) in contours.iter().enumerate() {
+        let depth = containers[i].len();
+        if depth % 2 == 0 {
+            continue;
+        }
+
+        // The hole belongs to the innermost of the contours that contain it.
+        let parent = containers[i]
+            .iter()
+            .find(|&&j| containers[j].len() == depth - 1)
+            .and_then(|&j| sketch_of_contour[j])
+            .expect("Hole must be contained in a contour");
+
+        let sketch = sketches[parent].clone().with_interior(
+            fj::PolyChain::from_segments(contour.segments.clone()),
+        );
+        sketches[parent] = sketch;
+    }
+
+    sketches
+}
+
+/// A closed contour
+pub struct Contour {
+    /// The segments of the contour, ending at its start point
+    segments: Vec<fj::SketchSegment>,
+
+    /// A polygon that approximates the contour, starting at its start point
+    polygon: Vec<Point<2>>,
+
+    /// The point that the contour starts and ends at
+    start: Point<2>,
+}
+
+impl Contour {
+    /// Start a contour at the given point
+    pub fn new(start: impl Into<Point<2>>) -> Self {
+        let start = start.into();
+
+        Self {
+            segments: Vec::new(),
+            polygon: vec![start],
+            start,
+        }
+    }
+
+    /// Add a segment, starting at the end of the previous one
+    ///
+    /// Segments that don't go anywhere are ignored, as they would result in
+    /// invalid edges.
+    pub fn push(&mut self, segment: fj::SketchSegment) {
+        let start = self.polygon[self.polygon.len() - 1];
+        let point = Point::from(segment.point());
+
+        if point == start {
+            return;
+        }
+
+        let samples = match segment {
+            fj::SketchSegment::LineTo { .. } => vec![point],
+            fj::SketchSegment::ArcTo {
+                radius, direction, ..
+            } => {
+                // The center is on the side of the chord that makes the arc
+                // cover less than a half turn.
+                let chord = point - start;
+                let half = chord.magnitude() / 2.;
+                let height = (Scalar::from(radius) * radius - half * half)
+                    .max(Scalar::ZERO)
+                    .sqrt();
+                let left = [-chord.v, chord.u].map(|s| s / (half * 2.));
+                let sign = match direction {
+                    fj::ArcDirection::Counterclockwise => 1.,
+                    fj::ArcDirection::Clockwise => -1.,
+                };
+                let center = start
+                    + chord / 2.
+                    + fj_math::Vector::from(left) * height * sign;
+
+                arc_samples(start, point, center, direction)
+            }
+            fj::SketchSegment::ArcAroundCenterTo {
+                center, direction, ..
+            } => arc_samples(start, point, Point::from(center), direction),
+            fj::SketchSegment::QuadraticBezierTo { control, .. } => {
+                BezierCurve::from_control_points([start, control.into(), point])
+                    .approx(NUM_SAMPLES_PER_CURVE)
+                    .into_iter()
+                    .skip(1)
+                    .collect()
+            }
+            fj::SketchSegment::CubicBezierTo { controls, .. } => {
+                BezierCurve::from_control_points([
+                    start,
+                    controls[0].into(),
+                    controls[1].into(),
+                    point,
+                ])
+                .approx(NUM_SAMPLES_PER_CURVE)
+                .into_iter()
+                .skip(1)
+                .collect()
+            }
+        };
+
+        self.segments.push(segment);
+        self.polygon.extend(samples);
+
+        // The samples of curves might not end exactly where the segment does.
+        // The next segment starts at the latter.
+        if let Some(end) = self.polygon.last_mut() {
+            *end = point;
+        }
+    }
+
+    /// Close the contour with a line back to its start, if necessary
+    pub fn close(&mut self) {
+        let point = [self.start.u, self.start.v].map(|s| s.into_f64());
+        self.push(fj::SketchSegment::LineTo { point });
+    }
+
+    /// Indicate whether the contour contains a point, using the even-odd rule
+    fn contains(&self, point: Point<2>) -> bool {
+        let mut inside = false;
+
+        for (a, b) in
+            self.polygon.iter().zip(self.polygon.iter().cycle().skip(1))
+        {
+            if (a.v > point.v) == (b.v > point.v) {
+                continue;
+            }
+
+            let t = (point.v - a.v) / (b.v - a.v);
+            if point.u < a.u + (b.u - a.u) * t {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+
+    /// Compute the signed area of the contour
+    ///
+    /// The area is positive, if the contour winds counterclockwise.
+    fn signed_area(&self) -> f64 {
+        self.polygon
+            .iter()
+            .zip(self.polygon.iter().cycle().skip(1))
+            .map(|(a, b)| (a.u * b.v - b.u * a.v).into_f64() / 2.)
+            .sum()
+    }
+
+    /// Compute the segments of the contour, going around it in reverse
+    fn reversed_segments(&self) -> Vec<fj::SketchSegment> {
+        let start = [self.start.u, self.start.v].map(|s| s.into_f64());
+
+        self.segments
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, segment)| {
+                // Each segment starts where the previous one ends.
+                let point = match i {
+                    0 => start,
+                    _ => self.segments[i - 1].point(),
+                };
+
+                match *segment {
+                    fj::SketchSegment::LineTo { .. } => {
+                        fj::SketchSegment::LineTo { point }
+                    }
+                    fj::SketchSegment::ArcTo {
+                        radius, direction, ..
+                    } => fj::SketchSegment::ArcTo {
+                        point,
+                        radius,
+                        direction: reversed(direction),
+                    },
+                    fj::SketchSegment::ArcAroundCenterTo {
+                        center,
+                        direction,
+                        ..
+                    } => fj::SketchSegment::ArcAroundCenterTo {
+                        point,
+                        center,
+                        direction: reversed(direction),
+                    },
+                    fj::SketchSegment::QuadraticBezierTo {
+                        control, ..
+                    } => {
+                        fj::SketchSegment::QuadraticBezierTo { control, point }
+                    }
+                    fj::SketchSegment::CubicBezierTo {
+                        controls: [a, b],
+                        ..
+                    } => fj::SketchSegment::CubicBezierTo {
+                        controls: [b, a],
+                        point,
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+impl Contour {
+    /// Access the start point and the segments, rounded to compare them
+    pub fn rounded(&self) -> ([f64; 2], Vec<fj::SketchSegment>) {
+        let round = |point: [f64; 2]| point.map(|s| (s * 1e9).round() / 1e9);
+
+        let start = round([self.start.u, self.start.v].map(|s| s.into_f64()));
+        let segments = self
+            .segments
+            .iter()
+            .map(|segment| match *segment {
+                fj::SketchSegment::LineTo { point } => {
+                    fj::SketchSegment::LineTo {
+                        point: round(point),
+                    }
+                }
+                fj::SketchSegment::ArcTo {
+                    point,
+                    radius,
+                    direction,
+                } => fj::SketchSegment::ArcTo {
+                    point: round(point),
+                    radius,
+                    direction,
+                },
+                fj::SketchSegment::ArcAroundCenterTo {
+                    point,
+                    center,
+                    direction,
+                } => fj::SketchSegment::ArcAroundCenterTo {
+                    point: round(point),
+                    center: round(center),
+                    direction,
+                },
+                fj::SketchSegment::QuadraticBezierTo { control, point } => {
+                    fj::SketchSegment::QuadraticBezierTo {
+                        control: round(control),
+                        point: round(point),
+                    }
+                }
+                fj::SketchSegment::CubicBezierTo { controls, point } => {
+                    fj::SketchSegment::CubicBezierTo {
+                        controls: controls.map(round),
+                        point: round(point),
+                    }
+                }
+            })
+            .collect();
+
+        (start, segments)
+    }
+}
+
+/// Sample the points of an arc, excluding its start point
+fn arc_samples(
+    start: Point<2>,
+    end: Point<2>,
+    center: Point<2>,
+    direction: fj::ArcDirection,
+) -> Vec<Point<2>> {
+    let [a, b] = [start, end].map(|point| {
+        let v = point - center;
+        v.v.into_f64().atan2(v.u.into_f64())
+    });
+    let angle = match direction {
+        fj::ArcDirection::Counterclockwise => (b - a).rem_euclid(TAU),
+        fj::ArcDirection::Clockwise => -(a - b).rem_euclid(TAU),
+    };
+    let radius = (start - center).magnitude();
+
+    (1..=NUM_SAMPLES_PER_CURVE)
+        .map(|i| {
+            let angle = a + angle * i as f64 / NUM_SAMPLES_PER_CURVE as f64;
+            center + [angle.cos(), angle.sin()].map(|s| radius * s)
+        })
+        .collect()
+}
+
+fn reversed(direction: fj::ArcDirection) -> fj::ArcDirection {
+    match direction {
+        fj::ArcDirection::Clockwise => fj::ArcDirection::Counterclockwise,
+        fj::ArcDirection::Counterclockwise => fj::ArcDirection::Clockwise,
+    }
+}
@@ -1,35 +1,37 @@
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
+    algorithms::approx::Tolerance,
     objects::{FaceSet, Objects},
     services::Service,
 };
 use fj_math::Aabb;
 
-use super::Shape;
+use super::{Error, Shape};
 
 impl Shape for fj::Group {
     type Brep = FaceSet;
 
     fn compute_brep(
         &self,
+        tolerance: Tolerance,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
+    ) -> Result<Self::Brep, Error> {
         let mut faces = FaceSet::new();
 
-        let a = self.a.compute_brep(objects, debug_info);
-        let b = self.b.compute_brep(objects, debug_info);
+        let a = self.a.compute_brep(tolerance, objects, debug_info)?;
+        let b = self.b.compute_brep(tolerance, objects, debug_info)?;
 
         faces.extend(a);
         faces.extend(b);
 
-        faces
+        Ok(faces)
     }
 
-    fn bounding_volume(&self) -> Aabb<3> {
-        let a = self.a.bounding_volume();
-        let b = self.b.bounding_volume();
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        let a = self.a.bounding_volume()?;
+        let b = self.b.bounding_volume()?;
 
-        a.merged(&b)
+        Ok(a.merged(&b))
     }
 }
@@ -0,0 +1,281 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{approx::Tolerance, offset::Offset, reverse::Reverse},
+    insert::Insert,
+    objects::{Face, FaceSet, Objects, Shell, Solid},
+    services::Service,
+    storage::Handle,
+    validate::ValidationConfig,
+};
+use fj_math::{Aabb, Scalar, Vector};
+
+use super::{
+    boolean::{self, face_normal, is_polygonal, Operation},
+    workplane, Error, Shape,
+};
+
+impl Shape for fj::Hollow {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let thickness = self.thickness();
+        if !(thickness.is_finite() && thickness > 0.) {
+            return Err(Error::InvalidThickness { thickness });
+        }
+
+        let faces =
+            self.shape().compute_brep(tolerance, objects, debug_info)?;
+        if !(&faces).into_iter().all(|face| is_polygonal(face)) {
+            return Err(Error::CurvedHollow);
+        }
+        let open_faces = open_faces(self.open_faces(), &faces)?;
+
+        let thickness = Scalar::from(thickness);
+        let outer = Shell::new(faces.clone()).insert(objects);
+
+        // Open faces are moved outward instead of inward, so the hollow space
+        // breaks through them.
+        let inner = outer.clone().offset_faces(
+            |face| {
+                if open_faces.contains(face) {
+                    thickness
+                } else {
+                    -thickness
+                }
+            },
+            objects,
+        )?;
+
+        if open_faces.is_empty() {
+            let inner = Shell::new(
+                inner
+                    .faces()
+                    .into_iter()
+                    .map(|face| face.clone().reverse(objects))
+                    .collect::<Vec<_>>(),
+            )
+            .insert(objects);
+
+            return Ok(Solid::new([outer, inner]));
+        }
+
+        let aabb = self.shape().bounding_volume()?;
+        let margin = Vector::from([thickness, thickness, thickness]);
+        let inner_aabb = Aabb {
+            min: aabb.min - margin,
+            max: aabb.max + margin,
+        };
+
+        let faces = boolean::compute(
+            Operation::Difference,
+            [faces, inner.faces().clone()],
+            [aabb, inner_aabb],
+            tolerance,
+            objects,
+        );
+
+        Ok(Solid::new([Shell::new(faces).insert(objects)]))
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        self.shape().bounding_volume()
+    }
+}
+
+/// Find the faces that are left open
+fn open_faces(
+    named_faces: &[fj::NamedFace],
+    faces: &FaceSet,
+) -> Result<Vec<Handle<Face>>, Error> {
+    let tolerance = ValidationConfig::default().distinct_min_distance;
+
+    let mut open_faces = Vec::new();
+    for named_face in named_faces {
+        let [normal, _] = workplane::orientation(named_face);
+        let distance = workplane::distance_of(named_face, faces)
+            .ok_or(Error::MissingFace { face: *named_face })?;
+
+        // The named face might consist of multiple faces in the same plane.
+        open_faces.extend(
+            faces
+                .into_iter()
+                .filter(|face| {
+                    let faces_out = matches!(
+                        face_normal(face),
+                        Some(n) if n.dot(&normal) >= Scalar::ONE - 1e-9
+                    );
+                    let point = face
+                        .surface()
+                        .geometry()
+                        .point_from_surface_coords([0., 0.]);
+
+                    faces_out
+                        && (normal.dot(&point.coords) - distance).abs()
+                            < tolerance
+                })
+                .cloned(),
+        );
+    }
+
+    Ok(open_faces)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, offset::OffsetError},
+        objects::{Face, Solid},
+        services::Services,
+        storage::Handle,
+    };
+    use fj_math::{Point, Scalar};
+
+    use crate::{boolean::face_normal, Error, Shape};
+
+    fn compute(hollow: fj::Hollow) -> Result<Solid, Error> {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        hollow.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )
+    }
+
+    /// A cube with an edge length of 2, centered on the z-axis, on top of the
+    /// xy-plane
+    fn cube() -> fj::Shape {
+        fj::Cuboid::from_size([2., 2., 2.]).into()
+    }
+
+    fn vertices<'a>(
+        faces: impl IntoIterator<Item = &'a Handle<Face>>,
+    ) -> BTreeSet<Point<3>> {
+        faces
+            .into_iter()
+            .flat_map(|face| face.all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| half_edge.back().global_form().position())
+            .collect()
+    }
+
+    #[test]
+    fn closed_cube() -> Result<(), Error> {
+        let solid = compute(fj::Hollow::from_thickness(cube(), 0.25))?;
+
+        assert_eq!(solid.shells().count(), 2);
+        let inner = solid
+            .shells()
+            .map(|shell| shell.faces())
+            .find(|faces| {
+                vertices(*faces).iter().all(|point| point.x < Scalar::ONE)
+            })
+            .unwrap();
+
+        let expected = [-0.75, 0.75]
+            .into_iter()
+            .flat_map(|x| [-0.75, 0.75].map(|y| [x, y]))
+            .flat_map(|[x, y]| [0.25, 1.75].map(|z| Point::from([x, y, z])))
+            .collect::<BTreeSet<_>>();
+        assert_eq!(vertices(inner), expected);
+
+        // The faces of the inner shell bound the hollow space, so they face
+        // toward its center.
+        let center = Point::from([0., 0., 1.]);
+        for face in inner {
+            let normal = face_normal(face).unwrap();
+            let point = face
+                .surface()
+                .geometry()
+                .point_from_surface_coords([0., 0.]);
+            assert!(normal.dot(&(center - point)) > Scalar::ZERO);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn open_cube() -> Result<(), Error> {
+        let hollow = fj::Hollow::from_thickness(cube(), 0.25)
+            .with_open_face(fj::NamedFace::Top);
+        let solid = compute(hollow)?;
+
+        let shells = solid.shells().collect::<Vec<_>>();
+        assert_eq!(shells.len(), 1);
+        let faces = shells[0].faces();
+
+        // Five outer faces, the rim around the opening, and five inner faces
+        assert_eq!(faces.into_iter().count(), 11);
+
+        let rims = faces
+            .into_iter()
+            .filter(|face| face.interiors().count() == 1)
+            .collect::<Vec<_>>();
+        assert_eq!(rims.len(), 1);
+
+        let expected = [1., 0.75]
+            .into_iter()
+            .flat_map(|a| [[-a, -a], [a, -a], [a, a], [-a, a]])
+            .map(|[x, y]| Point::from([x, y, 2.]))
+            .collect::<BTreeSet<_>>();
+        assert_eq!(vertices(rims), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn walls_too_thick() {
+        let cuboid = fj::Cuboid::from_size([1., 1., 1.]);
+        let hollow = fj::Hollow::from_thickness(cuboid, 0.6);
+
+        assert!(matches!(
+            compute(hollow),
+            Err(Error::Offset(OffsetError::TurnsInsideOut { .. }))
+        ));
+    }
+
+    #[test]
+    fn invalid_thickness() {
+        for thickness in [0., -0.1, f64::NAN, f64::INFINITY] {
+            let hollow = fj::Hollow::from_thickness(cube(), thickness);
+
+            assert!(matches!(
+                compute(hollow),
+                Err(Error::InvalidThickness { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn missing_open_face() {
+        // A sweep of a triangle has no planar face that faces right.
+        let sketch =
+            fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]);
+        let sweep = fj::Sweep::from_path(sketch.into(), [0., 0., 1.]);
+        let hollow = fj::Hollow::from_thickness(sweep, 0.1)
+            .with_open_face(fj::NamedFace::Right);
+
+        assert!(matches!(
+            compute(hollow),
+            Err(Error::MissingFace {
+                face: fj::NamedFace::Right
+            })
+        ));
+    }
+
+    #[test]
+    fn curved_shape() {
+        let cylinder = fj::Cylinder::from_radius_and_height(1., 2.);
+        let hollow = fj::Hollow::from_thickness(cylinder, 0.1);
+
+        assert!(matches!(compute(hollow), Err(Error::CurvedHollow)));
+    }
+}
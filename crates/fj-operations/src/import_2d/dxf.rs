@@ -0,0 +1,622 @@
+//! Import of outlines from DXF files
+
+use std::f64::consts::TAU;
+
+use crate::contour::Contour;
+
+use super::Error;
+
+/// The distance within which the ends of lines and arcs are joined
+const TOLERANCE: f64 = 1e-6;
+
+/// Read the outlines of the entities in a DXF file
+///
+/// If any layers are given, only entities on those layers are read. Lines,
+/// arcs, and open polylines are joined into outlines where their ends meet.
+/// Those that don't form closed outlines are ignored.
+pub fn contours(
+    data: &str,
+    layers: &[&str],
+    scale: f64,
+) -> Result<Vec<Contour>, Error> {
+    let mut closed = Vec::new();
+    let mut open = Vec::new();
+
+    for entity in entities(data)? {
+        let is_on_layer = layers.is_empty()
+            || entity.values(8).any(|(_, layer)| layers.contains(&layer));
+        if !is_on_layer {
+            continue;
+        }
+
+        match entity.kind {
+            "LINE" => {
+                let start = entity.point(10, 20)?;
+                let end = entity.point(11, 21)?;
+                open.push(Piece {
+                    start,
+                    segments: vec![Segment::Line(end)],
+                });
+            }
+            "ARC" => {
+                let center = entity.point(10, 20)?;
+                let radius = entity.number(40)?;
+                let [start_angle, end_angle] =
+                    [entity.number(50)?, entity.number(51)?]
+                        .map(f64::to_radians);
+
+                let point = |angle: f64| {
+                    [
+                        center[0] + radius * angle.cos(),
+                        center[1] + radius * angle.sin(),
+                    ]
+                };
+
+                // Arcs go counterclockwise from their start angle.
+                let mut angle = (end_angle - start_angle).rem_euclid(TAU);
+                if angle == 0. {
+                    angle = TAU;
+                }
+                let middle = point(start_angle + angle / 2.);
+
+                open.push(entity.ocs(Piece {
+                    start: point(start_angle),
+                    segments: vec![
+                        Segment::Arc {
+                            end: middle,
+                            center,
+                            counterclockwise: true,
+                        },
+                        Segment::Arc {
+                            end: point(end_angle),
+                            center,
+                            counterclockwise: true,
+                        },
+                    ],
+                }));
+            }
+            "CIRCLE" => {
+                let [x, y] = entity.point(10, 20)?;
+                let radius = entity.number(40)?;
+
+                let arc = |end| Segment::Arc {
+                    end,
+                    center: [x, y],
+                    counterclockwise: true,
+                };
+                closed.push(entity.ocs(Piece {
+                    start: [x + radius, y],
+                    segments: vec![arc([x - radius, y]), arc([x + radius, y])],
+                }));
+            }
+            "LWPOLYLINE" => {
+                let mut vertices: Vec<([f64; 2], f64)> = Vec::new();
+                for &(code, value, line) in &entity.pairs {
+                    let number = || parse(value, line);
+                    match (code, vertices.last_mut()) {
+                        (10, _) => vertices.push(([number()?, 0.], 0.)),
+                        (20, Some((point, _))) => point[1] = number()?,
+                        (42, Some((_, bulge))) => *bulge = number()?,
+                        _ => {}
+                    }
+                }
+
+                let is_closed = entity.number(70).unwrap_or(0.) as i64 & 1 == 1;
+
+                let mut segments = Vec::new();
+                for (i, &(start, bulge)) in vertices.iter().enumerate() {
+                    let end = match vertices.get(i + 1) {
+                        Some(&(end, _)) => end,
+                        None if is_closed => vertices[0].0,
+                        None => break,
+                    };
+                    segments.push(bulge_segment(start, end, bulge));
+                }
+
+                if let Some(&(start, _)) = vertices.first() {
+                    let piece = entity.ocs(Piece { start, segments });
+                    if is_closed {
+                        closed.push(piece);
+                    } else {
+                        open.push(piece);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    closed.extend(chains(open));
+
+    Ok(closed
+        .into_iter()
+        .map(|piece| piece.contour(scale))
+        .collect())
+}
+
+/// Split a DXF file into the entities of its `ENTITIES` section
+fn entities(data: &str) -> Result<Vec<Entity<'_>>, Error> {
+    // A DXF file consists of pairs of lines: A group code, that defines the
+    // meaning of the value on the following line.
+    let lines = data.lines().map(str::trim).collect::<Vec<_>>();
+    let mut pairs = Vec::new();
+    for (i, pair) in lines.chunks_exact(2).enumerate() {
+        let line = i * 2 + 1;
+        let code = pair[0].parse::<i32>().map_err(|_| Error::DxfValue {
+            line,
+            value: pair[0].into(),
+        })?;
+        pairs.push((code, pair[1], line + 1));
+    }
+
+    let mut entities = Vec::new();
+    let mut in_entities = false;
+
+    for (i, &(code, value, _)) in pairs.iter().enumerate() {
+        if code != 0 {
+            continue;
+        }
+
+        match value {
+            "SECTION" => {
+                in_entities =
+                    matches!(pairs.get(i + 1), Some(&(2, "ENTITIES", _)));
+            }
+            "ENDSEC" => {
+                in_entities = false;
+            }
+            kind if in_entities => {
+                let pairs = pairs[i + 1..]
+                    .iter()
+                    .take_while(|&&(code, _, _)| code != 0)
+                    .copied()
+                    .collect();
+                entities.push(Entity { kind, pairs });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(entities)
+}
+
+/// An entity of a DXF file
+struct Entity<'r> {
+    /// The type of entity
+    kind: &'r str,
+
+    /// The group codes and values of the entity, with their line numbers
+    pairs: Vec<(i32, &'r str, usize)>,
+}
+
+impl<'r> Entity<'r> {
+    fn values(&self, code: i32) -> impl Iterator<Item = (usize, &'r str)> + '_ {
+        self.pairs
+            .iter()
+            .filter(move |&&(c, _, _)| c == code)
+            .map(|&(_, value, line)| (line, value))
+    }
+
+    /// Access the number with the given group code
+    ///
+    /// Missing numbers are zero, which is the default for all numbers that are
+    /// read here.
+    fn number(&self, code: i32) -> Result<f64, Error> {
+        match self.values(code).next() {
+            Some((line, value)) => parse(value, line),
+            None => Ok(0.),
+        }
+    }
+
+    fn point(&self, x: i32, y: i32) -> Result<[f64; 2], Error> {
+        Ok([self.number(x)?, self.number(y)?])
+    }
+
+    /// Convert a piece from the entity's coordinate system
+    ///
+    /// Arcs, circles, and polylines are defined in an object coordinate system,
+    /// whose z-axis is the extrusion direction of the entity. Only extrusion
+    /// directions along the z-axis are supported. If it points down, the
+    /// x-axis is mirrored.
+    fn ocs(&self, piece: Piece) -> Piece {
+        match self.number(230) {
+            Ok(z) if z < 0. => piece.mirrored(),
+            _ => piece,
+        }
+    }
+}
+
+/// A chain of segments
+struct Piece {
+    start: [f64; 2],
+    segments: Vec<Segment>,
+}
+
+impl Piece {
+    fn end(&self) -> [f64; 2] {
+        match self.segments.last() {
+            Some(segment) => segment.end(),
+            None => self.start,
+        }
+    }
+
+    fn reversed(self) -> Self {
+        let mut starts = vec![self.start];
+        starts.extend(self.segments.iter().map(Segment::end));
+        let start = starts.pop().unwrap_or(self.start);
+
+        // Each segment ends where the one before it started.
+        let segments = self
+            .segments
+            .into_iter()
+            .zip(starts)
+            .rev()
+            .map(|(segment, end)| match segment {
+                Segment::Line(_) => Segment::Line(end),
+                Segment::Arc {
+                    center,
+                    counterclockwise,
+                    ..
+                } => Segment::Arc {
+                    end,
+                    center,
+                    counterclockwise: !counterclockwise,
+                },
+            })
+            .collect();
+
+        Self { start, segments }
+    }
+
+    fn mirrored(self) -> Self {
+        let mirror = |[x, y]: [f64; 2]| [-x, y];
+
+        Self {
+            start: mirror(self.start),
+            segments: self
+                .segments
+                .into_iter()
+                .map(|segment| match segment {
+                    Segment::Line(end) => Segment::Line(mirror(end)),
+                    Segment::Arc {
+                        end,
+                        center,
+                        counterclockwise,
+                    } => Segment::Arc {
+                        end: mirror(end),
+                        center: mirror(center),
+                        counterclockwise: !counterclockwise,
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    fn contour(self, scale: f64) -> Contour {
+        let scaled = |[x, y]: [f64; 2]| [x * scale, y * scale];
+
+        let mut contour = Contour::new(scaled(self.start));
+        for segment in self.segments {
+            let segment = match segment {
+                Segment::Line(end) => {
+                    fj::SketchSegment::LineTo { point: scaled(end) }
+                }
+                Segment::Arc {
+                    end,
+                    center,
+                    counterclockwise,
+                } => fj::SketchSegment::ArcAroundCenterTo {
+                    point: scaled(end),
+                    center: scaled(center),
+                    direction: if counterclockwise {
+                        fj::ArcDirection::Counterclockwise
+                    } else {
+                        fj::ArcDirection::Clockwise
+                    },
+                },
+            };
+            contour.push(segment);
+        }
+        contour.close();
+
+        contour
+    }
+}
+
+enum Segment {
+    Line([f64; 2]),
+    Arc {
+        end: [f64; 2],
+        center: [f64; 2],
+        counterclockwise: bool,
+    },
+}
+
+impl Segment {
+    fn end(&self) -> [f64; 2] {
+        match *self {
+            Self::Line(end) | Self::Arc { end, .. } => end,
+        }
+    }
+
+    fn set_end(&mut self, point: [f64; 2]) {
+        match self {
+            Self::Line(end) | Self::Arc { end, .. } => *end = point,
+        }
+    }
+}
+
+/// Create the segment between two vertices of a polyline
+///
+/// The bulge is the tangent of a quarter of the angle of the arc between the
+/// vertices. It is positive, if the arc goes counterclockwise, and zero for a
+/// straight line.
+fn bulge_segment(start: [f64; 2], end: [f64; 2], bulge: f64) -> Segment {
+    if bulge == 0. || start == end {
+        return Segment::Line(end);
+    }
+
+    let angle = 4. * bulge.atan();
+    let chord = [end[0] - start[0], end[1] - start[1]];
+    let length = chord[0].hypot(chord[1]);
+
+    // The center is on the perpendicular bisector of the chord.
+    let distance = length / 2. / (angle / 2.).tan();
+    let left = [-chord[1] / length, chord[0] / length];
+    let center = [
+        (start[0] + end[0]) / 2. + left[0] * distance,
+        (start[1] + end[1]) / 2. + left[1] * distance,
+    ];
+
+    Segment::Arc {
+        end,
+        center,
+        counterclockwise: bulge > 0.,
+    }
+}
+
+/// Join pieces into closed chains, where their ends meet
+///
+/// Pieces are reversed, if necessary. Pieces that don't end up in a closed
+/// chain are dropped.
+fn chains(mut pieces: Vec<Piece>) -> Vec<Piece> {
+    let meet = |[ax, ay]: [f64; 2], [bx, by]: [f64; 2]| {
+        (ax - bx).hypot(ay - by) <= TOLERANCE
+    };
+
+    let mut chains = Vec::new();
+
+    while let Some(mut chain) = pieces.pop() {
+        loop {
+            if !chain.segments.is_empty() && meet(chain.end(), chain.start) {
+                // Snap the end to the start, so closing the contour doesn't
+                // add a tiny line.
+                let start = chain.start;
+                if let Some(segment) = chain.segments.last_mut() {
+                    segment.set_end(start);
+                }
+                chains.push(chain);
+                break;
+            }
+
+            let end = chain.end();
+            let next = pieces.iter().position(|piece| {
+                meet(piece.start, end) || meet(piece.end(), end)
+            });
+            let next = match next {
+                Some(i) => pieces.swap_remove(i),
+                None => break,
+            };
+            let next = if meet(next.start, end) {
+                next
+            } else {
+                next.reversed()
+            };
+
+            chain.segments.extend(next.segments);
+        }
+    }
+
+    chains
+}
+
+fn parse(value: &str, line: usize) -> Result<f64, Error> {
+    value.parse().map_err(|_| Error::DxfValue {
+        line,
+        value: value.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fj::{ArcDirection, SketchSegment};
+
+    use crate::import_2d::Error;
+
+    /// Create a DXF file, from its entities
+    fn dxf(entities: &[String]) -> String {
+        format!(
+            "0\nSECTION\n2\nENTITIES\n{}0\nENDSEC\n0\nEOF\n",
+            entities.concat()
+        )
+    }
+
+    /// Create an entity, from its type, layer, and values
+    fn entity(kind: &str, layer: &str, values: &[(i32, f64)]) -> String {
+        let mut entity = format!("0\n{kind}\n8\n{layer}\n");
+        for (code, value) in values {
+            entity.push_str(&format!("{code}\n{value}\n"));
+        }
+
+        entity
+    }
+
+    fn line(point: [f64; 2]) -> SketchSegment {
+        SketchSegment::LineTo { point }
+    }
+
+    fn arc(point: [f64; 2], center: [f64; 2]) -> SketchSegment {
+        SketchSegment::ArcAroundCenterTo {
+            point,
+            center,
+            direction: ArcDirection::Counterclockwise,
+        }
+    }
+
+    fn rectangle() -> String {
+        // The lines are out of order, one is reversed, and one is not part of
+        // a closed outline.
+        dxf(&[
+            entity("LINE", "0", &[(10, 0.), (20, 0.), (11, 10.), (21, 0.)]),
+            entity("LINE", "0", &[(10, 10.), (20, 0.), (11, 10.), (21, 5.)]),
+            entity("LINE", "0", &[(10, 20.), (20, 0.), (11, 30.), (21, 0.)]),
+            entity("LINE", "0", &[(10, 0.), (20, 5.), (11, 10.), (21, 5.)]),
+            entity("LINE", "0", &[(10, 0.), (20, 5.), (11, 0.), (21, 0.)]),
+        ])
+    }
+
+    #[test]
+    fn lines() -> Result<(), Error> {
+        let contours = super::contours(&rectangle(), &[], 1.)?;
+        let contours = contours
+            .iter()
+            .map(|contour| contour.rounded())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            contours,
+            [(
+                [0., 5.],
+                vec![
+                    line([0., 0.]),
+                    line([10., 0.]),
+                    line([10., 5.]),
+                    line([0., 5.]),
+                ]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn arcs() -> Result<(), Error> {
+        let data = dxf(&[
+            entity("CIRCLE", "0", &[(10, 5.), (20, 5.), (40, 2.)]),
+            entity("ARC", "0", &[(40, 1.), (50, 0.), (51, 180.)]),
+            entity("LINE", "0", &[(10, -1.), (20, 0.), (11, 1.), (21, 0.)]),
+            entity(
+                "LWPOLYLINE",
+                "0",
+                &[
+                    (70, 1.),
+                    (10, 20.),
+                    (20, 0.),
+                    (10, 30.),
+                    (20, 0.),
+                    (42, 1.),
+                    (10, 30.),
+                    (20, 10.),
+                    (10, 20.),
+                    (20, 10.),
+                ],
+            ),
+        ]);
+        let contours = super::contours(&data, &[], 1.)?;
+        let contours = contours
+            .iter()
+            .map(|contour| contour.rounded())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            contours,
+            [
+                (
+                    [7., 5.],
+                    vec![arc([3., 5.], [5., 5.]), arc([7., 5.], [5., 5.])]
+                ),
+                (
+                    [20., 0.],
+                    vec![
+                        line([30., 0.]),
+                        arc([30., 10.], [30., 5.]),
+                        line([20., 10.]),
+                        line([20., 0.]),
+                    ]
+                ),
+                (
+                    [-1., 0.],
+                    vec![
+                        line([1., 0.]),
+                        arc([0., 1.], [0., 0.]),
+                        arc([-1., 0.], [0., 0.]),
+                    ]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn layers() -> Result<(), Error> {
+        let data = dxf(&[
+            entity("CIRCLE", "Cut", &[(10, 0.), (40, 1.)]),
+            entity("CIRCLE", "Engrave", &[(10, 2.), (40, 1.)]),
+            entity("CIRCLE", "Other", &[(10, 4.), (40, 1.)]),
+        ]);
+
+        let starts = |layers: &[&str]| -> Result<Vec<[f64; 2]>, Error> {
+            Ok(super::contours(&data, layers, 1.)?
+                .iter()
+                .map(|contour| contour.rounded().0)
+                .collect())
+        };
+
+        assert_eq!(starts(&[])?, [[1., 0.], [3., 0.], [5., 0.]]);
+        assert_eq!(starts(&["Cut", "Engrave"])?, [[1., 0.], [3., 0.]]);
+        assert!(starts(&["missing"])?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn scale() -> Result<(), Error> {
+        let contours = super::contours(&rectangle(), &[], 2.)?;
+        let contours = contours
+            .iter()
+            .map(|contour| contour.rounded())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            contours,
+            [(
+                [0., 10.],
+                vec![
+                    line([0., 0.]),
+                    line([20., 0.]),
+                    line([20., 10.]),
+                    line([0., 10.]),
+                ]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(matches!(
+            super::contours("0\nSECTION\nx\nENTITIES\n", &[], 1.),
+            Err(Error::DxfValue { line: 3, value }) if value == "x"
+        ));
+        assert!(matches!(
+            super::contours(
+                "0\nSECTION\n2\nENTITIES\n0\nCIRCLE\n40\nbig\n0\nENDSEC\n",
+                &[],
+                1.
+            ),
+            Err(Error::DxfValue { line: 8, value }) if value == "big"
+        ));
+    }
+}
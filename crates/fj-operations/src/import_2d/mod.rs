@@ -0,0 +1,118 @@
+//! Import of 2-dimensional shapes from drawing files
+
+mod dxf;
+mod svg;
+
+use std::{fs, io};
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::approx::Tolerance,
+    objects::{Objects, Sketch},
+    services::Service,
+};
+use fj_math::{Aabb, Point};
+
+use super::{
+    contour::{self, Contour},
+    workplane, Shape,
+};
+
+impl Shape for fj::Import2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, crate::Error> {
+        let mut faces = Vec::new();
+        for sketch in sketches(self)? {
+            let sketch = sketch.compute_brep(tolerance, objects, debug_info)?;
+            faces.extend(sketch.faces().clone());
+        }
+
+        Ok(Sketch::new(faces))
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, crate::Error> {
+        let aabb = sketches(self)?
+            .iter()
+            .map(|sketch| sketch.bounding_volume())
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .reduce(|a, b| a.merged(&b));
+
+        match aabb {
+            Some(aabb) => Ok(aabb),
+            None => {
+                let origin = workplane::placement(self.workplane())?
+                    .transform_point(&Point::origin());
+                Ok(Aabb {
+                    min: origin,
+                    max: origin,
+                })
+            }
+        }
+    }
+}
+
+/// Read the outlines of a drawing file, and convert them into sketches
+fn sketches(import: &fj::Import2d) -> Result<Vec<fj::Sketch>, crate::Error> {
+    let contours = contours(import).map_err(|error| crate::Error::Import {
+        path: import.path().into(),
+        error,
+    })?;
+
+    Ok(contour::sketches(
+        contours,
+        import.color(),
+        import.workplane(),
+    ))
+}
+
+fn contours(import: &fj::Import2d) -> Result<Vec<Contour>, Error> {
+    let data = fs::read_to_string(import.path())?;
+
+    match import.format() {
+        fj::ImportFormat::Svg => {
+            svg::contours(&data, &import.layers(), import.scale())
+        }
+        fj::ImportFormat::Dxf => {
+            dxf::contours(&data, &import.layers(), import.scale())
+        }
+    }
+}
+
+/// An error that occurred while reading a drawing file
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The file could not be read
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    /// The SVG file is not valid XML
+    #[error("Invalid XML: {0}")]
+    Xml(#[from] roxmltree::Error),
+
+    /// A value in the SVG file could not be parsed
+    #[error("Invalid value `{value}` for attribute `{attribute}`")]
+    SvgValue {
+        /// The name of the attribute
+        attribute: String,
+
+        /// The value of the attribute
+        value: String,
+    },
+
+    /// A value in the DXF file could not be parsed
+    #[error("Invalid value `{value}` in line {line}")]
+    DxfValue {
+        /// The number of the line that the value is on
+        line: usize,
+
+        /// The value
+        value: String,
+    },
+}
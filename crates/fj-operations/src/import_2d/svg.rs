@@ -0,0 +1,682 @@
+//! Import of outlines from SVG files
+
+use std::{f64::consts::TAU, str::FromStr};
+
+use fj_math::{Point, Transform};
+use svgtypes::{PathParser, PathSegment, PointsParser};
+
+use crate::contour::Contour;
+
+use super::Error;
+
+/// The namespace of the attributes that Inkscape adds to SVG files
+const INKSCAPE_NAMESPACE: &str = "http://www.inkscape.org/namespaces/inkscape";
+
+/// The number of straight lines that approximate an elliptical arc
+const NUM_ELLIPSE_SEGMENTS: usize = 16;
+
+/// Elements whose content is not drawn directly
+const UNDRAWN_ELEMENTS: [&str; 6] =
+    ["clipPath", "defs", "marker", "mask", "pattern", "symbol"];
+
+/// Read the outlines of the shapes in an SVG file
+///
+/// If any layers are given, only shapes within a group that is one of those
+/// layers are read.
+pub fn contours(
+    data: &str,
+    layers: &[&str],
+    scale: f64,
+) -> Result<Vec<Contour>, Error> {
+    let document = roxmltree::Document::parse(data)?;
+
+    // The y-axis of SVG files points down.
+    let flip = Transform::scaling([scale, -scale, 1.]);
+
+    let mut outline = Outline {
+        contours: Vec::new(),
+        transform: flip,
+        current: [0., 0.],
+        start: [0., 0.],
+        is_open: false,
+    };
+
+    for node in document.descendants().filter(|node| node.is_element()) {
+        let is_drawn = node.ancestors().all(|ancestor| {
+            !UNDRAWN_ELEMENTS.contains(&ancestor.tag_name().name())
+        });
+        let is_on_layer = layers.is_empty()
+            || node.ancestors().any(|ancestor| {
+                ancestor.has_tag_name("g")
+                    && [
+                        ancestor.attribute((INKSCAPE_NAMESPACE, "label")),
+                        ancestor.attribute("id"),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .any(|name| layers.contains(&name))
+            });
+        if !is_drawn || !is_on_layer {
+            continue;
+        }
+
+        // Transforms of outer elements apply last.
+        let mut transform = flip;
+        for ancestor in node.ancestors().collect::<Vec<_>>().into_iter().rev() {
+            if let Some(value) = ancestor.attribute("transform") {
+                let t = svgtypes::Transform::from_str(value)
+                    .map_err(|_| invalid("transform", value))?;
+                transform = transform
+                    * Transform::from_matrix([
+                        [t.a, t.c, 0., t.e],
+                        [t.b, t.d, 0., t.f],
+                        [0., 0., 1., 0.],
+                    ])
+                    .ok_or_else(|| invalid("transform", value))?;
+            }
+        }
+        outline.transform = transform;
+
+        let number = |attribute: &str| -> Result<f64, Error> {
+            match node.attribute(attribute) {
+                Some(value) => svgtypes::Length::from_str(value)
+                    .map(|length| length.number)
+                    .map_err(|_| invalid(attribute, value)),
+                None => Ok(0.),
+            }
+        };
+
+        match node.tag_name().name() {
+            "path" => {
+                let data = node.attribute("d").unwrap_or_default();
+                outline.path(data)?;
+            }
+            "rect" => {
+                let [x, y, width, height] =
+                    ["x", "y", "width", "height"].map(number);
+                let [x, y, width, height] = [x?, y?, width?, height?];
+
+                // A missing radius is the same as the other one.
+                let [rx, ry] = match [number("rx")?, number("ry")?] {
+                    [rx, ry] if rx > 0. && ry > 0. => [rx, ry],
+                    [rx, ry] => [rx.max(ry); 2],
+                };
+                let [rx, ry] = [rx.min(width / 2.), ry.min(height / 2.)];
+
+                outline.rect([x, y], [width, height], [rx, ry]);
+            }
+            "circle" => {
+                let [cx, cy, r] = [number("cx")?, number("cy")?, number("r")?];
+                outline.ellipse([cx, cy], [r, r]);
+            }
+            "ellipse" => {
+                let [cx, cy] = [number("cx")?, number("cy")?];
+                let [rx, ry] = [number("rx")?, number("ry")?];
+                outline.ellipse([cx, cy], [rx, ry]);
+            }
+            "polygon" | "polyline" => {
+                let points = node.attribute("points").unwrap_or_default();
+                for (i, (x, y)) in PointsParser::from(points).enumerate() {
+                    if i == 0 {
+                        outline.move_to([x, y]);
+                    } else {
+                        outline.line_to([x, y]);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        // Filled shapes are closed implicitly.
+        outline.close();
+    }
+
+    Ok(outline.contours)
+}
+
+/// Collects the contours of the shapes in an SVG file
+///
+/// Points are given in the coordinates of the shape, and converted into the
+/// coordinates of the sketch.
+struct Outline {
+    contours: Vec<Contour>,
+
+    /// The transform from the coordinates of the shape to those of the sketch
+    transform: Transform,
+
+    /// The current point, in the coordinates of the shape
+    current: [f64; 2],
+
+    /// The start point of the current contour
+    start: [f64; 2],
+
+    /// Indicates whether the last contour is still open
+    is_open: bool,
+}
+
+impl Outline {
+    fn point(&self, [x, y]: [f64; 2]) -> [f64; 2] {
+        let point = self.transform.transform_point(&Point::from([x, y, 0.]));
+        [point.x, point.y].map(|s| s.into_f64())
+    }
+
+    fn move_to(&mut self, point: [f64; 2]) {
+        self.close();
+
+        self.contours.push(Contour::new(self.point(point)));
+        self.current = point;
+        self.start = point;
+        self.is_open = true;
+    }
+
+    fn line_to(&mut self, point: [f64; 2]) {
+        self.push(fj::SketchSegment::LineTo {
+            point: self.point(point),
+        });
+        self.current = point;
+    }
+
+    fn close(&mut self) {
+        if self.is_open {
+            if let Some(contour) = self.contours.last_mut() {
+                contour.close();
+            }
+            self.current = self.start;
+        }
+        self.is_open = false;
+    }
+
+    fn push(&mut self, segment: fj::SketchSegment) {
+        // Drawing after closing a contour starts a new one, where the closed
+        // one ended.
+        if !self.is_open {
+            self.move_to(self.current);
+        }
+        if let Some(contour) = self.contours.last_mut() {
+            contour.push(segment);
+        }
+    }
+
+    fn rect(&mut self, [x, y]: [f64; 2], [w, h]: [f64; 2], [rx, ry]: [f64; 2]) {
+        if w <= 0. || h <= 0. {
+            return;
+        }
+
+        self.move_to([x + rx, y]);
+
+        // Each corner is a quarter of an ellipse, starting at the given angle.
+        let corners = [
+            (
+                [x + w - rx, y],
+                [x + w - rx, y + ry],
+                -TAU / 4.,
+                [x + w, y + ry],
+            ),
+            (
+                [x + w, y + h - ry],
+                [x + w - rx, y + h - ry],
+                0.,
+                [x + w - rx, y + h],
+            ),
+            (
+                [x + rx, y + h],
+                [x + rx, y + h - ry],
+                TAU / 4.,
+                [x, y + h - ry],
+            ),
+            ([x, y + ry], [x + rx, y + ry], TAU / 2., [x + rx, y]),
+        ];
+        for (start, center, start_angle, end) in corners {
+            self.line_to(start);
+            if rx > 0. && ry > 0. {
+                self.elliptical_arc(
+                    center,
+                    [rx, ry],
+                    0.,
+                    [start_angle, TAU / 4.],
+                    end,
+                );
+            }
+        }
+
+        self.close();
+    }
+
+    fn ellipse(&mut self, [cx, cy]: [f64; 2], [rx, ry]: [f64; 2]) {
+        if rx <= 0. || ry <= 0. {
+            return;
+        }
+
+        let start = [cx + rx, cy];
+        self.move_to(start);
+        self.elliptical_arc([cx, cy], [rx, ry], 0., [0., TAU], start);
+        self.close();
+    }
+
+    /// Add an arc of an ellipse
+    ///
+    /// The ellipse is rotated by `rotation`. The arc goes from the start angle
+    /// by the given angle, into the direction of positive angles, if that is
+    /// positive. The end point is passed explicitly, as computing it from the
+    /// angles would not match other points exactly.
+    fn elliptical_arc(
+        &mut self,
+        [cx, cy]: [f64; 2],
+        [rx, ry]: [f64; 2],
+        rotation: f64,
+        [start_angle, angle]: [f64; 2],
+        end: [f64; 2],
+    ) {
+        let (sin, cos) = rotation.sin_cos();
+        let point_at = |t: f64| {
+            if t == 1. {
+                return end;
+            }
+
+            let angle = start_angle + angle * t;
+            let [x, y] = [rx * angle.cos(), ry * angle.sin()];
+            [cx + cos * x - sin * y, cy + sin * x + cos * y]
+        };
+
+        // The transform must not distort circles, for the arc to stay one.
+        let m = self.transform.data();
+        let [a, b, c, d] = [m[0], m[1], m[4], m[5]];
+        let is_similarity = ((a * a + b * b) - (c * c + d * d)).abs()
+            <= 1e-9 * (a * a + b * b)
+            && (a * c + b * d).abs() <= 1e-9 * (a * a + b * b);
+
+        if (rx - ry).abs() <= 1e-9 * rx && is_similarity {
+            let direction = if (angle > 0.) != self.transform.is_mirroring() {
+                fj::ArcDirection::Counterclockwise
+            } else {
+                fj::ArcDirection::Clockwise
+            };
+
+            // Split the arc into quarter turns or less, so a full turn doesn't
+            // start and end at the same point.
+            let num_arcs = (angle.abs() / (TAU / 4.)).ceil().max(1.) as usize;
+            for i in 1..=num_arcs {
+                let point = point_at(i as f64 / num_arcs as f64);
+                self.push(fj::SketchSegment::ArcAroundCenterTo {
+                    point: self.point(point),
+                    center: self.point([cx, cy]),
+                    direction,
+                });
+            }
+        } else {
+            for i in 1..=NUM_ELLIPSE_SEGMENTS {
+                let point = point_at(i as f64 / NUM_ELLIPSE_SEGMENTS as f64);
+                self.push(fj::SketchSegment::LineTo {
+                    point: self.point(point),
+                });
+            }
+        }
+
+        self.current = end;
+    }
+
+    /// Add the contours of a path
+    fn path(&mut self, data: &str) -> Result<(), Error> {
+        // The control point of the previous curve, for smooth curves that
+        // mirror it.
+        let mut previous_control = None;
+
+        for segment in PathParser::from(data) {
+            let segment = segment.map_err(|_| invalid("d", data))?;
+
+            let [x0, y0] = self.current;
+            let absolute = |abs: bool, [x, y]: [f64; 2]| {
+                if abs {
+                    [x, y]
+                } else {
+                    [x0 + x, y0 + y]
+                }
+            };
+            let mirrored = |control: Option<[f64; 2]>| match control {
+                Some([x, y]) => [2. * x0 - x, 2. * y0 - y],
+                None => [x0, y0],
+            };
+
+            let mut control = None;
+
+            match segment {
+                PathSegment::MoveTo { abs, x, y } => {
+                    self.move_to(absolute(abs, [x, y]));
+                }
+                PathSegment::LineTo { abs, x, y } => {
+                    self.line_to(absolute(abs, [x, y]));
+                }
+                PathSegment::HorizontalLineTo { abs, x } => {
+                    let x = if abs { x } else { x0 + x };
+                    self.line_to([x, y0]);
+                }
+                PathSegment::VerticalLineTo { abs, y } => {
+                    let y = if abs { y } else { y0 + y };
+                    self.line_to([x0, y]);
+                }
+                PathSegment::CurveTo {
+                    abs,
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    x,
+                    y,
+                } => {
+                    let c1 = absolute(abs, [x1, y1]);
+                    let c2 = absolute(abs, [x2, y2]);
+                    self.cubic_to(c1, c2, absolute(abs, [x, y]));
+                    control = Some(Curve::Cubic(c2));
+                }
+                PathSegment::SmoothCurveTo { abs, x2, y2, x, y } => {
+                    let c1 = match previous_control {
+                        Some(Curve::Cubic(c)) => mirrored(Some(c)),
+                        _ => mirrored(None),
+                    };
+                    let c2 = absolute(abs, [x2, y2]);
+                    self.cubic_to(c1, c2, absolute(abs, [x, y]));
+                    control = Some(Curve::Cubic(c2));
+                }
+                PathSegment::Quadratic { abs, x1, y1, x, y } => {
+                    let c = absolute(abs, [x1, y1]);
+                    self.quadratic_to(c, absolute(abs, [x, y]));
+                    control = Some(Curve::Quadratic(c));
+                }
+                PathSegment::SmoothQuadratic { abs, x, y } => {
+                    let c = match previous_control {
+                        Some(Curve::Quadratic(c)) => mirrored(Some(c)),
+                        _ => mirrored(None),
+                    };
+                    self.quadratic_to(c, absolute(abs, [x, y]));
+                    control = Some(Curve::Quadratic(c));
+                }
+                PathSegment::EllipticalArc {
+                    abs,
+                    rx,
+                    ry,
+                    x_axis_rotation,
+                    large_arc,
+                    sweep,
+                    x,
+                    y,
+                } => {
+                    self.arc_to(
+                        [rx, ry],
+                        x_axis_rotation.to_radians(),
+                        large_arc,
+                        sweep,
+                        absolute(abs, [x, y]),
+                    );
+                }
+                PathSegment::ClosePath { .. } => {
+                    self.close();
+                }
+            }
+
+            previous_control = control;
+        }
+
+        Ok(())
+    }
+
+    fn quadratic_to(&mut self, control: [f64; 2], point: [f64; 2]) {
+        self.push(fj::SketchSegment::QuadraticBezierTo {
+            control: self.point(control),
+            point: self.point(point),
+        });
+        self.current = point;
+    }
+
+    fn cubic_to(&mut self, c1: [f64; 2], c2: [f64; 2], point: [f64; 2]) {
+        self.push(fj::SketchSegment::CubicBezierTo {
+            controls: [self.point(c1), self.point(c2)],
+            point: self.point(point),
+        });
+        self.current = point;
+    }
+
+    /// Add an elliptical arc, as it is specified in path data
+    ///
+    /// The arc is converted into its center parameterization, as described
+    /// in the SVG specification:
+    /// <https://www.w3.org/TR/SVG/implnote.html#ArcConversionEndpointToCenter>
+    fn arc_to(
+        &mut self,
+        [rx, ry]: [f64; 2],
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        end: [f64; 2],
+    ) {
+        let [x1, y1] = self.current;
+        let [x2, y2] = end;
+        if [x1, y1] == end {
+            return;
+        }
+
+        let [mut rx, mut ry] = [rx.abs(), ry.abs()];
+        if rx == 0. || ry == 0. {
+            self.line_to(end);
+            return;
+        }
+
+        let (sin, cos) = rotation.sin_cos();
+        let [dx, dy] = [(x1 - x2) / 2., (y1 - y2) / 2.];
+        let [x, y] = [cos * dx + sin * dy, -sin * dx + cos * dy];
+
+        // Radii that are too small are scaled up, until the arc fits.
+        let lambda = (x * x) / (rx * rx) + (y * y) / (ry * ry);
+        if lambda > 1. {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let numerator = rx * rx * ry * ry - rx * rx * y * y - ry * ry * x * x;
+        let denominator = rx * rx * y * y + ry * ry * x * x;
+        let coefficient = (numerator / denominator).max(0.).sqrt()
+            * if large_arc == sweep { -1. } else { 1. };
+        let [cx, cy] = [coefficient * rx * y / ry, -coefficient * ry * x / rx];
+
+        let center = [
+            cos * cx - sin * cy + (x1 + x2) / 2.,
+            sin * cx + cos * cy + (y1 + y2) / 2.,
+        ];
+
+        let start_angle = ((y - cy) / ry).atan2((x - cx) / rx);
+        let end_angle = ((-y - cy) / ry).atan2((-x - cx) / rx);
+        let mut angle = (end_angle - start_angle).rem_euclid(TAU);
+        if !sweep && angle > 0. {
+            angle -= TAU;
+        }
+
+        self.elliptical_arc(
+            center,
+            [rx, ry],
+            rotation,
+            [start_angle, angle],
+            end,
+        );
+    }
+}
+
+/// The control point of a curve, for continuing it with a smooth curve
+enum Curve {
+    Quadratic([f64; 2]),
+    Cubic([f64; 2]),
+}
+
+fn invalid(attribute: &str, value: &str) -> Error {
+    Error::SvgValue {
+        attribute: attribute.into(),
+        value: value.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj::{ArcDirection, SketchSegment};
+
+    use crate::import_2d::Error;
+
+    fn svg(content: &str) -> String {
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+            xmlns:inkscape=\"http://www.inkscape.org/namespaces/inkscape\">\
+            {content}</svg>"
+        )
+    }
+
+    fn line(point: [f64; 2]) -> SketchSegment {
+        SketchSegment::LineTo { point }
+    }
+
+    #[test]
+    fn paths() -> Result<(), Error> {
+        let data = svg(
+            "<path d=\"M 0 0 h 10 v 5 L 0 5 z m 20 0 l 5 0 Q 25 10 20 0 Z\"/>\
+            <defs><rect width=\"1\" height=\"1\"/></defs>",
+        );
+        let contours = super::contours(&data, &[], 1.)?;
+        let contours = contours
+            .iter()
+            .map(|contour| contour.rounded())
+            .collect::<Vec<_>>();
+
+        // The y-axis is flipped, and relative commands continue from the
+        // start of the previous, closed contour.
+        assert_eq!(
+            contours,
+            [
+                (
+                    [0., 0.],
+                    vec![
+                        line([10., 0.]),
+                        line([10., -5.]),
+                        line([0., -5.]),
+                        line([0., 0.]),
+                    ]
+                ),
+                (
+                    [20., 0.],
+                    vec![
+                        line([25., 0.]),
+                        SketchSegment::QuadraticBezierTo {
+                            control: [25., -10.],
+                            point: [20., 0.],
+                        },
+                    ]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn arcs() -> Result<(), Error> {
+        let data = svg("<path d=\"M 0 0 A 5 5 0 0 1 10 0 Z\"/>\
+            <circle cx=\"5\" cy=\"5\" r=\"2\"/>");
+        let contours = super::contours(&data, &[], 1.)?;
+        let contours = contours
+            .iter()
+            .map(|contour| contour.rounded())
+            .collect::<Vec<_>>();
+
+        // Arcs that go into the direction of positive angles in the SVG file,
+        // go clockwise after flipping the y-axis.
+        let arc = |point, center| SketchSegment::ArcAroundCenterTo {
+            point,
+            center,
+            direction: ArcDirection::Clockwise,
+        };
+        assert_eq!(
+            contours,
+            [
+                (
+                    [0., 0.],
+                    vec![
+                        arc([5., 5.], [5., 0.]),
+                        arc([10., 0.], [5., 0.]),
+                        line([0., 0.]),
+                    ]
+                ),
+                (
+                    [7., -5.],
+                    vec![
+                        arc([5., -7.], [5., -5.]),
+                        arc([3., -5.], [5., -5.]),
+                        arc([5., -3.], [5., -5.]),
+                        arc([7., -5.], [5., -5.]),
+                    ]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn layers() -> Result<(), Error> {
+        let data = svg(
+            "<g inkscape:label=\"Cut\"><rect width=\"1\" height=\"1\"/></g>\
+            <g id=\"engrave\"><rect x=\"2\" width=\"1\" height=\"1\"/></g>\
+            <g id=\"other\"><rect x=\"4\" width=\"1\" height=\"1\"/></g>",
+        );
+
+        let starts = |layers: &[&str]| -> Result<Vec<[f64; 2]>, Error> {
+            Ok(super::contours(&data, layers, 1.)?
+                .iter()
+                .map(|contour| contour.rounded().0)
+                .collect())
+        };
+
+        assert_eq!(starts(&[])?, [[0., 0.], [2., 0.], [4., 0.]]);
+        assert_eq!(starts(&["Cut", "engrave"])?, [[0., 0.], [2., 0.]]);
+        assert!(starts(&["missing"])?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn scale() -> Result<(), Error> {
+        let data = svg("<g transform=\"translate(1 0)\">\
+            <rect y=\"2\" width=\"3\" height=\"4\"/></g>");
+        let contours = super::contours(&data, &[], 2.)?;
+        let contours = contours
+            .iter()
+            .map(|contour| contour.rounded())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            contours,
+            [(
+                [2., -4.],
+                vec![
+                    line([8., -4.]),
+                    line([8., -12.]),
+                    line([2., -12.]),
+                    line([2., -4.]),
+                ]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(matches!(
+            super::contours("<svg><path></svg>", &[], 1.),
+            Err(Error::Xml(_))
+        ));
+        assert!(matches!(
+            super::contours(&svg("<path d=\"M 0 0 L x\"/>"), &[], 1.),
+            Err(Error::SvgValue { attribute, .. }) if attribute == "d"
+        ));
+        assert!(matches!(
+            super::contours(&svg("<circle r=\"big\"/>"), &[], 1.),
+            Err(Error::SvgValue { attribute, value })
+                if attribute == "r" && value == "big"
+        ));
+    }
+}
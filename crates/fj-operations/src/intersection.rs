@@ -0,0 +1,61 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::approx::Tolerance,
+    objects::{FaceSet, Objects},
+    services::Service,
+};
+use fj_math::Aabb;
+
+use super::{
+    boolean::{self, Operation},
+    Error, Shape,
+};
+
+impl Shape for fj::Intersection {
+    type Brep = FaceSet;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let [a, b] = self.shapes();
+
+        let shapes = [
+            a.compute_brep(tolerance, objects, debug_info)?,
+            b.compute_brep(tolerance, objects, debug_info)?,
+        ];
+        let bounding_volumes = [a.bounding_volume()?, b.bounding_volume()?];
+
+        Ok(boolean::compute(
+            Operation::Intersection,
+            shapes,
+            bounding_volumes,
+            tolerance,
+            objects,
+        ))
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        let [a, b] = self.shapes();
+        let [a, b] = [a.bounding_volume()?, b.bounding_volume()?];
+
+        let mut min = a.min;
+        let mut max = a.max;
+        for i in 0..3 {
+            min.coords.components[i] =
+                a.min.coords.components[i].max(b.min.coords.components[i]);
+            max.coords.components[i] =
+                a.max.coords.components[i].min(b.max.coords.components[i]);
+        }
+
+        // If the bounding volumes don't overlap, the intersection is empty. In
+        // that case, `min` and `max` must be equal.
+        if (0..3).any(|i| min.coords.components[i] > max.coords.components[i]) {
+            return Ok(Aabb { min, max: min });
+        }
+
+        Ok(Aabb { min, max })
+    }
+}
@@ -0,0 +1,46 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::approx::Tolerance,
+    objects::{Objects, Sketch},
+    services::Service,
+};
+use fj_math::Aabb;
+
+use super::{
+    boolean::Operation, boolean_2d, shape_processor::material_id, Error, Shape,
+};
+
+impl Shape for fj::Intersection2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let [a, b] = self.shapes();
+
+        let shapes = [
+            a.compute_brep(tolerance, objects, debug_info)?,
+            b.compute_brep(tolerance, objects, debug_info)?,
+        ];
+        let bounding_volumes = [a.bounding_volume()?, b.bounding_volume()?];
+
+        boolean_2d::compute(
+            Operation::Intersection,
+            shapes,
+            bounding_volumes,
+            tolerance,
+            self.color(),
+            self.material().map(material_id),
+            objects,
+        )
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        // This is a conservative estimate of the bounding box: The intersection
+        // is never going to be bigger than the first shape.
+        self.shapes()[0].bounding_volume()
+    }
+}
@@ -23,36 +23,255 @@
 
 pub mod shape_processor;
 
+mod blend;
+mod boolean;
+mod boolean_2d;
+mod contour;
+mod difference;
 mod difference_2d;
 mod group;
+mod hollow;
+mod import_2d;
+mod intersection;
+mod intersection_2d;
+mod loft;
+mod offset_2d;
+mod pattern;
+mod primitives;
+mod revolve;
 mod sketch;
 mod sweep;
+mod sweep_along_path;
+mod text;
 mod transform;
+mod union;
+mod union_2d;
+mod workplane;
 
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    objects::{FaceSet, Objects, Sketch},
+    algorithms::{
+        approx::Tolerance,
+        loft::LoftError,
+        offset::OffsetError,
+        revolve::RevolveError,
+        sweep::{SweepAlongPathError, SweepWithDraftError},
+    },
+    objects::{FaceSet, Objects, Sketch, Solid},
     services::Service,
 };
 use fj_math::Aabb;
 
+pub use self::{import_2d::Error as ImportError, text::Error as FontError};
+
+/// An error that can occur while computing a shape
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An arc in a sketch ends where it starts
+    #[error("Arc ends at its start point {point:?}")]
+    ArcEndsAtStart {
+        /// The point that the arc starts and ends at
+        point: [f64; 2],
+    },
+
+    /// The points of an arc in a path lie on a straight line
+    #[error(
+        "Arc through {via:?} to {point:?} is a straight line, as its points \
+        lie on one"
+    )]
+    ArcPointsOnLine {
+        /// The point that the arc passes through
+        via: [f64; 3],
+
+        /// The destination point of the arc
+        point: [f64; 3],
+    },
+
+    /// The start and end point of an arc in a sketch have different distances
+    /// from its center
+    #[error(
+        "Start and end point of arc have different distances {start} and \
+        {end} from its center"
+    )]
+    ArcRadiiDiffer {
+        /// The distance of the start point from the center
+        start: f64,
+
+        /// The distance of the end point from the center
+        end: f64,
+    },
+
+    /// The radius of an arc in a sketch is too small to reach its end point
+    #[error(
+        "Radius {radius} of arc is smaller than half the distance {distance} \
+        to its end point"
+    )]
+    ArcRadiusTooSmall {
+        /// The radius of the arc
+        radius: f64,
+
+        /// The distance between the start and end points of the arc
+        distance: f64,
+    },
+
+    /// Edges were blended on a sweep with draft or twist
+    #[error("Edges can only be blended on sweeps without draft or twist")]
+    BlendedDraftOrTwist,
+
+    /// A shape with curved faces or edges was hollowed out
+    #[error(
+        "Only shapes with planar faces and straight edges can be hollowed out"
+    )]
+    CurvedHollow,
+
+    /// Sketches on different surfaces were combined
+    #[error("Only sketches on the same workplane can be combined")]
+    DifferentSurfaces,
+
+    /// An edge index of a blend is out of range
+    #[error(
+        "Edge index {index} is out of range, as sweep has {num_edges} edges"
+    )]
+    EdgeIndexOutOfRange {
+        /// The edge index that is out of range
+        index: u32,
+
+        /// The number of edges of the sweep
+        num_edges: usize,
+    },
+
+    /// A font file could not be loaded
+    #[error("Failed to load font file `{path}`: {error}")]
+    Font {
+        /// The path of the font file
+        path: String,
+
+        /// The error that occurred while loading it
+        error: FontError,
+    },
+
+    /// A drawing file could not be imported
+    #[error("Failed to import drawing file `{path}`: {error}")]
+    Import {
+        /// The path of the drawing file
+        path: String,
+
+        /// The error that occurred while importing it
+        error: ImportError,
+    },
+
+    /// The direction of a linear pattern, or the axis of a circular one, is
+    /// zero
+    #[error("Direction or axis {axis:?} of pattern is zero")]
+    InvalidPattern {
+        /// The direction or axis of the pattern
+        axis: [f64; 3],
+    },
+
+    /// A dimension of a primitive is invalid
+    ///
+    /// Sizes, heights, and radii must be positive. The exception are the radii
+    /// of a cone, one of which may be zero. The minor radius of a torus must be
+    /// smaller than its major radius.
+    #[error("Invalid {dimension} {value} of primitive")]
+    InvalidPrimitive {
+        /// The name of the invalid dimension
+        dimension: &'static str,
+
+        /// The value of the invalid dimension
+        value: f64,
+    },
+
+    /// The normal of a plane is zero, or parallel to its x-direction
+    #[error(
+        "Normal {normal:?} of plane is zero, or parallel to its x-direction \
+        {x_direction:?}"
+    )]
+    InvalidPlane {
+        /// The normal of the plane
+        normal: [f64; 3],
+
+        /// The direction that the x-axis of the plane was to point to
+        x_direction: [f64; 3],
+    },
+
+    /// The radius of a circle or arc in a sketch is not a positive number
+    #[error("Invalid radius {radius} of circle or arc")]
+    InvalidRadius {
+        /// The radius of the circle or arc
+        radius: f64,
+    },
+
+    /// The wall thickness of a hollow shape is not positive
+    #[error("Invalid wall thickness {thickness} of hollow shape")]
+    InvalidThickness {
+        /// The thickness of the walls
+        thickness: f64,
+    },
+
+    /// A loft can't be computed
+    #[error("Failed to loft through profiles: {0}")]
+    Loft(#[from] LoftError),
+
+    /// A shape has no planar face that faces into a named direction
+    #[error("Shape has no planar face that faces into direction `{face:?}`")]
+    MissingFace {
+        /// The named face that is missing
+        face: fj::NamedFace,
+    },
+
+    /// The faces of a shape can't be offset
+    #[error("Failed to offset faces: {0}")]
+    Offset(#[from] OffsetError),
+
+    /// A shape can't be revolved
+    #[error("Failed to revolve shape: {0}")]
+    Revolve(#[from] RevolveError),
+
+    /// The matrix of a transform is not invertible, or not finite
+    #[error("Matrix {matrix:?} of transform is not invertible, or not finite")]
+    SingularTransform {
+        /// The first three rows of the matrix
+        matrix: [[f64; 4]; 3],
+    },
+
+    /// A shape can't be swept along a path
+    #[error("Failed to sweep shape along path: {0}")]
+    SweepAlongPath(#[from] SweepAlongPathError),
+
+    /// A shape can't be swept with draft and twist
+    #[error("Failed to sweep shape with draft and twist: {0}")]
+    SweepWithDraft(#[from] SweepWithDraftError),
+}
+
 /// Implemented for all operations from the [`fj`] crate
 pub trait Shape {
     /// The type that is used for the shape's boundary representation
     type Brep;
 
     /// Compute the boundary representation of the shape
+    ///
+    /// Curved edges and faces that an operation can't keep curved are
+    /// approximated within `tolerance`.
+    ///
+    /// Returns an error, if the shape's definition can't be turned into a
+    /// boundary representation, like a file that it is made from can't be
+    /// read.
     fn compute_brep(
         &self,
+        tolerance: Tolerance,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep;
+    ) -> Result<Self::Brep, Error>;
 
     /// Access the axis-aligned bounding box of a shape
     ///
     /// If a shape is empty, its [`Aabb`]'s `min` and `max` points must be equal
     /// (but are otherwise not specified).
-    fn bounding_volume(&self) -> Aabb<3>;
+    ///
+    /// Returns an error in the same cases as [`Shape::compute_brep`], if the
+    /// bounding box can't be determined without computing the shape.
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error>;
 }
 
 impl Shape for fj::Shape {
@@ -60,55 +279,153 @@ impl Shape for fj::Shape {
 
     fn compute_brep(
         &self,
+        tolerance: Tolerance,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
-        match self {
-            Self::Shape2d(shape) => {
-                shape.compute_brep(objects, debug_info).faces().clone()
-            }
-            Self::Group(shape) => shape.compute_brep(objects, debug_info),
-            Self::Sweep(shape) => shape
-                .compute_brep(objects, debug_info)
-                .shells()
-                .map(|shell| shell.faces().clone())
-                .reduce(|mut a, b| {
-                    a.extend(b);
-                    a
-                })
-                .unwrap_or_default(),
-            Self::Transform(shape) => shape.compute_brep(objects, debug_info),
-        }
+    ) -> Result<Self::Brep, Error> {
+        let brep = match self {
+            Self::Shape2d(shape) => shape
+                .compute_brep(tolerance, objects, debug_info)?
+                .faces()
+                .clone(),
+            Self::Chamfer(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Cone(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Cuboid(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Cylinder(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Difference(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Fillet(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Group(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Hollow(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Intersection(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Loft(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Pattern(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Revolve(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Sphere(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Sweep(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::SweepAlongPath(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Transform(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Torus(shape) => {
+                faces_of(&shape.compute_brep(tolerance, objects, debug_info)?)
+            }
+            Self::Union(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+        };
+
+        Ok(brep)
     }
 
-    fn bounding_volume(&self) -> Aabb<3> {
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
         match self {
             Self::Shape2d(shape) => shape.bounding_volume(),
+            Self::Chamfer(shape) => shape.bounding_volume(),
+            Self::Cone(shape) => shape.bounding_volume(),
+            Self::Cuboid(shape) => shape.bounding_volume(),
+            Self::Cylinder(shape) => shape.bounding_volume(),
+            Self::Difference(shape) => shape.bounding_volume(),
+            Self::Fillet(shape) => shape.bounding_volume(),
             Self::Group(shape) => shape.bounding_volume(),
+            Self::Hollow(shape) => shape.bounding_volume(),
+            Self::Intersection(shape) => shape.bounding_volume(),
+            Self::Loft(shape) => shape.bounding_volume(),
+            Self::Pattern(shape) => shape.bounding_volume(),
+            Self::Revolve(shape) => shape.bounding_volume(),
+            Self::Sphere(shape) => shape.bounding_volume(),
             Self::Sweep(shape) => shape.bounding_volume(),
+            Self::SweepAlongPath(shape) => shape.bounding_volume(),
             Self::Transform(shape) => shape.bounding_volume(),
+            Self::Torus(shape) => shape.bounding_volume(),
+            Self::Union(shape) => shape.bounding_volume(),
         }
     }
 }
 
+/// Collect the faces of all shells of a solid
+fn faces_of(solid: &Solid) -> FaceSet {
+    let mut faces = FaceSet::new();
+    for shell in solid.shells() {
+        faces.extend(shell.faces().clone());
+    }
+    faces
+}
+
 impl Shape for fj::Shape2d {
     type Brep = Sketch;
 
     fn compute_brep(
         &self,
+        tolerance: Tolerance,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
-        match self {
-            Self::Difference(shape) => shape.compute_brep(objects, debug_info),
-            Self::Sketch(shape) => shape.compute_brep(objects, debug_info),
-        }
+    ) -> Result<Self::Brep, Error> {
+        let brep = match self {
+            Self::Difference(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Import(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Intersection(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Offset(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Sketch(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Text(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+            Self::Union(shape) => {
+                shape.compute_brep(tolerance, objects, debug_info)?
+            }
+        };
+
+        Ok(brep)
     }
 
-    fn bounding_volume(&self) -> Aabb<3> {
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
         match self {
             Self::Difference(shape) => shape.bounding_volume(),
+            Self::Import(shape) => shape.bounding_volume(),
+            Self::Intersection(shape) => shape.bounding_volume(),
+            Self::Offset(shape) => shape.bounding_volume(),
             Self::Sketch(shape) => shape.bounding_volume(),
+            Self::Text(shape) => shape.bounding_volume(),
+            Self::Union(shape) => shape.bounding_volume(),
         }
     }
 }
@@ -0,0 +1,138 @@
+use std::ops::Deref;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        loft::{Loft, LoftError},
+        transform::TransformObject,
+    },
+    insert::Insert,
+    objects::{Objects, Solid},
+    services::Service,
+};
+use fj_math::{Aabb, Point, Transform, Vector};
+
+use super::{workplane, Error, Shape};
+
+impl Shape for fj::Loft {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let sketches = self
+            .profiles()
+            .iter()
+            .map(|profile| {
+                let sketch = profile
+                    .sketch()
+                    .compute_brep(tolerance, objects, debug_info)?;
+                Ok(sketch
+                    .insert(objects)
+                    .transform(&placement(profile)?, objects))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let solid = sketches.loft(tolerance, objects)?;
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        if self.profiles().len() < 2 {
+            return Err(LoftError::TooFewProfiles.into());
+        }
+
+        let aabbs = self
+            .profiles()
+            .iter()
+            .map(|profile| {
+                let aabb = profile.sketch().bounding_volume()?;
+                Ok(placement(profile)?.transform_aabb(&aabb))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // Can't panic, as we made sure there are profiles.
+        Ok(aabbs.into_iter().reduce(|a, b| a.merged(&b)).unwrap())
+    }
+}
+
+/// Compute the transform that places a sketch on the plane of its profile
+fn placement(profile: &fj::LoftProfile) -> Result<Transform, Error> {
+    workplane::placement_on_plane(
+        Point::from(profile.origin()),
+        Vector::from(profile.normal()),
+        Vector::from(profile.x_direction()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, loft::LoftError},
+        services::Services,
+    };
+
+    use crate::{Error, Shape};
+
+    #[test]
+    fn circles() -> Result<(), Error> {
+        let circle = fj::Sketch::from_circle(fj::Circle::from_radius(1.));
+        let loft = fj::Loft::from_profiles(vec![
+            fj::LoftProfile::at_height(circle.clone(), 0.),
+            fj::LoftProfile::at_height(circle, 2.),
+        ]);
+
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        let solid = loft.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )?;
+
+        let num_vertices = (PI / (1. - 0.01_f64).acos()).ceil() as usize;
+
+        // Two caps, and a planar side face for each edge.
+        let num_faces = solid
+            .shells()
+            .map(|shell| shell.faces().into_iter().count())
+            .sum::<usize>();
+        assert_eq!(num_faces, num_vertices + 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn holes_differ() {
+        let square = fj::Sketch::from_points(vec![
+            [-1., -1.],
+            [1., -1.],
+            [1., 1.],
+            [-1., 1.],
+        ]);
+        let with_hole =
+            square.clone().with_interior(fj::Circle::from_radius(0.5));
+        let loft = fj::Loft::from_profiles(vec![
+            fj::LoftProfile::at_height(square, 0.),
+            fj::LoftProfile::at_height(with_hole, 1.),
+        ]);
+
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        assert!(matches!(
+            loft.compute_brep(
+                tolerance,
+                &mut services.objects,
+                &mut DebugInfo::new()
+            ),
+            Err(Error::Loft(LoftError::HolesDiffer))
+        ));
+    }
+}
@@ -0,0 +1,610 @@
+use std::{f64::consts::PI, ops::Deref};
+
+use fj_interop::{debug::DebugInfo, ext::ArrayExt, mesh::Color};
+use fj_kernel::{
+    algorithms::approx::Tolerance,
+    geometry::path::SurfacePath,
+    insert::Insert,
+    objects::{HalfEdge, Objects, Sketch},
+    partial::{Partial, PartialFace, PartialObject, PartialSketch},
+    services::Service,
+};
+use fj_math::{Aabb, Point, Scalar, Vector, Winding};
+
+use super::{
+    boolean::Operation, boolean_2d, shape_processor::material_id, sketch,
+    Error, Shape,
+};
+
+/// How far a miter join may reach from its corner, relative to the distance
+const MITER_LIMIT: f64 = 2.;
+
+impl Shape for fj::Offset2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let mut shape =
+            self.shape().compute_brep(tolerance, objects, debug_info)?;
+
+        let surface = match shape.faces().into_iter().next() {
+            Some(face) => face.surface().clone(),
+            None => return Ok(shape),
+        };
+        if self.distance() == 0. {
+            return Ok(shape);
+        }
+
+        let offset = Offset {
+            distance: Scalar::from(self.distance().abs()),
+            outward: self.distance() > 0.,
+            join: self.join(),
+        };
+
+        let mut chains = Vec::new();
+        for face in shape.faces() {
+            for (i, cycle) in face.all_cycles().enumerate() {
+                // The shape is on the left of exteriors that wind
+                // counterclockwise, and of interiors that wind clockwise.
+                let is_exterior = i == 0;
+                let shape_on_left =
+                    (cycle.winding() == Winding::Ccw) == is_exterior;
+
+                let segments = cycle
+                    .half_edges()
+                    .map(|half_edge| Segment::from_half_edge(half_edge))
+                    .collect::<Vec<_>>();
+                chains.extend(offset.chains(&segments, shape_on_left));
+            }
+        }
+
+        // Growing the shape adds everything that is within the distance of its
+        // boundary. Shrinking it removes that. All boolean operations use the
+        // same bounding volume, so they decide in the same way whether points
+        // are identical.
+        let operation = if offset.outward {
+            Operation::Union
+        } else {
+            Operation::Difference
+        };
+        let aabb = expanded(
+            self.shape().bounding_volume()?,
+            offset.distance * MITER_LIMIT,
+        );
+
+        for chain in chains {
+            let exterior =
+                sketch::cycle(&chain, surface.clone(), tolerance, objects)?;
+            let face = PartialFace {
+                exterior: Partial::from(exterior),
+                interiors: Vec::new(),
+                color: Some(Color(self.color())),
+                material: self.material().map(material_id),
+            };
+            let area = PartialSketch {
+                faces: vec![Partial::from_partial(face)],
+            }
+            .build(objects)
+            .insert(objects);
+
+            shape = boolean_2d::compute(
+                operation,
+                [shape, area.deref().clone()],
+                [aabb, aabb],
+                tolerance,
+                self.color(),
+                self.material().map(material_id),
+                objects,
+            )?;
+        }
+
+        Ok(shape)
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        let aabb = self.shape().bounding_volume()?;
+        if self.distance() <= 0. {
+            return Ok(aabb);
+        }
+
+        // Miter joins reach furthest from the original shape.
+        Ok(expanded(aabb, Scalar::from(self.distance() * MITER_LIMIT)))
+    }
+}
+
+/// The parameters of an offset
+struct Offset {
+    distance: Scalar,
+    outward: bool,
+    join: fj::OffsetJoin,
+}
+
+impl Offset {
+    /// Compute the chains that cover the area around a cycle's boundary
+    ///
+    /// The area around each segment is covered from both sides, so the chains
+    /// don't share any edges with the shape. The joins only cover the side
+    /// that the boundary moves to, where the segments diverge.
+    fn chains(
+        &self,
+        segments: &[Segment],
+        shape_on_left: bool,
+    ) -> Vec<fj::Chain> {
+        let e = self.distance;
+
+        // The side of the boundary that it moves to.
+        let to_right = self.outward == shape_on_left;
+        let normal = |tangent: Vector<2>| {
+            if to_right {
+                Vector::from([tangent.v, -tangent.u])
+            } else {
+                Vector::from([-tangent.v, tangent.u])
+            }
+        };
+
+        let mut chains = Vec::new();
+
+        for segment in segments {
+            match *segment {
+                Segment::Line([a, b]) => {
+                    let n = normal((b - a).normalize()) * e;
+                    chains.push(polygon(vec![a - n, b - n, b + n, a + n]));
+                }
+                Segment::Arc {
+                    points: [start, _],
+                    center,
+                    radius,
+                    angle,
+                } => {
+                    let r = start - center;
+                    let start_angle = r.v.atan2(r.u);
+                    let [from, to] = if angle > Scalar::ZERO {
+                        [start_angle, start_angle + angle]
+                    } else {
+                        [start_angle + angle, start_angle]
+                    };
+
+                    let radii = [radius - e, radius + e];
+                    if angle.abs() > Scalar::from(PI) {
+                        let middle = (from + to) / 2.;
+                        chains.push(sector(center, radii, [from, middle]));
+                        chains.push(sector(center, radii, [middle, to]));
+                    } else {
+                        chains.push(sector(center, radii, [from, to]));
+                    }
+                }
+            }
+        }
+
+        for (i, incoming) in segments.iter().enumerate() {
+            let outgoing = &segments[(i + 1) % segments.len()];
+
+            let vertex = incoming.points()[1];
+            let [_, t_in] = incoming.tangents();
+            let [t_out, _] = outgoing.tangents();
+
+            // The boundary diverges where it turns away from the side it moves
+            // to. That includes turning back on itself.
+            let cross = t_in.cross2d(&t_out);
+            let turn = if to_right { cross } else { -cross };
+            let diverges = turn > Scalar::from(1e-9)
+                || (turn.abs() <= Scalar::from(1e-9)
+                    && t_in.dot(&t_out) < Scalar::ZERO);
+            if !diverges {
+                continue;
+            }
+
+            let [n_in, n_out] = [t_in, t_out].map(normal);
+            let bisector = {
+                let sum = n_in + n_out;
+                if sum.magnitude() > Scalar::from(1e-9) {
+                    sum.normalize()
+                } else {
+                    t_in
+                }
+            };
+
+            // All points of the join are within the distance of the vertex, or
+            // part of the area between the offset segments.
+            let base = vertex - bisector * e;
+            let [p_in, p_out] = [vertex + n_in * e, vertex + n_out * e];
+            let cos = n_in.dot(&bisector);
+
+            let chain = match self.join {
+                fj::OffsetJoin::Round => {
+                    // The arc goes around the vertex counterclockwise, if the
+                    // boundary moves to its right.
+                    let [from, to] = if to_right {
+                        [p_in, p_out]
+                    } else {
+                        [p_out, p_in]
+                    };
+
+                    fj::Chain::PolyChain(fj::PolyChain::from_segments(vec![
+                        fj::SketchSegment::LineTo { point: array(from) },
+                        fj::SketchSegment::ArcAroundCenterTo {
+                            point: array(to),
+                            center: array(vertex),
+                            direction: fj::ArcDirection::Counterclockwise,
+                        },
+                        fj::SketchSegment::LineTo { point: array(base) },
+                    ]))
+                }
+                fj::OffsetJoin::Miter if cos * MITER_LIMIT >= Scalar::ONE => {
+                    let miter = vertex + bisector * (e / cos);
+                    polygon(vec![base, p_in, miter, p_out])
+                }
+                fj::OffsetJoin::Miter | fj::OffsetJoin::Square => {
+                    let length = e * (Scalar::ONE - cos) / t_in.dot(&bisector);
+                    polygon(vec![
+                        base,
+                        p_in,
+                        p_in + t_in * length,
+                        p_out - t_out * length,
+                        p_out,
+                    ])
+                }
+            };
+
+            chains.push(chain);
+        }
+
+        chains
+    }
+}
+
+/// A segment of a cycle's boundary
+enum Segment {
+    Line([Point<2>; 2]),
+    Arc {
+        points: [Point<2>; 2],
+        center: Point<2>,
+        radius: Scalar,
+
+        /// The angle of the arc, positive if it's counterclockwise
+        angle: Scalar,
+    },
+}
+
+impl Segment {
+    fn from_half_edge(half_edge: &HalfEdge) -> Self {
+        let points = half_edge
+            .vertices()
+            .each_ref_ext()
+            .map(|vertex| vertex.surface_form().position());
+
+        match half_edge.curve().path() {
+            SurfacePath::Line(_) => Self::Line(points),
+            SurfacePath::Circle(circle) => {
+                let [start, end] = half_edge
+                    .vertices()
+                    .each_ref_ext()
+                    .map(|vertex| vertex.position().t);
+                let angle = if circle.a().cross2d(&circle.b()) > Scalar::ZERO {
+                    end - start
+                } else {
+                    start - end
+                };
+
+                Self::Arc {
+                    points,
+                    center: circle.center(),
+                    radius: circle.a().magnitude(),
+                    angle,
+                }
+            }
+        }
+    }
+
+    fn points(&self) -> [Point<2>; 2] {
+        match self {
+            Self::Line(points) | Self::Arc { points, .. } => *points,
+        }
+    }
+
+    /// Compute the directions of the segment at its start and end
+    fn tangents(&self) -> [Vector<2>; 2] {
+        match *self {
+            Self::Line([a, b]) => {
+                let direction = (b - a).normalize();
+                [direction, direction]
+            }
+            Self::Arc {
+                points,
+                center,
+                angle,
+                ..
+            } => points.map(|point| {
+                let r = (point - center).normalize();
+                let tangent = Vector::from([-r.v, r.u]);
+
+                if angle > Scalar::ZERO {
+                    tangent
+                } else {
+                    -tangent
+                }
+            }),
+        }
+    }
+}
+
+/// Create a chain from a polygon, making sure it winds counterclockwise
+fn polygon(mut points: Vec<Point<2>>) -> fj::Chain {
+    let double_area = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| a.coords.cross2d(&b.coords))
+        .fold(Scalar::ZERO, |sum, double_area| sum + double_area);
+    if double_area < Scalar::ZERO {
+        points.reverse();
+    }
+
+    fj::Chain::PolyChain(fj::PolyChain::from_points(
+        points.into_iter().map(array).collect(),
+    ))
+}
+
+/// Create a chain that bounds a sector of an annulus
+///
+/// The sector goes counterclockwise between the two angles. If the inner
+/// radius is not positive, the sector reaches to the center instead.
+fn sector(
+    center: Point<2>,
+    [inner, outer]: [Scalar; 2],
+    angles: [Scalar; 2],
+) -> fj::Chain {
+    let point = |radius: Scalar, angle: Scalar| {
+        let (sin, cos) = angle.sin_cos();
+        array(center + Vector::from([cos, sin]) * radius)
+    };
+    let [from, to] = angles;
+
+    let mut segments = vec![
+        fj::SketchSegment::LineTo {
+            point: point(outer, from),
+        },
+        fj::SketchSegment::ArcAroundCenterTo {
+            point: point(outer, to),
+            center: array(center),
+            direction: fj::ArcDirection::Counterclockwise,
+        },
+    ];
+
+    if inner > Scalar::ZERO {
+        segments.extend([
+            fj::SketchSegment::LineTo {
+                point: point(inner, to),
+            },
+            fj::SketchSegment::ArcAroundCenterTo {
+                point: point(inner, from),
+                center: array(center),
+                direction: fj::ArcDirection::Clockwise,
+            },
+        ]);
+    } else {
+        segments.push(fj::SketchSegment::LineTo {
+            point: array(center),
+        });
+    }
+
+    fj::Chain::PolyChain(fj::PolyChain::from_segments(segments))
+}
+
+fn array(point: Point<2>) -> [f64; 2] {
+    [point.u, point.v].map(|s| s.into_f64())
+}
+
+fn expanded(aabb: Aabb<3>, distance: Scalar) -> Aabb<3> {
+    let margin = Vector::from([distance, distance, distance]);
+    Aabb {
+        min: aabb.min - margin,
+        max: aabb.max + margin,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{PI, SQRT_2};
+
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::approx::Tolerance, objects::Cycle, services::Services,
+    };
+
+    use crate::{Error, Shape};
+
+    /// The outline of each face, and the outlines of its holes
+    type Polygons = Vec<(Vec<[f64; 2]>, Vec<Vec<[f64; 2]>>)>;
+
+    fn square([x, y]: [f64; 2], size: f64) -> Vec<[f64; 2]> {
+        vec![[x, y], [x + size, y], [x + size, y + size], [x, y + size]]
+    }
+
+    /// A square with a side length of 4, and a square hole of side length 2
+    fn frame() -> fj::Sketch {
+        fj::Sketch::from_points(square([0., 0.], 4.))
+            .with_interior(fj::PolyChain::from_points(square([1., 1.], 2.)))
+    }
+
+    fn polygons(offset: fj::Offset2d) -> Result<Polygons, Error> {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        let sketch = offset.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )?;
+
+        let polygon = |cycle: &Cycle| {
+            cycle
+                .half_edges()
+                .map(|half_edge| {
+                    let point = half_edge.back().surface_form().position();
+                    [point.u, point.v].map(|s| s.into_f64())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        Ok(sketch
+            .faces()
+            .into_iter()
+            .map(|face| {
+                (
+                    polygon(face.exterior()),
+                    face.interiors().map(|cycle| polygon(cycle)).collect(),
+                )
+            })
+            .collect())
+    }
+
+    /// Check the area and the bounding box of a polygon
+    ///
+    /// Round joins are approximated by straight lines within the tolerance of
+    /// the boolean operations, which cuts off some of their area. The area may
+    /// be smaller than expected by up to `max_missing_area`.
+    fn check(
+        polygon: &[[f64; 2]],
+        expected_area: f64,
+        max_missing_area: f64,
+        expected_bounding_box: [[f64; 2]; 2],
+    ) {
+        let area = polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .map(|(a, b)| (a[0] * b[1] - b[0] * a[1]) / 2.)
+            .sum::<f64>()
+            .abs();
+        assert!(
+            area <= expected_area + 1e-9
+                && area >= expected_area - max_missing_area - 1e-9,
+            "Expected area {expected_area}, got {area}"
+        );
+
+        let bounding_box = polygon.iter().fold(
+            [[f64::INFINITY; 2], [f64::NEG_INFINITY; 2]],
+            |[min, max], point| {
+                [
+                    [min[0].min(point[0]), min[1].min(point[1])],
+                    [max[0].max(point[0]), max[1].max(point[1])],
+                ]
+            },
+        );
+        let matches = bounding_box
+            .iter()
+            .flatten()
+            .zip(expected_bounding_box.iter().flatten())
+            .all(|(a, b)| (a - b).abs() < 1e-9);
+        assert!(
+            matches,
+            "Expected bounding box {expected_bounding_box:?}, got \
+            {bounding_box:?}"
+        );
+    }
+
+    #[test]
+    fn grow() -> Result<(), Error> {
+        // The sides move out by the distance, and the gaps at the corners are
+        // closed. Miter joins keep the corners sharp, square joins cut them off
+        // at the distance from the corner, and round joins round them off.
+        let joins = [
+            (fj::OffsetJoin::Miter, 0.25, 0.),
+            (fj::OffsetJoin::Square, 0.5 * (SQRT_2 - 1.), 0.),
+            (fj::OffsetJoin::Round, PI / 16., 0.05),
+        ];
+
+        for (join, corner_area, max_missing_area) in joins {
+            let sketch = fj::Sketch::from_points(square([0., 0.], 2.));
+            let offset =
+                fj::Offset2d::from_distance(sketch, 0.5).with_join(join);
+
+            let polygons = polygons(offset)?;
+            assert_eq!(polygons.len(), 1, "{join:?}");
+
+            let (exterior, interiors) = &polygons[0];
+            assert!(interiors.is_empty(), "{join:?}");
+            check(
+                exterior,
+                4. + 4. * 2. * 0.5 + 4. * corner_area,
+                max_missing_area,
+                [[-0.5, -0.5], [2.5, 2.5]],
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn shrink() -> Result<(), Error> {
+        // The boundary doesn't diverge at convex corners, if the shape is
+        // shrunk. The corners stay sharp, regardless of the join.
+        let joins = [
+            fj::OffsetJoin::Miter,
+            fj::OffsetJoin::Square,
+            fj::OffsetJoin::Round,
+        ];
+
+        for join in joins {
+            let sketch = fj::Sketch::from_points(square([0., 0.], 2.));
+            let offset =
+                fj::Offset2d::from_distance(sketch, -0.5).with_join(join);
+
+            let polygons = polygons(offset)?;
+            assert_eq!(polygons.len(), 1, "{join:?}");
+
+            let (exterior, interiors) = &polygons[0];
+            assert!(interiors.is_empty(), "{join:?}");
+            check(exterior, 1., 0., [[0.5, 0.5], [1.5, 1.5]]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn interiors() -> Result<(), Error> {
+        // Growing the shape shrinks its hole, whose corners stay sharp.
+        let offset = fj::Offset2d::from_distance(frame(), 0.5);
+
+        let grown = polygons(offset)?;
+        assert_eq!(grown.len(), 1);
+
+        let (exterior, interiors) = &grown[0];
+        check(
+            exterior,
+            16. + 4. * 4. * 0.5 + PI / 4.,
+            0.05,
+            [[-0.5, -0.5], [4.5, 4.5]],
+        );
+        assert_eq!(interiors.len(), 1);
+        check(&interiors[0], 1., 0., [[1.5, 1.5], [2.5, 2.5]]);
+
+        // Shrinking the shape grows its hole, whose corners are joined.
+        let joins = [
+            (fj::OffsetJoin::Miter, 0.0625, 0.),
+            (fj::OffsetJoin::Round, PI / 64., 0.05),
+        ];
+
+        for (join, corner_area, max_missing_area) in joins {
+            let offset =
+                fj::Offset2d::from_distance(frame(), -0.25).with_join(join);
+
+            let shrunk = polygons(offset)?;
+            assert_eq!(shrunk.len(), 1, "{join:?}");
+
+            let (exterior, interiors) = &shrunk[0];
+            check(exterior, 3.5 * 3.5, 0., [[0.25, 0.25], [3.75, 3.75]]);
+            assert_eq!(interiors.len(), 1, "{join:?}");
+            check(
+                &interiors[0],
+                4. + 4. * 2. * 0.25 + 4. * corner_area,
+                max_missing_area,
+                [[0.75, 0.75], [3.25, 3.25]],
+            );
+        }
+
+        Ok(())
+    }
+}
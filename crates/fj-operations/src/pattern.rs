@@ -0,0 +1,255 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        approx::Tolerance,
+        transform::{TransformCache, TransformObject},
+    },
+    objects::{FaceSet, Objects},
+    services::Service,
+};
+use fj_math::{Aabb, Point, Scalar, Transform, Vector};
+
+use super::{Error, Shape};
+
+impl Shape for fj::Pattern {
+    type Brep = FaceSet;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        // The shape is only computed once. Each further copy is made by
+        // transforming the previous copy by the same step. That way, a single
+        // cache can be shared by all copies, and every object is transformed
+        // only once. Objects that are shared between faces stay shared within
+        // each copy.
+        let step = step(self)?;
+        let mut cache = TransformCache::default();
+
+        let mut faces = FaceSet::new();
+        let mut copy =
+            self.shape().compute_brep(tolerance, objects, debug_info)?;
+        for i in 0..self.count() {
+            if i > 0 {
+                copy = copy.transform_with_cache(&step, objects, &mut cache);
+            }
+
+            faces.extend(copy.clone());
+        }
+
+        Ok(faces)
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        let shape = self.shape().bounding_volume()?;
+
+        let step = step(self)?;
+        let mut transform = Transform::identity();
+
+        let mut aabb: Option<Aabb<3>> = None;
+        for _ in 0..self.count() {
+            let copy = transform.transform_aabb(&shape);
+            aabb = Some(match aabb {
+                Some(aabb) => aabb.merged(&copy),
+                None => copy,
+            });
+
+            transform = step * transform;
+        }
+
+        Ok(aabb.unwrap_or(Aabb {
+            min: Point::origin(),
+            max: Point::origin(),
+        }))
+    }
+}
+
+/// Compute the transform that places a copy relative to the previous one
+///
+/// Returns an error, if the direction or axis of the pattern is zero.
+fn step(pattern: &fj::Pattern) -> Result<Transform, Error> {
+    let normalize = |axis: [f64; 3]| {
+        let vector = Vector::from(axis);
+        if vector.magnitude() == Scalar::ZERO {
+            return Err(Error::InvalidPattern { axis });
+        }
+
+        Ok(vector.normalize())
+    };
+
+    match *pattern.layout() {
+        fj::PatternLayout::Linear { direction, spacing } => {
+            Ok(Transform::translation(normalize(direction)? * spacing))
+        }
+        fj::PatternLayout::Circular { axis, angle } => {
+            Ok(Transform::rotation(normalize(axis)? * angle.rad()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj::syntax::*;
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::approx::Tolerance, objects::FaceSet, services::Services,
+    };
+    use fj_math::{Aabb, Point};
+
+    use crate::{Error, Shape};
+
+    /// A unit cube, with its origin at the given point of the xy-plane
+    fn cube(origin: [f64; 2]) -> fj::Sweep {
+        let [x, y] = origin;
+        fj::Sketch::from_points(vec![
+            [x, y],
+            [x + 1., y],
+            [x + 1., y + 1.],
+            [x, y + 1.],
+        ])
+        .sweep([0., 0., 1.])
+    }
+
+    fn compute(pattern: &fj::Pattern) -> Result<FaceSet, Error> {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        pattern.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )
+    }
+
+    /// The positions of all vertices, rounded to avoid numerical noise
+    fn vertices(faces: &FaceSet) -> Vec<[i64; 3]> {
+        let mut vertices = faces
+            .into_iter()
+            .flat_map(|face| face.all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| {
+                half_edge
+                    .back()
+                    .global_form()
+                    .position()
+                    .coords
+                    .components
+                    .map(|s| (s.into_f64() * 1e6).round() as i64)
+            })
+            .collect::<Vec<_>>();
+
+        vertices.sort();
+        vertices.dedup();
+        vertices
+    }
+
+    fn aabb(min: [f64; 3], max: [f64; 3]) -> Aabb<3> {
+        Aabb {
+            min: Point::from(min),
+            max: Point::from(max),
+        }
+    }
+
+    #[test]
+    fn linear() -> Result<(), Error> {
+        let pattern = fj::Pattern::linear(cube([0., 0.]), [2., 0., 0.], 3, 2.);
+
+        let faces = compute(&pattern)?;
+        assert_eq!((&faces).into_iter().count(), 3 * 6);
+
+        // The copies are spaced along the normalized direction.
+        let mut expected = Vec::new();
+        for x in [0, 1, 2, 3, 4, 5] {
+            for y in [0, 1] {
+                for z in [0, 1] {
+                    expected.push([x, y, z].map(|c| c * 1_000_000));
+                }
+            }
+        }
+        assert_eq!(vertices(&faces), expected);
+
+        assert_eq!(
+            pattern.bounding_volume()?,
+            aabb([0., 0., 0.], [5., 1., 1.])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn circular() -> Result<(), Error> {
+        let pattern = fj::Pattern::circular(
+            cube([2., 0.]),
+            [0., 0., 1.],
+            4,
+            fj::Angle::from_deg(90.),
+        );
+
+        let faces = compute(&pattern)?;
+        assert_eq!((&faces).into_iter().count(), 4 * 6);
+
+        // Each copy is rotated by a quarter turn, relative to the previous
+        // one. Rotating by a quarter turn maps `[x, y]` to `[-y, x]`.
+        let mut corners = vec![[2, 0], [3, 0], [3, 1], [2, 1]];
+        let mut expected = Vec::new();
+        for _ in 0..4 {
+            for &[x, y] in &corners {
+                for z in [0, 1] {
+                    expected.push([x, y, z].map(|c| c * 1_000_000));
+                }
+            }
+            corners = corners.into_iter().map(|[x, y]| [-y, x]).collect();
+        }
+        expected.sort();
+        assert_eq!(vertices(&faces), expected);
+
+        // The rotation is not exact, so the bounding volume is neither.
+        let aabb = pattern.bounding_volume()?;
+        let distance = |point: Point<3>, expected: [f64; 3]| {
+            (point - Point::from(expected)).magnitude().into_f64()
+        };
+        assert!(distance(aabb.min, [-3., -3., 0.]) < 1e-9);
+        assert!(distance(aabb.max, [3., 3., 1.]) < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_direction_or_axis() {
+        let patterns = [
+            fj::Pattern::linear(cube([0., 0.]), [0., 0., 0.], 3, 2.),
+            fj::Pattern::circular(
+                cube([2., 0.]),
+                [0., 0., 0.],
+                4,
+                fj::Angle::from_deg(90.),
+            ),
+        ];
+
+        for pattern in patterns {
+            assert!(matches!(
+                compute(&pattern),
+                Err(Error::InvalidPattern { .. })
+            ));
+            assert!(matches!(
+                pattern.bounding_volume(),
+                Err(Error::InvalidPattern { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn single_copy() -> Result<(), Error> {
+        let pattern = fj::Pattern::linear(cube([0., 0.]), [1., 0., 0.], 1, 2.);
+
+        let faces = compute(&pattern)?;
+        assert_eq!((&faces).into_iter().count(), 6);
+        assert_eq!(
+            pattern.bounding_volume()?,
+            aabb([0., 0., 0.], [1., 1., 1.])
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,326 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::approx::Tolerance,
+    builder::SolidBuilder,
+    objects::{Objects, Solid},
+    partial::{PartialObject, PartialSolid},
+    services::Service,
+};
+use fj_math::{Aabb, Point};
+
+use super::{shape_processor::material_id, Error, Shape};
+
+impl Shape for fj::Cuboid {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        _: Tolerance,
+        objects: &mut Service<Objects>,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        validate(self)?;
+
+        let mut solid = PartialSolid::default();
+        solid.with_cuboid_from_size(self.size(), objects);
+        Ok(build(solid, self.color(), self.material(), objects))
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        validate(self)?;
+
+        let [x, y, z] = self.size();
+        Ok(Aabb {
+            min: Point::from([-x / 2., -y / 2., 0.]),
+            max: Point::from([x / 2., y / 2., z]),
+        })
+    }
+}
+
+impl Shape for fj::Cylinder {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        _: Tolerance,
+        objects: &mut Service<Objects>,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        validate(self)?;
+
+        let mut solid = PartialSolid::default();
+        solid.with_cylinder_from_radius_and_height(
+            self.radius(),
+            self.height(),
+            objects,
+        );
+        Ok(build(solid, self.color(), self.material(), objects))
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        validate(self)?;
+
+        let r = self.radius();
+        Ok(Aabb {
+            min: Point::from([-r, -r, 0.]),
+            max: Point::from([r, r, self.height()]),
+        })
+    }
+}
+
+impl Shape for fj::Cone {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        validate(self)?;
+
+        let mut solid = PartialSolid::default();
+        solid.with_cone_from_radii_and_height(
+            self.bottom_radius(),
+            self.top_radius(),
+            self.height(),
+            tolerance,
+            objects,
+        );
+        Ok(build(solid, self.color(), self.material(), objects))
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        validate(self)?;
+
+        let r = self.bottom_radius().max(self.top_radius());
+        Ok(Aabb {
+            min: Point::from([-r, -r, 0.]),
+            max: Point::from([r, r, self.height()]),
+        })
+    }
+}
+
+impl Shape for fj::Sphere {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        validate(self)?;
+
+        let mut solid = PartialSolid::default();
+        solid.with_sphere_from_radius(self.radius(), tolerance, objects);
+        Ok(build(solid, self.color(), self.material(), objects))
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        validate(self)?;
+
+        let r = self.radius();
+        Ok(Aabb {
+            min: Point::from([-r, -r, -r]),
+            max: Point::from([r, r, r]),
+        })
+    }
+}
+
+impl Shape for fj::Torus {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        validate(self)?;
+
+        let mut solid = PartialSolid::default();
+        solid.with_torus_from_radii(
+            self.major_radius(),
+            self.minor_radius(),
+            tolerance,
+            objects,
+        );
+        Ok(build(solid, self.color(), self.material(), objects))
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        validate(self)?;
+
+        let r = self.minor_radius();
+        let outer = self.major_radius() + r;
+        Ok(Aabb {
+            min: Point::from([-outer, -outer, -r]),
+            max: Point::from([outer, outer, r]),
+        })
+    }
+}
+
+/// Check the dimensions of a primitive
+///
+/// See [`Error::InvalidPrimitive`].
+fn validate(primitive: &impl Primitive) -> Result<(), Error> {
+    for (dimension, value, is_valid) in primitive.dimensions() {
+        if !is_valid {
+            return Err(Error::InvalidPrimitive { dimension, value });
+        }
+    }
+
+    Ok(())
+}
+
+/// A primitive, whose dimensions can be validated
+trait Primitive {
+    /// The dimensions of the primitive, and whether each of them is valid
+    fn dimensions(&self) -> Vec<(&'static str, f64, bool)>;
+}
+
+impl Primitive for fj::Cuboid {
+    fn dimensions(&self) -> Vec<(&'static str, f64, bool)> {
+        let [x, y, z] = self.size();
+        vec![
+            ("size along x-axis", x, x > 0.),
+            ("size along y-axis", y, y > 0.),
+            ("size along z-axis", z, z > 0.),
+        ]
+    }
+}
+
+impl Primitive for fj::Cylinder {
+    fn dimensions(&self) -> Vec<(&'static str, f64, bool)> {
+        vec![
+            ("radius", self.radius(), self.radius() > 0.),
+            ("height", self.height(), self.height() > 0.),
+        ]
+    }
+}
+
+impl Primitive for fj::Cone {
+    fn dimensions(&self) -> Vec<(&'static str, f64, bool)> {
+        let [bottom, top] = [self.bottom_radius(), self.top_radius()];
+        vec![
+            (
+                "bottom radius",
+                bottom,
+                bottom >= 0. && (bottom > 0. || top > 0.),
+            ),
+            ("top radius", top, top >= 0.),
+            ("height", self.height(), self.height() > 0.),
+        ]
+    }
+}
+
+impl Primitive for fj::Sphere {
+    fn dimensions(&self) -> Vec<(&'static str, f64, bool)> {
+        vec![("radius", self.radius(), self.radius() > 0.)]
+    }
+}
+
+impl Primitive for fj::Torus {
+    fn dimensions(&self) -> Vec<(&'static str, f64, bool)> {
+        let [major, minor] = [self.major_radius(), self.minor_radius()];
+        vec![
+            ("major radius", major, major > 0.),
+            ("minor radius", minor, minor > 0. && minor < major),
+        ]
+    }
+}
+
+/// Build a primitive, giving all of its faces the same color and material
+fn build(
+    mut solid: PartialSolid,
+    color: [u8; 4],
+    material: Option<&fj::Material>,
+    objects: &mut Service<Objects>,
+) -> Solid {
+    for shell in &mut solid.shells {
+        for face in &mut shell.write().faces {
+            let mut face = face.write();
+            face.color = Some(Color(color));
+            face.material = material.map(material_id);
+        }
+    }
+
+    solid.build(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{algorithms::approx::Tolerance, services::Services};
+
+    use crate::{shape_processor::material_id, Error, Shape};
+
+    #[test]
+    fn invalid_dimensions() {
+        let primitives: [(fj::Shape, &str); 7] = [
+            (
+                fj::Cuboid::from_size([1., 0., 1.]).into(),
+                "size along y-axis",
+            ),
+            (
+                fj::Cylinder::from_radius_and_height(1., -1.).into(),
+                "height",
+            ),
+            (
+                fj::Cone::from_radii_and_height(0., 0., 1.).into(),
+                "bottom radius",
+            ),
+            (
+                fj::Cone::from_radii_and_height(1., -1., 1.).into(),
+                "top radius",
+            ),
+            (fj::Sphere::from_radius(0.).into(), "radius"),
+            (fj::Torus::from_radii(1., 1.).into(), "minor radius"),
+            (fj::Torus::from_radii(-2., 1.).into(), "major radius"),
+        ];
+
+        for (primitive, expected) in primitives {
+            let tolerance = Tolerance::from_scalar(0.01).unwrap();
+            let mut services = Services::new();
+            let result = primitive.compute_brep(
+                tolerance,
+                &mut services.objects,
+                &mut DebugInfo::new(),
+            );
+
+            assert!(
+                matches!(
+                    result,
+                    Err(Error::InvalidPrimitive { dimension, .. })
+                        if dimension == expected
+                ),
+                "Expected invalid {expected} of {primitive:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn material() -> Result<(), Error> {
+        let material =
+            fj::Material::from_name_and_color("PLA", [0, 0, 255, 255]);
+        let sphere =
+            fj::Sphere::from_radius(1.).with_material(material.clone());
+
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        let solid = sphere.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )?;
+
+        let all_have_material = solid
+            .shells()
+            .flat_map(|shell| shell.faces())
+            .all(|face| face.material() == Some(material_id(&material)));
+        assert!(all_have_material);
+
+        Ok(())
+    }
+}
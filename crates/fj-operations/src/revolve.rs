@@ -0,0 +1,217 @@
+use std::ops::Deref;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{approx::Tolerance, revolve::Revolve},
+    insert::Insert,
+    objects::{Objects, Solid},
+    services::Service,
+};
+use fj_math::{Aabb, Line, Point, Scalar, Vector};
+
+use super::{workplane, Error, Shape};
+
+impl Shape for fj::Revolve {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let sketch =
+            self.shape().compute_brep(tolerance, objects, debug_info)?;
+        let sketch = sketch.insert(objects);
+
+        let angle = if self.is_full() {
+            Scalar::TAU
+        } else {
+            Scalar::from(self.angle().rad())
+        };
+
+        let solid = sketch.revolve(axis(self)?, angle, objects)?;
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        // The revolved shape is contained in a cylinder around the axis, which
+        // encloses all corners of the shape's bounding volume.
+        let axis = axis(self)?;
+        let direction = axis.direction().normalize();
+
+        let mut min = Scalar::MAX;
+        let mut max = -Scalar::MAX;
+        let mut radius = Scalar::ZERO;
+
+        for vertex in self.shape().bounding_volume()?.vertices() {
+            let offset = vertex - axis.origin();
+            let along_axis = offset.dot(&direction);
+
+            if along_axis < min {
+                min = along_axis;
+            }
+            if along_axis > max {
+                max = along_axis;
+            }
+            radius = radius.max((offset - direction * along_axis).magnitude());
+        }
+
+        let radius = Vector::from([radius, radius, radius]);
+        let points = [min, max].into_iter().flat_map(|t| {
+            let end = axis.origin() + direction * t;
+            [end - radius, end + radius]
+        });
+
+        Ok(Aabb::<3>::from_points(points))
+    }
+}
+
+/// Compute the axis of the revolution
+///
+/// The axis is defined in the coordinates of the shape's workplane.
+fn axis(revolve: &fj::Revolve) -> Result<Line<3>, Error> {
+    let [x, y] = revolve.axis_origin();
+    let [dx, dy] = revolve.axis_direction();
+
+    let placement = workplane::placement(workplane::of(revolve.shape()))?;
+
+    Ok(Line::from_origin_and_direction(
+        placement.transform_point(&Point::from([x, y, 0.])),
+        placement.transform_vector(&Vector::from([dx, dy, 0.])),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{
+            approx::Tolerance, revolve::RevolveError, triangulate::Triangulate,
+        },
+        services::Services,
+    };
+    use fj_math::{Aabb, Point};
+
+    use crate::{Error, Shape};
+
+    /// Revolve a square around the y-axis, and compute the bounds of its mesh
+    fn revolve(
+        axis_direction: [f64; 2],
+        angle: fj::Angle,
+    ) -> Result<[[f64; 3]; 2], Error> {
+        let square = fj::Sketch::from_points(vec![
+            [1., 0.],
+            [2., 0.],
+            [2., 1.],
+            [1., 1.],
+        ]);
+        let revolve = fj::Revolve::from_axis_and_angle(
+            square.into(),
+            [0., 0.],
+            axis_direction,
+            angle,
+        );
+
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        let solid = revolve.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )?;
+
+        let mesh = (&solid, tolerance).triangulate();
+        let aabb = Aabb::<3>::from_points(mesh.vertices());
+
+        Ok([aabb.min, aabb.max].map(|point: Point<3>| {
+            point
+                .coords
+                .components
+                .map(|s| (s.into_f64() * 1e9).round() / 1e9)
+        }))
+    }
+
+    #[test]
+    fn angle() -> Result<(), Error> {
+        let full = [[-2., 0., -2.], [2., 1., 2.]];
+
+        // Angles wrap around, so a negative angle revolves the shape
+        // counterclockwise by the rest of a full turn.
+        assert_eq!(
+            revolve([0., 1.], fj::Angle::from_deg(-90.))?,
+            revolve([0., 1.], fj::Angle::from_deg(270.))?,
+        );
+        assert_eq!(revolve([0., 1.], fj::Angle::from_deg(-90.))?, full);
+
+        // Reversing the axis revolves the shape clockwise instead.
+        assert_eq!(
+            revolve([0., 1.], fj::Angle::from_deg(90.))?,
+            [[0., 0., -2.], [2., 1., 0.]],
+        );
+        assert_eq!(
+            revolve([0., -1.], fj::Angle::from_deg(90.))?,
+            [[0., 0., 0.], [2., 1., 2.]],
+        );
+
+        // A full turn wraps around to zero, which is a full revolution.
+        let full_turn = fj::Angle::from_deg(360.);
+        assert_eq!(full_turn.rad(), 0.);
+        assert_eq!(revolve([0., 1.], full_turn)?, full);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_shapes() {
+        let revolve = |shape: fj::Sketch| {
+            let revolve = fj::Revolve::from_axis_and_angle(
+                shape.into(),
+                [0., 0.],
+                [0., 1.],
+                fj::Angle::from_deg(90.),
+            );
+
+            let tolerance = Tolerance::from_scalar(0.01).unwrap();
+            let mut services = Services::new();
+            revolve.compute_brep(
+                tolerance,
+                &mut services.objects,
+                &mut DebugInfo::new(),
+            )
+        };
+
+        // Revolving the slanted edge of a chamfer would result in a cone.
+        let chamfered = fj::Sketch::from_points(vec![
+            [1., 0.],
+            [2., 0.],
+            [2., 0.5],
+            [1.5, 1.],
+            [1., 1.],
+        ]);
+        assert!(matches!(
+            revolve(chamfered),
+            Err(Error::Revolve(RevolveError::SlantedEdge))
+        ));
+
+        // Revolving a circle would result in a torus.
+        let circle = fj::Sketch::from_circle(
+            fj::Circle::from_center_and_radius([2., 0.], 1.),
+        );
+        assert!(matches!(
+            revolve(circle),
+            Err(Error::Revolve(RevolveError::CurvedEdge))
+        ));
+
+        let crossing = fj::Sketch::from_points(vec![
+            [-1., 0.],
+            [1., 0.],
+            [1., 1.],
+            [-1., 1.],
+        ]);
+        assert!(matches!(
+            revolve(crossing),
+            Err(Error::Revolve(RevolveError::FaceCrossesAxis))
+        ));
+    }
+}
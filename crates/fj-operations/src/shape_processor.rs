@@ -1,6 +1,10 @@
 //! API for processing shapes
 
-use fj_interop::{debug::DebugInfo, processed_shape::ProcessedShape};
+use fj_interop::{
+    debug::DebugInfo,
+    mesh::{Color, Material, MaterialId},
+    processed_shape::ProcessedShape,
+};
 use fj_kernel::{
     algorithms::{
         approx::{InvalidTolerance, Tolerance},
@@ -9,7 +13,7 @@ use fj_kernel::{
     services::Services,
     validate::ValidationError,
 };
-use fj_math::Scalar;
+use fj_math::{Aabb, Scalar};
 
 use crate::Shape as _;
 
@@ -22,39 +26,178 @@ pub struct ShapeProcessor {
 impl ShapeProcessor {
     /// Process an [`fj::Shape`] into [`ProcessedShape`]
     pub fn process(&self, shape: &fj::Shape) -> Result<ProcessedShape, Error> {
-        let aabb = shape.bounding_volume();
+        let aabb = shape.bounding_volume()?;
 
         let tolerance = match self.tolerance {
-            None => {
-                // Compute a reasonable default for the tolerance value. To do
-                // this, we just look at the smallest non-zero extent of the
-                // bounding box and divide that by some value.
-                let mut min_extent = Scalar::MAX;
-                for extent in aabb.size().components {
-                    if extent > Scalar::ZERO && extent < min_extent {
-                        min_extent = extent;
-                    }
-                }
-
-                let tolerance = min_extent / Scalar::from_f64(1000.);
-                Tolerance::from_scalar(tolerance)?
-            }
+            None => default_tolerance(&aabb)?,
             Some(user_defined_tolerance) => user_defined_tolerance,
         };
 
+        let mut materials = Vec::new();
+        collect_materials(shape, &mut materials);
+
         let mut services = Services::new();
         let mut debug_info = DebugInfo::new();
-        let shape = shape.compute_brep(&mut services.objects, &mut debug_info);
+        let shape = shape.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut debug_info,
+        )?;
         let mesh = (&shape, tolerance).triangulate();
 
         Ok(ProcessedShape {
             aabb,
             mesh,
+            materials,
             debug_info,
         })
     }
 }
 
+/// Compute a reasonable default for the tolerance value of a shape
+///
+/// To do this, we just look at the smallest non-zero extent of the shape's
+/// bounding box and divide that by some value.
+pub(crate) fn default_tolerance(
+    aabb: &Aabb<3>,
+) -> Result<Tolerance, InvalidTolerance> {
+    let mut min_extent = Scalar::MAX;
+    for extent in aabb.size().components {
+        if extent > Scalar::ZERO && extent < min_extent {
+            min_extent = extent;
+        }
+    }
+
+    let tolerance = min_extent / Scalar::from_f64(1000.);
+    Tolerance::from_scalar(tolerance)
+}
+
+/// Collect the materials of the sketches and primitives that a shape is made
+/// from
+///
+/// Materials that multiple of them are made of are only collected once.
+fn collect_materials(shape: &fj::Shape, materials: &mut Vec<Material>) {
+    match shape {
+        fj::Shape::Shape2d(shape) => collect_materials_2d(shape, materials),
+        fj::Shape::Chamfer(shape) => {
+            collect_materials_2d(shape.sweep().shape(), materials);
+        }
+        fj::Shape::Fillet(shape) => {
+            collect_materials_2d(shape.sweep().shape(), materials);
+        }
+        fj::Shape::Difference(shape) => {
+            for shape in shape.shapes() {
+                collect_materials(shape, materials);
+            }
+        }
+        fj::Shape::Group(shape) => {
+            collect_materials(&shape.a, materials);
+            collect_materials(&shape.b, materials);
+        }
+        fj::Shape::Hollow(shape) => collect_materials(shape.shape(), materials),
+        fj::Shape::Intersection(shape) => {
+            for shape in shape.shapes() {
+                collect_materials(shape, materials);
+            }
+        }
+        fj::Shape::Loft(shape) => {
+            for profile in shape.profiles() {
+                collect_material(profile.sketch().material(), materials);
+            }
+        }
+        fj::Shape::Pattern(shape) => {
+            collect_materials(shape.shape(), materials);
+        }
+        fj::Shape::Revolve(shape) => {
+            collect_materials_2d(shape.shape(), materials);
+        }
+        fj::Shape::Sweep(shape) => {
+            collect_materials_2d(shape.shape(), materials);
+        }
+        fj::Shape::SweepAlongPath(shape) => {
+            collect_materials_2d(shape.shape(), materials);
+        }
+        fj::Shape::Transform(shape) => {
+            collect_materials(&shape.shape, materials);
+        }
+        fj::Shape::Union(shape) => {
+            for shape in shape.shapes() {
+                collect_materials(shape, materials);
+            }
+        }
+        fj::Shape::Cone(shape) => collect_material(shape.material(), materials),
+        fj::Shape::Cuboid(shape) => {
+            collect_material(shape.material(), materials);
+        }
+        fj::Shape::Cylinder(shape) => {
+            collect_material(shape.material(), materials);
+        }
+        fj::Shape::Sphere(shape) => {
+            collect_material(shape.material(), materials);
+        }
+        fj::Shape::Torus(shape) => {
+            collect_material(shape.material(), materials);
+        }
+    }
+}
+
+fn collect_materials_2d(shape: &fj::Shape2d, materials: &mut Vec<Material>) {
+    match shape {
+        fj::Shape2d::Difference(shape) => {
+            for shape in shape.shapes() {
+                collect_materials_2d(shape, materials);
+            }
+        }
+        fj::Shape2d::Intersection(shape) => {
+            for shape in shape.shapes() {
+                collect_materials_2d(shape, materials);
+            }
+        }
+        fj::Shape2d::Offset(shape) => {
+            collect_materials_2d(shape.shape(), materials);
+        }
+        fj::Shape2d::Sketch(sketch) => {
+            collect_material(sketch.material(), materials);
+        }
+        fj::Shape2d::Union(shape) => {
+            for shape in shape.shapes() {
+                collect_materials_2d(shape, materials);
+            }
+        }
+        fj::Shape2d::Import(_) | fj::Shape2d::Text(_) => {}
+    }
+}
+
+fn collect_material(
+    material: Option<&fj::Material>,
+    materials: &mut Vec<Material>,
+) {
+    if let Some(material) = material {
+        let id = material_id(material);
+        if materials.iter().any(|material| material.id == id) {
+            return;
+        }
+
+        materials.push(Material {
+            id,
+            name: material.name().to_owned(),
+            color: Color(material.color()),
+            density: material.density(),
+        });
+    }
+}
+
+/// Compute the ID of a material
+///
+/// See [`MaterialId::from_properties`].
+pub(crate) fn material_id(material: &fj::Material) -> MaterialId {
+    MaterialId::from_properties(
+        material.name(),
+        material.color(),
+        material.density(),
+    )
+}
+
 /// A shape processing error
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, thiserror::Error)]
@@ -66,4 +209,60 @@ pub enum Error {
     /// Model has zero size
     #[error("Model has zero size")]
     Extent(#[from] InvalidTolerance),
+
+    /// The shape could not be computed
+    #[error(transparent)]
+    Shape(#[from] crate::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::{Error, ShapeProcessor};
+
+    #[test]
+    fn materials_that_share_a_color() -> Result<(), Error> {
+        let color = [0, 0, 255, 255];
+        let pla = fj::Material::from_name_and_color("PLA", color);
+        let petg = fj::Material::from_name_and_color("PETG", color);
+
+        let sketch = |material: fj::Material, x: f64| {
+            let points = vec![[x, 0.], [x + 1., 0.], [x, 1.]];
+            let sketch =
+                fj::Sketch::from_points(points).with_material(material);
+            fj::Sweep::from_path(sketch.into(), [0., 0., 1.])
+        };
+        let group = fj::Group {
+            a: sketch(pla.clone(), 0.).into(),
+            b: sketch(petg, 2.).into(),
+        };
+        let shape = fj::Shape::from(fj::Group {
+            a: group.into(),
+            b: sketch(pla, 4.).into(),
+        });
+
+        let processed = ShapeProcessor { tolerance: None }.process(&shape)?;
+
+        let names = processed
+            .materials
+            .iter()
+            .map(|material| material.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["PLA", "PETG"]);
+
+        let ids = processed
+            .materials
+            .iter()
+            .map(|material| Some(material.id))
+            .collect::<BTreeSet<_>>();
+        let triangle_ids = processed
+            .mesh
+            .triangles()
+            .map(|triangle| triangle.material)
+            .collect::<BTreeSet<_>>();
+        assert_eq!(triangle_ids, ids);
+
+        Ok(())
+    }
 }
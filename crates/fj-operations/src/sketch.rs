@@ -1,98 +1,589 @@
-use std::ops::Deref;
+use std::{f64::consts::TAU, ops::Deref};
 
 use fj_interop::{debug::DebugInfo, mesh::Color};
 use fj_kernel::{
-    builder::{FaceBuilder, HalfEdgeBuilder},
+    algorithms::{approx::Tolerance, reverse::Reverse},
+    builder::{CycleBuilder, HalfEdgeBuilder},
     insert::Insert,
-    objects::{Objects, Sketch},
+    objects::{Cycle, Objects, Sketch, Surface},
     partial::{
         Partial, PartialCycle, PartialFace, PartialHalfEdge, PartialObject,
         PartialSketch,
     },
     services::Service,
+    storage::Handle,
 };
-use fj_math::{Aabb, Point};
+use fj_math::{Aabb, Arc, BezierCurve, Point, Scalar};
 
-use super::Shape;
+use super::{shape_processor::material_id, workplane, Error, Shape};
 
 impl Shape for fj::Sketch {
     type Brep = Sketch;
 
     fn compute_brep(
         &self,
+        tolerance: Tolerance,
         objects: &mut Service<Objects>,
         _: &mut DebugInfo,
-    ) -> Self::Brep {
-        let surface = objects.surfaces.xy_plane();
+    ) -> Result<Self::Brep, Error> {
+        let surface = workplane::surface(self.workplane(), objects)?;
 
-        let face = match self.chain() {
-            fj::Chain::Circle(circle) => {
-                let half_edge = {
-                    let surface = Partial::from(surface);
+        let exterior =
+            cycle(self.chain(), surface.clone(), tolerance, objects)?;
 
-                    let mut half_edge = PartialHalfEdge::default();
+        // Interior cycles must have the opposite winding of the exterior
+        // cycle. We don't require that from the user, so fix it, if necessary.
+        let interiors = self
+            .interiors()
+            .iter()
+            .map(|chain| {
+                let interior =
+                    cycle(chain, surface.clone(), tolerance, objects)?;
 
-                    half_edge.curve().write().surface = surface.clone();
+                let interior = if interior.winding() == exterior.winding() {
+                    interior.reverse(objects)
+                } else {
+                    interior
+                };
 
-                    for vertex in &mut half_edge.vertices {
-                        vertex.write().surface_form.write().surface =
-                            surface.clone();
-                    }
+                Ok(Partial::from(interior))
+            })
+            .collect::<Result<_, Error>>()?;
 
-                    half_edge.update_as_circle_from_radius(circle.radius());
+        let face = PartialFace {
+            exterior: Partial::from(exterior),
+            interiors,
+            color: Some(Color(self.color())),
+            material: self.material().map(material_id),
+        };
 
-                    Partial::from_partial(half_edge)
-                };
-                let exterior = {
-                    let mut cycle = PartialCycle::default();
-                    cycle.half_edges.push(half_edge);
-                    Partial::from_partial(cycle)
-                };
+        let sketch = PartialSketch {
+            faces: vec![Partial::from_partial(face)],
+        }
+        .build(objects)
+        .insert(objects);
+        Ok(sketch.deref().clone())
+    }
 
-                PartialFace {
-                    exterior,
-                    color: Some(Color(self.color())),
-                    ..Default::default()
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        // Interior chains are contained within the exterior one, so we only
+        // need to look at that.
+        let aabb = match self.chain() {
+            fj::Chain::Circle(circle) => {
+                let center = Point::from(circle.center());
+                let radius = validate_radius(circle.radius())?;
+
+                Aabb {
+                    min: (center - [radius, radius]).to_xyz(),
+                    max: (center + [radius, radius]).to_xyz(),
                 }
             }
             fj::Chain::PolyChain(poly_chain) => {
-                let points = poly_chain
-                    .to_segments()
-                    .into_iter()
-                    .map(|fj::SketchSegment::LineTo { point }| point)
-                    .map(Point::from);
-
-                let mut face = PartialFace::default();
-                face.exterior.write().surface = Partial::from(surface);
-                face.update_exterior_as_polygon(points);
-                face.color = Some(Color(self.color()));
-
-                face
+                let vertices = vertices(poly_chain, None)?;
+
+                let mut points = Vec::new();
+                for (i, vertex) in vertices.iter().enumerate() {
+                    let start = vertices
+                        [(i + vertices.len() - 1) % vertices.len()]
+                    .point;
+
+                    if let Some(angle) = vertex.arc_angle {
+                        let arc = Arc::from_endpoints_and_angle(
+                            start,
+                            vertex.point,
+                            angle,
+                        );
+                        points.extend(arc_extremes(&arc));
+                    }
+
+                    points.push(vertex.point);
+                }
+
+                Aabb::<3>::from_points(points.into_iter().map(Point::to_xyz))
             }
         };
 
-        let sketch = PartialSketch {
-            faces: vec![Partial::from_partial(face)],
+        if *self.workplane() == fj::Workplane::xy() {
+            Ok(aabb)
+        } else {
+            Ok(workplane::placement(self.workplane())?.transform_aabb(&aabb))
         }
-        .build(objects)
-        .insert(objects);
-        sketch.deref().clone()
     }
+}
+
+/// Create a cycle from a chain
+///
+/// Bézier curves are approximated by straight lines within `tolerance`.
+pub fn cycle(
+    chain: &fj::Chain,
+    surface: Handle<Surface>,
+    tolerance: Tolerance,
+    objects: &mut Service<Objects>,
+) -> Result<Handle<Cycle>, Error> {
+    let surface = Partial::from(surface);
+
+    let cycle = match chain {
+        fj::Chain::Circle(circle) => {
+            let half_edge = {
+                let mut half_edge = PartialHalfEdge::default();
+
+                half_edge.curve().write().surface = surface.clone();
+
+                for vertex in &mut half_edge.vertices {
+                    vertex.write().surface_form.write().surface =
+                        surface.clone();
+                }
+
+                half_edge.update_as_circle_from_center_and_radius(
+                    circle.center(),
+                    validate_radius(circle.radius())?,
+                );
+
+                Partial::from_partial(half_edge)
+            };
 
-    fn bounding_volume(&self) -> Aabb<3> {
-        match self.chain() {
-            fj::Chain::Circle(circle) => Aabb {
-                min: Point::from([-circle.radius(), -circle.radius(), 0.0]),
-                max: Point::from([circle.radius(), circle.radius(), 0.0]),
+            let mut cycle = PartialCycle::default();
+            cycle.half_edges.push(half_edge);
+            cycle
+        }
+        fj::Chain::PolyChain(poly_chain) => {
+            let vertices = vertices(poly_chain, Some(tolerance))?;
+
+            let mut cycle = PartialCycle {
+                surface,
+                ..Default::default()
+            };
+            let half_edges = cycle.update_as_polygon_from_points(
+                vertices.iter().map(|vertex| vertex.point),
+            );
+
+            // Each half-edge ends at the vertex following the one it starts
+            // at.
+            for (i, mut half_edge) in half_edges.into_iter().enumerate() {
+                let vertex = &vertices[(i + 1) % vertices.len()];
+
+                if let Some(angle) = vertex.arc_angle {
+                    half_edge.write().update_as_arc(angle);
+                }
+            }
+
+            cycle
+        }
+    };
+
+    Ok(cycle.build(objects).insert(objects))
+}
+
+/// A vertex of a polygonal chain
+struct Vertex {
+    /// The position of the vertex
+    point: Point<2>,
+
+    /// The angle of the arc that leads to this vertex, if any
+    ///
+    /// `None`, if the vertex is reached by a straight line.
+    arc_angle: Option<Scalar>,
+}
+
+/// Convert the segments of a polygonal chain into vertices
+///
+/// Bézier curves are approximated by straight lines within `tolerance`, as the
+/// kernel doesn't support them yet. Without a tolerance, their control points
+/// become vertices instead. Those surround the curve, which is enough to
+/// compute a bounding volume.
+fn vertices(
+    poly_chain: &fj::PolyChain,
+    tolerance: Option<Tolerance>,
+) -> Result<Vec<Vertex>, Error> {
+    let segments = poly_chain.to_segments();
+
+    // The first segment starts where the last one ends.
+    let mut start = match segments.last() {
+        Some(segment) => Point::from(segment.point()),
+        None => return Ok(Vec::new()),
+    };
+
+    let mut vertices = Vec::new();
+
+    for segment in segments {
+        let point = Point::from(segment.point());
+
+        let is_arc = matches!(
+            segment,
+            fj::SketchSegment::ArcTo { .. }
+                | fj::SketchSegment::ArcAroundCenterTo { .. }
+        );
+        if is_arc && point == start {
+            return Err(Error::ArcEndsAtStart {
+                point: segment.point(),
+            });
+        }
+
+        match segment {
+            fj::SketchSegment::LineTo { .. } => {
+                vertices.push(Vertex {
+                    point,
+                    arc_angle: None,
+                });
+            }
+            fj::SketchSegment::ArcTo {
+                radius, direction, ..
+            } => {
+                let radius = validate_radius(radius)?;
+                let distance = start.distance_to(&point).into_f64();
+                // Allow for rounding errors, as arcs that are half circles
+                // are common.
+                if 2. * radius < distance * (1. - 1e-9) {
+                    return Err(Error::ArcRadiusTooSmall { radius, distance });
+                }
+                let angle = 2. * (distance / (2. * radius)).min(1.).asin();
+
+                vertices.push(Vertex {
+                    point,
+                    arc_angle: Some(arc_angle(angle, direction)),
+                });
+            }
+            fj::SketchSegment::ArcAroundCenterTo {
+                center, direction, ..
+            } => {
+                let center = Point::from(center);
+
+                let [r_start, r_end] = [start, point]
+                    .map(|point| point.distance_to(&center).into_f64());
+                // Allow for rounding errors, as the points are often computed
+                // from the center.
+                if (r_start - r_end).abs() > r_start.max(r_end) * 1e-9 {
+                    return Err(Error::ArcRadiiDiffer {
+                        start: r_start,
+                        end: r_end,
+                    });
+                }
+
+                let [a, b] = [start, point].map(|point| {
+                    let v = point - center;
+                    v.v.into_f64().atan2(v.u.into_f64())
+                });
+
+                let angle = match direction {
+                    fj::ArcDirection::Clockwise => (a - b).rem_euclid(TAU),
+                    fj::ArcDirection::Counterclockwise => {
+                        (b - a).rem_euclid(TAU)
+                    }
+                };
+
+                vertices.push(Vertex {
+                    point,
+                    arc_angle: Some(arc_angle(angle, direction)),
+                });
+            }
+            fj::SketchSegment::QuadraticBezierTo { control, .. } => {
+                let curve = BezierCurve::from_control_points([
+                    start,
+                    Point::from(control),
+                    point,
+                ]);
+                vertices.extend(bezier_vertices(&curve, tolerance));
+            }
+            fj::SketchSegment::CubicBezierTo { controls, .. } => {
+                let [c0, c1] = controls.map(Point::from);
+                let curve =
+                    BezierCurve::from_control_points([start, c0, c1, point]);
+                vertices.extend(bezier_vertices(&curve, tolerance));
+            }
+        }
+
+        start = point;
+    }
+
+    Ok(vertices)
+}
+
+/// Check that the radius of a circle or arc is a positive number
+///
+/// See [`Error::InvalidRadius`].
+fn validate_radius(radius: f64) -> Result<f64, Error> {
+    if radius.is_finite() && radius > 0. {
+        Ok(radius)
+    } else {
+        Err(Error::InvalidRadius { radius })
+    }
+}
+
+/// Give the (positive) angle of an arc the sign that matches its direction
+fn arc_angle(angle: f64, direction: fj::ArcDirection) -> Scalar {
+    let angle = match direction {
+        fj::ArcDirection::Clockwise => -angle,
+        fj::ArcDirection::Counterclockwise => angle,
+    };
+
+    Scalar::from(angle)
+}
+
+fn bezier_vertices(
+    curve: &BezierCurve<2>,
+    tolerance: Option<Tolerance>,
+) -> impl Iterator<Item = Vertex> {
+    let points = match tolerance {
+        Some(tolerance) => curve.approx(curve.num_segments(tolerance.inner())),
+        None => curve.control_points().to_vec(),
+    };
+
+    // The start point is the end point of the previous segment.
+    points.into_iter().skip(1).map(|point| Vertex {
+        point,
+        arc_angle: None,
+    })
+}
+
+/// Compute the points at which the arc reaches its furthest extent along the
+/// coordinate axes
+fn arc_extremes(arc: &Arc) -> Vec<Point<2>> {
+    let [start, end] = [arc.start_angle(), arc.end_angle()];
+    let [min, max] = if start < end {
+        [start, end]
+    } else {
+        [end, start]
+    };
+
+    [0., 1., 2., 3.]
+        .into_iter()
+        .filter_map(|quarter_turns| {
+            let angle = Scalar::from(quarter_turns * TAU / 4.);
+
+            // Find the first angle that is equivalent to `angle` and not
+            // smaller than `min`.
+            let turns = ((min - angle) / TAU).ceil();
+            let angle = angle + turns * TAU;
+
+            if angle > max {
+                return None;
+            }
+
+            let (sin, cos) = angle.sin_cos();
+            Some(arc.center() + [cos, sin].map(|c| c * arc.radius()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::approx::Tolerance, geometry::path::SurfacePath,
+        services::Services,
+    };
+    use fj_math::{Point, Scalar};
+
+    use crate::{Error, Shape};
+
+    #[test]
+    fn interiors() -> Result<(), Error> {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+
+        // The first interior has the same winding as the exterior, the second
+        // one the opposite winding.
+        let sketch = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [4., 0.],
+            [4., 4.],
+            [0., 4.],
+        ])
+        .with_interior(fj::PolyChain::from_points(vec![
+            [1., 1.],
+            [2., 1.],
+            [2., 2.],
+        ]))
+        .with_interior(fj::PolyChain::from_points(vec![
+            [3., 3.],
+            [3., 2.],
+            [2., 3.],
+        ]));
+
+        let sketch = sketch.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )?;
+
+        assert_eq!(sketch.faces().into_iter().count(), 1);
+        let face = sketch.faces().into_iter().next().unwrap();
+
+        assert_eq!(face.exterior().half_edges().count(), 4);
+        assert_eq!(face.interiors().count(), 2);
+        for interior in face.interiors() {
+            assert_eq!(interior.half_edges().count(), 3);
+            assert_ne!(interior.winding(), face.exterior().winding());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn circle_with_center() -> Result<(), Error> {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+
+        let sketch = fj::Sketch::from_circle(
+            fj::Circle::from_center_and_radius([2., 2.], 2.),
+        )
+        .with_interior(fj::Circle::from_center_and_radius([1., 2.], 0.5));
+
+        let aabb = sketch.bounding_volume()?;
+        assert_eq!(aabb.min, Point::from([0., 0., 0.]));
+        assert_eq!(aabb.max, Point::from([4., 4., 0.]));
+
+        let sketch = sketch.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )?;
+        let face = sketch.faces().into_iter().next().unwrap();
+
+        let circles = face
+            .all_cycles()
+            .map(|cycle| {
+                assert_eq!(cycle.half_edges().count(), 1);
+                let half_edge = cycle.half_edges().next().unwrap();
+
+                match half_edge.curve().path() {
+                    SurfacePath::Circle(circle) => {
+                        (circle.center(), circle.radius())
+                    }
+                    SurfacePath::Line(_) => panic!("Expected a circle"),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            circles,
+            [
+                (Point::from([2., 2.]), Scalar::from(2.)),
+                (Point::from([1., 2.]), Scalar::from(0.5)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    fn arc(radius: f64) -> fj::Sketch {
+        fj::Sketch::from_segments(vec![
+            fj::SketchSegment::LineTo { point: [0., 0.] },
+            fj::SketchSegment::ArcTo {
+                point: [2., 0.],
+                radius,
+                direction: fj::ArcDirection::Counterclockwise,
             },
-            fj::Chain::PolyChain(poly_chain) => Aabb::<3>::from_points(
-                poly_chain
-                    .to_segments()
-                    .into_iter()
-                    .map(|fj::SketchSegment::LineTo { point }| point)
-                    .map(Point::from)
-                    .map(Point::to_xyz),
+        ])
+    }
+
+    #[test]
+    fn arc_radius() -> Result<(), Error> {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+
+        // A radius of half the distance makes the arc a half circle.
+        arc(1.).compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )?;
+
+        assert!(matches!(
+            arc(0.9).compute_brep(
+                tolerance,
+                &mut services.objects,
+                &mut DebugInfo::new()
             ),
+            Err(Error::ArcRadiusTooSmall { .. })
+        ));
+        assert!(matches!(
+            arc(0.9).bounding_volume(),
+            Err(Error::ArcRadiusTooSmall { .. })
+        ));
+
+        for radius in [-1., f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                arc(radius).compute_brep(
+                    tolerance,
+                    &mut services.objects,
+                    &mut DebugInfo::new()
+                ),
+                Err(Error::InvalidRadius { .. })
+            ));
+            assert!(matches!(
+                arc(radius).bounding_volume(),
+                Err(Error::InvalidRadius { .. })
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn circle_radius() {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+
+        for radius in [0., -1., f64::NAN] {
+            let circle =
+                fj::Sketch::from_circle(fj::Circle::from_radius(radius));
+
+            assert!(matches!(
+                circle.compute_brep(
+                    tolerance,
+                    &mut services.objects,
+                    &mut DebugInfo::new()
+                ),
+                Err(Error::InvalidRadius { .. })
+            ));
+            assert!(matches!(
+                circle.bounding_volume(),
+                Err(Error::InvalidRadius { .. })
+            ));
         }
     }
+
+    #[test]
+    fn arc_end_point() {
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+
+        let arc_to_start = fj::Sketch::from_segments(vec![
+            fj::SketchSegment::LineTo { point: [1., 0.] },
+            fj::SketchSegment::LineTo { point: [0., 0.] },
+            fj::SketchSegment::ArcTo {
+                point: [0., 0.],
+                radius: 1.,
+                direction: fj::ArcDirection::Counterclockwise,
+            },
+        ]);
+        assert!(matches!(
+            arc_to_start.bounding_volume(),
+            Err(Error::ArcEndsAtStart { .. })
+        ));
+
+        let arc_around_center = |point| {
+            fj::Sketch::from_segments(vec![
+                fj::SketchSegment::LineTo { point: [1., 0.] },
+                fj::SketchSegment::ArcAroundCenterTo {
+                    point,
+                    center: [0., 0.],
+                    direction: fj::ArcDirection::Counterclockwise,
+                },
+            ])
+        };
+        assert!(matches!(
+            arc_around_center([0., 2.]).compute_brep(
+                tolerance,
+                &mut services.objects,
+                &mut DebugInfo::new()
+            ),
+            Err(Error::ArcRadiiDiffer { .. })
+        ));
+        assert!(matches!(
+            arc_around_center([1., 0.]).bounding_volume(),
+            Err(Error::ArcEndsAtStart { .. })
+        ));
+    }
 }
@@ -1,41 +1,169 @@
-use std::ops::Deref;
+use std::{
+    f64::consts::{PI, TAU},
+    ops::Deref,
+};
 
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    algorithms::sweep::Sweep,
+    algorithms::{approx::Tolerance, sweep::SweepWithDraft},
     insert::Insert,
     objects::{Objects, Solid},
     services::Service,
 };
-use fj_math::{Aabb, Vector};
+use fj_math::{Aabb, Point, Scalar, Vector};
 
-use super::Shape;
+use super::{workplane, Error, Shape};
 
 impl Shape for fj::Sweep {
     type Brep = Solid;
 
     fn compute_brep(
         &self,
+        tolerance: Tolerance,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
-        let sketch = self.shape().compute_brep(objects, debug_info);
+    ) -> Result<Self::Brep, Error> {
+        let sketch =
+            self.shape().compute_brep(tolerance, objects, debug_info)?;
         let sketch = sketch.insert(objects);
 
         let path = Vector::from(self.path());
+        let draft = signed(self.draft());
+        let twist = self.twist();
 
-        let solid = sketch.sweep(path, objects);
-        solid.deref().clone()
+        let solid =
+            sketch.sweep_with_draft(path, draft, twist, tolerance, objects)?;
+        Ok(solid.deref().clone())
     }
 
-    fn bounding_volume(&self) -> Aabb<3> {
-        self.shape()
-            .bounding_volume()
-            .merged(&Aabb::<3>::from_points(
-                self.shape()
-                    .bounding_volume()
-                    .vertices()
-                    .map(|v| v + self.path()),
-            ))
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        let shape = self.shape().bounding_volume()?;
+        let path = Vector::from(self.path());
+
+        // A negative draft grows the shape along the path.
+        let growth = (path.magnitude()
+            * -Scalar::from(signed(self.draft())).tan())
+        .max(Scalar::ZERO);
+
+        let shape = if self.twist() == 0. {
+            shape
+        } else {
+            // The shape rotates around an axis through the origin of its
+            // workplane, along the path. It is contained in a cylinder around
+            // that axis, which encloses all corners of the shape's bounding
+            // volume.
+            let origin = workplane::placement(workplane::of(self.shape()))?
+                .transform_point(&Point::origin());
+            let direction = path.normalize();
+
+            let mut min = Scalar::MAX;
+            let mut max = -Scalar::MAX;
+            let mut radius = Scalar::ZERO;
+
+            for vertex in shape.vertices() {
+                let offset = vertex - origin;
+                let along_axis = offset.dot(&direction);
+
+                min = min.min(along_axis);
+                max = max.max(along_axis);
+                radius =
+                    radius.max((offset - direction * along_axis).magnitude());
+            }
+
+            let radius = Vector::from([radius, radius, radius]);
+            Aabb::<3>::from_points([min, max].into_iter().flat_map(|t| {
+                let center = origin + direction * t;
+                [center - radius, center + radius]
+            }))
+        };
+        let growth = Vector::from([growth, growth, growth]);
+        let shape = Aabb {
+            min: shape.min - growth,
+            max: shape.max + growth,
+        };
+
+        Ok(shape.merged(&Aabb::<3>::from_points(
+            shape.vertices().map(|vertex| vertex + path),
+        )))
+    }
+}
+
+/// Convert an angle into radians, within the range of half a turn around zero
+///
+/// See the documentation of [`fj::Sweep`].
+fn signed(angle: fj::Angle) -> f64 {
+    if angle.rad() > PI {
+        angle.rad() - TAU
+    } else {
+        angle.rad()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use fj::syntax::*;
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, sweep::SweepWithDraftError},
+        services::Services,
+    };
+    use fj_math::Scalar;
+
+    use crate::{Error, Shape};
+
+    fn square() -> fj::Sketch {
+        fj::Sketch::from_points(vec![
+            [-1., -1.],
+            [1., -1.],
+            [1., 1.],
+            [-1., 1.],
+        ])
+    }
+
+    #[test]
+    fn twist_of_several_turns() -> Result<(), Error> {
+        // The profile is rotated around its origin, so its corners are
+        // contained in a cylinder around the path.
+        let sweep = square().sweep([0., 0., 1.]).with_twist(TAU * 2.5);
+        let aabb = sweep.bounding_volume()?;
+        let radius = 2_f64.sqrt();
+        assert_eq!(
+            [aabb.min.x, aabb.min.y],
+            [-radius, -radius].map(Scalar::from)
+        );
+        assert_eq!(
+            [aabb.max.x, aabb.max.y],
+            [radius, radius].map(Scalar::from)
+        );
+
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        sweep.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn draft_collapses_shape() {
+        let sweep = square()
+            .sweep([0., 0., 2.])
+            .with_draft(fj::Angle::from_deg(30.));
+
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        assert!(matches!(
+            sweep.compute_brep(
+                tolerance,
+                &mut services.objects,
+                &mut DebugInfo::new()
+            ),
+            Err(Error::SweepWithDraft(SweepWithDraftError::ProfileCollapses))
+        ));
     }
 }
@@ -0,0 +1,485 @@
+use std::ops::Deref;
+
+use fj::PathSegment;
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        sweep::{ProfileOrientation, SweepAlongPath},
+    },
+    builder::FaceBuilder,
+    insert::Insert,
+    objects::{Cycle, Objects, Sketch, Solid},
+    partial::{Partial, PartialFace, PartialObject, PartialSketch},
+    services::Service,
+    storage::Handle,
+};
+use fj_math::{Aabb, BezierCurve, Point, Scalar, Vector};
+
+use super::{boolean::is_polygonal, Error, Shape};
+
+impl Shape for fj::SweepAlongPath {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let sketch =
+            self.shape().compute_brep(tolerance, objects, debug_info)?;
+
+        let (sketch, orientation) = match self.orientation() {
+            fj::ProfileOrientation::Fixed => {
+                (sketch, ProfileOrientation::Fixed)
+            }
+            fj::ProfileOrientation::FollowTangent => {
+                let sketch = polygonal(sketch, tolerance, objects);
+                (sketch, ProfileOrientation::FollowTangent)
+            }
+        };
+        let sketch = sketch.insert(objects);
+
+        let solid = sketch.sweep_along_path(
+            path_points(self.path(), Some(tolerance))?,
+            orientation,
+            objects,
+        )?;
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        let shape = self.shape().bounding_volume()?;
+        let points = path_points(self.path(), None)?;
+
+        let aabb = match self.orientation() {
+            fj::ProfileOrientation::Fixed => points
+                .into_iter()
+                .map(|point| {
+                    Aabb::<3>::from_points(
+                        shape.vertices().map(|vertex| vertex + point.coords),
+                    )
+                })
+                .reduce(|a, b| a.merged(&b))
+                .unwrap_or(shape),
+            fj::ProfileOrientation::FollowTangent => {
+                // The shape is rotated around the path, so it is contained in
+                // a sphere around each point of the path, which encloses all
+                // corners of the shape's bounding volume.
+                let radius = shape
+                    .vertices()
+                    .into_iter()
+                    .map(|vertex| vertex.coords.magnitude())
+                    .fold(Scalar::ZERO, Scalar::max);
+                let radius = Vector::from([radius, radius, radius]);
+
+                Aabb::<3>::from_points(
+                    points
+                        .into_iter()
+                        .flat_map(|point| [point - radius, point + radius]),
+                )
+            }
+        };
+
+        Ok(aabb)
+    }
+}
+
+/// Replace the curved edges of a sketch by straight ones
+///
+/// Profiles that follow the tangent of a path must be bounded by straight
+/// edges, so circles and arcs are approximated by polygons within `tolerance`.
+fn polygonal(
+    sketch: Sketch,
+    tolerance: Tolerance,
+    objects: &mut Service<Objects>,
+) -> Sketch {
+    if sketch.faces().into_iter().all(|face| is_polygonal(face)) {
+        return sketch;
+    }
+
+    // The approximation of a cycle ends with its first point.
+    let points = |cycle: &Handle<Cycle>| {
+        let mut points = cycle
+            .approx(tolerance)
+            .points()
+            .into_iter()
+            .map(|point| point.local_form)
+            .collect::<Vec<_>>();
+        points.pop();
+        points
+    };
+
+    let mut faces = Vec::new();
+    for face in sketch.faces() {
+        let mut polygon = PartialFace::default();
+        polygon.exterior.write().surface =
+            Partial::from(face.surface().clone());
+        polygon.update_exterior_as_polygon(points(face.exterior()));
+        for interior in face.interiors() {
+            polygon.add_interior_polygon(points(interior));
+        }
+        polygon.color = Some(face.color());
+        polygon.material = face.material();
+
+        faces.push(Partial::from_partial(polygon));
+    }
+
+    PartialSketch { faces }.build(objects)
+}
+
+/// Approximate the path by a polyline
+///
+/// The path starts at the origin. Arcs and Bézier curves are approximated
+/// within `tolerance`. Without a tolerance, the points that an arc reaches
+/// furthest along the coordinate axes and the control points of Bézier curves
+/// are returned instead. Those surround the path, which is enough to compute a
+/// bounding volume.
+fn path_points(
+    path: &[PathSegment],
+    tolerance: Option<Tolerance>,
+) -> Result<Vec<Point<3>>, Error> {
+    let mut points = vec![Point::origin()];
+
+    for segment in path {
+        // Can't panic, as we initialized the `Vec` with one element.
+        let start = *points.last().unwrap();
+
+        // The start point of each segment is the end point of the previous
+        // one, so skip it.
+        match segment {
+            PathSegment::LineTo { point } => {
+                points.push(Point::from(*point));
+            }
+            PathSegment::ArcTo { via, point } => {
+                let arc =
+                    PathArc::from_points(start, (*via).into(), (*point).into())
+                        .ok_or(Error::ArcPointsOnLine {
+                            via: *via,
+                            point: *point,
+                        })?;
+
+                match tolerance {
+                    Some(tolerance) => points.extend(arc.approx(tolerance)),
+                    None => {
+                        points.extend(arc.extremes());
+                        points.push(Point::from(*point));
+                    }
+                }
+            }
+            PathSegment::QuadraticBezierTo { control, point } => {
+                let curve = BezierCurve::from_control_points([
+                    start,
+                    (*control).into(),
+                    (*point).into(),
+                ]);
+                points.extend(bezier_points(&curve, tolerance));
+            }
+            PathSegment::CubicBezierTo {
+                controls: [a, b],
+                point,
+            } => {
+                let curve = BezierCurve::from_control_points([
+                    start,
+                    (*a).into(),
+                    (*b).into(),
+                    (*point).into(),
+                ]);
+                points.extend(bezier_points(&curve, tolerance));
+            }
+        }
+    }
+
+    Ok(points)
+}
+
+/// Approximate a Bézier curve, skipping its start point
+///
+/// Returns the control points instead, if there's no tolerance.
+fn bezier_points(
+    curve: &BezierCurve<3>,
+    tolerance: Option<Tolerance>,
+) -> impl Iterator<Item = Point<3>> {
+    let points = match tolerance {
+        Some(tolerance) => curve.approx(curve.num_segments(tolerance.inner())),
+        None => curve.control_points().to_vec(),
+    };
+
+    points.into_iter().skip(1)
+}
+
+/// A circular arc of a path
+struct PathArc {
+    /// The center of the circle that the arc is part of
+    center: Point<3>,
+
+    /// The offset of the arc's start point from the center
+    a: Vector<3>,
+
+    /// The offset of the point a quarter turn along the arc from the center
+    b: Vector<3>,
+
+    /// The angle of the arc, which is always positive
+    angle: Scalar,
+
+    /// The end point of the arc
+    end: Point<3>,
+}
+
+impl PathArc {
+    /// Compute the circular arc from `start` to `end`, through `via`
+    ///
+    /// Returns `None`, if the points lie on a straight line.
+    fn from_points(
+        start: Point<3>,
+        via: Point<3>,
+        end: Point<3>,
+    ) -> Option<Self> {
+        let u = via - start;
+        let w = end - start;
+        let normal = u.cross(&w);
+
+        // The magnitude of the normal is the sine of the angle between `u` and
+        // `w`, scaled by their lengths. Allow for rounding errors.
+        if normal.magnitude() <= u.magnitude() * w.magnitude() * 1e-9 {
+            return None;
+        }
+
+        // The center of the circle that passes through all three points.
+        let center = start
+            + (normal.cross(&u) * w.dot(&w) + w.cross(&normal) * u.dot(&u))
+                / (normal.dot(&normal) * 2.);
+
+        // The arc goes around the normal counterclockwise.
+        let a = start - center;
+        let b = normal.normalize().cross(&a);
+
+        let angle = {
+            let offset = end - center;
+            let angle = Scalar::atan2(offset.dot(&b), offset.dot(&a));
+
+            if angle > Scalar::ZERO {
+                angle
+            } else {
+                angle + Scalar::TAU
+            }
+        };
+
+        Some(Self {
+            center,
+            a,
+            b,
+            angle,
+            end,
+        })
+    }
+
+    /// Access the point of the arc at an angle from its start point
+    fn point(&self, angle: Scalar) -> Point<3> {
+        let (sin, cos) = angle.sin_cos();
+        self.center + self.a * cos + self.b * sin
+    }
+
+    /// Approximate the arc, skipping its start point
+    fn approx(&self, tolerance: Tolerance) -> Vec<Point<3>> {
+        // Each segment deviates from the arc by at most the tolerance, like the
+        // segments that approximate circles in the kernel.
+        let increment = Scalar::max(
+            Scalar::ONE - tolerance.inner() / self.a.magnitude(),
+            -Scalar::ONE,
+        )
+        .acos()
+            * 2.;
+        let num_segments =
+            usize::max((self.angle / increment).ceil().into_f64() as usize, 1);
+
+        let mut points = (1..num_segments)
+            .map(|i| self.point(self.angle * (i as f64 / num_segments as f64)))
+            .collect::<Vec<_>>();
+        points.push(self.end);
+
+        points
+    }
+
+    /// Compute the points at which the arc reaches furthest along the
+    /// coordinate axes
+    fn extremes(&self) -> Vec<Point<3>> {
+        (0..3)
+            .flat_map(|axis| {
+                // The coordinate along the axis is extreme where its derivative
+                // is zero, which happens twice per turn, half a turn apart.
+                let angle = Scalar::atan2(
+                    self.b.components[axis],
+                    self.a.components[axis],
+                );
+                [angle, angle + Scalar::PI]
+            })
+            .map(|angle| {
+                if angle < Scalar::ZERO {
+                    angle + Scalar::TAU
+                } else {
+                    angle
+                }
+            })
+            .filter(|&angle| angle < self.angle)
+            .map(|angle| self.point(angle))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj::{PathSegment, ProfileOrientation};
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{
+        algorithms::{approx::Tolerance, sweep::SweepAlongPathError},
+        objects::Solid,
+        services::Services,
+    };
+    use fj_math::{Aabb, Point, Scalar};
+
+    use crate::{Error, Shape};
+
+    use super::path_points;
+
+    fn sweep(
+        shape: fj::Sketch,
+        path: &[[f64; 3]],
+        orientation: ProfileOrientation,
+    ) -> Result<Solid, Error> {
+        let path = path
+            .iter()
+            .map(|&point| PathSegment::LineTo { point })
+            .collect();
+        let sweep =
+            fj::SweepAlongPath::from_segments(shape.into(), path, orientation);
+
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        sweep.compute_brep(
+            tolerance,
+            &mut services.objects,
+            &mut DebugInfo::new(),
+        )
+    }
+
+    fn triangle() -> fj::Sketch {
+        fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]])
+    }
+
+    #[test]
+    fn round_pipe() -> Result<(), Error> {
+        let circle = fj::Sketch::from_circle(
+            fj::Circle::from_center_and_radius([0., 0.], 1.),
+        );
+        let solid = sweep(
+            circle,
+            &[[0., 0., 4.], [4., 0., 8.]],
+            ProfileOrientation::FollowTangent,
+        )?;
+
+        // The circle is approximated by a polygon, each of whose edges creates
+        // a side face along each segment.
+        let num_faces = solid
+            .shells()
+            .map(|shell| shell.faces().into_iter().count())
+            .sum::<usize>();
+        let num_edges = (num_faces - 2) / 2;
+        assert_eq!(num_edges * 2 + 2, num_faces);
+        assert!(num_edges > 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_paths() {
+        assert!(matches!(
+            sweep(triangle(), &[], ProfileOrientation::Fixed),
+            Err(Error::SweepAlongPath(SweepAlongPathError::EmptyPath))
+        ));
+        assert!(matches!(
+            sweep(
+                triangle(),
+                &[[0., 0., 1.], [1., 0., 1.]],
+                ProfileOrientation::Fixed
+            ),
+            Err(Error::SweepAlongPath(
+                SweepAlongPathError::SegmentAlongProfile
+            ))
+        ));
+        assert!(matches!(
+            sweep(
+                triangle(),
+                &[[0., 0., 1.], [0., 0., 0.5]],
+                ProfileOrientation::FollowTangent
+            ),
+            Err(Error::SweepAlongPath(SweepAlongPathError::PathReverses))
+        ));
+        assert!(matches!(
+            sweep(
+                triangle(),
+                &[[0., 0., 1.], [1., 0., 1.]],
+                ProfileOrientation::FollowTangent
+            ),
+            Err(Error::SweepAlongPath(
+                SweepAlongPathError::ProfileTooLargeForBend
+            ))
+        ));
+
+        assert!(matches!(
+            path_points(
+                &[PathSegment::ArcTo {
+                    via: [0., 0., 1.],
+                    point: [0., 0., 2.],
+                }],
+                None
+            ),
+            Err(Error::ArcPointsOnLine { .. })
+        ));
+    }
+
+    #[test]
+    fn arc_within_tolerance() -> Result<(), Error> {
+        // A half circle around `[0., 0., 1.]`.
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let points = path_points(
+            &[PathSegment::ArcTo {
+                via: [1., 0., 1.],
+                point: [0., 0., 2.],
+            }],
+            Some(tolerance),
+        )?;
+
+        let center = Point::from([0., 0., 1.]);
+
+        assert!(points.len() > 3);
+        for segment in points.windows(2) {
+            let middle = segment[0] + (segment[1] - segment[0]) / 2.;
+            let deviation = Scalar::ONE - middle.distance_to(&center);
+
+            assert!(deviation > Scalar::ZERO);
+            assert!(deviation <= tolerance.inner());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn arc_bounding_volume() -> Result<(), Error> {
+        // An arc around `[0., 0., 1.]`, which reaches furthest along the
+        // x-axis between its start and end points.
+        let arc = PathSegment::ArcTo {
+            via: [1., 0., 1.],
+            point: [1. / 2_f64.sqrt(), 0., 1. + 1. / 2_f64.sqrt()],
+        };
+
+        let points = path_points(&[arc], None)?;
+        let aabb = Aabb::<3>::from_points(points);
+
+        assert_eq!(aabb.min, Point::from([0., 0., 0.]));
+        assert_eq!(aabb.max.x, Scalar::ONE);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+use std::{fs, io};
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::approx::Tolerance,
+    objects::{Objects, Sketch},
+    services::Service,
+};
+use fj_math::{Aabb, Point};
+use ttf_parser::{GlyphId, OutlineBuilder};
+
+use super::{
+    contour::{self, Contour},
+    workplane, Shape,
+};
+
+impl Shape for fj::Text {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, crate::Error> {
+        let mut faces = Vec::new();
+        for sketch in sketches(self)? {
+            let sketch = sketch.compute_brep(tolerance, objects, debug_info)?;
+            faces.extend(sketch.faces().clone());
+        }
+
+        Ok(Sketch::new(faces))
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, crate::Error> {
+        let aabb = sketches(self)?
+            .iter()
+            .map(|sketch| sketch.bounding_volume())
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .reduce(|a, b| a.merged(&b));
+
+        match aabb {
+            Some(aabb) => Ok(aabb),
+            None => {
+                let origin = workplane::placement(self.workplane())?
+                    .transform_point(&Point::origin());
+                Ok(Aabb {
+                    min: origin,
+                    max: origin,
+                })
+            }
+        }
+    }
+}
+
+/// Convert the glyph outlines of a text into sketches
+///
+/// Each outline that is not within another one becomes a sketch, with the
+/// outlines directly inside of it as holes. Outlines within those holes start
+/// the next level of sketches, and so on.
+fn sketches(text: &fj::Text) -> Result<Vec<fj::Sketch>, crate::Error> {
+    let font_error = |error| crate::Error::Font {
+        path: text.font().into(),
+        error,
+    };
+
+    let data = fs::read(text.font()).map_err(|err| font_error(err.into()))?;
+    let face = ttf_parser::Face::parse(&data, 0)
+        .map_err(|err| font_error(err.into()))?;
+
+    let scale = text.size() / f64::from(face.units_per_em());
+    let line_height = (f64::from(face.ascender())
+        - f64::from(face.descender())
+        + f64::from(face.line_gap()))
+        * scale;
+
+    let mut outline = Outline {
+        contours: Vec::new(),
+        scale,
+        offset: [0., 0.],
+    };
+    for (i, line) in text.text().lines().enumerate() {
+        outline.offset = [0., -(i as f64) * line_height];
+
+        for c in line.chars() {
+            // Glyph 0 is the glyph for missing characters.
+            let glyph = face.glyph_index(c).unwrap_or(GlyphId(0));
+            face.outline_glyph(glyph, &mut outline);
+
+            let advance = face.glyph_hor_advance(glyph).unwrap_or(0);
+            outline.offset[0] += f64::from(advance) * scale;
+        }
+    }
+
+    Ok(contour::sketches(
+        outline.contours,
+        text.color(),
+        text.workplane(),
+    ))
+}
+
+/// An error that occurred while loading a font file
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The font file could not be read
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    /// The font file could not be parsed
+    #[error("Invalid font: {0}")]
+    Parse(#[from] ttf_parser::FaceParsingError),
+}
+
+/// Collects the contours of glyph outlines
+struct Outline {
+    contours: Vec<Contour>,
+
+    /// The factor that converts font units into the units of the model
+    scale: f64,
+
+    /// The position of the current glyph's origin
+    offset: [f64; 2],
+}
+
+impl Outline {
+    fn point(&self, x: f32, y: f32) -> [f64; 2] {
+        let [u, v] = self.offset;
+        [u + f64::from(x) * self.scale, v + f64::from(y) * self.scale]
+    }
+
+    fn current_contour(&mut self) -> &mut Contour {
+        self.contours
+            .last_mut()
+            .expect("Contour must be started before adding segments")
+    }
+}
+
+impl OutlineBuilder for Outline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let start = self.point(x, y);
+        self.contours.push(Contour::new(start));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let point = self.point(x, y);
+        self.current_contour()
+            .push(fj::SketchSegment::LineTo { point });
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let [control, point] = [self.point(x1, y1), self.point(x, y)];
+        self.current_contour()
+            .push(fj::SketchSegment::QuadraticBezierTo { control, point });
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let controls = [self.point(x1, y1), self.point(x2, y2)];
+        let point = self.point(x, y);
+        self.current_contour()
+            .push(fj::SketchSegment::CubicBezierTo { controls, point });
+    }
+
+    fn close(&mut self) {
+        self.current_contour().close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point};
+
+    use crate::{Error, FontError, Shape};
+
+    /// A font with glyphs for "A", "O", and "i"
+    ///
+    /// The "O" is made from quadratic curves, and has a hole, like the "A".
+    /// The "i" is made from two separate outlines.
+    const FONT: &str =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fonts/outlines.ttf");
+
+    fn text(text: &str) -> fj::Text {
+        fj::Text::from_text_size_and_font(text, 10., FONT)
+    }
+
+    #[test]
+    fn glyph_outlines() -> Result<(), Error> {
+        let holes = |text: fj::Text| -> Result<Vec<usize>, Error> {
+            Ok(super::sketches(&text)?
+                .iter()
+                .map(|sketch| sketch.interiors().len())
+                .collect())
+        };
+
+        assert_eq!(holes(text("OA"))?, [1, 1]);
+        assert_eq!(holes(text("i"))?, [0, 0]);
+        assert_eq!(holes(text(""))?, []);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bounding_volume() -> Result<(), Error> {
+        // The font has 1000 units per em, its outlines are placed one after
+        // the other, and its lines are 1000 units apart.
+        assert_eq!(
+            text("O").bounding_volume()?,
+            Aabb {
+                min: Point::from([1., 0., 0.]),
+                max: Point::from([5., 7., 0.]),
+            }
+        );
+        assert_eq!(
+            text("OO\nO").bounding_volume()?,
+            Aabb {
+                min: Point::from([1., -10., 0.]),
+                max: Point::from([11., 7., 0.]),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_font() {
+        let text =
+            |font: &str| fj::Text::from_text_size_and_font("O", 1., font);
+
+        assert!(matches!(
+            super::sketches(&text("missing.ttf")),
+            Err(Error::Font {
+                error: FontError::Io(_),
+                ..
+            })
+        ));
+        assert!(matches!(
+            super::sketches(&text(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/Cargo.toml"
+            ))),
+            Err(Error::Font {
+                error: FontError::Parse(_),
+                ..
+            })
+        ));
+    }
+}
@@ -1,33 +1,93 @@
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    algorithms::transform::TransformObject,
+    algorithms::{approx::Tolerance, transform::TransformObject},
     objects::{FaceSet, Objects},
     services::Service,
 };
-use fj_math::{Aabb, Transform, Vector};
+use fj_math::{Aabb, Transform};
 
-use super::Shape;
+use super::{Error, Shape};
 
 impl Shape for fj::Transform {
     type Brep = FaceSet;
 
     fn compute_brep(
         &self,
+        tolerance: Tolerance,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
-        self.shape
-            .compute_brep(objects, debug_info)
-            .transform(&make_transform(self), objects)
+    ) -> Result<Self::Brep, Error> {
+        let transform = make_transform(self)?;
+        Ok(self
+            .shape
+            .compute_brep(tolerance, objects, debug_info)?
+            .transform(&transform, objects))
     }
 
-    fn bounding_volume(&self) -> Aabb<3> {
-        make_transform(self).transform_aabb(&self.shape.bounding_volume())
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        Ok(
+            make_transform(self)?
+                .transform_aabb(&self.shape.bounding_volume()?),
+        )
     }
 }
 
-fn make_transform(transform: &fj::Transform) -> Transform {
-    let axis = Vector::from(transform.axis).normalize();
-    Transform::translation(transform.offset)
-        * Transform::rotation(axis * transform.angle.rad())
+fn make_transform(transform: &fj::Transform) -> Result<Transform, Error> {
+    Transform::from_matrix(transform.matrix).ok_or(Error::SingularTransform {
+        matrix: transform.matrix,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{algorithms::approx::Tolerance, services::Services};
+
+    use crate::{Error, Shape};
+
+    #[test]
+    fn singular_matrix() {
+        let square = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ]);
+        let transform = fj::Transform::scaling(square.into(), [1., 0., 1.]);
+
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let mut services = Services::new();
+        assert!(matches!(
+            transform.compute_brep(
+                tolerance,
+                &mut services.objects,
+                &mut DebugInfo::new()
+            ),
+            Err(Error::SingularTransform { .. })
+        ));
+        assert!(matches!(
+            transform.bounding_volume(),
+            Err(Error::SingularTransform { .. })
+        ));
+    }
+
+    #[test]
+    fn rotation_around_zero_axis() {
+        let square = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ]);
+        let transform = fj::Transform::rotation(
+            square.into(),
+            [0., 0., 0.],
+            fj::Angle::from_deg(90.),
+        );
+
+        assert!(matches!(
+            transform.bounding_volume(),
+            Err(Error::SingularTransform { .. })
+        ));
+    }
 }
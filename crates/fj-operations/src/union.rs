@@ -0,0 +1,44 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::approx::Tolerance,
+    objects::{FaceSet, Objects},
+    services::Service,
+};
+use fj_math::Aabb;
+
+use super::{
+    boolean::{self, Operation},
+    Error, Shape,
+};
+
+impl Shape for fj::Union {
+    type Brep = FaceSet;
+
+    fn compute_brep(
+        &self,
+        tolerance: Tolerance,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, Error> {
+        let [a, b] = self.shapes();
+
+        let shapes = [
+            a.compute_brep(tolerance, objects, debug_info)?,
+            b.compute_brep(tolerance, objects, debug_info)?,
+        ];
+        let bounding_volumes = [a.bounding_volume()?, b.bounding_volume()?];
+
+        Ok(boolean::compute(
+            Operation::Union,
+            shapes,
+            bounding_volumes,
+            tolerance,
+            objects,
+        ))
+    }
+
+    fn bounding_volume(&self) -> Result<Aabb<3>, Error> {
+        let [a, b] = self.shapes();
+        Ok(a.bounding_volume()?.merged(&b.bounding_volume()?))
+    }
+}
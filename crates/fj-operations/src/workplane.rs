@@ -0,0 +1,294 @@
+//! Placement of sketches on workplanes
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    geometry::{path::GlobalPath, surface::SurfaceGeometry},
+    insert::Insert,
+    objects::{FaceSet, Objects, Surface},
+    services::{Service, Services},
+    storage::Handle,
+};
+use fj_math::{Line, Point, Scalar, Transform, Vector};
+
+use super::{
+    boolean::face_normal, shape_processor::default_tolerance, Error, Shape,
+};
+
+/// Access the workplane of a 2-dimensional shape
+///
+/// Shapes that combine other shapes are placed on the workplane of the first
+/// one.
+pub fn of(shape: &fj::Shape2d) -> &fj::Workplane {
+    match shape {
+        fj::Shape2d::Difference(shape) => of(&shape.shapes()[0]),
+        fj::Shape2d::Import(shape) => shape.workplane(),
+        fj::Shape2d::Intersection(shape) => of(&shape.shapes()[0]),
+        fj::Shape2d::Offset(shape) => of(shape.shape()),
+        fj::Shape2d::Sketch(shape) => shape.workplane(),
+        fj::Shape2d::Text(shape) => shape.workplane(),
+        fj::Shape2d::Union(shape) => of(&shape.shapes()[0]),
+    }
+}
+
+/// Compute the transform that places the xy-plane onto a workplane
+///
+/// If the workplane is defined by the face of a shape, that shape needs to be
+/// computed to find the face. This happens in a separate set of objects, as
+/// the shape itself doesn't become part of the result. The placement isn't
+/// remembered, so the shape is computed again on every call.
+///
+/// The placement is needed to compute bounding volumes, before the tolerance
+/// that a model is processed with is known. The shape is computed with the
+/// default tolerance for its size instead, which only matters for faces that
+/// approximate curved ones. Those are never found, unless they happen to face
+/// into the named direction.
+///
+/// Only the six faces that face into the direction of a coordinate axis can be
+/// named (see [`fj::NamedFace`]), so tilted faces are never found.
+///
+/// Returns an error, if the workplane's normal is parallel to its x-direction,
+/// or if the shape has no planar face that faces into the named direction.
+pub fn placement(workplane: &fj::Workplane) -> Result<Transform, Error> {
+    let (shape, face) = match workplane {
+        fj::Workplane::Plane {
+            origin,
+            normal,
+            x_direction,
+        } => {
+            return placement_on_plane(
+                Point::from(*origin),
+                Vector::from(*normal),
+                Vector::from(*x_direction),
+            );
+        }
+        fj::Workplane::Face { shape, face } => (shape, face),
+    };
+
+    let [normal, x_direction] = orientation(face);
+
+    // Only a shape without faces has no size.
+    let missing_face = || Error::MissingFace { face: *face };
+    let tolerance = default_tolerance(&shape.bounding_volume()?)
+        .map_err(|_| missing_face())?;
+
+    let mut services = Services::new();
+    let faces = shape.compute_brep(
+        tolerance,
+        &mut services.objects,
+        &mut DebugInfo::new(),
+    )?;
+
+    let distance = distance_of(face, &faces).ok_or_else(missing_face)?;
+
+    placement_on_plane(Point::origin() + normal * distance, normal, x_direction)
+}
+
+/// Access the normal and x-direction of the plane of a named face
+pub fn orientation(face: &fj::NamedFace) -> [Vector<3>; 2] {
+    match face {
+        fj::NamedFace::Top => [[0., 0., 1.], [1., 0., 0.]],
+        fj::NamedFace::Bottom => [[0., 0., -1.], [1., 0., 0.]],
+        fj::NamedFace::Front => [[0., -1., 0.], [1., 0., 0.]],
+        fj::NamedFace::Back => [[0., 1., 0.], [-1., 0., 0.]],
+        fj::NamedFace::Left => [[-1., 0., 0.], [0., -1., 0.]],
+        fj::NamedFace::Right => [[1., 0., 0.], [0., 1., 0.]],
+    }
+    .map(Vector::from)
+}
+
+/// Compute the distance of a named face's plane from the origin
+///
+/// The distance is measured along the normal of the named face. Returns `None`,
+/// if none of the faces is a planar face that faces into the right direction.
+pub fn distance_of(face: &fj::NamedFace, faces: &FaceSet) -> Option<Scalar> {
+    let [normal, _] = orientation(face);
+
+    // We're looking for the outermost of the faces that face into the right
+    // direction.
+    faces
+        .into_iter()
+        .filter_map(|candidate| {
+            let face_normal = face_normal(candidate)?;
+            if face_normal.dot(&normal) < Scalar::ONE - 1e-9 {
+                return None;
+            }
+
+            let point = candidate
+                .surface()
+                .geometry()
+                .point_from_surface_coords([0., 0.]);
+            Some(normal.dot(&point.coords))
+        })
+        .max()
+}
+
+/// Compute the transform that places the xy-plane onto a plane
+///
+/// The plane is defined by its origin, its normal, and the direction that the
+/// x-axis is placed along. That direction is projected into the plane, so it
+/// doesn't need to be exactly perpendicular to the normal. It must not be
+/// parallel to the normal though, or there is no direction to project it to.
+pub fn placement_on_plane(
+    origin: Point<3>,
+    normal: Vector<3>,
+    x_direction: Vector<3>,
+) -> Result<Transform, Error> {
+    let invalid = || Error::InvalidPlane {
+        normal: normal.components.map(|s| s.into_f64()),
+        x_direction: x_direction.components.map(|s| s.into_f64()),
+    };
+
+    if normal.magnitude() == Scalar::ZERO {
+        return Err(invalid());
+    }
+    let normal = normal.normalize();
+
+    let x_axis = x_direction - normal * x_direction.dot(&normal);
+    if x_axis.magnitude() <= Scalar::from(1e-12) * x_direction.magnitude() {
+        return Err(invalid());
+    }
+    let x_axis = x_axis.normalize();
+    let y_axis = normal.cross(&x_axis);
+
+    // The axes become the columns of the matrix. Building it from them
+    // directly, instead of composing rotations, keeps the transform exact for
+    // planes that are aligned with the coordinate axes.
+    let columns = [x_axis, y_axis, normal, origin.coords];
+    let row = |i: usize| columns.map(|column| column.components[i].into_f64());
+
+    Transform::from_matrix([row(0), row(1), row(2)]).ok_or_else(invalid)
+}
+
+/// Access the surface that sketches on a workplane are built on
+///
+/// The coordinate planes are shared by all sketches placed on them. Any other
+/// workplane results in a new surface.
+pub fn surface(
+    workplane: &fj::Workplane,
+    objects: &mut Service<Objects>,
+) -> Result<Handle<Surface>, Error> {
+    if *workplane == fj::Workplane::xy() {
+        return Ok(objects.surfaces.xy_plane());
+    }
+    if *workplane == fj::Workplane::xz() {
+        return Ok(objects.surfaces.xz_plane());
+    }
+    if *workplane == fj::Workplane::yz() {
+        return Ok(objects.surfaces.yz_plane());
+    }
+
+    let placement = placement(workplane)?;
+
+    let origin = placement.transform_point(&Point::origin());
+    let u = placement.transform_vector(&Vector::unit_x());
+    let v = placement.transform_vector(&Vector::unit_y());
+
+    let surface = Surface::new(SurfaceGeometry {
+        u: GlobalPath::Line(Line::from_origin_and_direction(origin, u)),
+        v,
+    })
+    .insert(objects);
+
+    Ok(surface)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::Error;
+
+    use super::placement;
+
+    #[test]
+    fn plane() -> Result<(), Error> {
+        let workplane = fj::Workplane::from_origin_normal_and_x_direction(
+            [0., 0., 1.],
+            [0., 1., 1.],
+            [1., 0., 0.],
+        );
+        let placement = placement(&workplane)?;
+
+        let y = Scalar::from(0.5).sqrt();
+        let expected = [
+            ([0., 0.], [0., 0., 1.]),
+            ([1., 0.], [1., 0., 1.]),
+            ([0., 1.], [0., y.into_f64(), 1. - y.into_f64()]),
+        ];
+        for ([u, v], point) in expected {
+            let placed = placement.transform_point(&Point::from([u, v, 0.]));
+            assert!(
+                (placed - Point::from(point)).magnitude() < Scalar::from(1e-12)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn plane_without_x_axis() {
+        for (normal, x_direction) in [
+            ([0., 0., 1.], [0., 0., 2.]),
+            ([0., 0., 1.], [0., 0., -1.]),
+            ([0., 0., 0.], [1., 0., 0.]),
+        ] {
+            let workplane = fj::Workplane::from_origin_normal_and_x_direction(
+                [0., 0., 0.],
+                normal,
+                x_direction,
+            );
+            assert!(matches!(
+                placement(&workplane),
+                Err(Error::InvalidPlane { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn face() -> Result<(), Error> {
+        let square = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ]);
+        let cuboid =
+            fj::Shape::from(fj::Sweep::from_path(square.into(), [0., 0., 2.]));
+
+        let expected = [
+            (fj::NamedFace::Top, [0., 0., 2.], [1., 0., 0.]),
+            (fj::NamedFace::Bottom, [0., 0., 0.], [1., 0., 0.]),
+            (fj::NamedFace::Right, [1., 0., 0.], [0., 1., 0.]),
+            (fj::NamedFace::Back, [0., 1., 0.], [-1., 0., 0.]),
+        ];
+        for (face, origin, x_axis) in expected {
+            let workplane = fj::Workplane::from_face(cuboid.clone(), face);
+            let placement = placement(&workplane)?;
+
+            assert_eq!(
+                placement.transform_point(&Point::origin()),
+                Point::from(origin)
+            );
+            assert_eq!(
+                placement.transform_vector(&Vector::unit_x()),
+                Vector::from(x_axis)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_face() {
+        let square =
+            fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [1., 1.]]);
+        let workplane = fj::Workplane::from_face(square, fj::NamedFace::Bottom);
+
+        assert!(matches!(
+            placement(&workplane),
+            Err(Error::MissingFace {
+                face: fj::NamedFace::Bottom
+            })
+        ));
+    }
+}
@@ -0,0 +1,264 @@
+#!/usr/bin/env python3
+"""Generate `outlines.ttf`, the font that the text tests use
+
+The font is made from scratch, with glyph outlines defined below. It contains
+no data from any other font, and is covered by the same license as the rest of
+this repository.
+
+It has glyphs for "A", "O", and "i":
+
+- The "A" is made from straight lines, and has a hole.
+- The "O" is made from quadratic curves, and has a hole.
+- The "i" is made from two separate outlines.
+
+The font has 1000 units per em, and its lines are 1000 units apart. Only the
+tables that are required to parse the font and outline its glyphs are written.
+
+Run this script from any directory, with no arguments. It only uses the Python
+standard library, and overwrites `outlines.ttf` next to it.
+"""
+
+import os
+import struct
+
+UNITS_PER_EM = 1000
+ASCENDER = 800
+DESCENDER = -200
+
+# Each glyph is given as its advance width, and its contours. Each contour is a
+# list of points `(x, y, on_curve)`.
+GLYPHS = [
+    # Glyph 0, for missing characters, is empty.
+    (500, []),
+    # "A"
+    (
+        600,
+        [
+            [(173, 267, True), (369, 267, True), (270, 587, True)],
+            [
+                (6, 0, True),
+                (224, 656, True),
+                (320, 656, True),
+                (541, 0, True),
+                (452, 0, True),
+                (390, 200, True),
+                (151, 200, True),
+                (85, 0, True),
+            ],
+        ],
+    ),
+    # "O"
+    (
+        600,
+        [
+            [
+                (300, 0, True),
+                (500, 0, False),
+                (500, 350, True),
+                (500, 700, False),
+                (300, 700, True),
+                (100, 700, False),
+                (100, 350, True),
+                (100, 0, False),
+            ],
+            [
+                (300, 100, True),
+                (200, 100, False),
+                (200, 350, True),
+                (200, 600, False),
+                (300, 600, True),
+                (400, 600, False),
+                (400, 350, True),
+                (400, 100, False),
+            ],
+        ],
+    ),
+    # "i"
+    (
+        300,
+        [
+            [(100, 0, True), (100, 500, True), (200, 500, True), (200, 0, True)],
+            [
+                (100, 600, True),
+                (100, 700, True),
+                (200, 700, True),
+                (200, 600, True),
+            ],
+        ],
+    ),
+]
+
+# The characters that are mapped to the glyphs, starting with glyph 1
+CHARACTERS = "AOi"
+
+
+def glyph(contours):
+    if not contours:
+        return b""
+
+    points = [point for contour in contours for point in contour]
+    xs = [x for x, _, _ in points]
+    ys = [y for _, y, _ in points]
+
+    data = struct.pack(
+        ">hhhhh", len(contours), min(xs), min(ys), max(xs), max(ys)
+    )
+
+    end = -1
+    for contour in contours:
+        end += len(contour)
+        data += struct.pack(">H", end)
+
+    # No instructions
+    data += struct.pack(">H", 0)
+
+    data += bytes(1 if on_curve else 0 for _, _, on_curve in points)
+
+    # Coordinates are stored as 16-bit deltas from the previous point.
+    for coords in [xs, ys]:
+        previous = 0
+        for c in coords:
+            data += struct.pack(">h", c - previous)
+            previous = c
+
+    return data
+
+
+def pad(data):
+    return data + b"\0" * (-len(data) % 4)
+
+
+def checksum(data):
+    data = pad(data)
+    return sum(struct.unpack(f">{len(data) // 4}I", data)) & 0xFFFFFFFF
+
+
+def font():
+    glyf = b""
+    loca = [0]
+    for _, contours in GLYPHS:
+        data = glyph(contours)
+        glyf += data + b"\0" * (len(data) % 2)
+        loca.append(len(glyf))
+
+    points = [
+        (x, y)
+        for _, contours in GLYPHS
+        for contour in contours
+        for x, y, _ in contour
+    ]
+    x_max = max(advance for advance, _ in GLYPHS)
+    y_max = max(y for _, y in points)
+
+    # The checksum adjustment is filled in, once the whole font is written.
+    head = struct.pack(
+        ">IIIIHHQQhhhhHHhhh",
+        0x00010000,
+        0x00010000,
+        0,
+        0x5F0F3CF5,
+        0,
+        UNITS_PER_EM,
+        0,
+        0,
+        0,
+        0,
+        x_max,
+        y_max,
+        0,
+        8,
+        2,
+        0,
+        0,
+    )
+    hhea = struct.pack(
+        ">IhhhHhhhhhhhhhhhH",
+        0x00010000,
+        ASCENDER,
+        DESCENDER,
+        0,
+        x_max,
+        0,
+        0,
+        x_max,
+        1,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        len(GLYPHS),
+    )
+    hmtx = b"".join(struct.pack(">Hh", advance, 0) for advance, _ in GLYPHS)
+    maxp = struct.pack(">IH", 0x00005000, len(GLYPHS))
+
+    # A format 4 subtable, with a segment for each character, and the final
+    # segment that the format requires
+    codes = [ord(c) for c in CHARACTERS] + [0xFFFF]
+    deltas = [i + 1 - ord(c) for i, c in enumerate(CHARACTERS)] + [1]
+    seg_count = len(codes)
+    search_range = 2 ** (seg_count.bit_length() - 1) * 2
+    subtable = struct.pack(
+        ">HHHHHHH",
+        4,
+        16 + 8 * seg_count,
+        0,
+        seg_count * 2,
+        search_range,
+        search_range.bit_length() - 2,
+        seg_count * 2 - search_range,
+    )
+    subtable += struct.pack(f">{seg_count}H", *codes)
+    subtable += struct.pack(">H", 0)
+    subtable += struct.pack(f">{seg_count}H", *codes)
+    subtable += struct.pack(f">{seg_count}H", *[d & 0xFFFF for d in deltas])
+    subtable += struct.pack(f">{seg_count}H", *[0] * seg_count)
+    cmap = struct.pack(">HHHHI", 0, 1, 3, 1, 12) + subtable
+
+    tables = {
+        b"cmap": cmap,
+        b"glyf": glyf,
+        b"head": head,
+        b"hhea": hhea,
+        b"hmtx": hmtx,
+        b"loca": struct.pack(f">{len(loca)}H", *[o // 2 for o in loca]),
+        b"maxp": maxp,
+    }
+
+    num_tables = len(tables)
+    search_range = 2 ** (num_tables.bit_length() - 1) * 16
+    header = struct.pack(
+        ">IHHHH",
+        0x00010000,
+        num_tables,
+        search_range,
+        search_range.bit_length() - 5,
+        num_tables * 16 - search_range,
+    )
+
+    directory = b""
+    body = b""
+    offset = len(header) + 16 * num_tables
+    for tag, data in sorted(tables.items()):
+        directory += struct.pack(
+            ">4sIII", tag, checksum(data), offset + len(body), len(data)
+        )
+        body += pad(data)
+
+    data = bytearray(header + directory + body)
+
+    head_offset = offset + sum(
+        len(pad(data)) for tag, data in sorted(tables.items()) if tag < b"head"
+    )
+    adjustment = (0xB1B0AFBA - checksum(bytes(data))) & 0xFFFFFFFF
+    data[head_offset + 8 : head_offset + 12] = struct.pack(">I", adjustment)
+
+    return bytes(data)
+
+
+if __name__ == "__main__":
+    path = os.path.join(os.path.dirname(__file__), "outlines.ttf")
+    with open(path, "wb") as file:
+        file.write(font())
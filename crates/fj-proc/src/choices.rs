@@ -0,0 +1,72 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+/// Generate the implementations of `#[derive(fj::Choices)]`
+pub(crate) fn expand(item: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &item.data else {
+        return Err(syn::Error::new_spanned(
+            &item.ident,
+            "`Choices` can only be derived for enums",
+        ));
+    };
+
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`Choices` can only be derived for enums without fields",
+            ));
+        }
+
+        variants.push(&variant.ident);
+    }
+
+    let ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        item.generics.split_for_impl();
+
+    let names: Vec<_> = variants.iter().map(|v| v.to_string()).collect();
+    let error_message = format!(
+        "Expected one of {}, found \"{{}}\"",
+        names
+            .iter()
+            .map(|name| format!("\"{name}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    Ok(quote! {
+        impl #impl_generics fj::models::Choices for #ident #ty_generics
+            #where_clause
+        {
+            const CHOICES: &'static [&'static str] = &[#( #names ),*];
+        }
+
+        impl #impl_generics std::str::FromStr for #ident #ty_generics
+            #where_clause
+        {
+            type Err = fj::models::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #( #names => Ok(Self::#variants), )*
+                    _ => Err(format!(#error_message, s).into()),
+                }
+            }
+        }
+
+        impl #impl_generics std::fmt::Display for #ident #ty_generics
+            #where_clause
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let name = match *self {
+                    #( Self::#variants => #names, )*
+                };
+
+                f.write_str(name)
+            }
+        }
+    })
+}
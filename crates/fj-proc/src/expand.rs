@@ -1,26 +1,17 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
+use syn::{Expr, ExprLit, ExprPath, Lit};
 
 use crate::parse::{
     ArgumentMetadata, Constraint, ConstraintKind, ExtractedArgument,
-    GeometryFunction, Initializer, Metadata, Model,
+    GeometryFunction, Initializer, Metadata, Model, Parameters,
 };
 
 impl Initializer {
-    fn register() -> TokenStream {
+    fn register(model: &Ident) -> TokenStream {
         quote! {
             const _: () = {
-                fj::register_model!(|host| {
-                    fj::models::HostExt::register_model(host, Model);
-
-                    Ok(
-                        fj::models::Metadata::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
-                            .with_short_description(env!("CARGO_PKG_DESCRIPTION"))
-                            .with_homepage(env!("CARGO_PKG_HOMEPAGE"))
-                            .with_repository(env!("CARGO_PKG_REPOSITORY"))
-                            .with_license(env!("CARGO_PKG_LICENSE")),
-                    )
-                });
+                fj::register_models!(#model);
             };
         }
     }
@@ -28,23 +19,32 @@ impl Initializer {
 
 impl ToTokens for Initializer {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self { model } = self;
+        let Self { model, register } = self;
 
-        tokens.extend(Self::register());
+        if *register {
+            tokens.extend(Self::register(&model.ident));
+        }
         model.to_tokens(tokens);
     }
 }
 
 impl Model {
-    fn definition() -> TokenStream {
-        quote! { struct Model; }
+    fn definition(&self) -> TokenStream {
+        let Self { ident, vis, .. } = self;
+
+        quote! { #vis struct #ident; }
     }
 
     fn trait_implementation(&self) -> TokenStream {
-        let Self { metadata, geometry } = self;
+        let Self {
+            ident,
+            metadata,
+            geometry,
+            ..
+        } = self;
 
         quote! {
-            impl fj::models::Model for Model {
+            impl fj::models::Model for #ident {
                 #metadata
                 #geometry
             }
@@ -54,38 +54,196 @@ impl Model {
 
 impl ToTokens for Model {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.extend(Self::definition());
+        tokens.extend(self.definition());
         tokens.extend(self.trait_implementation());
     }
 }
 
 impl ToTokens for Metadata {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self { name, arguments } = self;
+        let Self {
+            name,
+            units,
+            arguments,
+        } = self;
+
+        let units = units.as_ref().map(|units| quote! { .with_units(#units) });
+        let arguments = arguments.iter().map(|argument| {
+            let name = &argument.name;
+            argument.expand(quote! { String::new() }, quote! { #name })
+        });
 
         tokens.extend(quote! {
             fn metadata(&self) -> fj::models::ModelMetadata {
-                fj::models::ModelMetadata::new(#name)
-                #( .with_argument(#arguments) )*
+                let mut metadata = fj::models::ModelMetadata::new(#name)
+                    #units;
+                #( metadata.arguments.extend(#arguments); )*
+                metadata
             }
         });
     }
 }
 
-impl ToTokens for ArgumentMetadata {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
+impl ArgumentMetadata {
+    /// Generate the metadata of the argument
+    ///
+    /// Evaluates to a `Vec` of metadata. If the argument is a group of
+    /// arguments, that contains the metadata of each argument in the group,
+    /// with their names prefixed by `prefix`. Otherwise, it only contains the
+    /// metadata of the argument itself, which is named `name`.
+    pub(crate) fn expand(
+        &self,
+        prefix: TokenStream,
+        name: TokenStream,
+    ) -> TokenStream {
         let Self {
-            name,
+            ty,
+            description,
             default_value,
+            minimum,
+            maximum,
+            ..
         } = self;
 
-        tokens.extend(quote! { fj::models::ArgumentMetadata::new(#name) });
+        let mut tokens = quote! {
+            fj::models::ArgumentMetadata::new(#name)
+                .with_type_name(stringify!(#ty))
+        };
+
+        if let Some(description) = description {
+            tokens.extend(quote! {
+                .with_description(#description)
+            });
+        }
+
+        // Only types that implement `fj::models::Choices` have choices. Which
+        // ones do can only be determined by the compiler, so this resolves to
+        // a method of `ChoicesOfType` for those, and `ChoicesOfAnyType` for
+        // all others.
+        let choices = quote! {{
+            #[allow(unused_imports)]
+            use fj::models::{ChoicesOfAnyType as _, ChoicesOfType as _};
+
+            let choices = fj::models::ChoicesOf::<#ty>(
+                std::marker::PhantomData
+            );
+            (&choices).choices()
+        }};
 
         if let Some(default_value) = default_value {
+            let default_value = match default_value {
+                // String literals are parsed like any other value, so the
+                // default is what they contain.
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) => s.to_token_stream(),
+                // A variant of an enum, like `Standard::Iso`, is referred to by
+                // its name, if that is one of the choices of the type. Any
+                // other path, like `std::f64::consts::PI`, is kept as it is.
+                Expr::Path(ExprPath { path, .. })
+                    if path.segments.len() > 1 =>
+                {
+                    let variant = path.segments.last().map(|s| &s.ident);
+                    quote! {
+                        if #choices.contains(&stringify!(#variant)) {
+                            stringify!(#variant)
+                        } else {
+                            stringify!(#path)
+                        }
+                    }
+                }
+                default_value => quote! { stringify!(#default_value) },
+            };
+
+            tokens.extend(quote! {
+                .with_default_value(#default_value)
+            });
+        }
+
+        tokens.extend(quote! {
+            .with_choices(#choices.iter().copied())
+        });
+
+        if let Some(minimum) = minimum {
             tokens.extend(quote! {
-                .with_default_value(stringify!(#default_value))
+                .with_minimum(stringify!(#minimum))
             });
         }
+
+        if let Some(maximum) = maximum {
+            tokens.extend(quote! {
+                .with_maximum(stringify!(#maximum))
+            });
+        }
+
+        quote! {
+            {
+                #[allow(unused_imports)]
+                use fj::models::{
+                    ArgumentOfAnyType as _, ArgumentOfParametersType as _,
+                };
+
+                let argument = fj::models::ArgumentOf::<#ty>(
+                    std::marker::PhantomData
+                );
+                (&argument).arguments(#prefix, || #tokens)
+            }
+        }
+    }
+}
+
+impl ToTokens for Parameters {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self {
+            ident,
+            generics,
+            arguments,
+            fields,
+            constraints,
+        } = self;
+
+        let (impl_generics, ty_generics, where_clause) =
+            generics.split_for_impl();
+
+        // Groups that are nested in this one are named after their field.
+        let arguments = arguments.iter().map(|argument| {
+            let name = &argument.name;
+            argument.expand(
+                quote! { format!("{prefix}{}.", #name) },
+                quote! { format!("{prefix}{}", #name) },
+            )
+        });
+        let field_names = fields.iter().map(|field| &field.ident);
+        let fields = fields.iter().map(|field| {
+            let name = field.ident.to_string();
+            field.expand(
+                quote! { &format!("{prefix}{}.", #name) },
+                quote! { &format!("{prefix}{}", #name) },
+            )
+        });
+
+        tokens.extend(quote! {
+            impl #impl_generics fj::models::Parameters for #ident #ty_generics
+                #where_clause
+            {
+                fn arguments(
+                    prefix: &str,
+                ) -> Vec<fj::models::ArgumentMetadata> {
+                    let mut arguments = Vec::new();
+                    #( arguments.extend(#arguments); )*
+                    arguments
+                }
+
+                fn from_context(
+                    ctx: &dyn fj::models::Context,
+                    prefix: &str,
+                ) -> Result<Self, fj::models::Error> {
+                    #( #fields )*
+                    #( #constraints )*
+                    Ok(Self { #( #field_names ),* })
+                }
+            }
+        });
     }
 }
 
@@ -124,32 +282,58 @@ impl ToTokens for GeometryFunction {
 
 impl ToTokens for ExtractedArgument {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.ident.to_string();
+        tokens.extend(self.expand(quote! { "" }, quote! { #name }));
+    }
+}
+
+impl ExtractedArgument {
+    /// Generate the extraction of the argument from the context
+    ///
+    /// If the argument is a group of arguments, the names of the arguments in
+    /// the group are prefixed by `prefix`. Otherwise, the argument is named
+    /// `name`.
+    pub(crate) fn expand(
+        &self,
+        prefix: TokenStream,
+        name: TokenStream,
+    ) -> TokenStream {
         let Self {
             ident,
             ty,
             default_value,
         } = self;
 
-        let name = ident.to_string();
-        let t = match default_value {
-            Some(default) => quote! {
-                let #ident: #ty = match ctx.get_argument(#name) {
-                    Some(value) => value.parse()?,
-                    None => #default
-                };
+        let default = match default_value {
+            // String literals are parsed into the type of the argument, which
+            // makes them usable as defaults for strings and enums.
+            Some(
+                default @ Expr::Lit(ExprLit {
+                    lit: Lit::Str(_), ..
+                }),
+            ) => quote! { Some(|| Ok(#default.parse()?)) },
+            Some(default) => quote! { Some(|| Ok(#default)) },
+            None => quote! {
+                None::<fn() -> Result<#ty, fj::models::Error>>
             },
-            None => {
-                let error_message = format!("Expected {name}");
-                quote! {
-                    let #ident: #ty = match ctx.get_argument(#name) {
-                        Some(value) => value.parse()?,
-                        None => return Err(#error_message.into()),
-                    };
-                }
-            }
         };
 
-        tokens.extend(t);
+        quote! {
+            // The `?` in the default is needed, if the error type of the
+            // argument is not `fj::models::Error`.
+            #[allow(clippy::needless_question_mark)]
+            let #ident: #ty = {
+                #[allow(unused_imports)]
+                use fj::models::{
+                    ArgumentOfAnyType as _, ArgumentOfParametersType as _,
+                };
+
+                let argument = fj::models::ArgumentOf::<#ty>(
+                    std::marker::PhantomData
+                );
+                (&argument).extract(ctx, #prefix, #name, #default)?
+            };
+        }
     }
 }
 
@@ -1,8 +1,9 @@
+mod choices;
 mod expand;
 mod parse;
 
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, FnArg, ItemFn};
+use syn::{parse_macro_input, DeriveInput, FnArg, ItemFn};
 
 /// Define a function-based model.
 ///
@@ -50,6 +51,21 @@ use syn::{parse_macro_input, FnArg, ItemFn};
 /// fn cylinder(height: f64, label: String, is_horizontal: bool) -> fj::Shape { todo!() }
 /// ```
 ///
+/// Doc comments on the arguments become their descriptions, in the model's
+/// metadata.
+///
+/// ```rust
+/// #[fj::model]
+/// pub fn washer(
+///     /// The diameter of the hole in the middle
+///     inner: f64,
+///     /// The outer diameter
+///     outer: f64,
+/// ) -> fj::Shape {
+///     todo!()
+/// }
+/// ```
+///
 /// Constraints and default values can be added to an argument using the
 /// `#[param]` attribute.
 ///
@@ -65,13 +81,100 @@ use syn::{parse_macro_input, FnArg, ItemFn};
 ///     let outer_edge = fj::Sketch::from_circle(fj::Circle::from_radius(outer));
 ///     let inner_edge = fj::Sketch::from_circle(fj::Circle::from_radius(inner));
 ///
-///     let footprint = outer_edge.difference(&inner_edge);
+///     let footprint = outer_edge.difference_2d(&inner_edge);
 ///     let spacer = footprint.sweep([0., 0., height]);
 ///
 ///     spacer.into()
 /// }
 /// ```
 ///
+/// Arguments whose values are chosen from a fixed set, use `bool`, or an enum
+/// that derives [`Choices`]. The choices are listed in the model's metadata.
+/// Defaults for strings and enums can be given as string literals, which are
+/// parsed like any other value.
+///
+/// ```rust
+/// #[derive(fj::Choices)]
+/// pub enum Standard {
+///     Iso,
+///     Ansi,
+/// }
+///
+/// #[fj::model]
+/// pub fn bolt(
+///     #[param(default = "Iso")] standard: Standard,
+///     #[param(default = "M3")] size: String,
+///     #[param(default = false)] is_countersunk: bool,
+/// ) -> fj::Shape {
+///     todo!()
+/// }
+/// ```
+///
+/// Defaults can also be paths. A path to a variant of such an enum refers to
+/// the variant by its name, while any other path, like that of a constant, is
+/// kept as it is.
+///
+/// ```rust
+/// #[derive(fj::Choices)]
+/// pub enum Standard {
+///     Iso,
+///     Ansi,
+/// }
+///
+/// #[fj::model]
+/// pub fn countersink(
+///     #[param(default = Standard::Ansi)] standard: Standard,
+///     #[param(default = std::f64::consts::FRAC_PI_2)] angle: f64,
+/// ) -> fj::Shape {
+///     todo!()
+/// }
+///
+/// use fj::models::Model as _;
+///
+/// let defaults: Vec<_> = Model
+///     .metadata()
+///     .arguments
+///     .into_iter()
+///     .filter_map(|argument| argument.default_value)
+///     .map(|default| default.replace(' ', ""))
+///     .collect();
+/// assert_eq!(defaults, ["Ansi", "std::f64::consts::FRAC_PI_2"]);
+/// ```
+///
+/// The units that the model's coordinates are in can be declared using the
+/// `units` argument. Models that don't declare their units are assumed to be
+/// in millimeters.
+///
+/// ```rust
+/// use fj::syntax::*;
+///
+/// #[fj::model(units = fj::models::Units::Inches)]
+/// pub fn plate(#[param(default = 0.25)] thickness: f64) -> fj::Shape {
+///     let outline = fj::Sketch::from_points(vec![[0., 0.], [4., 0.], [4., 2.], [0., 2.]]);
+///     outline.sweep([0., 0., thickness]).into()
+/// }
+/// ```
+///
+/// A crate that contains several models, like every part of a product, gives
+/// each one a struct name using the `model` argument. Named models are not
+/// registered automatically. Instead, the crate registers all of them using
+/// `fj::register_models!`, and the host selects between them by the names of
+/// their functions.
+///
+/// ```rust
+/// #[fj::model(model = Bracket)]
+/// pub fn bracket(#[param(default = 2.0)] thickness: f64) -> fj::Shape {
+///     todo!()
+/// }
+///
+/// #[fj::model(model = Lid)]
+/// pub fn lid() -> fj::Shape {
+///     todo!()
+/// }
+///
+/// fj::register_models!(Bracket, Lid);
+/// ```
+///
 /// For more complex situations, model functions are allowed to return any
 /// error type that converts into a model error.
 ///
@@ -86,10 +189,11 @@ use syn::{parse_macro_input, FnArg, ItemFn};
 /// fn assert_convertible(e: std::env::VarError) -> fj::models::Error { e.into() }
 /// ```
 #[proc_macro_attribute]
-pub fn model(_: TokenStream, input: TokenStream) -> TokenStream {
+pub fn model(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let attribute = parse_macro_input!(attr as parse::ModelAttribute);
     let item = parse_macro_input!(input as syn::ItemFn);
 
-    match parse::parse(&item) {
+    match parse::parse(&item, attribute) {
         Ok(init) => {
             let item = without_param_attrs(item);
 
@@ -104,14 +208,98 @@ pub fn model(_: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
-/// Strip out any of our `#[param(...)]` attributes so the item will compile.
+/// Derive the choices of an enum, for use as a model's argument.
+///
+/// Implements `fj::models::Choices`, [`std::str::FromStr`], and
+/// [`std::fmt::Display`] for an enum without fields. Each variant is parsed
+/// from and displayed as its name.
+///
+/// ```rust
+/// use fj::models::Choices as _;
+///
+/// #[derive(Debug, PartialEq, fj::Choices)]
+/// pub enum Mounting {
+///     Flush,
+///     Recessed,
+/// }
+///
+/// assert_eq!(Mounting::CHOICES, ["Flush", "Recessed"]);
+/// assert_eq!("Recessed".parse::<Mounting>().unwrap(), Mounting::Recessed);
+/// assert_eq!(Mounting::Flush.to_string(), "Flush");
+/// assert!("recessed".parse::<Mounting>().is_err());
+/// ```
+#[proc_macro_derive(Choices)]
+pub fn derive_choices(input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as DeriveInput);
+
+    match choices::expand(&item) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// Derive a group of arguments, that a model can take as a single argument.
+///
+/// Implements `fj::models::Parameters` for a struct with named fields. Each
+/// field is an argument of the model, and can have the same `#[param]`
+/// attribute and doc comment as the arguments of a model function. Fields can
+/// be groups of arguments themselves, whose arguments are then named after the
+/// field, like `hinge.pin_diameter`.
+///
+/// ```rust
+/// #[derive(fj::Parameters)]
+/// pub struct Hinge {
+///     /// The diameter of the pin that the lid rotates around
+///     #[param(default = 2.0, min = 0.5)]
+///     pin_diameter: f64,
+/// }
+///
+/// #[derive(fj::Parameters)]
+/// pub struct Enclosure {
+///     #[param(default = 50.0)]
+///     width: f64,
+///     #[param(default = 2.0, max = width / 4.)]
+///     wall_thickness: f64,
+///     hinge: Hinge,
+/// }
+///
+/// #[fj::model]
+/// pub fn enclosure(params: Enclosure) -> fj::Shape {
+///     let Enclosure { width, wall_thickness, hinge } = params;
+///     todo!("Use {width}, {wall_thickness}, and {}", hinge.pin_diameter)
+/// }
+///
+/// use fj::models::Model as _;
+///
+/// let names: Vec<_> = Model
+///     .metadata()
+///     .arguments
+///     .into_iter()
+///     .map(|argument| argument.name)
+///     .collect();
+/// assert_eq!(names, ["width", "wall_thickness", "hinge.pin_diameter"]);
+/// ```
+#[proc_macro_derive(Parameters, attributes(param))]
+pub fn derive_parameters(input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as DeriveInput);
+
+    match parse::parse_parameters(&item) {
+        Ok(parameters) => quote::quote! { #parameters }.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// Strip out any of our `#[param(...)]` attributes, and the doc comments of
+/// arguments, so the item will compile.
 fn without_param_attrs(mut item: ItemFn) -> ItemFn {
     for input in &mut item.sig.inputs {
         let attrs = match input {
             FnArg::Receiver(r) => &mut r.attrs,
             FnArg::Typed(t) => &mut t.attrs,
         };
-        attrs.retain(|attr| !attr.path.is_ident("param"));
+        attrs.retain(|attr| {
+            !attr.path.is_ident("param") && !attr.path.is_ident("doc")
+        });
     }
 
     item
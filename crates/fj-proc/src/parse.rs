@@ -1,18 +1,24 @@
 use proc_macro2::Ident;
+use quote::ToTokens;
 use syn::{
-    bracketed, parenthesized, parse::Parse, parse_quote, Expr, ItemFn,
-    ReturnType, Type,
+    bracketed, parenthesized, parse::Parse, parse_quote, Data, DataStruct,
+    DeriveInput, Expr, Fields, Generics, ItemFn, ReturnType, Type, Visibility,
 };
 
-/// The call to `fj::register_model!()`.
+/// The call to `fj::register_models!()`, and the model it registers.
 #[derive(Debug)]
 pub(crate) struct Initializer {
     pub(crate) model: Model,
+
+    /// Whether the model is registered, which it's not if it has been named
+    pub(crate) register: bool,
 }
 
 /// The generated `Model` struct and its `fj::Model` impl.
 #[derive(Debug)]
 pub(crate) struct Model {
+    pub(crate) ident: Ident,
+    pub(crate) vis: Visibility,
     pub(crate) metadata: Metadata,
     pub(crate) geometry: GeometryFunction,
 }
@@ -21,14 +27,70 @@ pub(crate) struct Model {
 #[derive(Debug)]
 pub(crate) struct Metadata {
     pub(crate) name: String,
+    pub(crate) units: Option<Expr>,
     pub(crate) arguments: Vec<ArgumentMetadata>,
 }
 
+/// The arguments of the `#[fj::model]` attribute itself, eg:
+///
+/// ```text
+/// #[fj::model(units = fj::models::Units::Inches, model = Plate)]
+///             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^  ^^^^^^^^^^^^^
+/// ```
+#[derive(Debug, Default)]
+pub(crate) struct ModelAttribute {
+    pub(crate) units: Option<Expr>,
+    pub(crate) model: Option<Ident>,
+}
+
+impl Parse for ModelAttribute {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut attribute = Self::default();
+
+        let params = syn::punctuated::Punctuated::<DefaultParam, syn::Token![,]>::parse_terminated(input)?;
+        for param in params {
+            if param.ident == "units" {
+                attribute.units = Some(param.val);
+            } else if param.ident == "model" {
+                let ident = match &param.val {
+                    Expr::Path(path) if path.qself.is_none() => {
+                        path.path.get_ident().cloned()
+                    }
+                    _ => None,
+                };
+                let Some(ident) = ident else {
+                    return Err(syn::Error::new_spanned(
+                        &param.val,
+                        "Expected the name of the model's struct",
+                    ));
+                };
+
+                attribute.model = Some(ident);
+            } else {
+                return Err(syn::Error::new_spanned(
+                    &param.ident,
+                    format!(
+                        "Unknown argument \"{}\" found, expected \"units\" \
+                        or \"model\"",
+                        param.ident
+                    ),
+                ));
+            }
+        }
+
+        Ok(attribute)
+    }
+}
+
 /// Metadata for a specific argument.
 #[derive(Debug)]
 pub(crate) struct ArgumentMetadata {
     pub(crate) name: String,
+    pub(crate) ty: Type,
+    pub(crate) description: Option<String>,
     pub(crate) default_value: Option<Expr>,
+    pub(crate) minimum: Option<Expr>,
+    pub(crate) maximum: Option<Expr>,
 }
 
 /// The `<_ as fj::Model>::shape()` function.
@@ -40,6 +102,16 @@ pub(crate) struct GeometryFunction {
     pub(crate) fallible: bool,
 }
 
+/// The `<_ as fj::models::Parameters>` impl of `#[derive(fj::Parameters)]`.
+#[derive(Debug)]
+pub(crate) struct Parameters {
+    pub(crate) ident: Ident,
+    pub(crate) generics: Generics,
+    pub(crate) arguments: Vec<ArgumentMetadata>,
+    pub(crate) fields: Vec<ExtractedArgument>,
+    pub(crate) constraints: Vec<Constraint>,
+}
+
 #[derive(Debug)]
 pub(crate) struct ExtractedArgument {
     pub(crate) ident: Ident,
@@ -60,15 +132,26 @@ pub(crate) enum ConstraintKind {
     Max,
 }
 
-pub(crate) fn parse(f: &ItemFn) -> syn::Result<Initializer> {
-    let model = parse_model(f)?;
+pub(crate) fn parse(
+    f: &ItemFn,
+    attribute: ModelAttribute,
+) -> syn::Result<Initializer> {
+    // Named models are registered by the crate, together with its other
+    // models.
+    let register = attribute.model.is_none();
+    let model = parse_model(f, attribute)?;
 
-    Ok(Initializer { model })
+    Ok(Initializer { model, register })
 }
 
-fn parse_model(item: &ItemFn) -> syn::Result<Model> {
+fn parse_model(item: &ItemFn, attribute: ModelAttribute) -> syn::Result<Model> {
     let geometry_function = item.sig.ident.clone();
 
+    let (ident, vis) = match attribute.model {
+        Some(ident) => (ident, item.vis.clone()),
+        None => (parse_quote!(Model), Visibility::Inherited),
+    };
+
     let args: Vec<Argument> = item
         .sig
         .inputs
@@ -78,25 +161,13 @@ fn parse_model(item: &ItemFn) -> syn::Result<Model> {
 
     let metadata = Metadata {
         name: geometry_function.to_string(),
-        arguments: args
-            .iter()
-            .map(|a| ArgumentMetadata {
-                name: a.ident.to_string(),
-                default_value: a.default(),
-            })
-            .collect(),
+        units: attribute.units,
+        arguments: args.iter().map(argument_metadata).collect(),
     };
 
     let geometry = GeometryFunction {
         geometry_function,
-        arguments: args
-            .iter()
-            .map(|a| ExtractedArgument {
-                ident: a.ident.clone(),
-                default_value: a.default(),
-                ty: a.ty.clone(),
-            })
-            .collect(),
+        arguments: args.iter().map(extracted_argument).collect(),
         constraints: args.iter().flat_map(argument_constraints).collect(),
         fallible: match &item.sig.output {
             ReturnType::Default => false,
@@ -104,7 +175,74 @@ fn parse_model(item: &ItemFn) -> syn::Result<Model> {
         },
     };
 
-    Ok(Model { metadata, geometry })
+    Ok(Model {
+        ident,
+        vis,
+        metadata,
+        geometry,
+    })
+}
+
+pub(crate) fn parse_parameters(item: &DeriveInput) -> syn::Result<Parameters> {
+    let fields = match &item.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => return Err(syn::Error::new_spanned(
+            &item.ident,
+            "`Parameters` can only be derived for structs with named fields",
+        )),
+    };
+
+    let args: Vec<Argument> = fields
+        .iter()
+        .map(|field| {
+            // Only doc comments and our own attributes are relevant here.
+            let attrs = field.attrs.iter().filter(|attr| {
+                attr.path.is_ident("doc") || attr.path.is_ident("param")
+            });
+            let ident = &field.ident;
+            let ty = &field.ty;
+
+            parse_quote!(#( #attrs )* #ident: #ty)
+        })
+        .collect();
+
+    Ok(Parameters {
+        ident: item.ident.clone(),
+        generics: item.generics.clone(),
+        arguments: args.iter().map(argument_metadata).collect(),
+        fields: args.iter().map(extracted_argument).collect(),
+        constraints: args.iter().flat_map(argument_constraints).collect(),
+    })
+}
+
+fn argument_metadata(arg: &Argument) -> ArgumentMetadata {
+    ArgumentMetadata {
+        name: arg.ident.to_string(),
+        ty: arg.ty.clone(),
+        description: arg.description(),
+        default_value: arg.default(),
+        minimum: arg
+            .attr
+            .as_ref()
+            .and_then(|attr| attr.get_minimum())
+            .map(|param| param.val),
+        maximum: arg
+            .attr
+            .as_ref()
+            .and_then(|attr| attr.get_maximum())
+            .map(|param| param.val),
+    }
+}
+
+fn extracted_argument(arg: &Argument) -> ExtractedArgument {
+    ExtractedArgument {
+        ident: arg.ident.clone(),
+        default_value: arg.default(),
+        ty: arg.ty.clone(),
+    }
 }
 
 fn contains_result(ty: &Type) -> bool {
@@ -116,7 +254,7 @@ fn contains_result(ty: &Type) -> bool {
 
 fn argument_constraints(arg: &Argument) -> Vec<Constraint> {
     let Some(attr) = arg.attr.as_ref() else {
-        return Vec::new()
+        return Vec::new();
     };
 
     let mut constraints = Vec::new();
@@ -143,6 +281,7 @@ fn argument_constraints(arg: &Argument) -> Vec<Constraint> {
 /// Represents one parameter given to the `model`.
 ///
 /// ```text
+/// /// The number of points
 /// #[param(default=3, min=4)] num_points: u64
 /// ^^^^^^^^^^^^^^^^^^^^^^^^^^ ~~~~~~~~~~  ^^^-- ty
 ///            |                    |
@@ -150,6 +289,7 @@ fn argument_constraints(arg: &Argument) -> Vec<Constraint> {
 /// ```
 #[derive(Debug, Clone)]
 struct Argument {
+    docs: Vec<String>,
     attr: Option<HelperAttribute>,
     ident: Ident,
     ty: Type,
@@ -162,21 +302,59 @@ impl Argument {
             .and_then(|attr| attr.get_default())
             .map(|param| param.val)
     }
+
+    /// The description of the argument, from its doc comment
+    fn description(&self) -> Option<String> {
+        // Doc comments keep the space after the `///`.
+        let lines: Vec<_> = self
+            .docs
+            .iter()
+            .map(|line| line.strip_prefix(' ').unwrap_or(line))
+            .collect();
+        let description = lines.join("\n").trim().to_string();
+
+        if description.is_empty() {
+            None
+        } else {
+            Some(description)
+        }
+    }
 }
 
 impl Parse for Argument {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let attr = if input.peek(syn::token::Pound) {
-            Some(input.parse()?)
-        } else {
-            None
-        };
+        let mut docs = Vec::new();
+        let mut attr = None;
+        for attribute in input.call(syn::Attribute::parse_outer)? {
+            if attribute.path.is_ident("doc") {
+                match attribute.parse_meta()? {
+                    syn::Meta::NameValue(syn::MetaNameValue {
+                        lit: syn::Lit::Str(doc),
+                        ..
+                    }) => docs.push(doc.value()),
+                    meta => {
+                        return Err(syn::Error::new_spanned(
+                            meta,
+                            "Expected doc comment",
+                        ))
+                    }
+                }
+            } else {
+                attr = Some(syn::parse2(attribute.to_token_stream())?);
+            }
+        }
+
         let ident: Ident = input.parse()?;
 
         let _: syn::token::Colon = input.parse()?;
 
         let ty: Type = input.parse()?;
-        Ok(Self { attr, ident, ty })
+        Ok(Self {
+            docs,
+            attr,
+            ident,
+            ty,
+        })
     }
 }
 
@@ -292,7 +470,7 @@ mod tests {
                 let outer_edge = fj::Sketch::from_circle(fj::Circle::from_radius(outer));
                 let inner_edge = fj::Sketch::from_circle(fj::Circle::from_radius(inner));
 
-                let footprint = outer_edge.difference(&inner_edge);
+                let footprint = outer_edge.difference_2d(&inner_edge);
                 let spacer = footprint.sweep([0., 0., height]);
 
                 spacer.into()
@@ -301,14 +479,28 @@ mod tests {
         let function: ItemFn = syn::parse2(tokens).unwrap();
 
         let Initializer {
-            model: Model { metadata, geometry },
-        } = parse(&function).unwrap();
+            model:
+                Model {
+                    ident,
+                    metadata,
+                    geometry,
+                    ..
+                },
+            register,
+        } = parse(&function, ModelAttribute::default()).unwrap();
+        assert_eq!(ident, "Model");
+        assert!(register);
 
         // Note: we can't #[derive(PartialEq)] on our parsed structs because
         // proc_macro2::Ident and friends don't implement PartialEq, so let's
         // manually check everything parsed correctly.
-        let Metadata { name, arguments } = metadata;
+        let Metadata {
+            name,
+            units,
+            arguments,
+        } = metadata;
         assert_eq!(name, "spacer");
+        assert!(units.is_none());
         let expected_meta = &[
             ("outer".to_string(), Some("1.0".to_string())),
             ("inner".to_string(), Some("0.5".to_string())),
@@ -372,6 +564,193 @@ mod tests {
         assert_eq!(constraints, expected_constraints);
     }
 
+    #[test]
+    fn parse_model_attribute() {
+        let attribute: ModelAttribute =
+            syn::parse2(quote! { units = fj::models::Units::Inches }).unwrap();
+        let function: ItemFn =
+            syn::parse2(quote! { fn model() -> fj::Shape { todo!() } })
+                .unwrap();
+
+        let init = parse(&function, attribute).unwrap();
+
+        let units = init.model.metadata.units.unwrap();
+        assert_eq!(
+            units.to_token_stream().to_string(),
+            "fj :: models :: Units :: Inches"
+        );
+
+        assert!(syn::parse2::<ModelAttribute>(quote! { unit = 1 }).is_err());
+    }
+
+    #[test]
+    fn parse_named_model() {
+        let attribute: ModelAttribute =
+            syn::parse2(quote! { model = Bracket }).unwrap();
+        let function: ItemFn =
+            syn::parse2(quote! { pub fn bracket() -> fj::Shape { todo!() } })
+                .unwrap();
+
+        let init = parse(&function, attribute).unwrap();
+
+        assert_eq!(init.model.ident, "Bracket");
+        assert_eq!(init.model.metadata.name, "bracket");
+        assert!(matches!(init.model.vis, Visibility::Public(_)));
+        assert!(!init.register);
+
+        assert!(syn::parse2::<ModelAttribute>(quote! { model = a::Bracket })
+            .is_err());
+    }
+
+    #[test]
+    fn parse_argument_descriptions() {
+        let tokens = quote! {
+            pub fn bolt(
+                /// The standard that the bolt conforms to
+                ///
+                /// Metric or imperial.
+                #[param(default = "Iso")] standard: Standard,
+                length: f64,
+            ) -> fj::Shape {
+                todo!()
+            }
+        };
+        let function: ItemFn = syn::parse2(tokens).unwrap();
+
+        let init = parse(&function, ModelAttribute::default()).unwrap();
+
+        let arguments: Vec<_> = init
+            .model
+            .metadata
+            .arguments
+            .iter()
+            .map(|arg| {
+                (
+                    arg.name.as_str(),
+                    arg.ty.to_token_stream().to_string(),
+                    arg.description.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            arguments,
+            [
+                (
+                    "standard",
+                    "Standard".to_string(),
+                    Some(
+                        "The standard that the bolt conforms to\n\nMetric or imperial."
+                    ),
+                ),
+                ("length", "f64".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_argument_limits() {
+        let tokens = quote! {
+            pub fn spacer(
+                #[param(default = 1.0, min = inner * 1.01)] outer: f64,
+                #[param(default = 0.5, min = 0.1, max = outer * 0.99)]
+                inner: f64,
+                height: f64,
+            ) -> fj::Shape {
+                todo!()
+            }
+        };
+        let function: ItemFn = syn::parse2(tokens).unwrap();
+
+        let init = parse(&function, ModelAttribute::default()).unwrap();
+
+        let to_string = |expr: &Option<Expr>| {
+            expr.as_ref().map(|e| e.to_token_stream().to_string())
+        };
+        let limits: Vec<_> = init
+            .model
+            .metadata
+            .arguments
+            .iter()
+            .map(|arg| (to_string(&arg.minimum), to_string(&arg.maximum)))
+            .collect();
+        assert_eq!(
+            limits,
+            [
+                (Some("inner * 1.01".to_string()), None),
+                (Some("0.1".to_string()), Some("outer * 0.99".to_string())),
+                (None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_parameters_struct() {
+        let tokens = quote! {
+            pub struct Enclosure {
+                /// The width of the enclosure
+                #[param(default = 50.0)]
+                pub width: f64,
+                #[param(default = 2.0, max = width / 4.)]
+                #[allow(dead_code)]
+                wall_thickness: f64,
+                hinge: Hinge,
+            }
+        };
+        let item: DeriveInput = syn::parse2(tokens).unwrap();
+
+        let parameters = parse_parameters(&item).unwrap();
+
+        let arguments: Vec<_> = parameters
+            .arguments
+            .iter()
+            .map(|arg| {
+                (
+                    arg.name.as_str(),
+                    arg.description.as_deref(),
+                    arg.default_value
+                        .as_ref()
+                        .map(|v| v.to_token_stream().to_string()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            arguments,
+            [
+                (
+                    "width",
+                    Some("The width of the enclosure"),
+                    Some("50.0".to_string())
+                ),
+                ("wall_thickness", None, Some("2.0".to_string())),
+                ("hinge", None, None),
+            ]
+        );
+
+        let constraints: Vec<_> = parameters
+            .constraints
+            .iter()
+            .map(|Constraint { kind, expr, target }| {
+                (
+                    target.to_string(),
+                    expr.to_token_stream().to_string(),
+                    *kind,
+                )
+            })
+            .collect();
+        assert_eq!(
+            constraints,
+            [(
+                "wall_thickness".to_string(),
+                "width / 4.".to_string(),
+                ConstraintKind::Max,
+            )]
+        );
+
+        let tuple: DeriveInput =
+            syn::parse2(quote! { struct A(f64); }).unwrap();
+        assert!(parse_parameters(&tuple).is_err());
+    }
+
     #[test]
     fn parse_fallible_function() {
         let tokens = quote! {
@@ -381,7 +760,7 @@ mod tests {
         };
         let function: ItemFn = syn::parse2(tokens).unwrap();
 
-        let init = parse(&function).unwrap();
+        let init = parse(&function, ModelAttribute::default()).unwrap();
 
         assert!(init.model.geometry.fallible);
     }
@@ -87,7 +87,7 @@ impl Gui {
         aabb: &Aabb<3>,
         line_drawing_available: bool,
         state: GuiState,
-    ) -> Option<PathBuf> {
+    ) -> GuiOutput {
         self.context.set_pixels_per_point(pixels_per_point);
         self.context.begin_frame(egui_input);
 
@@ -96,9 +96,34 @@ impl Gui {
             format!("Model bounding box size:\n{x:0.1} {y:0.1} {z:0.1}")
         };
 
+        let mut new_model_name = None;
+
         egui::SidePanel::left("fj-left-panel").show(&self.context, |ui| {
             ui.add_space(16.0);
 
+            if state.model_names.len() > 1 {
+                ui.group(|ui| {
+                    let selected = state.model_name.unwrap_or_default();
+
+                    ui.label("Model");
+                    egui::ComboBox::from_id_source("fj-model-selector")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            for name in state.model_names {
+                                let selectable = ui.selectable_label(
+                                    name == selected,
+                                    name.as_str(),
+                                );
+                                if selectable.clicked() && name != selected {
+                                    new_model_name = Some(name.clone());
+                                }
+                            }
+                        });
+                });
+
+                ui.add_space(16.0);
+            }
+
             ui.group(|ui| {
                 ui.checkbox(&mut config.draw_model, "Render model")
                     .on_hover_text_at_pointer("Toggle with 1");
@@ -270,7 +295,10 @@ impl Gui {
                 });
         }
 
-        new_model_path
+        GuiOutput {
+            new_model_path,
+            new_model_name,
+        }
     }
 
     pub(crate) fn prepare_draw(
@@ -348,4 +376,23 @@ pub struct GuiState<'a> {
 
     /// Indicates whether a model is currently available
     pub model_available: bool,
+
+    /// The name of the model that is currently shown, if it has one
+    pub model_name: Option<&'a str>,
+
+    /// The names of the models that can be selected instead
+    ///
+    /// These are the models in the same crate as the current one. A selector
+    /// is only shown, if there is more than one.
+    pub model_names: &'a [String],
+}
+
+/// The changes to the model that the user asked for, through the GUI
+pub struct GuiOutput {
+    /// The path of a new model that the user picked
+    pub new_model_path: Option<PathBuf>,
+
+    /// The name of a model, out of [`GuiState::model_names`], that the user
+    /// selected
+    pub new_model_name: Option<String>,
 }
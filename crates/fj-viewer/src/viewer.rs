@@ -1,13 +1,11 @@
-use std::path::PathBuf;
-
 use fj_interop::processed_shape::ProcessedShape;
 use fj_math::Aabb;
 use tracing::warn;
 
 use crate::{
-    camera::FocusPoint, gui::Gui, Camera, DrawConfig, GuiState, InputEvent,
-    InputHandler, NormalizedScreenPosition, Renderer, RendererInitError,
-    Screen, ScreenSize,
+    camera::FocusPoint, gui::Gui, Camera, DrawConfig, GuiOutput, GuiState,
+    InputEvent, InputHandler, NormalizedScreenPosition, Renderer,
+    RendererInitError, Screen, ScreenSize,
 };
 
 /// The Fornjot model viewer
@@ -119,7 +117,7 @@ impl Viewer {
         pixels_per_point: f32,
         egui_input: egui::RawInput,
         gui_state: GuiState,
-    ) -> Option<PathBuf> {
+    ) -> GuiOutput {
         let aabb = self
             .shape
             .as_ref()
@@ -128,7 +126,7 @@ impl Viewer {
 
         self.camera.update_planes(&aabb);
 
-        let new_model_path = self.gui.update(
+        let output = self.gui.update(
             pixels_per_point,
             egui_input,
             &mut self.draw_config,
@@ -146,6 +144,6 @@ impl Viewer {
             warn!("Draw error: {}", err);
         }
 
-        new_model_path
+        output
     }
 }
@@ -1,8 +1,8 @@
 use fj_host::{Host, Model, ModelEvent, Parameters};
 use fj_operations::shape_processor::{self, ShapeProcessor};
 use fj_viewer::{
-    GuiState, InputEvent, NormalizedScreenPosition, Screen, ScreenSize,
-    StatusReport, Viewer,
+    GuiOutput, GuiState, InputEvent, NormalizedScreenPosition, Screen,
+    ScreenSize, StatusReport, Viewer,
 };
 use winit::{
     dpi::PhysicalPosition,
@@ -23,6 +23,13 @@ pub struct EventLoopHandler {
     pub egui_winit_state: egui_winit::State,
     pub host: Option<Host>,
     pub status: StatusReport,
+
+    /// The name of the model that was last evaluated
+    pub model_name: Option<String>,
+
+    /// The names of all models in the crate of the last evaluated model
+    pub model_names: Vec<String>,
+
     pub held_mouse_button: Option<MouseButton>,
 
     /// Only handle resize events once every frame. This filters out spurious
@@ -72,6 +79,9 @@ impl EventLoopHandler {
                             self.shape_processor.process(&evaluation.shape)?;
                         self.viewer.handle_shape_update(shape);
 
+                        self.model_name = evaluation.model_name;
+                        self.model_names = evaluation.model_names;
+
                         self.status.update_status("Model processed.");
                     }
 
@@ -184,8 +194,13 @@ impl EventLoopHandler {
                     let gui_state = GuiState {
                         status: &self.status,
                         model_available: self.host.is_some(),
+                        model_name: self.model_name.as_deref(),
+                        model_names: &self.model_names,
                     };
-                    let new_model_path = self.viewer.draw(
+                    let GuiOutput {
+                        new_model_path,
+                        new_model_name,
+                    } = self.viewer.draw(
                         pixels_per_point,
                         egui_input,
                         gui_state,
@@ -196,6 +211,14 @@ impl EventLoopHandler {
                         let new_host = Host::from_model(model)?;
                         self.host = Some(new_host);
                     }
+                    if let Some(model_name) = new_model_name {
+                        if let Some(host) = &self.host {
+                            let model =
+                                host.model().clone().with_name(model_name);
+                            let new_host = Host::from_model(model)?;
+                            self.host = Some(new_host);
+                        }
+                    }
                 }
             }
             _ => {}
@@ -42,6 +42,8 @@ pub fn run(
         egui_winit_state,
         host,
         status: StatusReport::new(),
+        model_name: None,
+        model_names: Vec::new(),
         held_mouse_button: None,
         new_size: None,
         stop_drawing: false,
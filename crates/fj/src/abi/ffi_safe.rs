@@ -212,6 +212,29 @@ impl Deref for String {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::ser::Serialize for String {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Deserialize<'de> for String {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        Ok(std::string::String::deserialize(deserializer)?.into())
+    }
+}
+
 /// A version of `Result` that is `#[repr(C)]`.
 #[must_use]
 #[repr(C)]
@@ -362,7 +385,7 @@ impl From<Error> for BoxedError {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
 pub enum Option<T> {
     Some(T),
@@ -398,3 +421,36 @@ impl<T> From<Option<T>> for std::option::Option<T> {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+impl<T> serde::ser::Serialize for Option<T>
+where
+    T: serde::ser::Serialize,
+{
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let opt = match self {
+            Self::Some(value) => Some(value),
+            Self::None => None,
+        };
+        opt.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::de::Deserialize<'de> for Option<T>
+where
+    T: serde::de::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        Ok(std::option::Option::<T>::deserialize(deserializer)?.into())
+    }
+}
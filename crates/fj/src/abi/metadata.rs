@@ -1,10 +1,11 @@
-use crate::abi::ffi_safe;
+use crate::{abi::ffi_safe, models::Units};
 
 #[derive(Debug)]
 #[repr(C)]
 pub struct ModelMetadata {
     name: ffi_safe::String,
     description: ffi_safe::Option<ffi_safe::String>,
+    units: ffi_safe::Option<Units>,
     arguments: ffi_safe::Vec<ArgumentMetadata>,
 }
 
@@ -13,12 +14,14 @@ impl From<ModelMetadata> for crate::models::ModelMetadata {
         let ModelMetadata {
             name,
             description,
+            units,
             arguments,
         } = m;
 
         Self {
             name: name.into(),
             description: description.map(Into::into).into(),
+            units: units.into(),
             arguments: arguments.iter().cloned().map(Into::into).collect(),
         }
     }
@@ -29,12 +32,14 @@ impl From<crate::models::ModelMetadata> for ModelMetadata {
         let crate::models::ModelMetadata {
             name,
             description,
+            units,
             arguments,
         } = m;
 
         Self {
             name: name.into(),
             description: description.into(),
+            units: units.into(),
             arguments: arguments.into_iter().map(Into::into).collect(),
         }
     }
@@ -104,22 +109,34 @@ impl From<crate::models::Metadata> for Metadata {
 #[repr(C)]
 pub struct ArgumentMetadata {
     name: ffi_safe::String,
+    type_name: ffi_safe::Option<ffi_safe::String>,
     description: ffi_safe::Option<ffi_safe::String>,
     default_value: ffi_safe::Option<ffi_safe::String>,
+    choices: ffi_safe::Vec<ffi_safe::String>,
+    minimum: ffi_safe::Option<ffi_safe::String>,
+    maximum: ffi_safe::Option<ffi_safe::String>,
 }
 
 impl From<crate::models::ArgumentMetadata> for ArgumentMetadata {
     fn from(meta: crate::models::ArgumentMetadata) -> Self {
         let crate::models::ArgumentMetadata {
             name,
+            type_name,
             description,
             default_value,
+            choices,
+            minimum,
+            maximum,
         } = meta;
 
         Self {
             name: name.into(),
+            type_name: type_name.into(),
             description: description.into(),
             default_value: default_value.into(),
+            choices: choices.into_iter().map(Into::into).collect(),
+            minimum: minimum.into(),
+            maximum: maximum.into(),
         }
     }
 }
@@ -128,14 +145,22 @@ impl From<ArgumentMetadata> for crate::models::ArgumentMetadata {
     fn from(meta: ArgumentMetadata) -> Self {
         let ArgumentMetadata {
             name,
+            type_name,
             description,
             default_value,
+            choices,
+            minimum,
+            maximum,
         } = meta;
 
         Self {
             name: name.into(),
+            type_name: type_name.map(Into::into).into(),
             description: description.map(Into::into).into(),
             default_value: default_value.map(Into::into).into(),
+            choices: choices.iter().cloned().map(Into::into).collect(),
+            minimum: minimum.map(Into::into).into(),
+            maximum: maximum.map(Into::into).into(),
         }
     }
 }
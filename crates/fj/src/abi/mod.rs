@@ -101,6 +101,54 @@ macro_rules! register_model {
     };
 }
 
+/// Register models, together with the metadata of the crate's package
+///
+/// This is a shorthand for [`register_model`], for crates that define several
+/// models using `#[fj::model(model = ...)]`, like every part of a product. The
+/// host can select between them by the names in their [`ModelMetadata`].
+///
+/// [`ModelMetadata`]: crate::models::ModelMetadata
+///
+/// # Examples
+///
+/// ```rust
+/// use fj::models::Model as _;
+///
+/// #[fj::model(model = Bracket)]
+/// pub fn bracket(#[param(default = 2.0)] thickness: f64) -> fj::Shape {
+///     todo!()
+/// }
+///
+/// #[fj::model(model = Lid)]
+/// pub fn lid() -> fj::Shape {
+///     todo!()
+/// }
+///
+/// fj::register_models!(Bracket, Lid);
+///
+/// assert_eq!(Bracket.metadata().name, "bracket");
+/// assert_eq!(Lid.metadata().name, "lid");
+/// ```
+#[macro_export]
+macro_rules! register_models {
+    ($($model:expr),* $(,)?) => {
+        $crate::register_model!(|host| {
+            $( $crate::models::HostExt::register_model(host, $model); )*
+
+            Ok(
+                $crate::models::Metadata::new(
+                    env!("CARGO_PKG_NAME"),
+                    env!("CARGO_PKG_VERSION"),
+                )
+                .with_short_description(env!("CARGO_PKG_DESCRIPTION"))
+                .with_homepage(env!("CARGO_PKG_HOMEPAGE"))
+                .with_repository(env!("CARGO_PKG_REPOSITORY"))
+                .with_license(env!("CARGO_PKG_LICENSE")),
+            )
+        });
+    };
+}
+
 /// The signature of the function generated by [`register_model`].
 ///
 /// ```rust
@@ -6,6 +6,7 @@ const GON_RAD: f64 = PI / 200.;
 /// An angle
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct Angle {
     // The value of the angle in radians
     rad: f64,
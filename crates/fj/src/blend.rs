@@ -0,0 +1,229 @@
+//! Fillets and chamfers on the edges of sweeps
+
+use std::path::{Path, PathBuf};
+
+use crate::{abi::ffi_safe, Shape, Sweep};
+
+/// A sweep with rounded edges
+///
+/// # Examples
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]);
+/// use fj::syntax::*;
+///
+/// // Round the vertical edges of a sweep
+/// let rounded = fj::Fillet::from_edges_and_radius(
+///     shape.sweep([0., 0., 1.]),
+///     fj::EdgeSelection::Vertical,
+///     0.2,
+/// );
+/// ```
+///
+/// # Limitations
+///
+/// Only sweeps without draft or twist can be filleted, and computing the shape
+/// fails for any others. Vertical edges are only rounded where two straight
+/// edges of the swept shape meet. Fillets of the top and bottom edges are
+/// approximated by planar faces, and so are any curved edges of the swept
+/// shape, if top or bottom edges are selected.
+///
+/// Where vertical edges and top or bottom edges meet, the rounded vertical edge
+/// is cut off by the rounded top or bottom.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Fillet {
+    sweep: Sweep,
+    edges: EdgeSelection,
+    radius: f64,
+}
+
+impl Fillet {
+    /// Create a `Fillet` from a sweep, the edges to round, and their radius
+    pub fn from_edges_and_radius(
+        sweep: Sweep,
+        edges: EdgeSelection,
+        radius: f64,
+    ) -> Self {
+        Self {
+            sweep,
+            edges,
+            radius,
+        }
+    }
+
+    /// Access the sweep whose edges are rounded
+    pub fn sweep(&self) -> &Sweep {
+        &self.sweep
+    }
+
+    /// Access the selection of rounded edges
+    pub fn edges(&self) -> &EdgeSelection {
+        &self.edges
+    }
+
+    /// Access the radius of the rounded edges
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        self.sweep.resolve_paths_into(base, paths);
+    }
+}
+
+impl From<Fillet> for Shape {
+    fn from(shape: Fillet) -> Self {
+        Self::Fillet(shape)
+    }
+}
+
+/// A sweep with beveled edges
+///
+/// Each selected edge is replaced by a planar face, which cuts the same
+/// distance into both faces next to the edge.
+///
+/// # Examples
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]);
+/// use fj::syntax::*;
+///
+/// // Bevel the edges around the top of a sweep
+/// let beveled = fj::Chamfer::from_edges_and_distance(
+///     shape.sweep([0., 0., 1.]),
+///     fj::EdgeSelection::Top,
+///     0.1,
+/// );
+/// ```
+///
+/// # Limitations
+///
+/// Only sweeps without draft or twist can be chamfered, and computing the shape
+/// fails for any others. Vertical edges are only beveled where two straight
+/// edges of the swept shape meet. Curved edges of the swept shape are
+/// approximated by straight lines, if top or bottom edges are selected.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Chamfer {
+    sweep: Sweep,
+    edges: EdgeSelection,
+    distance: f64,
+}
+
+impl Chamfer {
+    /// Create a `Chamfer` from a sweep, the edges to bevel, and the distance
+    pub fn from_edges_and_distance(
+        sweep: Sweep,
+        edges: EdgeSelection,
+        distance: f64,
+    ) -> Self {
+        Self {
+            sweep,
+            edges,
+            distance,
+        }
+    }
+
+    /// Access the sweep whose edges are beveled
+    pub fn sweep(&self) -> &Sweep {
+        &self.sweep
+    }
+
+    /// Access the selection of beveled edges
+    pub fn edges(&self) -> &EdgeSelection {
+        &self.edges
+    }
+
+    /// Access the distance that the bevel cuts into the faces next to an edge
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        self.sweep.resolve_paths_into(base, paths);
+    }
+}
+
+impl From<Chamfer> for Shape {
+    fn from(shape: Chamfer) -> Self {
+        Self::Chamfer(shape)
+    }
+}
+
+/// A selection of the edges of a sweep
+///
+/// The edges of a sweep are named relative to its path, as if it pointed up.
+/// Vertical edges run along the path, bottom edges are at its start, and top
+/// edges at its end.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum EdgeSelection {
+    /// All edges of the sweep
+    All,
+
+    /// The edges along the path of the sweep
+    Vertical,
+
+    /// The edges around the end of the sweep
+    Top,
+
+    /// The edges around the start of the sweep
+    Bottom,
+
+    /// The edges with the given indices
+    ///
+    /// See [`EdgeIndices`].
+    Indices(EdgeIndices),
+}
+
+impl EdgeSelection {
+    /// Create a selection of edges by their indices
+    pub fn from_indices(indices: Vec<u32>) -> Self {
+        Self::Indices(EdgeIndices::from_indices(indices))
+    }
+}
+
+/// The indices of selected edges of a sweep
+///
+/// The edges of the swept shape are numbered, starting with those of its
+/// outline, followed by those of its holes. A circle counts as one edge, while
+/// a Bézier curve counts as the number of lines that approximate it. If the
+/// swept shape has `n` edges, index `i` refers to:
+///
+/// - `0 <= i < n`: The vertical edge at the start of edge `i`.
+/// - `n <= i < 2n`: The bottom edge that is edge `i - n`.
+/// - `2n <= i < 3n`: The top edge that is edge `i - 2n`.
+///
+/// Computing a shape fails, if any index is out of range.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct EdgeIndices {
+    indices: ffi_safe::Vec<u32>,
+}
+
+impl EdgeIndices {
+    /// Create `EdgeIndices` from a list of indices
+    pub fn from_indices(indices: Vec<u32>) -> Self {
+        Self {
+            indices: indices.into(),
+        }
+    }
+
+    /// Access the indices
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+
+use crate::Shape;
+
+/// A difference between two 3-dimensional shapes
+///
+/// The second shape is subtracted from the first one. This is how holes and
+/// pockets are made in a solid.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]);
+/// # let b = fj::Sketch::from_points(vec![[2., 0.], [3., 0.], [2., 1.]]);
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape`
+/// let difference = a.difference(&b);
+/// ```
+///
+/// # Limitations
+///
+/// Curved faces are approximated by planar facets, if the shapes overlap. They
+/// deviate from the curved faces by no more than the tolerance that the model
+/// is triangulated with.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Difference {
+    shapes: [Shape; 2],
+}
+
+impl Difference {
+    /// Create a `Difference` from two shapes
+    ///
+    /// The second shape is subtracted from the first.
+    pub fn from_shapes(shapes: [Shape; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Access the shapes that make up the difference
+    pub fn shapes(&self) -> &[Shape; 2] {
+        &self.shapes
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        for shape in &mut self.shapes {
+            shape.resolve_paths_into(base, paths);
+        }
+    }
+}
+
+impl From<Difference> for Shape {
+    fn from(shape: Difference) -> Self {
+        Self::Difference(Box::new(shape))
+    }
+}
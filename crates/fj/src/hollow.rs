@@ -0,0 +1,87 @@
+use std::path::{Path, PathBuf};
+
+use crate::{abi::ffi_safe, NamedFace, Shape};
+
+/// A 3-dimensional shape, hollowed out to walls of a given thickness
+///
+/// The faces of the shape are moved inward by the thickness, to form the inner
+/// faces of the walls. Faces can be left open, to make the hollow space
+/// accessible from the outside.
+///
+/// # Examples
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]);
+/// use fj::syntax::*;
+///
+/// // A closed box with walls that are 0.1 units thick
+/// let closed = fj::Hollow::from_thickness(shape.sweep([0., 0., 1.]), 0.1);
+///
+/// // The same box, with an open top
+/// let open = closed.clone().with_open_face(fj::NamedFace::Top);
+/// ```
+///
+/// # Limitations
+///
+/// Only shapes with planar faces and straight edges can be hollowed out, and
+/// computing the shape fails for any others. Open faces are selected like the
+/// faces of a [`crate::Workplane`], so only the outermost face in each
+/// direction can be left open, and computing the shape fails, if there is no
+/// such face. Computing the shape also fails, if the thickness is not positive,
+/// or if the walls are too thick for the shape.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Hollow {
+    shape: Shape,
+    thickness: f64,
+    open_faces: ffi_safe::Vec<NamedFace>,
+}
+
+impl Hollow {
+    /// Create a `Hollow` from a shape and the thickness of its walls
+    pub fn from_thickness(shape: impl Into<Shape>, thickness: f64) -> Self {
+        Self {
+            shape: shape.into(),
+            thickness,
+            open_faces: Vec::new().into(),
+        }
+    }
+
+    /// Leave a face of the shape open
+    pub fn with_open_face(mut self, face: NamedFace) -> Self {
+        let mut open_faces: Vec<_> = self.open_faces.into();
+        open_faces.push(face);
+        self.open_faces = open_faces.into();
+        self
+    }
+
+    /// Access the shape that is hollowed out
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the thickness of the walls
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    /// Access the faces that are left open
+    pub fn open_faces(&self) -> &[NamedFace] {
+        &self.open_faces
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        self.shape.resolve_paths_into(base, paths);
+    }
+}
+
+impl From<Hollow> for Shape {
+    fn from(shape: Hollow) -> Self {
+        Self::Hollow(Box::new(shape))
+    }
+}
@@ -0,0 +1,174 @@
+use std::path::{Path, PathBuf};
+
+use crate::{abi::ffi_safe, Shape, Shape2d, Workplane};
+
+/// A 2-dimensional shape, imported from an SVG or DXF file
+///
+/// Each closed outline in the file becomes a loop of the resulting shape.
+/// Loops that are within other loops become holes, like with [`crate::Text`].
+/// The file is read when the shape is computed. A relative path is relative to
+/// the directory of the model, and the model is reloaded when the file changes.
+///
+/// # Examples
+///
+/// ``` rust
+/// use fj::syntax::*;
+///
+/// // All outlines of an SVG file, in millimeters
+/// let outline = fj::Import2d::from_svg("drawings/bracket.svg")
+///     .with_scale(25.4 / 96.);
+/// let bracket = outline.sweep([0., 0., 3.]);
+///
+/// // Only the outlines on one layer of a DXF file
+/// let gasket =
+///     fj::Import2d::from_dxf("drawings/pump.dxf").with_layer("GASKET");
+/// ```
+///
+/// # Supported content
+///
+/// From SVG files, paths, rectangles, circles, ellipses, and polygons are
+/// imported, including any transforms. Layers are groups that have a
+/// matching `inkscape:label` or `id`. The y-axis of SVG files points down, so
+/// it is flipped, to keep the outlines as they look in the file.
+///
+/// From DXF files, the entities `LINE`, `ARC`, `CIRCLE`, and `LWPOLYLINE` are
+/// imported. Lines and arcs are joined into outlines, where their ends meet.
+///
+/// # Limitations
+///
+/// Coordinates are imported as they are in the file, and multiplied by the
+/// scale. Units that are specified in the file are ignored. Elliptical arcs
+/// and arcs under non-uniform transforms are approximated by straight lines.
+/// Outlines that are not closed, or that overlap each other, are not
+/// supported.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Import2d {
+    path: ffi_safe::String,
+    format: ImportFormat,
+    layers: ffi_safe::Vec<ffi_safe::String>,
+    scale: f64,
+    color: [u8; 4],
+    workplane: Workplane,
+}
+
+impl Import2d {
+    /// Create an `Import2d` from the path of an SVG file
+    pub fn from_svg(path: impl Into<String>) -> Self {
+        Self::from_path_and_format(path, ImportFormat::Svg)
+    }
+
+    /// Create an `Import2d` from the path of a DXF file
+    pub fn from_dxf(path: impl Into<String>) -> Self {
+        Self::from_path_and_format(path, ImportFormat::Dxf)
+    }
+
+    /// Create an `Import2d` from the path of a file and its format
+    pub fn from_path_and_format(
+        path: impl Into<String>,
+        format: ImportFormat,
+    ) -> Self {
+        Self {
+            path: path.into().into(),
+            format,
+            layers: ffi_safe::Vec::default(),
+            scale: 1.,
+            color: [255, 0, 0, 255],
+            workplane: Workplane::default(),
+        }
+    }
+
+    /// Import the outlines on a layer
+    ///
+    /// If no layers are selected, all outlines in the file are imported.
+    pub fn with_layer(mut self, layer: impl Into<String>) -> Self {
+        let mut layers: Vec<_> = self.layers.into();
+        layers.push(layer.into().into());
+        self.layers = layers.into();
+        self
+    }
+
+    /// Set the factor that coordinates from the file are multiplied with
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the rendering color of the imported shape in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Place the imported shape on a workplane
+    ///
+    /// Imported shapes are placed on the xy-plane, unless specified otherwise.
+    pub fn with_workplane(mut self, workplane: Workplane) -> Self {
+        self.workplane = workplane;
+        self
+    }
+
+    /// Access the path of the file
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Access the format of the file
+    pub fn format(&self) -> ImportFormat {
+        self.format
+    }
+
+    /// Access the names of the layers that are imported
+    pub fn layers(&self) -> Vec<&str> {
+        self.layers.iter().map(|layer| &**layer).collect()
+    }
+
+    /// Access the factor that coordinates from the file are multiplied with
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Get the rendering color of the imported shape in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Access the workplane that the imported shape is placed on
+    pub fn workplane(&self) -> &Workplane {
+        &self.workplane
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        crate::resolve_path(&mut self.path, base, paths);
+        self.workplane.resolve_paths_into(base, paths);
+    }
+}
+
+impl From<Import2d> for Shape {
+    fn from(shape: Import2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Import2d> for Shape2d {
+    fn from(shape: Import2d) -> Self {
+        Self::Import(shape)
+    }
+}
+
+/// The format of a file that an [`Import2d`] is read from
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum ImportFormat {
+    /// Scalable Vector Graphics
+    Svg,
+
+    /// Drawing Exchange Format, in its ASCII variant
+    Dxf,
+}
@@ -18,20 +18,56 @@
 
 #![warn(missing_docs)]
 
+use std::path::{Path, PathBuf};
+
 pub mod syntax;
 
 #[doc(hidden)]
 pub mod abi;
 mod angle;
+mod blend;
+mod difference;
 mod group;
+mod hollow;
+mod import_2d;
+mod intersection;
+mod loft;
+mod material;
 pub mod models;
+mod offset_2d;
+mod pattern;
+mod primitives;
+mod revolve;
 mod shape_2d;
 mod sweep;
+mod sweep_along_path;
+mod text;
 mod transform;
+mod union;
 pub mod version;
+mod workplane;
 
 pub use self::{
-    angle::*, group::Group, shape_2d::*, sweep::Sweep, transform::Transform,
+    angle::*,
+    blend::{Chamfer, EdgeIndices, EdgeSelection, Fillet},
+    difference::Difference,
+    group::Group,
+    hollow::Hollow,
+    import_2d::{Import2d, ImportFormat},
+    intersection::Intersection,
+    loft::{Loft, LoftProfile},
+    material::Material,
+    offset_2d::{Offset2d, OffsetJoin},
+    pattern::{Pattern, PatternLayout},
+    primitives::{Cone, Cuboid, Cylinder, Sphere, Torus},
+    revolve::Revolve,
+    shape_2d::*,
+    sweep::Sweep,
+    sweep_along_path::{PathSegment, ProfileOrientation, SweepAlongPath},
+    text::Text,
+    transform::Transform,
+    union::Union,
+    workplane::{NamedFace, Workplane},
 };
 pub use fj_proc::*;
 
@@ -40,15 +76,170 @@ pub use fj_proc::*;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub enum Shape {
+    /// A sweep with beveled edges
+    Chamfer(Chamfer),
+
+    /// A cone
+    Cone(Cone),
+
+    /// A cuboid
+    Cuboid(Cuboid),
+
+    /// A cylinder
+    Cylinder(Cylinder),
+
+    /// A difference between two 3-dimensional shapes
+    Difference(Box<Difference>),
+
     /// A group of two 3-dimensional shapes
     Group(Box<Group>),
 
+    /// A sweep with rounded edges
+    Fillet(Fillet),
+
+    /// A 3-dimensional shape, hollowed out to walls of a given thickness
+    Hollow(Box<Hollow>),
+
+    /// An intersection of two 3-dimensional shapes
+    Intersection(Box<Intersection>),
+
+    /// A loft through two or more sketches
+    Loft(Loft),
+
+    /// A pattern of copies of a 3-dimensional shape
+    Pattern(Box<Pattern>),
+
+    /// A revolution of a 2-dimensional shape around an axis
+    Revolve(Revolve),
+
     /// A 2D shape
     Shape2d(Shape2d),
 
+    /// A sphere
+    Sphere(Sphere),
+
     /// A sweep of 2-dimensional shape along the z-axis
     Sweep(Sweep),
 
+    /// A sweep of a 2-dimensional shape along a path
+    SweepAlongPath(SweepAlongPath),
+
     /// A transformed 3-dimensional shape
     Transform(Box<Transform>),
+
+    /// A torus
+    Torus(Torus),
+
+    /// A union of two 3-dimensional shapes
+    Union(Box<Union>),
+}
+
+impl Shape {
+    /// Resolve the relative paths of the files that the shape reads
+    ///
+    /// Fonts and imported files are referred to by path. Relative paths are
+    /// joined to `base`, which should be the directory of the model, so they
+    /// don't depend on the directory that the application is run from.
+    /// Absolute paths are left as they are.
+    ///
+    /// Returns the resolved paths of all files that the shape reads.
+    pub fn resolve_paths(&mut self, base: &Path) -> Vec<PathBuf> {
+        let mut paths = Vec::new();
+        self.resolve_paths_into(base, &mut paths);
+        paths
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        match self {
+            Self::Chamfer(chamfer) => chamfer.resolve_paths_into(base, paths),
+            Self::Difference(difference) => {
+                difference.resolve_paths_into(base, paths)
+            }
+            Self::Group(group) => {
+                group.a.resolve_paths_into(base, paths);
+                group.b.resolve_paths_into(base, paths);
+            }
+            Self::Fillet(fillet) => fillet.resolve_paths_into(base, paths),
+            Self::Hollow(hollow) => hollow.resolve_paths_into(base, paths),
+            Self::Intersection(intersection) => {
+                intersection.resolve_paths_into(base, paths)
+            }
+            Self::Loft(loft) => loft.resolve_paths_into(base, paths),
+            Self::Pattern(pattern) => pattern.resolve_paths_into(base, paths),
+            Self::Revolve(revolve) => revolve.resolve_paths_into(base, paths),
+            Self::Shape2d(shape) => shape.resolve_paths_into(base, paths),
+            Self::Sweep(sweep) => sweep.resolve_paths_into(base, paths),
+            Self::SweepAlongPath(sweep) => {
+                sweep.resolve_paths_into(base, paths)
+            }
+            Self::Transform(transform) => {
+                transform.shape.resolve_paths_into(base, paths)
+            }
+            Self::Union(union) => union.resolve_paths_into(base, paths),
+            Self::Cone(_)
+            | Self::Cuboid(_)
+            | Self::Cylinder(_)
+            | Self::Sphere(_)
+            | Self::Torus(_) => {}
+        }
+    }
+}
+
+fn resolve_path(
+    path: &mut abi::ffi_safe::String,
+    base: &Path,
+    paths: &mut Vec<PathBuf>,
+) {
+    let resolved = base.join(&**path);
+    *path = resolved.to_string_lossy().into_owned().into();
+    paths.push(resolved);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{Import2d, NamedFace, Shape, Shape2d, Sweep, Text, Workplane};
+
+    #[test]
+    fn resolve_paths() {
+        let text = Text::from_text_size_and_font("A", 1., "fonts/font.ttf");
+        let workplane = Workplane::from_face(
+            Sweep::from_path(text.into(), [0., 0., 1.]),
+            NamedFace::Top,
+        );
+        let mut shape: Shape = Import2d::from_svg("/outline.svg")
+            .with_workplane(workplane)
+            .into();
+
+        let paths = shape.resolve_paths(Path::new("/model"));
+
+        assert_eq!(
+            paths,
+            [
+                PathBuf::from("/outline.svg"),
+                PathBuf::from("/model/fonts/font.ttf"),
+            ]
+        );
+
+        let Shape::Shape2d(Shape2d::Import(import)) = shape else {
+            panic!("Expected import");
+        };
+        assert_eq!(import.path(), "/outline.svg");
+
+        let Workplane::Face { shape, .. } = import.workplane() else {
+            panic!("Expected face");
+        };
+        let Shape::Sweep(sweep) = shape.as_ref() else {
+            panic!("Expected sweep");
+        };
+        let Shape2d::Text(text) = sweep.shape() else {
+            panic!("Expected text");
+        };
+        assert_eq!(text.font(), "/model/fonts/font.ttf");
+    }
 }
@@ -0,0 +1,160 @@
+use std::path::{Path, PathBuf};
+
+use crate::{abi::ffi_safe, Shape, Sketch};
+
+/// A loft through two or more sketches
+///
+/// The sketches are placed on planes in 3D space (see [`LoftProfile`]). The
+/// loft connects the outline of each sketch with the outline of the next one,
+/// by ruled side faces. The first and last sketch become the caps of the
+/// resulting solid.
+///
+/// # Examples
+///
+/// ``` rust
+/// let square = fj::Sketch::from_points(vec![
+///     [-1., -1.],
+///     [1., -1.],
+///     [1., 1.],
+///     [-1., 1.],
+/// ]);
+/// let circle = fj::Sketch::from_circle(fj::Circle::from_radius(0.5));
+///
+/// let loft = fj::Loft::from_profiles(vec![
+///     fj::LoftProfile::at_height(square, 0.),
+///     fj::LoftProfile::at_height(circle, 2.),
+/// ]);
+/// ```
+///
+/// # Limitations
+///
+/// All sketches must have the same number of interior chains (holes), or
+/// computing the shape fails. The holes are connected in the order they were
+/// added to the sketches.
+///
+/// Curved edges are approximated by straight lines. If the outlines of two
+/// sketches have a different number of vertices, additional vertices are
+/// inserted into the outline that has fewer of them.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Loft {
+    /// The profiles that the loft goes through
+    profiles: ffi_safe::Vec<LoftProfile>,
+}
+
+impl Loft {
+    /// Create a `Loft` through the given profiles
+    ///
+    /// # Panics
+    ///
+    /// Panics, if fewer than two profiles are provided.
+    pub fn from_profiles(profiles: Vec<LoftProfile>) -> Self {
+        assert!(profiles.len() >= 2, "Loft requires at least two profiles");
+
+        Self {
+            profiles: profiles.into(),
+        }
+    }
+
+    /// Access the profiles that the loft goes through
+    pub fn profiles(&self) -> &[LoftProfile] {
+        &self.profiles
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        // The profiles can't be modified in place, so they are replaced.
+        self.profiles = self
+            .profiles
+            .iter()
+            .cloned()
+            .map(|mut profile| {
+                profile.sketch.resolve_paths_into(base, paths);
+                profile
+            })
+            .collect();
+    }
+}
+
+impl From<Loft> for Shape {
+    fn from(shape: Loft) -> Self {
+        Self::Loft(shape)
+    }
+}
+
+/// A sketch, placed on a plane in 3D space
+///
+/// See [`Loft`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct LoftProfile {
+    /// The sketch
+    sketch: Sketch,
+
+    /// The point on the plane, that the origin of the sketch is placed at
+    origin: [f64; 3],
+
+    /// The normal of the plane
+    normal: [f64; 3],
+
+    /// The direction in the plane, that the x-axis of the sketch points to
+    x_direction: [f64; 3],
+}
+
+impl LoftProfile {
+    /// Place a sketch on a plane parallel to the xy-plane, at the given height
+    pub fn at_height(sketch: Sketch, height: f64) -> Self {
+        Self::on_plane(sketch, [0., 0., height], [0., 0., 1.], [1., 0., 0.])
+    }
+
+    /// Place a sketch on an arbitrary plane
+    ///
+    /// The plane is defined by its origin, its normal, and the direction that
+    /// the x-axis of the sketch points to. That direction is projected into the
+    /// plane, so it doesn't need to be exactly perpendicular to the normal.
+    ///
+    /// The sketch should be placed on the xy-plane. If it has been placed on
+    /// another [`Workplane`], it is placed relative to that.
+    ///
+    /// [`Workplane`]: crate::Workplane
+    pub fn on_plane(
+        sketch: Sketch,
+        origin: [f64; 3],
+        normal: [f64; 3],
+        x_direction: [f64; 3],
+    ) -> Self {
+        Self {
+            sketch,
+            origin,
+            normal,
+            x_direction,
+        }
+    }
+
+    /// Access the sketch
+    pub fn sketch(&self) -> &Sketch {
+        &self.sketch
+    }
+
+    /// Access the point on the plane, that the origin of the sketch is placed
+    /// at
+    pub fn origin(&self) -> [f64; 3] {
+        self.origin
+    }
+
+    /// Access the normal of the plane
+    pub fn normal(&self) -> [f64; 3] {
+        self.normal
+    }
+
+    /// Access the direction in the plane, that the x-axis of the sketch points
+    /// to
+    pub fn x_direction(&self) -> [f64; 3] {
+        self.x_direction
+    }
+}
@@ -0,0 +1,64 @@
+use crate::abi::ffi_safe;
+
+/// A named material that a shape is made of
+///
+/// Materials are assigned to sketches, and carry over to all faces that are
+/// made from them. They are exported along with the colors of a model, for
+/// example as the base materials of a 3MF file.
+///
+/// Materials are told apart by their name, color, and density, so different
+/// materials can share a color.
+///
+/// # Examples
+///
+/// ``` rust
+/// use fj::syntax::*;
+///
+/// let pla = fj::Material::from_name_and_color("PLA", [0, 0, 255, 255])
+///     .with_density(1.24);
+///
+/// let sketch = [[0., 0.], [1., 0.], [0., 1.]].sketch().with_material(pla);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Material {
+    name: ffi_safe::String,
+    color: [u8; 4],
+    density: ffi_safe::Option<f64>,
+}
+
+impl Material {
+    /// Create a `Material` from its name and its color in RGBA
+    pub fn from_name_and_color(
+        name: impl Into<String>,
+        color: [u8; 4],
+    ) -> Self {
+        Self {
+            name: name.into().into(),
+            color,
+            density: ffi_safe::Option::None,
+        }
+    }
+
+    /// Set the density of the material in g/cm³
+    pub fn with_density(mut self, density: f64) -> Self {
+        self.density = Some(density).into();
+        self
+    }
+
+    /// Access the name of the material
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the color of the material in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Get the density of the material in g/cm³, if it is known
+    pub fn density(&self) -> Option<f64> {
+        self.density.clone().into()
+    }
+}
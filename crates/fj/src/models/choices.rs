@@ -0,0 +1,55 @@
+use std::marker::PhantomData;
+
+/// A type whose values are chosen from a fixed set
+///
+/// The choices of a model's argument are listed in its
+/// [`ArgumentMetadata::choices`], if the type of the argument implements this
+/// trait. This is the case for `bool`, and for enums that use
+/// `#[derive(fj::Choices)]`.
+///
+/// The choices are the strings that the type can be parsed from, using
+/// [`std::str::FromStr`].
+///
+/// [`ArgumentMetadata::choices`]: crate::models::ArgumentMetadata::choices
+pub trait Choices {
+    /// The strings that values of the type can be parsed from
+    const CHOICES: &'static [&'static str];
+}
+
+impl Choices for bool {
+    const CHOICES: &'static [&'static str] = &["true", "false"];
+}
+
+/// Access the choices of an argument type, whether it has any or not
+///
+/// Used by the code that `#[fj::model]` generates, which doesn't know whether
+/// the type of an argument implements [`Choices`]. Calling `choices` on a
+/// reference to this struct resolves to [`ChoicesOfType`], if it does, and to
+/// [`ChoicesOfAnyType`] otherwise.
+#[doc(hidden)]
+pub struct ChoicesOf<T>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait ChoicesOfType {
+    fn choices(&self) -> &'static [&'static str];
+}
+
+impl<T> ChoicesOfType for ChoicesOf<T>
+where
+    T: Choices,
+{
+    fn choices(&self) -> &'static [&'static str] {
+        T::CHOICES
+    }
+}
+
+#[doc(hidden)]
+pub trait ChoicesOfAnyType {
+    fn choices(&self) -> &'static [&'static str];
+}
+
+impl<T> ChoicesOfAnyType for &ChoicesOf<T> {
+    fn choices(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
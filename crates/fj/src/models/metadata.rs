@@ -1,3 +1,5 @@
+use crate::models::Units;
+
 /// Information about a particular module that can be used by the host for
 /// things like introspection and search.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -130,6 +132,11 @@ pub struct ModelMetadata {
     /// A description of what this model does.
     pub description: Option<String>,
 
+    /// The units that the model's coordinates are in.
+    ///
+    /// If this is `None`, the model is assumed to be in millimeters.
+    pub units: Option<Units>,
+
     /// Arguments that the model uses when calculating its geometry.
     pub arguments: Vec<ArgumentMetadata>,
 }
@@ -147,6 +154,7 @@ impl ModelMetadata {
         Self {
             name,
             description: None,
+            units: None,
             arguments: Vec::new(),
         }
     }
@@ -164,6 +172,14 @@ impl ModelMetadata {
         }
     }
 
+    /// Set the [`ModelMetadata::units`].
+    pub fn with_units(self, units: Units) -> Self {
+        Self {
+            units: Some(units),
+            ..self
+        }
+    }
+
     /// Add an argument to the [`ModelMetadata::arguments`] list.
     ///
     /// As a convenience, string literals can be automatically converted into
@@ -180,12 +196,32 @@ pub struct ArgumentMetadata {
     /// The name used to refer to this argument.
     pub name: String,
 
+    /// The argument's type, as it is written in the model's code.
+    pub type_name: Option<String>,
+
     /// A short description of this argument that could be shown to the user
     /// in something like a tooltip.
     pub description: Option<String>,
 
     /// Something that could be used as a default if no value was provided.
     pub default_value: Option<String>,
+
+    /// The values that this argument can be chosen from.
+    ///
+    /// If this is empty, the argument can take any value that its type can be
+    /// parsed from.
+    pub choices: Vec<String>,
+
+    /// The smallest value that this argument can take.
+    ///
+    /// This is written as in the model's code, and might be an expression that
+    /// refers to other arguments.
+    pub minimum: Option<String>,
+
+    /// The largest value that this argument can take.
+    ///
+    /// Like [`ArgumentMetadata::minimum`], this might be an expression.
+    pub maximum: Option<String>,
 }
 
 impl ArgumentMetadata {
@@ -199,11 +235,21 @@ impl ArgumentMetadata {
         assert!(!name.is_empty());
         Self {
             name,
+            type_name: None,
             description: None,
             default_value: None,
+            choices: Vec::new(),
+            minimum: None,
+            maximum: None,
         }
     }
 
+    /// Set the [`ArgumentMetadata::type_name`].
+    pub fn with_type_name(mut self, type_name: impl Into<String>) -> Self {
+        self.type_name = Some(type_name.into());
+        self
+    }
+
     /// Set the [`ArgumentMetadata::description`].
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
         let description = description.into();
@@ -223,6 +269,27 @@ impl ArgumentMetadata {
         self.default_value = Some(default_value.into());
         self
     }
+
+    /// Set the [`ArgumentMetadata::choices`].
+    pub fn with_choices(
+        mut self,
+        choices: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.choices = choices.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the [`ArgumentMetadata::minimum`].
+    pub fn with_minimum(mut self, minimum: impl Into<String>) -> Self {
+        self.minimum = Some(minimum.into());
+        self
+    }
+
+    /// Set the [`ArgumentMetadata::maximum`].
+    pub fn with_maximum(mut self, maximum: impl Into<String>) -> Self {
+        self.maximum = Some(maximum.into());
+        self
+    }
 }
 
 impl From<&str> for ArgumentMetadata {
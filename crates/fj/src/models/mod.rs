@@ -1,15 +1,23 @@
 //! Interfaces used when defining models.
 
+mod choices;
 mod context;
 mod host;
 mod metadata;
 mod model;
+mod parameters;
+mod units;
 
 pub use self::{
+    choices::{Choices, ChoicesOf, ChoicesOfAnyType, ChoicesOfType},
     context::Context,
     host::{Host, HostExt},
     metadata::{ArgumentMetadata, Metadata, ModelMetadata},
     model::Model,
+    parameters::{
+        ArgumentOf, ArgumentOfAnyType, ArgumentOfParametersType, Parameters,
+    },
+    units::Units,
 };
 
 /// A generic error used when defining a model.
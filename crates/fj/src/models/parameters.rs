@@ -0,0 +1,205 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use crate::models::{ArgumentMetadata, Context, Error};
+
+/// A group of a model's arguments
+///
+/// Instead of taking each of its arguments separately, a model can take
+/// structs that implement this trait. Each field of such a struct is an
+/// argument, or another group of arguments. This is implemented using
+/// `#[derive(fj::Parameters)]`.
+///
+/// The arguments of a group that is nested within another group are named
+/// after the field that contains them, like `hinge.pin_diameter`.
+pub trait Parameters: Sized {
+    /// Access the metadata of the arguments in the group
+    ///
+    /// The `prefix` is put in front of the name of each argument. It is empty
+    /// for groups that are arguments of the model, and ends with a `.`
+    /// otherwise.
+    fn arguments(prefix: &str) -> Vec<ArgumentMetadata>;
+
+    /// Extract the values of the arguments in the group from the context
+    ///
+    /// The `prefix` is the same as for [`Parameters::arguments`].
+    fn from_context(ctx: &dyn Context, prefix: &str) -> Result<Self, Error>;
+}
+
+/// Access an argument, whether it is a group of arguments or a single one
+///
+/// Used by the code that `#[fj::model]` and `#[derive(fj::Parameters)]`
+/// generate, which don't know whether the type of an argument implements
+/// [`Parameters`]. Calling methods on a reference to this struct resolves to
+/// [`ArgumentOfParametersType`], if it does, and to [`ArgumentOfAnyType`]
+/// otherwise.
+#[doc(hidden)]
+pub struct ArgumentOf<T>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait ArgumentOfParametersType<T> {
+    fn arguments(
+        &self,
+        prefix: String,
+        argument: impl FnOnce() -> ArgumentMetadata,
+    ) -> Vec<ArgumentMetadata>;
+
+    fn extract(
+        &self,
+        ctx: &dyn Context,
+        prefix: &str,
+        name: &str,
+        default: Option<impl FnOnce() -> Result<T, Error>>,
+    ) -> Result<T, Error>;
+}
+
+impl<T> ArgumentOfParametersType<T> for ArgumentOf<T>
+where
+    T: Parameters,
+{
+    fn arguments(
+        &self,
+        prefix: String,
+        _: impl FnOnce() -> ArgumentMetadata,
+    ) -> Vec<ArgumentMetadata> {
+        T::arguments(&prefix)
+    }
+
+    fn extract(
+        &self,
+        ctx: &dyn Context,
+        prefix: &str,
+        _: &str,
+        _: Option<impl FnOnce() -> Result<T, Error>>,
+    ) -> Result<T, Error> {
+        T::from_context(ctx, prefix)
+    }
+}
+
+#[doc(hidden)]
+pub trait ArgumentOfAnyType<T> {
+    fn arguments(
+        &self,
+        prefix: String,
+        argument: impl FnOnce() -> ArgumentMetadata,
+    ) -> Vec<ArgumentMetadata>;
+
+    fn extract(
+        &self,
+        ctx: &dyn Context,
+        prefix: &str,
+        name: &str,
+        default: Option<impl FnOnce() -> Result<T, Error>>,
+    ) -> Result<T, Error>;
+}
+
+impl<T> ArgumentOfAnyType<T> for &ArgumentOf<T>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    fn arguments(
+        &self,
+        _: String,
+        argument: impl FnOnce() -> ArgumentMetadata,
+    ) -> Vec<ArgumentMetadata> {
+        vec![argument()]
+    }
+
+    fn extract(
+        &self,
+        ctx: &dyn Context,
+        _: &str,
+        name: &str,
+        default: Option<impl FnOnce() -> Result<T, Error>>,
+    ) -> Result<T, Error> {
+        match (ctx.get_argument(name), default) {
+            (Some(value), _) => Ok(value.parse()?),
+            (None, Some(default)) => default(),
+            (None, None) => Err(format!("Expected {name}").into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, marker::PhantomData};
+
+    use crate::models::{ArgumentMetadata, Context, Error};
+
+    use super::{
+        ArgumentOf, ArgumentOfAnyType as _, ArgumentOfParametersType as _,
+        Parameters,
+    };
+
+    struct Hinge {
+        pin_diameter: f64,
+    }
+
+    impl Parameters for Hinge {
+        fn arguments(prefix: &str) -> Vec<ArgumentMetadata> {
+            vec![ArgumentMetadata::new(format!("{prefix}pin_diameter"))]
+        }
+
+        fn from_context(
+            ctx: &dyn Context,
+            prefix: &str,
+        ) -> Result<Self, Error> {
+            let pin_diameter = (&ArgumentOf::<f64>(PhantomData)).extract(
+                ctx,
+                "",
+                &format!("{prefix}pin_diameter"),
+                Some(|| Ok(2.)),
+            )?;
+
+            Ok(Self { pin_diameter })
+        }
+    }
+
+    struct Arguments(HashMap<&'static str, &'static str>);
+
+    impl Context for Arguments {
+        fn get_argument(&self, name: &str) -> Option<&str> {
+            self.0.get(name).copied()
+        }
+    }
+
+    #[test]
+    fn arguments_resolve_by_type() {
+        let group = ArgumentOf::<Hinge>(PhantomData);
+        let single = ArgumentOf::<f64>(PhantomData);
+
+        let names = |arguments: Vec<ArgumentMetadata>| {
+            arguments.into_iter().map(|a| a.name).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(group.arguments("hinge.".into(), || unreachable!())),
+            ["hinge.pin_diameter"]
+        );
+        assert_eq!(
+            names((&single).arguments("width.".into(), || {
+                ArgumentMetadata::new("width")
+            })),
+            ["width"]
+        );
+
+        let ctx = Arguments(HashMap::from([("hinge.pin_diameter", "3")]));
+        let no_default = None::<fn() -> Result<f64, Error>>;
+
+        let hinge = group
+            .extract(&ctx, "hinge.", "hinge", None::<fn() -> _>)
+            .unwrap();
+        assert_eq!(hinge.pin_diameter, 3.);
+
+        let hinge = group
+            .extract(
+                &Arguments(HashMap::new()),
+                "hinge.",
+                "hinge",
+                None::<fn() -> _>,
+            )
+            .unwrap();
+        assert_eq!(hinge.pin_diameter, 2.);
+
+        assert!((&single).extract(&ctx, "", "width", no_default).is_err());
+    }
+}
@@ -0,0 +1,19 @@
+/// The units that the coordinates of a model are in
+///
+/// Models that don't declare their units are assumed to be in millimeters.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[repr(C)]
+pub enum Units {
+    /// Millimeters
+    #[default]
+    Millimeters,
+
+    /// Centimeters
+    Centimeters,
+
+    /// Meters
+    Meters,
+
+    /// Inches
+    Inches,
+}
@@ -0,0 +1,124 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Material, Shape, Shape2d};
+
+/// A 2-dimensional shape, grown or shrunk by a distance
+///
+/// A positive distance grows the shape, moving its boundary outward. Holes
+/// shrink accordingly. A negative distance shrinks the shape, and parts of it
+/// that are thinner than twice the distance disappear.
+///
+/// Where the boundary grows away from a corner, the gap that opens up is
+/// closed according to the [`OffsetJoin`]. Round joins are used by default.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]);
+/// use fj::syntax::*;
+///
+/// // A gasket outline around `shape`
+/// let gasket = shape.offset_2d(0.5).difference_2d(&shape);
+///
+/// // `shape`, shrunk by a clearance gap, keeping sharp corners
+/// let insert = fj::Offset2d::from_distance(shape, -0.1)
+///     .with_join(fj::OffsetJoin::Miter);
+/// ```
+///
+/// # Limitations
+///
+/// The offset is computed by combining the shape with the area around its
+/// boundary, so the same limitations as for [`crate::Union2d`] and
+/// [`crate::Difference2d`] apply.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Offset2d {
+    shape: Shape2d,
+    distance: f64,
+    join: OffsetJoin,
+}
+
+impl Offset2d {
+    /// Create an `Offset2d` from a shape and the distance to offset it by
+    pub fn from_distance(shape: impl Into<Shape2d>, distance: f64) -> Self {
+        Self {
+            shape: shape.into(),
+            distance,
+            join: OffsetJoin::Round,
+        }
+    }
+
+    /// Set how gaps at corners are closed
+    pub fn with_join(mut self, join: OffsetJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Get the rendering color of the offset shape in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shape.color()
+    }
+
+    /// Access the material of the offset shape, if it has one
+    pub fn material(&self) -> Option<&Material> {
+        self.shape.material()
+    }
+
+    /// Access the shape that is offset
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the distance that the shape is offset by
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Access the way gaps at corners are closed
+    pub fn join(&self) -> OffsetJoin {
+        self.join
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        self.shape.resolve_paths_into(base, paths);
+    }
+}
+
+impl From<Offset2d> for Shape {
+    fn from(shape: Offset2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Offset2d> for Shape2d {
+    fn from(shape: Offset2d) -> Self {
+        Self::Offset(Box::new(shape))
+    }
+}
+
+/// The way an [`Offset2d`] closes gaps at corners
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum OffsetJoin {
+    /// Close the gap with an arc around the corner
+    Round,
+
+    /// Extend the edges next to the corner, until they meet
+    ///
+    /// Where they'd meet further away from the corner than twice the offset
+    /// distance, the corner is cut off like a [`OffsetJoin::Square`] join.
+    Miter,
+
+    /// Extend the edges next to the corner, and cut them off straight
+    ///
+    /// The cut is as far away from the corner as the offset distance.
+    Square,
+}
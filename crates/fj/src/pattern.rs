@@ -0,0 +1,128 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Angle, Shape};
+
+/// A pattern of copies of a 3-dimensional shape
+///
+/// The first copy is the original shape, and each further copy is moved
+/// relative to the previous one. Like a [`crate::Group`], the copies are
+/// collected into a single shape, without being unified.
+///
+/// # Examples
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]);
+/// use fj::syntax::*;
+///
+/// // Five copies of `shape`, two units apart along the x-axis
+/// let row = fj::Pattern::linear(shape.sweep([0., 0., 1.]), [1., 0., 0.], 5, 2.);
+///
+/// // Six copies of `shape`, a sixth of a turn apart around the z-axis
+/// let ring = fj::Pattern::circular(
+///     shape.translate([3., 0., 0.]),
+///     [0., 0., 1.],
+///     6,
+///     fj::Angle::from_deg(60.),
+/// );
+/// ```
+///
+/// # Limitations
+///
+/// Whether the copies touch or overlap is not currently checked. Computing the
+/// shape fails, if the direction or axis of the pattern is zero.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Pattern {
+    shape: Shape,
+    layout: PatternLayout,
+    count: u32,
+}
+
+impl Pattern {
+    /// Create a pattern of copies along a straight line
+    ///
+    /// The copies are `spacing` apart, in the given direction.
+    pub fn linear(
+        shape: impl Into<Shape>,
+        direction: [f64; 3],
+        count: u32,
+        spacing: f64,
+    ) -> Self {
+        Self {
+            shape: shape.into(),
+            layout: PatternLayout::Linear { direction, spacing },
+            count,
+        }
+    }
+
+    /// Create a pattern of copies around an axis through the origin
+    ///
+    /// The copies are rotated by `angle` relative to each other. The rotation
+    /// is counterclockwise, when looking against the direction of the axis.
+    pub fn circular(
+        shape: impl Into<Shape>,
+        axis: [f64; 3],
+        count: u32,
+        angle: Angle,
+    ) -> Self {
+        Self {
+            shape: shape.into(),
+            layout: PatternLayout::Circular { axis, angle },
+            count,
+        }
+    }
+
+    /// Access the shape that is copied
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the layout of the copies
+    pub fn layout(&self) -> &PatternLayout {
+        &self.layout
+    }
+
+    /// Access the number of copies, including the original shape
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        self.shape.resolve_paths_into(base, paths);
+    }
+}
+
+impl From<Pattern> for Shape {
+    fn from(shape: Pattern) -> Self {
+        Self::Pattern(Box::new(shape))
+    }
+}
+
+/// The layout of the copies in a [`Pattern`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum PatternLayout {
+    /// Copies along a straight line
+    Linear {
+        /// The direction in which each copy is moved from the previous one
+        direction: [f64; 3],
+
+        /// The distance between each copy and the previous one
+        spacing: f64,
+    },
+
+    /// Copies around an axis through the origin
+    Circular {
+        /// The direction of the axis
+        axis: [f64; 3],
+
+        /// The angle between each copy and the previous one
+        angle: Angle,
+    },
+}
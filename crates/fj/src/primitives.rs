@@ -0,0 +1,425 @@
+//! Primitive 3-dimensional shapes
+//!
+//! Primitives are placed in a fixed position relative to the origin. Use
+//! [`crate::Transform`] to move them anywhere else.
+
+use crate::{abi::ffi_safe, Material, Shape};
+
+/// A cuboid
+///
+/// The cuboid is centered on the z-axis. Its bottom face lies in the xy-plane.
+///
+/// # Examples
+///
+/// ``` rust
+/// let cuboid = fj::Cuboid::from_size([3., 2., 1.]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cuboid {
+    size: [f64; 3],
+    color: [u8; 4],
+    material: ffi_safe::Option<Box<Material>>,
+}
+
+impl Cuboid {
+    /// Create a cuboid from its size along the x-, y-, and z-axis
+    ///
+    /// All components of the size must be positive.
+    pub fn from_size(size: [f64; 3]) -> Self {
+        Self {
+            size,
+            color: [255, 0, 0, 255],
+            material: ffi_safe::Option::None,
+        }
+    }
+
+    /// Set the rendering color of the cuboid in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the material of the cuboid
+    ///
+    /// This also sets the rendering color of the cuboid to the color of the
+    /// material.
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.color = material.color();
+        self.material = Some(Box::new(material)).into();
+        self
+    }
+
+    /// Access the size of the cuboid
+    pub fn size(&self) -> [f64; 3] {
+        self.size
+    }
+
+    /// Get the rendering color of the cuboid in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Access the material of the cuboid, if it has one
+    pub fn material(&self) -> Option<&Material> {
+        match &self.material {
+            ffi_safe::Option::Some(material) => Some(material),
+            ffi_safe::Option::None => None,
+        }
+    }
+}
+
+impl From<Cuboid> for Shape {
+    fn from(shape: Cuboid) -> Self {
+        Self::Cuboid(shape)
+    }
+}
+
+/// A cylinder
+///
+/// The axis of the cylinder is the z-axis. Its bottom face lies in the
+/// xy-plane.
+///
+/// # Examples
+///
+/// ``` rust
+/// let cylinder = fj::Cylinder::from_radius_and_height(1., 2.);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cylinder {
+    radius: f64,
+    height: f64,
+    color: [u8; 4],
+    material: ffi_safe::Option<Box<Material>>,
+}
+
+impl Cylinder {
+    /// Create a cylinder from its radius and height
+    ///
+    /// Both must be positive.
+    pub fn from_radius_and_height(radius: f64, height: f64) -> Self {
+        Self {
+            radius,
+            height,
+            color: [255, 0, 0, 255],
+            material: ffi_safe::Option::None,
+        }
+    }
+
+    /// Set the rendering color of the cylinder in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the material of the cylinder
+    ///
+    /// This also sets the rendering color of the cylinder to the color of the
+    /// material.
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.color = material.color();
+        self.material = Some(Box::new(material)).into();
+        self
+    }
+
+    /// Access the radius of the cylinder
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the height of the cylinder
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Get the rendering color of the cylinder in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Access the material of the cylinder, if it has one
+    pub fn material(&self) -> Option<&Material> {
+        match &self.material {
+            ffi_safe::Option::Some(material) => Some(material),
+            ffi_safe::Option::None => None,
+        }
+    }
+}
+
+impl From<Cylinder> for Shape {
+    fn from(shape: Cylinder) -> Self {
+        Self::Cylinder(shape)
+    }
+}
+
+/// A cone, or a truncated cone
+///
+/// The axis of the cone is the z-axis. Its bottom face lies in the xy-plane.
+/// Either of the radii can be zero, making that end of the cone a tip.
+///
+/// # Examples
+///
+/// ``` rust
+/// // A cone with its tip on top
+/// let cone = fj::Cone::from_radii_and_height(1., 0., 2.);
+///
+/// // A truncated cone
+/// let frustum = fj::Cone::from_radii_and_height(1., 0.5, 2.);
+/// ```
+///
+/// # Limitations
+///
+/// Unless both radii are equal, the curved face of the cone is approximated by
+/// planar faces around the z-axis. Their corners lie on the curved face, so the
+/// cone is slightly thinner than specified between those corners, but by no
+/// more than the tolerance that the model is triangulated with.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cone {
+    bottom_radius: f64,
+    top_radius: f64,
+    height: f64,
+    color: [u8; 4],
+    material: ffi_safe::Option<Box<Material>>,
+}
+
+impl Cone {
+    /// Create a cone from the radii of its ends and its height
+    ///
+    /// The height must be positive. The radii must not be negative, and at
+    /// least one of them must be positive.
+    pub fn from_radii_and_height(
+        bottom_radius: f64,
+        top_radius: f64,
+        height: f64,
+    ) -> Self {
+        Self {
+            bottom_radius,
+            top_radius,
+            height,
+            color: [255, 0, 0, 255],
+            material: ffi_safe::Option::None,
+        }
+    }
+
+    /// Set the rendering color of the cone in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the material of the cone
+    ///
+    /// This also sets the rendering color of the cone to the color of the
+    /// material.
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.color = material.color();
+        self.material = Some(Box::new(material)).into();
+        self
+    }
+
+    /// Access the radius of the bottom end of the cone
+    pub fn bottom_radius(&self) -> f64 {
+        self.bottom_radius
+    }
+
+    /// Access the radius of the top end of the cone
+    pub fn top_radius(&self) -> f64 {
+        self.top_radius
+    }
+
+    /// Access the height of the cone
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Get the rendering color of the cone in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Access the material of the cone, if it has one
+    pub fn material(&self) -> Option<&Material> {
+        match &self.material {
+            ffi_safe::Option::Some(material) => Some(material),
+            ffi_safe::Option::None => None,
+        }
+    }
+}
+
+impl From<Cone> for Shape {
+    fn from(shape: Cone) -> Self {
+        Self::Cone(shape)
+    }
+}
+
+/// A sphere
+///
+/// The sphere is centered on the origin.
+///
+/// # Examples
+///
+/// ``` rust
+/// let sphere = fj::Sphere::from_radius(1.);
+/// ```
+///
+/// # Limitations
+///
+/// The surface of the sphere is approximated by planar faces, around the z-axis
+/// and from pole to pole. Their corners lie on the surface, so the sphere is
+/// slightly smaller than specified between those corners, but by no more than
+/// the tolerance that the model is triangulated with.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Sphere {
+    radius: f64,
+    color: [u8; 4],
+    material: ffi_safe::Option<Box<Material>>,
+}
+
+impl Sphere {
+    /// Create a sphere from its radius
+    ///
+    /// The radius must be positive.
+    pub fn from_radius(radius: f64) -> Self {
+        Self {
+            radius,
+            color: [255, 0, 0, 255],
+            material: ffi_safe::Option::None,
+        }
+    }
+
+    /// Set the rendering color of the sphere in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the material of the sphere
+    ///
+    /// This also sets the rendering color of the sphere to the color of the
+    /// material.
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.color = material.color();
+        self.material = Some(Box::new(material)).into();
+        self
+    }
+
+    /// Access the radius of the sphere
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Get the rendering color of the sphere in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Access the material of the sphere, if it has one
+    pub fn material(&self) -> Option<&Material> {
+        match &self.material {
+            ffi_safe::Option::Some(material) => Some(material),
+            ffi_safe::Option::None => None,
+        }
+    }
+}
+
+impl From<Sphere> for Shape {
+    fn from(shape: Sphere) -> Self {
+        Self::Sphere(shape)
+    }
+}
+
+/// A torus
+///
+/// The torus is centered on the origin, and goes around the z-axis.
+///
+/// # Examples
+///
+/// ``` rust
+/// // A ring with a distance of 2 from the z-axis to the center of its tube,
+/// // and a tube with a radius of 0.5
+/// let torus = fj::Torus::from_radii(2., 0.5);
+/// ```
+///
+/// # Limitations
+///
+/// The surface of the torus is approximated by planar faces, around the z-axis
+/// and around the tube. Their corners lie on the surface, so the tube is
+/// slightly thinner than specified between those corners, but by no more than
+/// the tolerance that the model is triangulated with.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Torus {
+    major_radius: f64,
+    minor_radius: f64,
+    color: [u8; 4],
+    material: ffi_safe::Option<Box<Material>>,
+}
+
+impl Torus {
+    /// Create a torus from its major and minor radius
+    ///
+    /// The major radius is the distance from the z-axis to the center of the
+    /// tube. The minor radius is the radius of the tube. It must be positive,
+    /// and smaller than the major radius.
+    pub fn from_radii(major_radius: f64, minor_radius: f64) -> Self {
+        Self {
+            major_radius,
+            minor_radius,
+            color: [255, 0, 0, 255],
+            material: ffi_safe::Option::None,
+        }
+    }
+
+    /// Set the rendering color of the torus in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the material of the torus
+    ///
+    /// This also sets the rendering color of the torus to the color of the
+    /// material.
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.color = material.color();
+        self.material = Some(Box::new(material)).into();
+        self
+    }
+
+    /// Access the major radius of the torus
+    pub fn major_radius(&self) -> f64 {
+        self.major_radius
+    }
+
+    /// Access the minor radius of the torus
+    pub fn minor_radius(&self) -> f64 {
+        self.minor_radius
+    }
+
+    /// Get the rendering color of the torus in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Access the material of the torus, if it has one
+    pub fn material(&self) -> Option<&Material> {
+        match &self.material {
+            ffi_safe::Option::Some(material) => Some(material),
+            ffi_safe::Option::None => None,
+        }
+    }
+}
+
+impl From<Torus> for Shape {
+    fn from(shape: Torus) -> Self {
+        Self::Torus(shape)
+    }
+}
@@ -0,0 +1,120 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Angle, Shape, Shape2d};
+
+/// A revolution of a 2-dimensional shape around an axis
+///
+/// The axis is defined in the same coordinates as the 2-dimensional shape. It
+/// must not cross the shape, but may touch it.
+///
+/// A positive angle revolves the shape counterclockwise around the axis, when
+/// looking against the axis' direction. Since an [`Angle`] is always less
+/// than a full turn, an angle of zero is interpreted as a full revolution.
+///
+/// Negative angles wrap around too, so an angle of -90° revolves the shape by
+/// 270° counterclockwise. To revolve the shape clockwise, reverse the direction
+/// of the axis instead.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[1., 0.], [2., 0.], [2., 1.]]);
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape2d`
+/// let revolve = shape.revolve([0., 0.], [0., 1.], fj::Angle::from_deg(90.));
+/// ```
+///
+/// # Limitations
+///
+/// Only shapes that are bounded by straight lines can be revolved, and each of
+/// those lines must be parallel or perpendicular to the axis. Computing the
+/// shape fails for any other shapes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Revolve {
+    /// The 2-dimensional shape being revolved
+    shape: Shape2d,
+
+    /// A point on the axis
+    axis_origin: [f64; 2],
+
+    /// The direction of the axis
+    axis_direction: [f64; 2],
+
+    /// The angle of the revolution
+    angle: Angle,
+}
+
+impl Revolve {
+    /// Create a `Revolve` around an axis, by the given angle
+    pub fn from_axis_and_angle(
+        shape: Shape2d,
+        axis_origin: [f64; 2],
+        axis_direction: [f64; 2],
+        angle: Angle,
+    ) -> Self {
+        Self {
+            shape,
+            axis_origin,
+            axis_direction,
+            angle,
+        }
+    }
+
+    /// Create a full `Revolve` around an axis
+    pub fn from_axis(
+        shape: Shape2d,
+        axis_origin: [f64; 2],
+        axis_direction: [f64; 2],
+    ) -> Self {
+        Self::from_axis_and_angle(
+            shape,
+            axis_origin,
+            axis_direction,
+            Angle::from_rad(0.),
+        )
+    }
+
+    /// Access the shape being revolved
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the point on the axis
+    pub fn axis_origin(&self) -> [f64; 2] {
+        self.axis_origin
+    }
+
+    /// Access the direction of the axis
+    pub fn axis_direction(&self) -> [f64; 2] {
+        self.axis_direction
+    }
+
+    /// Access the angle of the revolution
+    pub fn angle(&self) -> Angle {
+        self.angle
+    }
+
+    /// Indicate whether this is a full revolution
+    pub fn is_full(&self) -> bool {
+        self.angle.rad() == 0.
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        self.shape.resolve_paths_into(base, paths);
+    }
+}
+
+impl From<Revolve> for Shape {
+    fn from(shape: Revolve) -> Self {
+        Self::Revolve(shape)
+    }
+}
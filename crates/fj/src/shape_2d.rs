@@ -1,4 +1,8 @@
-use crate::{abi::ffi_safe, Shape};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    abi::ffi_safe, Import2d, Material, Offset2d, Shape, Text, Workplane,
+};
 
 /// A 2-dimensional shape
 #[derive(Clone, Debug, PartialEq)]
@@ -8,8 +12,23 @@ pub enum Shape2d {
     /// A difference between two shapes
     Difference(Box<Difference2d>),
 
+    /// A shape, imported from a file
+    Import(Import2d),
+
+    /// An intersection of two shapes
+    Intersection(Box<Intersection2d>),
+
+    /// A shape, grown or shrunk by a distance
+    Offset(Box<Offset2d>),
+
     /// A sketch
     Sketch(Sketch),
+
+    /// Text, made from the glyph outlines of a font
+    Text(Text),
+
+    /// A union of two shapes
+    Union(Box<Union2d>),
 }
 
 impl Shape2d {
@@ -18,6 +37,41 @@ impl Shape2d {
         match &self {
             Self::Sketch(s) => s.color(),
             Self::Difference(d) => d.color(),
+            Self::Import(i) => i.color(),
+            Self::Intersection(i) => i.color(),
+            Self::Offset(o) => o.color(),
+            Self::Text(t) => t.color(),
+            Self::Union(u) => u.color(),
+        }
+    }
+
+    /// Access the material of the larger object, if it has one
+    ///
+    /// Imported shapes and text have no material.
+    pub fn material(&self) -> Option<&Material> {
+        match &self {
+            Self::Sketch(s) => s.material(),
+            Self::Difference(d) => d.material(),
+            Self::Import(_) | Self::Text(_) => None,
+            Self::Intersection(i) => i.material(),
+            Self::Offset(o) => o.material(),
+            Self::Union(u) => u.material(),
+        }
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        match self {
+            Self::Difference(d) => d.resolve_paths_into(base, paths),
+            Self::Import(i) => i.resolve_paths_into(base, paths),
+            Self::Intersection(i) => i.resolve_paths_into(base, paths),
+            Self::Offset(o) => o.resolve_paths_into(base, paths),
+            Self::Sketch(s) => s.resolve_paths_into(base, paths),
+            Self::Text(t) => t.resolve_paths_into(base, paths),
+            Self::Union(u) => u.resolve_paths_into(base, paths),
         }
     }
 }
@@ -34,7 +88,7 @@ impl Shape2d {
 /// use fj::syntax::*;
 ///
 /// // `a` and `b` can be anything that converts to `fj::Shape2d`
-/// let difference = a.difference(&b);
+/// let difference = a.difference_2d(&b);
 /// ```
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -54,10 +108,25 @@ impl Difference2d {
         self.shapes[0].color()
     }
 
+    /// Access the material of the larger object, if it has one
+    pub fn material(&self) -> Option<&Material> {
+        self.shapes[0].material()
+    }
+
     /// Access the shapes that make up the difference
     pub fn shapes(&self) -> &[Shape2d; 2] {
         &self.shapes
     }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        for shape in &mut self.shapes {
+            shape.resolve_paths_into(base, paths);
+        }
+    }
 }
 
 impl From<Difference2d> for Shape {
@@ -72,11 +141,165 @@ impl From<Difference2d> for Shape2d {
     }
 }
 
+/// An intersection of two shapes
+///
+/// Only the area that is covered by both shapes is kept. The outline of the
+/// result is computed from the points where the boundaries of the two shapes
+/// cross each other.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]);
+/// # let b = fj::Sketch::from_points(vec![[1., 0.], [3., 0.], [1., 2.]]);
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape2d`
+/// let intersection = a.intersection_2d(&b);
+/// ```
+///
+/// # Limitations
+///
+/// Curved edges that cross the boundary of the other shape are approximated by
+/// straight lines.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Intersection2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Intersection2d {
+    /// Create an `Intersection2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the first object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the material of the first object, if it has one
+    pub fn material(&self) -> Option<&Material> {
+        self.shapes[0].material()
+    }
+
+    /// Access the shapes that make up the intersection
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        for shape in &mut self.shapes {
+            shape.resolve_paths_into(base, paths);
+        }
+    }
+}
+
+impl From<Intersection2d> for Shape {
+    fn from(shape: Intersection2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Intersection2d> for Shape2d {
+    fn from(shape: Intersection2d) -> Self {
+        Self::Intersection(Box::new(shape))
+    }
+}
+
+/// A union of two shapes
+///
+/// The area that is covered by either shape is kept. Where the shapes overlap,
+/// their outlines are merged into one.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_points(vec![[0., 0.], [2., 0.], [0., 2.]]);
+/// # let b = fj::Sketch::from_points(vec![[1., 0.], [3., 0.], [1., 2.]]);
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape2d`
+/// let union = a.union_2d(&b);
+/// ```
+///
+/// # Limitations
+///
+/// Curved edges that cross the boundary of the other shape are approximated by
+/// straight lines.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Union2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Union2d {
+    /// Create a `Union2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the first object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the material of the first object, if it has one
+    pub fn material(&self) -> Option<&Material> {
+        self.shapes[0].material()
+    }
+
+    /// Access the shapes that make up the union
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        for shape in &mut self.shapes {
+            shape.resolve_paths_into(base, paths);
+        }
+    }
+}
+
+impl From<Union2d> for Shape {
+    fn from(shape: Union2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Union2d> for Shape2d {
+    fn from(shape: Union2d) -> Self {
+        Self::Union(Box::new(shape))
+    }
+}
+
 /// A sketch
 ///
-/// Sketches are currently limited to a single cycle of straight lines,
-/// represented by a number of points. For example, if the points a, b, and c
-/// are provided, the edges ab, bc, and ca are assumed.
+/// A sketch is made up of an exterior [`Chain`], which defines its outer
+/// boundary, and any number of interior chains, which define holes. Chains can
+/// be circles, or cycles of segments, which can be straight lines, arcs, or
+/// Bézier curves (see [`SketchSegment`]). Each segment starts where the
+/// previous one ends, and the first segment starts where the last one ends.
+/// For example, if lines to the points a, b, and c are provided, the edges ab,
+/// bc, and ca are assumed.
+///
+/// Interior chains must be fully contained within the exterior chain. They may
+/// have any winding; it is corrected as required.
 ///
 /// Nothing about these edges is checked right now, but algorithms might assume
 /// that the edges are non-overlapping. If you create a `Sketch` with
@@ -97,7 +320,10 @@ impl From<Difference2d> for Shape2d {
 #[repr(C)]
 pub struct Sketch {
     chain: Chain,
+    interiors: ffi_safe::Vec<Chain>,
     color: [u8; 4],
+    material: ffi_safe::Option<Box<Material>>,
+    workplane: Workplane,
 }
 
 impl Sketch {
@@ -105,7 +331,21 @@ impl Sketch {
     pub fn from_points(points: Vec<[f64; 2]>) -> Self {
         Self {
             chain: Chain::PolyChain(PolyChain::from_points(points)),
+            interiors: ffi_safe::Vec::default(),
+            color: [255, 0, 0, 255],
+            material: ffi_safe::Option::None,
+            workplane: Workplane::default(),
+        }
+    }
+
+    /// Create a sketch from a bunch of segments
+    pub fn from_segments(segments: Vec<SketchSegment>) -> Self {
+        Self {
+            chain: Chain::PolyChain(PolyChain::from_segments(segments)),
+            interiors: ffi_safe::Vec::default(),
             color: [255, 0, 0, 255],
+            material: ffi_safe::Option::None,
+            workplane: Workplane::default(),
         }
     }
 
@@ -113,25 +353,80 @@ impl Sketch {
     pub fn from_circle(circle: Circle) -> Self {
         Self {
             chain: Chain::Circle(circle),
+            interiors: ffi_safe::Vec::default(),
             color: [255, 0, 0, 255],
+            material: ffi_safe::Option::None,
+            workplane: Workplane::default(),
         }
     }
 
+    /// Add an interior chain to the sketch, making a hole in it
+    pub fn with_interior(mut self, interior: impl Into<Chain>) -> Self {
+        let mut interiors: Vec<_> = self.interiors.into();
+        interiors.push(interior.into());
+        self.interiors = interiors.into();
+        self
+    }
+
     /// Set the rendering color of the sketch in RGBA
     pub fn with_color(mut self, color: [u8; 4]) -> Self {
         self.color = color;
         self
     }
 
-    /// Access the chain of the sketch
+    /// Set the material of the sketch
+    ///
+    /// This also sets the rendering color of the sketch to the color of the
+    /// material.
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.color = material.color();
+        self.material = Some(Box::new(material)).into();
+        self
+    }
+
+    /// Place the sketch on a workplane
+    ///
+    /// Sketches are placed on the xy-plane, unless specified otherwise.
+    pub fn with_workplane(mut self, workplane: Workplane) -> Self {
+        self.workplane = workplane;
+        self
+    }
+
+    /// Access the exterior chain of the sketch
     pub fn chain(&self) -> &Chain {
         &self.chain
     }
 
+    /// Access the interior chains of the sketch
+    pub fn interiors(&self) -> &[Chain] {
+        &self.interiors
+    }
+
     /// Get the rendering color of the sketch in RGBA
     pub fn color(&self) -> [u8; 4] {
         self.color
     }
+
+    /// Access the material of the sketch, if it has one
+    pub fn material(&self) -> Option<&Material> {
+        match &self.material {
+            ffi_safe::Option::Some(material) => Some(material),
+            ffi_safe::Option::None => None,
+        }
+    }
+
+    /// Access the workplane that the sketch is placed on
+    pub fn workplane(&self) -> &Workplane {
+        &self.workplane
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        self.workplane.resolve_paths_into(base, paths);
+    }
 }
 
 impl From<Sketch> for Shape {
@@ -158,19 +453,46 @@ pub enum Chain {
     PolyChain(PolyChain),
 }
 
+impl From<Circle> for Chain {
+    fn from(circle: Circle) -> Self {
+        Self::Circle(circle)
+    }
+}
+
+impl From<PolyChain> for Chain {
+    fn from(poly_chain: PolyChain) -> Self {
+        Self::PolyChain(poly_chain)
+    }
+}
+
 /// A circle that is part of a [`Sketch`]
+///
+/// The radius must be positive. Computing the sketch fails otherwise.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Circle {
+    /// The center of the circle
+    center: [f64; 2],
+
     /// The radius of the circle
     radius: f64,
 }
 
 impl Circle {
-    /// Construct a new circle with a specific radius
+    /// Construct a new circle with a specific radius, centered at the origin
     pub fn from_radius(radius: f64) -> Self {
-        Self { radius }
+        Self::from_center_and_radius([0., 0.], radius)
+    }
+
+    /// Construct a new circle with a specific center and radius
+    pub fn from_center_and_radius(center: [f64; 2], radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    /// Access the circle's center
+    pub fn center(&self) -> [f64; 2] {
+        self.center
     }
 
     /// Access the circle's radius
@@ -197,6 +519,13 @@ impl PolyChain {
         Self { segments: points }
     }
 
+    /// Construct an instance from a list of segments
+    pub fn from_segments(segments: Vec<SketchSegment>) -> Self {
+        Self {
+            segments: segments.into(),
+        }
+    }
+
     /// Return the points that define the polygonal chain
     pub fn to_segments(&self) -> Vec<SketchSegment> {
         self.segments.clone().into()
@@ -206,6 +535,12 @@ impl PolyChain {
 /// A segment of a sketch
 ///
 /// Each segment starts at the previous point of the sketch.
+///
+/// # Limitations
+///
+/// Bézier curves are not supported by the kernel yet. They are approximated
+/// using straight lines, which deviate from a curve by no more than the
+/// tolerance that the model is triangulated with.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -215,4 +550,82 @@ pub enum SketchSegment {
         /// The destination point of the line
         point: [f64; 2],
     },
+
+    /// An arc to a point, with the given radius
+    ///
+    /// Of the two possible arcs, the one that covers less than a half turn is
+    /// chosen. Use [`SketchSegment::ArcAroundCenterTo`] for larger arcs.
+    ///
+    /// The destination point must be different from the start point, and the
+    /// radius must be positive and at least half the distance between them.
+    /// Computing the sketch fails otherwise.
+    ArcTo {
+        /// The destination point of the arc
+        point: [f64; 2],
+
+        /// The radius of the arc
+        radius: f64,
+
+        /// The direction in which the arc goes around its center
+        direction: ArcDirection,
+    },
+
+    /// An arc to a point, around the given center
+    ///
+    /// The start and the destination point must be different, and they must
+    /// have the same distance from the center. Computing the sketch fails
+    /// otherwise.
+    ArcAroundCenterTo {
+        /// The destination point of the arc
+        point: [f64; 2],
+
+        /// The center of the arc
+        center: [f64; 2],
+
+        /// The direction in which the arc goes around its center
+        direction: ArcDirection,
+    },
+
+    /// A quadratic Bézier curve to a point
+    QuadraticBezierTo {
+        /// The control point of the curve
+        control: [f64; 2],
+
+        /// The destination point of the curve
+        point: [f64; 2],
+    },
+
+    /// A cubic Bézier curve to a point
+    CubicBezierTo {
+        /// The control points of the curve
+        controls: [[f64; 2]; 2],
+
+        /// The destination point of the curve
+        point: [f64; 2],
+    },
+}
+
+impl SketchSegment {
+    /// Access the destination point of the segment
+    pub fn point(&self) -> [f64; 2] {
+        match self {
+            Self::LineTo { point }
+            | Self::ArcTo { point, .. }
+            | Self::ArcAroundCenterTo { point, .. }
+            | Self::QuadraticBezierTo { point, .. }
+            | Self::CubicBezierTo { point, .. } => *point,
+        }
+    }
+}
+
+/// The direction of an arc that is part of a [`Sketch`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum ArcDirection {
+    /// The arc goes clockwise around its center
+    Clockwise,
+
+    /// The arc goes counterclockwise around its center
+    Counterclockwise,
 }
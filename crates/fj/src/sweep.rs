@@ -1,4 +1,6 @@
-use crate::{Shape, Shape2d};
+use std::path::{Path, PathBuf};
+
+use crate::{Angle, Shape, Shape2d};
 
 /// A sweep of a 2-dimensional shape along straight path
 ///
@@ -13,6 +15,37 @@ use crate::{Shape, Shape2d};
 /// // `shape` can be anything that converts to `fj::Shape2d`
 /// let group = shape.sweep([0., 0., 1.]);
 /// ```
+///
+/// # Draft and twist
+///
+/// A draft angle tilts the side faces of the sweep, relative to the path. A
+/// positive draft shrinks the shape along the path, by moving each of its edges
+/// inward. Holes grow accordingly. A twist angle, given in radians, rotates the
+/// shape counterclockwise around the path, when looking against the direction
+/// of the path. The axis of that rotation goes through the origin of the
+/// shape's coordinate system.
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]);
+/// use fj::syntax::*;
+///
+/// let sweep = shape
+///     .sweep([0., 0., 1.])
+///     .with_draft(fj::Angle::from_deg(3.))
+///     .with_twist(-std::f64::consts::TAU * 1.5);
+/// ```
+///
+/// Since an [`Angle`] is always positive, draft angles of more than half a turn
+/// are interpreted as negative angles. The twist is not an [`Angle`], so it can
+/// be more than a full turn in either direction.
+///
+/// # Limitations
+///
+/// If a draft or twist is applied, curved edges of the shape are approximated
+/// by straight lines.
+///
+/// Computing the shape fails, if the draft is a quarter turn or more in either
+/// direction, or large enough to shrink an edge of the shape to nothing.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -22,12 +55,35 @@ pub struct Sweep {
 
     /// The length and direction of the sweep
     path: [f64; 3],
+
+    /// The angle by which the side faces are tilted inward
+    draft: Angle,
+
+    /// The angle in radians, by which the shape is rotated along the path
+    twist: f64,
 }
 
 impl Sweep {
     /// Create a `Sweep` along a straight path
     pub fn from_path(shape: Shape2d, path: [f64; 3]) -> Self {
-        Self { shape, path }
+        Self {
+            shape,
+            path,
+            draft: Angle::from_rad(0.),
+            twist: 0.,
+        }
+    }
+
+    /// Tilt the side faces of the sweep by a draft angle
+    pub fn with_draft(mut self, draft: Angle) -> Self {
+        self.draft = draft;
+        self
+    }
+
+    /// Rotate the shape along the path by a twist angle, in radians
+    pub fn with_twist(mut self, twist: f64) -> Self {
+        self.twist = twist;
+        self
     }
 
     /// Access the shape being swept
@@ -39,6 +95,24 @@ impl Sweep {
     pub fn path(&self) -> [f64; 3] {
         self.path
     }
+
+    /// Access the draft angle of the sweep
+    pub fn draft(&self) -> Angle {
+        self.draft
+    }
+
+    /// Access the twist angle of the sweep, in radians
+    pub fn twist(&self) -> f64 {
+        self.twist
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        self.shape.resolve_paths_into(base, paths);
+    }
 }
 
 impl From<Sweep> for Shape {
@@ -0,0 +1,164 @@
+use std::path::{Path, PathBuf};
+
+use crate::{abi::ffi_safe, Shape, Shape2d};
+
+/// A sweep of a 2-dimensional shape along a path
+///
+/// The path starts at the origin of the 2-dimensional shape's coordinate
+/// system. It is made up of straight lines, arcs, and Bézier curves (see
+/// [`PathSegment`]). Curved segments are approximated by straight lines.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]);
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape2d`
+/// let sweep = shape.sweep_along_path(
+///     vec![
+///         fj::PathSegment::LineTo { point: [0., 0., 2.] },
+///         fj::PathSegment::LineTo { point: [2., 0., 4.] },
+///     ],
+///     fj::ProfileOrientation::FollowTangent,
+/// );
+/// ```
+///
+/// # Limitations
+///
+/// With [`ProfileOrientation::Fixed`], every segment of the path must move the
+/// shape away from its plane, in the same direction.
+///
+/// With [`ProfileOrientation::FollowTangent`], circles and arcs of the shape
+/// are approximated by straight lines. The path must not reverse its direction,
+/// and its bends must be wide enough for the shape.
+///
+/// Computing the shape fails, if the path is empty, or doesn't meet these
+/// requirements.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct SweepAlongPath {
+    /// The 2-dimensional shape being swept
+    shape: Shape2d,
+
+    /// The segments of the path
+    path: ffi_safe::Vec<PathSegment>,
+
+    /// The orientation of the shape along the path
+    orientation: ProfileOrientation,
+}
+
+impl SweepAlongPath {
+    /// Create a `SweepAlongPath` from the segments of a path
+    pub fn from_segments(
+        shape: Shape2d,
+        path: Vec<PathSegment>,
+        orientation: ProfileOrientation,
+    ) -> Self {
+        Self {
+            shape,
+            path: path.into(),
+            orientation,
+        }
+    }
+
+    /// Access the shape being swept
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the segments of the path
+    pub fn path(&self) -> &[PathSegment] {
+        &self.path
+    }
+
+    /// Access the orientation of the shape along the path
+    pub fn orientation(&self) -> ProfileOrientation {
+        self.orientation
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        self.shape.resolve_paths_into(base, paths);
+    }
+}
+
+impl From<SweepAlongPath> for Shape {
+    fn from(shape: SweepAlongPath) -> Self {
+        Self::SweepAlongPath(shape)
+    }
+}
+
+/// A segment of a path in 3-dimensional space
+///
+/// Each segment starts where the previous one ended.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum PathSegment {
+    /// A line to a point
+    LineTo {
+        /// The destination point of the line
+        point: [f64; 3],
+    },
+
+    /// A circular arc to a point, that passes through another point
+    ///
+    /// The start point, the point passed through, and the destination point
+    /// must not lie on a straight line, or computing the shape fails.
+    ArcTo {
+        /// The point that the arc passes through
+        via: [f64; 3],
+
+        /// The destination point of the arc
+        point: [f64; 3],
+    },
+
+    /// A quadratic Bézier curve to a point
+    QuadraticBezierTo {
+        /// The control point of the curve
+        control: [f64; 3],
+
+        /// The destination point of the curve
+        point: [f64; 3],
+    },
+
+    /// A cubic Bézier curve to a point
+    CubicBezierTo {
+        /// The control points of the curve
+        controls: [[f64; 3]; 2],
+
+        /// The destination point of the curve
+        point: [f64; 3],
+    },
+}
+
+impl PathSegment {
+    /// Access the destination point of the segment
+    pub fn point(&self) -> [f64; 3] {
+        match self {
+            Self::LineTo { point }
+            | Self::ArcTo { point, .. }
+            | Self::QuadraticBezierTo { point, .. }
+            | Self::CubicBezierTo { point, .. } => *point,
+        }
+    }
+}
+
+/// The orientation of a shape, while it is swept along a path
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum ProfileOrientation {
+    /// The shape keeps its orientation along the whole path
+    Fixed,
+
+    /// The shape is rotated along with the tangent of the path
+    FollowTangent,
+}
@@ -3,21 +3,49 @@
 //! This model defines extension traits, which provide convenient syntax for
 //! the various operations defined in this trait.
 
+/// Convenient syntax to create an [`fj::Difference`]
+///
+/// [`fj::Difference`]: crate::Difference
+pub trait Difference {
+    /// Create a difference by subtracting `other` from `self`
+    fn difference<Other>(&self, other: &Other) -> crate::Difference
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Difference for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn difference<Other>(&self, other: &Other) -> crate::Difference
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Difference::from_shapes([a, b])
+    }
+}
+
 /// Convenient syntax to create an [`fj::Difference2d`]
 ///
+/// The method has a `_2d` suffix, as [`Difference`] already provides a
+/// `difference` method, which is also available for 2-dimensional shapes.
+///
 /// [`fj::Difference2d`]: crate::Difference2d
-pub trait Difference {
-    /// Create a difference between `self` and `other`
-    fn difference<Other>(&self, other: &Other) -> crate::Difference2d
+pub trait Difference2d {
+    /// Create a difference by subtracting `other` from `self`
+    fn difference_2d<Other>(&self, other: &Other) -> crate::Difference2d
     where
         Other: Clone + Into<crate::Shape2d>;
 }
 
-impl<T> Difference for T
+impl<T> Difference2d for T
 where
     T: Clone + Into<crate::Shape2d>,
 {
-    fn difference<Other>(&self, other: &Other) -> crate::Difference2d
+    fn difference_2d<Other>(&self, other: &Other) -> crate::Difference2d
     where
         Other: Clone + Into<crate::Shape2d>,
     {
@@ -53,6 +81,112 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::Intersection`]
+///
+/// [`fj::Intersection`]: crate::Intersection
+pub trait Intersection {
+    /// Create an intersection of `self` and `other`
+    fn intersection<Other>(&self, other: &Other) -> crate::Intersection
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Intersection for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn intersection<Other>(&self, other: &Other) -> crate::Intersection
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Intersection::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Intersection2d`]
+///
+/// The method has a `_2d` suffix, as [`Intersection`] already provides an
+/// `intersection` method, which is also available for 2-dimensional shapes.
+///
+/// [`fj::Intersection2d`]: crate::Intersection2d
+pub trait Intersection2d {
+    /// Create an intersection of `self` and `other`
+    fn intersection_2d<Other>(&self, other: &Other) -> crate::Intersection2d
+    where
+        Other: Clone + Into<crate::Shape2d>;
+}
+
+impl<T> Intersection2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn intersection_2d<Other>(&self, other: &Other) -> crate::Intersection2d
+    where
+        Other: Clone + Into<crate::Shape2d>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Intersection2d::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Offset2d`]
+///
+/// [`fj::Offset2d`]: crate::Offset2d
+pub trait Offset2d {
+    /// Grow or shrink `self` by the given distance, with round joins
+    fn offset_2d(&self, distance: f64) -> crate::Offset2d;
+}
+
+impl<T> Offset2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn offset_2d(&self, distance: f64) -> crate::Offset2d {
+        let shape = self.clone().into();
+        crate::Offset2d::from_distance(shape, distance)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Revolve`]
+///
+/// [`fj::Revolve`]: crate::Revolve
+pub trait Revolve {
+    /// Revolve `self` around an axis, by the given angle
+    ///
+    /// An angle of zero results in a full revolution. See [`crate::Revolve`].
+    fn revolve(
+        &self,
+        axis_origin: [f64; 2],
+        axis_direction: [f64; 2],
+        angle: crate::Angle,
+    ) -> crate::Revolve;
+}
+
+impl<T> Revolve for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn revolve(
+        &self,
+        axis_origin: [f64; 2],
+        axis_direction: [f64; 2],
+        angle: crate::Angle,
+    ) -> crate::Revolve {
+        let shape = self.clone().into();
+        crate::Revolve::from_axis_and_angle(
+            shape,
+            axis_origin,
+            axis_direction,
+            angle,
+        )
+    }
+}
+
 /// Convenient syntax to create an [`fj::Sketch`]
 ///
 /// [`fj::Sketch`]: crate::Sketch
@@ -91,6 +225,32 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::SweepAlongPath`]
+///
+/// [`fj::SweepAlongPath`]: crate::SweepAlongPath
+pub trait SweepAlongPath {
+    /// Sweep `self` along a path
+    fn sweep_along_path(
+        &self,
+        path: Vec<crate::PathSegment>,
+        orientation: crate::ProfileOrientation,
+    ) -> crate::SweepAlongPath;
+}
+
+impl<T> SweepAlongPath for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn sweep_along_path(
+        &self,
+        path: Vec<crate::PathSegment>,
+        orientation: crate::ProfileOrientation,
+    ) -> crate::SweepAlongPath {
+        let shape = self.clone().into();
+        crate::SweepAlongPath::from_segments(shape, path, orientation)
+    }
+}
+
 /// Convenient syntax to create an [`fj::Transform`]
 ///
 /// [`fj::Transform`]: crate::Transform
@@ -105,6 +265,23 @@ pub trait Transform {
     ///
     /// Create a translation that translates `shape` by `offset`.
     fn translate(&self, offset: [f64; 3]) -> crate::Transform;
+
+    /// Create a scaling
+    ///
+    /// Create a scaling that scales `shape` along the x-, y-, and z-axis by the
+    /// respective component of `factors`.
+    fn scale(&self, factors: [f64; 3]) -> crate::Transform;
+
+    /// Create a mirroring
+    ///
+    /// Create a mirroring that mirrors `shape` across the plane defined by
+    /// `origin` and `normal`.
+    fn mirror(&self, origin: [f64; 3], normal: [f64; 3]) -> crate::Transform;
+
+    /// Create an arbitrary affine transformation
+    ///
+    /// See [`crate::Transform::matrix`].
+    fn transform(&self, matrix: [[f64; 4]; 3]) -> crate::Transform;
 }
 
 impl<T> Transform for T
@@ -113,21 +290,79 @@ where
 {
     fn rotate(&self, axis: [f64; 3], angle: crate::Angle) -> crate::Transform {
         let shape = self.clone().into();
-        crate::Transform {
-            shape,
-            axis,
-            angle,
-            offset: [0.; 3],
-        }
+        crate::Transform::rotation(shape, axis, angle)
     }
 
     fn translate(&self, offset: [f64; 3]) -> crate::Transform {
         let shape = self.clone().into();
-        crate::Transform {
-            shape,
-            axis: [1., 0., 0.],
-            angle: crate::Angle::from_rad(0.),
-            offset,
-        }
+        crate::Transform::translation(shape, offset)
+    }
+
+    fn scale(&self, factors: [f64; 3]) -> crate::Transform {
+        let shape = self.clone().into();
+        crate::Transform::scaling(shape, factors)
+    }
+
+    fn mirror(&self, origin: [f64; 3], normal: [f64; 3]) -> crate::Transform {
+        let shape = self.clone().into();
+        crate::Transform::mirror(shape, origin, normal)
+    }
+
+    fn transform(&self, matrix: [[f64; 4]; 3]) -> crate::Transform {
+        let shape = self.clone().into();
+        crate::Transform::from_matrix(shape, matrix)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Union`]
+///
+/// [`fj::Union`]: crate::Union
+pub trait Union {
+    /// Create a union of `self` and `other`
+    fn union<Other>(&self, other: &Other) -> crate::Union
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Union for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn union<Other>(&self, other: &Other) -> crate::Union
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Union::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Union2d`]
+///
+/// The method has a `_2d` suffix, as [`Union`] already provides a `union`
+/// method, which is also available for 2-dimensional shapes.
+///
+/// [`fj::Union2d`]: crate::Union2d
+pub trait Union2d {
+    /// Create a union of `self` and `other`
+    fn union_2d<Other>(&self, other: &Other) -> crate::Union2d
+    where
+        Other: Clone + Into<crate::Shape2d>;
+}
+
+impl<T> Union2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn union_2d<Other>(&self, other: &Other) -> crate::Union2d
+    where
+        Other: Clone + Into<crate::Shape2d>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Union2d::from_shapes([a, b])
     }
 }
@@ -0,0 +1,134 @@
+use std::path::{Path, PathBuf};
+
+use crate::{abi::ffi_safe, Shape, Shape2d, Workplane};
+
+/// A line of text, made from the glyph outlines of a font
+///
+/// The glyphs are placed on a baseline along the x-axis, starting at the
+/// origin. Every line break in the text starts a new line below the previous
+/// one. Each glyph outline becomes a loop of the resulting 2-dimensional shape.
+/// Loops that are within other loops, like the inside of an "O", become holes.
+///
+/// The font file is read when the shape is computed, which fails if the file
+/// can't be read, or isn't a font. TrueType and OpenType fonts are supported.
+/// A relative path is relative to the directory of the model, and the model is
+/// reloaded when the font file changes.
+///
+/// # Examples
+///
+/// ``` rust
+/// use fj::syntax::*;
+///
+/// let label = fj::Text::from_text_size_and_font(
+///     "A-1234",
+///     5.,
+///     "fonts/DejaVuSans.ttf",
+/// );
+///
+/// // Like any other 2-dimensional shape, text can be swept...
+/// let raised = label.sweep([0., 0., 0.5]);
+///
+/// // ...or subtracted from another shape.
+/// let plate = fj::Sketch::from_points(vec![
+///     [-1., -2.],
+///     [30., -2.],
+///     [30., 7.],
+///     [-1., 7.],
+/// ]);
+/// let stencil = plate.difference_2d(&label);
+/// ```
+///
+/// # Limitations
+///
+/// Curves of glyph outlines are Bézier curves, which are approximated by
+/// straight lines (see [`crate::SketchSegment`]). Kerning is not applied, and
+/// glyphs must not overlap each other, or themselves.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Text {
+    text: ffi_safe::String,
+    size: f64,
+    font: ffi_safe::String,
+    color: [u8; 4],
+    workplane: Workplane,
+}
+
+impl Text {
+    /// Create a `Text` from a string, a font size, and the path of a font file
+    ///
+    /// The font size is the height of the font's em square.
+    pub fn from_text_size_and_font(
+        text: impl Into<String>,
+        size: f64,
+        font: impl Into<String>,
+    ) -> Self {
+        Self {
+            text: text.into().into(),
+            size,
+            font: font.into().into(),
+            color: [255, 0, 0, 255],
+            workplane: Workplane::default(),
+        }
+    }
+
+    /// Set the rendering color of the text in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Place the text on a workplane
+    ///
+    /// Text is placed on the xy-plane, unless specified otherwise.
+    pub fn with_workplane(mut self, workplane: Workplane) -> Self {
+        self.workplane = workplane;
+        self
+    }
+
+    /// Access the string of the text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Access the font size of the text
+    pub fn size(&self) -> f64 {
+        self.size
+    }
+
+    /// Access the path of the font file
+    pub fn font(&self) -> &str {
+        &self.font
+    }
+
+    /// Get the rendering color of the text in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Access the workplane that the text is placed on
+    pub fn workplane(&self) -> &Workplane {
+        &self.workplane
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        crate::resolve_path(&mut self.font, base, paths);
+        self.workplane.resolve_paths_into(base, paths);
+    }
+}
+
+impl From<Text> for Shape {
+    fn from(shape: Text) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Text> for Shape2d {
+    fn from(shape: Text) -> Self {
+        Self::Text(shape)
+    }
+}
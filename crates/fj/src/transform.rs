@@ -2,6 +2,9 @@ use crate::{Angle, Shape};
 
 /// A transformed 3-dimensional shape
 ///
+/// The transformation is an arbitrary affine transformation, which can be a
+/// rotation, translation, scaling, mirroring, or any combination of them.
+///
 /// # Examples
 ///
 /// Convenient syntax for this operation is available through [`crate::syntax`].
@@ -13,15 +16,18 @@ use crate::{Angle, Shape};
 /// // `shape` can be anything that converts to `fj::Shape`
 /// let rotated = shape.rotate([0., 0., 1.], fj::Angle::from_rev(0.5));
 /// let translated = shape.translate([1., 2., 3.]);
+/// let scaled = shape.scale([1., 2., 1.]);
+/// let mirrored = shape.mirror([0., 0., 0.], [1., 0., 0.]);
 /// ```
 ///
 /// # Limitations
 ///
-/// Transformations are currently limited to a rotation, followed by a
-/// translation.
+/// Not all operations support shapes that have been transformed by a
+/// non-uniform scaling, as that turns circles into ellipses.
 ///
-/// See issue:
-/// <https://github.com/hannobraun/Fornjot/issues/101>
+/// Computing the shape fails, if the matrix is not invertible or has entries
+/// that aren't finite. That includes a scaling by zero, and a rotation or a
+/// mirror around an axis of zero length.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -29,14 +35,96 @@ pub struct Transform {
     /// The shape being transformed
     pub shape: Shape,
 
-    /// The axis of the rotation
-    pub axis: [f64; 3],
+    /// The matrix of the affine transformation
+    ///
+    /// Contains the first three rows of the matrix. The last row of an affine
+    /// transformation matrix is always `[0., 0., 0., 1.]`.
+    pub matrix: [[f64; 4]; 3],
+}
+
+impl Transform {
+    /// Create a `Transform` from an affine transformation matrix
+    ///
+    /// See [`Transform::matrix`].
+    pub fn from_matrix(shape: Shape, matrix: [[f64; 4]; 3]) -> Self {
+        Self { shape, matrix }
+    }
+
+    /// Create a rotation around an axis through the origin
+    pub fn rotation(shape: Shape, axis: [f64; 3], angle: Angle) -> Self {
+        let length = axis.iter().map(|c| c * c).sum::<f64>().sqrt();
+        let [x, y, z] = axis.map(|c| c / length);
+        let (sin, cos) = angle.rad().sin_cos();
+        let t = 1. - cos;
+
+        Self::from_matrix(
+            shape,
+            [
+                [
+                    t * x * x + cos,
+                    t * x * y - sin * z,
+                    t * x * z + sin * y,
+                    0.,
+                ],
+                [
+                    t * x * y + sin * z,
+                    t * y * y + cos,
+                    t * y * z - sin * x,
+                    0.,
+                ],
+                [
+                    t * x * z - sin * y,
+                    t * y * z + sin * x,
+                    t * z * z + cos,
+                    0.,
+                ],
+            ],
+        )
+    }
 
-    /// The angle of the rotation
-    pub angle: Angle,
+    /// Create a translation
+    pub fn translation(shape: Shape, offset: [f64; 3]) -> Self {
+        let [x, y, z] = offset;
 
-    /// The offset of the translation
-    pub offset: [f64; 3],
+        Self::from_matrix(
+            shape,
+            [[1., 0., 0., x], [0., 1., 0., y], [0., 0., 1., z]],
+        )
+    }
+
+    /// Create a scaling relative to the origin
+    ///
+    /// Scales along the x-, y-, and z-axis by the respective factor. A negative
+    /// factor also mirrors along that axis.
+    pub fn scaling(shape: Shape, factors: [f64; 3]) -> Self {
+        let [x, y, z] = factors;
+
+        Self::from_matrix(
+            shape,
+            [[x, 0., 0., 0.], [0., y, 0., 0.], [0., 0., z, 0.]],
+        )
+    }
+
+    /// Create a mirroring across a plane
+    ///
+    /// The plane is defined by a point on it and its normal.
+    pub fn mirror(shape: Shape, origin: [f64; 3], normal: [f64; 3]) -> Self {
+        let length = normal.iter().map(|c| c * c).sum::<f64>().sqrt();
+        let n = normal.map(|c| c / length);
+        let distance = origin.iter().zip(n).map(|(o, n)| o * n).sum::<f64>();
+
+        let row = |i: usize| {
+            let mut row = [0.; 4];
+            for (j, value) in row.iter_mut().take(3).enumerate() {
+                let identity = if i == j { 1. } else { 0. };
+                *value = identity - 2. * n[i] * n[j];
+            }
+            row[3] = 2. * distance * n[i];
+            row
+        };
+
+        Self::from_matrix(shape, [row(0), row(1), row(2)])
+    }
 }
 
 impl From<Transform> for Shape {
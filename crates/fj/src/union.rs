@@ -0,0 +1,61 @@
+use std::path::{Path, PathBuf};
+
+use crate::Shape;
+
+/// A union of two 3-dimensional shapes
+///
+/// The resulting shape contains all points that are part of either of the two
+/// shapes. Unlike [`crate::Group`], the shapes may touch or overlap.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]);
+/// # let b = fj::Sketch::from_points(vec![[2., 0.], [3., 0.], [2., 1.]]);
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape`
+/// let union = a.union(&b);
+/// ```
+///
+/// # Limitations
+///
+/// Curved faces are approximated by planar facets, if the shapes overlap. They
+/// deviate from the curved faces by no more than the tolerance that the model
+/// is triangulated with.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Union {
+    shapes: [Shape; 2],
+}
+
+impl Union {
+    /// Create a `Union` from two shapes
+    pub fn from_shapes(shapes: [Shape; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Access the shapes that make up the union
+    pub fn shapes(&self) -> &[Shape; 2] {
+        &self.shapes
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        for shape in &mut self.shapes {
+            shape.resolve_paths_into(base, paths);
+        }
+    }
+}
+
+impl From<Union> for Shape {
+    fn from(shape: Union) -> Self {
+        Self::Union(Box::new(shape))
+    }
+}
@@ -0,0 +1,193 @@
+use std::path::{Path, PathBuf};
+
+use crate::Shape;
+
+/// A plane in 3D space, that a sketch is placed on
+///
+/// The x- and y-axis of the sketch are mapped to the x- and y-direction of the
+/// workplane. The y-direction is always perpendicular to the normal and the
+/// x-direction, such that x-direction, y-direction, and normal form a
+/// right-handed coordinate system. The front side of a sketch faces into the
+/// direction of the normal.
+///
+/// # Examples
+///
+/// ``` rust
+/// use fj::syntax::*;
+///
+/// let square = fj::Sketch::from_points(vec![
+///     [0., 0.],
+///     [1., 0.],
+///     [1., 1.],
+///     [0., 1.],
+/// ]);
+///
+/// // Place a sketch on the xz-plane.
+/// let side = square.clone().with_workplane(fj::Workplane::xz());
+///
+/// // Place a sketch on a tilted plane, floating above the origin.
+/// let tilted = square.clone().with_workplane(
+///     fj::Workplane::from_origin_normal_and_x_direction(
+///         [0., 0., 1.],
+///         [0., 1., 1.],
+///         [1., 0., 0.],
+///     ),
+/// );
+///
+/// // Place a sketch on the top face of another shape.
+/// let cube = square.sweep([0., 0., 1.]);
+/// let top = square.with_workplane(fj::Workplane::from_face(
+///     cube,
+///     fj::NamedFace::Top,
+/// ));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum Workplane {
+    /// A plane, defined by its origin, normal, and x-direction
+    ///
+    /// The x-direction is projected into the plane, so it doesn't need to be
+    /// exactly perpendicular to the normal.
+    Plane {
+        /// The point on the plane, that the origin of the sketch is placed at
+        origin: [f64; 3],
+
+        /// The normal of the plane
+        normal: [f64; 3],
+
+        /// The direction in the plane, that the x-axis of the sketch points to
+        x_direction: [f64; 3],
+    },
+
+    /// The plane of a planar face of a shape
+    ///
+    /// See [`NamedFace`] for how the plane is oriented. The shape is only used
+    /// to find the plane. It doesn't become part of the sketch.
+    ///
+    /// Only faces that face into the direction of one of the coordinate axes
+    /// can be named, so tilted faces can't be used as workplanes this way.
+    Face {
+        /// The shape that the face is part of
+        shape: Box<Shape>,
+
+        /// The face
+        face: NamedFace,
+    },
+}
+
+impl Workplane {
+    /// The xy-plane
+    ///
+    /// The normal of this plane points into the positive z-direction. This is
+    /// the workplane that sketches are placed on by default.
+    pub fn xy() -> Self {
+        Self::from_origin_normal_and_x_direction(
+            [0., 0., 0.],
+            [0., 0., 1.],
+            [1., 0., 0.],
+        )
+    }
+
+    /// The xz-plane
+    ///
+    /// The normal of this plane points into the negative y-direction, so the
+    /// y-axis of a sketch maps to the z-axis.
+    pub fn xz() -> Self {
+        Self::from_origin_normal_and_x_direction(
+            [0., 0., 0.],
+            [0., -1., 0.],
+            [1., 0., 0.],
+        )
+    }
+
+    /// The yz-plane
+    ///
+    /// The normal of this plane points into the positive x-direction, so the
+    /// x-axis of a sketch maps to the y-axis, and its y-axis to the z-axis.
+    pub fn yz() -> Self {
+        Self::from_origin_normal_and_x_direction(
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [0., 1., 0.],
+        )
+    }
+
+    /// Create a workplane from its origin, normal, and x-direction
+    pub fn from_origin_normal_and_x_direction(
+        origin: [f64; 3],
+        normal: [f64; 3],
+        x_direction: [f64; 3],
+    ) -> Self {
+        Self::Plane {
+            origin,
+            normal,
+            x_direction,
+        }
+    }
+
+    /// Create a workplane from a named face of a shape
+    ///
+    /// Only the six faces that face into the direction of a coordinate axis
+    /// can be named. See [`NamedFace`].
+    pub fn from_face(shape: impl Into<Shape>, face: NamedFace) -> Self {
+        Self::Face {
+            shape: Box::new(shape.into()),
+            face,
+        }
+    }
+
+    pub(crate) fn resolve_paths_into(
+        &mut self,
+        base: &Path,
+        paths: &mut Vec<PathBuf>,
+    ) {
+        if let Self::Face { shape, .. } = self {
+            shape.resolve_paths_into(base, paths);
+        }
+    }
+}
+
+impl Default for Workplane {
+    fn default() -> Self {
+        Self::xy()
+    }
+}
+
+/// A planar face of a shape, named after the direction it faces
+///
+/// Refers to the outermost planar face of the shape, that faces into the given
+/// direction. The origin of a sketch that is placed on the face is the point on
+/// the face's plane, that is closest to the global origin.
+///
+/// The x-axis of a sketch on the top or bottom face points into the positive
+/// x-direction. On the other faces, the y-axis of the sketch points into the
+/// positive z-direction, and its x-axis points to the right, when looking at
+/// the face from the outside.
+///
+/// There are only the six faces that face into the positive or negative
+/// direction of a coordinate axis. Faces that face into any other direction
+/// can't be named. Computing a shape fails, if it refers to a face that the
+/// referenced shape doesn't have.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum NamedFace {
+    /// The face that faces into the positive z-direction
+    Top,
+
+    /// The face that faces into the negative z-direction
+    Bottom,
+
+    /// The face that faces into the negative y-direction
+    Front,
+
+    /// The face that faces into the positive y-direction
+    Back,
+
+    /// The face that faces into the negative x-direction
+    Left,
+
+    /// The face that faces into the positive x-direction
+    Right,
+}
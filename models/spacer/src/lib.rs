@@ -9,7 +9,7 @@ pub fn model(
     let outer_edge = fj::Sketch::from_circle(fj::Circle::from_radius(outer));
     let inner_edge = fj::Sketch::from_circle(fj::Circle::from_radius(inner));
 
-    let footprint = outer_edge.difference(&inner_edge);
+    let footprint = outer_edge.difference_2d(&inner_edge);
     let spacer = footprint.sweep([0., 0., height]);
 
     spacer.into()
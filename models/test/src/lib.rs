@@ -58,11 +58,14 @@ fn spacer() -> fj::Shape {
     let inner = 1.;
     let height = 2.;
 
+    let material = fj::Material::from_name_and_color("PLA", [0, 0, 255, 255])
+        .with_density(1.24);
+
     let outer_edge = fj::Sketch::from_circle(fj::Circle::from_radius(outer))
-        .with_color([0, 0, 255, 255]);
+        .with_material(material);
     let inner_edge = fj::Sketch::from_circle(fj::Circle::from_radius(inner));
 
-    let footprint = outer_edge.difference(&inner_edge);
+    let footprint = outer_edge.difference_2d(&inner_edge);
     let spacer = footprint.sweep([0., 0., height]);
 
     spacer.into()